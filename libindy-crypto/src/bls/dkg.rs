@@ -0,0 +1,641 @@
+use bls::{Generator, SignKey, VerKey};
+use bls::threshold::{SignKeyShare, VerKeyShare};
+use errors::IndyCryptoError;
+use pair::{GroupOrderElement, PointG2};
+use secret_sharing::SecretSharing;
+use utils::json::{JsonEncodable, JsonDecodable};
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Broadcast message of dealing phase: Feldman commitments `gen * a_k` to coefficients of dealer polynomial.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DkgCommitments {
+    from: u32,
+    commitments: Vec<PointG2>
+}
+
+impl DkgCommitments {
+    pub fn get_from(&self) -> u32 {
+        self.from
+    }
+}
+
+impl JsonEncodable for DkgCommitments {}
+
+impl<'a> JsonDecodable<'a> for DkgCommitments {}
+
+/// Private message of dealing phase: share of dealer polynomial for one participant.
+/// Must be delivered by confidential channel.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DkgShare {
+    from: u32,
+    to: u32,
+    share: GroupOrderElement
+}
+
+impl DkgShare {
+    pub fn get_from(&self) -> u32 {
+        self.from
+    }
+
+    pub fn get_to(&self) -> u32 {
+        self.to
+    }
+}
+
+impl JsonEncodable for DkgShare {}
+
+impl<'a> JsonDecodable<'a> for DkgShare {}
+
+/// Broadcast message of complaint phase: participant `from` didn't receive valid share from `against`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DkgComplaint {
+    from: u32,
+    against: u32
+}
+
+impl DkgComplaint {
+    pub fn get_from(&self) -> u32 {
+        self.from
+    }
+
+    pub fn get_against(&self) -> u32 {
+        self.against
+    }
+}
+
+impl JsonEncodable for DkgComplaint {}
+
+impl<'a> JsonDecodable<'a> for DkgComplaint {}
+
+/// Broadcast message of complaint phase: accused dealer reveals share of complainer.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DkgComplaintResponse {
+    from: u32,
+    to: u32,
+    share: GroupOrderElement
+}
+
+impl DkgComplaintResponse {
+    pub fn get_from(&self) -> u32 {
+        self.from
+    }
+}
+
+impl JsonEncodable for DkgComplaintResponse {}
+
+impl<'a> JsonDecodable<'a> for DkgComplaintResponse {}
+
+/// Result of distributed key generation for one participant.
+#[derive(Debug)]
+pub struct DkgOutput {
+    sign_key_share: SignKeyShare,
+    ver_key: VerKey,
+    ver_key_shares: Vec<VerKeyShare>,
+    disqualified: Vec<u32>
+}
+
+impl DkgOutput {
+    /// Returns sign key share of this participant.
+    pub fn get_sign_key_share(&self) -> &SignKeyShare {
+        &self.sign_key_share
+    }
+
+    /// Returns group verification key.
+    pub fn get_ver_key(&self) -> &VerKey {
+        &self.ver_key
+    }
+
+    /// Returns verification key shares of all participants (including disqualified ones).
+    pub fn get_ver_key_shares(&self) -> &[VerKeyShare] {
+        &self.ver_key_shares
+    }
+
+    /// Returns indexes of misbehaving participants excluded from key generation.
+    pub fn get_disqualified(&self) -> &[u32] {
+        &self.disqualified
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DkgPhase {
+    Dealing,
+    Sharing,
+    Complaining,
+    Finished
+}
+
+/// Participant of joint Feldman distributed key generation of threshold BLS keys.
+///
+/// Protocol is implemented as message-in/message-out state machine and assumes
+/// reliable broadcast channel for commitments, complaints and complaint responses
+/// and confidential channel for shares:
+///
+/// 1. `deal` - every participant broadcasts commitments to random polynomial of degree `threshold - 1`
+///    and sends its value at index `j` to participant `j`.
+/// 2. `receive_commitments`, `receive_share` - shares are checked against commitments.
+/// 3. `complain` - complaints are broadcast against dealers of missing or invalid shares.
+/// 4. `receive_complaint` - accused dealer responds by revealing disputed share.
+/// 5. `receive_complaint_response` - revealed shares are checked against commitments.
+/// 6. `finalize` - dealers without commitments, with invalid or unresolved complaints are disqualified,
+///    sign key share is the sum of shares of qualified dealers.
+///
+/// Resulting sign key shares are compatible with `ThresholdBls::sign_partial` and `ThresholdBls::combine`.
+#[derive(Debug)]
+pub struct DkgParticipant {
+    gen: PointG2,
+    index: u32,
+    threshold: usize,
+    participants_count: usize,
+    phase: DkgPhase,
+    coefficients: Vec<GroupOrderElement>,
+    commitments: BTreeMap<u32, Vec<PointG2>>,
+    shares: BTreeMap<u32, GroupOrderElement>,
+    complaints: BTreeMap<u32, BTreeSet<u32>>, /* against -> complainers */
+    disqualified: BTreeSet<u32>
+}
+
+impl DkgParticipant {
+    /// Creates participant of distributed key generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `gen` - Generator point
+    /// * `index` - Index of participant from 1 to `participants_count`
+    /// * `threshold` - Number of partial signatures required to create signature
+    /// * `participants_count` - Number of participants
+    pub fn new(gen: &Generator, index: u32, threshold: usize, participants_count: usize) -> Result<DkgParticipant, IndyCryptoError> {
+        trace!("DkgParticipant::new: >>> index: {:?}, threshold: {:?}, participants_count: {:?}", index, threshold, participants_count);
+
+        if threshold == 0 || threshold > participants_count || participants_count >= u32::max_value() as usize {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid threshold {} for {} participants", threshold, participants_count)));
+        }
+
+        if index == 0 || index as usize > participants_count {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid participant index {}", index)));
+        }
+
+        let mut coefficients: Vec<GroupOrderElement> = Vec::new();
+        for _ in 0..threshold {
+            coefficients.push(GroupOrderElement::new()?);
+        }
+
+        let participant = DkgParticipant {
+            gen: gen.point,
+            index,
+            threshold,
+            participants_count,
+            phase: DkgPhase::Dealing,
+            coefficients,
+            commitments: BTreeMap::new(),
+            shares: BTreeMap::new(),
+            complaints: BTreeMap::new(),
+            disqualified: BTreeSet::new()
+        };
+
+        trace!("DkgParticipant::new: <<<");
+
+        Ok(participant)
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns commitments to broadcast and shares to send to every other participant.
+    pub fn deal(&mut self) -> Result<(DkgCommitments, Vec<DkgShare>), IndyCryptoError> {
+        trace!("DkgParticipant::deal: >>> index: {:?}", self.index);
+
+        self._check_phase(DkgPhase::Dealing)?;
+
+        let mut commitments: Vec<PointG2> = Vec::new();
+        for coefficient in self.coefficients.iter() {
+            commitments.push(self.gen.mul(coefficient)?);
+        }
+
+        let mut shares: Vec<DkgShare> = Vec::new();
+        for to in 1..(self.participants_count as u32 + 1) {
+            let share = SecretSharing::eval_polynomial(&self.coefficients, &SecretSharing::index_to_element(to)?)?;
+
+            if to == self.index {
+                self.shares.insert(self.index, share);
+            } else {
+                shares.push(DkgShare { from: self.index, to, share });
+            }
+        }
+
+        self.commitments.insert(self.index, commitments.clone());
+        self.phase = DkgPhase::Sharing;
+
+        let commitments = DkgCommitments { from: self.index, commitments };
+
+        trace!("DkgParticipant::deal: <<< commitments: {:?}", commitments);
+
+        Ok((commitments, shares))
+    }
+
+    /// Handles broadcast commitments of other participant.
+    /// Dealer with malformed or conflicting commitments is disqualified.
+    pub fn receive_commitments(&mut self, msg: &DkgCommitments) -> Result<(), IndyCryptoError> {
+        trace!("DkgParticipant::receive_commitments: >>> index: {:?}, msg: {:?}", self.index, msg);
+
+        self._check_phase(DkgPhase::Sharing)?;
+        self._check_index(msg.from)?;
+
+        if msg.from == self.index {
+            return Ok(());
+        }
+
+        if msg.commitments.len() != self.threshold {
+            self.disqualified.insert(msg.from);
+        } else if let Some(commitments) = self.commitments.get(&msg.from) {
+            if *commitments != msg.commitments {
+                self.disqualified.insert(msg.from);
+            }
+        }
+
+        if !self.disqualified.contains(&msg.from) {
+            self.commitments.insert(msg.from, msg.commitments.clone());
+        }
+
+        trace!("DkgParticipant::receive_commitments: <<<");
+
+        Ok(())
+    }
+
+    /// Handles share sent to this participant. Share is checked against commitments of dealer,
+    /// so commitments must be received first. Invalid share is ignored and dealer gets complaint.
+    /// Shares of already disqualified dealers are ignored.
+    pub fn receive_share(&mut self, msg: &DkgShare) -> Result<(), IndyCryptoError> {
+        trace!("DkgParticipant::receive_share: >>> index: {:?}, from: {:?}", self.index, msg.from);
+
+        self._check_phase(DkgPhase::Sharing)?;
+        self._check_index(msg.from)?;
+
+        if msg.to != self.index {
+            return Err(IndyCryptoError::InvalidStructure(format!("Share is addressed to participant {}", msg.to)));
+        }
+
+        if self.disqualified.contains(&msg.from) {
+            trace!("DkgParticipant::receive_share: <<< dealer is disqualified");
+            return Ok(());
+        }
+
+        if !self.commitments.contains_key(&msg.from) {
+            return Err(IndyCryptoError::InvalidState(format!("Commitments of participant {} are not received", msg.from)));
+        }
+
+        if msg.from != self.index && self._verify_share(msg.from, self.index, &msg.share)? {
+            self.shares.insert(msg.from, msg.share);
+        }
+
+        trace!("DkgParticipant::receive_share: <<<");
+
+        Ok(())
+    }
+
+    /// Finishes sharing phase and returns complaints to broadcast against every dealer
+    /// whose share is missing or invalid. Dealers without commitments are disqualified.
+    pub fn complain(&mut self) -> Result<Vec<DkgComplaint>, IndyCryptoError> {
+        trace!("DkgParticipant::complain: >>> index: {:?}", self.index);
+
+        self._check_phase(DkgPhase::Sharing)?;
+
+        let mut complaints: Vec<DkgComplaint> = Vec::new();
+
+        for dealer in 1..(self.participants_count as u32 + 1) {
+            if !self.commitments.contains_key(&dealer) {
+                self.disqualified.insert(dealer);
+            } else if !self.disqualified.contains(&dealer) && !self.shares.contains_key(&dealer) {
+                complaints.push(DkgComplaint { from: self.index, against: dealer });
+                self.complaints.entry(dealer).or_insert_with(BTreeSet::new).insert(self.index);
+            }
+        }
+
+        self.phase = DkgPhase::Complaining;
+
+        trace!("DkgParticipant::complain: <<< complaints: {:?}", complaints);
+
+        Ok(complaints)
+    }
+
+    /// Handles broadcast complaint. Returns response to broadcast if complaint is against this participant.
+    pub fn receive_complaint(&mut self, msg: &DkgComplaint) -> Result<Option<DkgComplaintResponse>, IndyCryptoError> {
+        trace!("DkgParticipant::receive_complaint: >>> index: {:?}, msg: {:?}", self.index, msg);
+
+        self._check_phase(DkgPhase::Complaining)?;
+        self._check_index(msg.from)?;
+        self._check_index(msg.against)?;
+
+        self.complaints.entry(msg.against).or_insert_with(BTreeSet::new).insert(msg.from);
+
+        let response = if msg.against == self.index {
+            Some(DkgComplaintResponse {
+                from: self.index,
+                to: msg.from,
+                share: SecretSharing::eval_polynomial(&self.coefficients, &SecretSharing::index_to_element(msg.from)?)?
+            })
+        } else {
+            None
+        };
+
+        trace!("DkgParticipant::receive_complaint: <<< response: {:?}", response);
+
+        Ok(response)
+    }
+
+    /// Handles broadcast complaint response. Dealer that reveals invalid share is disqualified,
+    /// valid revealed share resolves complaint.
+    pub fn receive_complaint_response(&mut self, msg: &DkgComplaintResponse) -> Result<(), IndyCryptoError> {
+        trace!("DkgParticipant::receive_complaint_response: >>> index: {:?}, msg: {:?}", self.index, msg);
+
+        self._check_phase(DkgPhase::Complaining)?;
+        self._check_index(msg.from)?;
+        self._check_index(msg.to)?;
+
+        let complained = self.complaints.get(&msg.from).map_or(false, |complainers| complainers.contains(&msg.to));
+
+        if !complained || self.disqualified.contains(&msg.from) {
+            return Ok(());
+        }
+
+        if self._verify_share(msg.from, msg.to, &msg.share)? {
+            if let Some(complainers) = self.complaints.get_mut(&msg.from) {
+                complainers.remove(&msg.to);
+            }
+
+            if msg.to == self.index {
+                self.shares.insert(msg.from, msg.share);
+            }
+        } else {
+            self.disqualified.insert(msg.from);
+        }
+
+        trace!("DkgParticipant::receive_complaint_response: <<<");
+
+        Ok(())
+    }
+
+    /// Finishes key generation. Dealers with unresolved complaints are disqualified.
+    pub fn finalize(&mut self) -> Result<DkgOutput, IndyCryptoError> {
+        trace!("DkgParticipant::finalize: >>> index: {:?}", self.index);
+
+        self._check_phase(DkgPhase::Complaining)?;
+
+        for (against, complainers) in self.complaints.iter() {
+            if !complainers.is_empty() {
+                self.disqualified.insert(*against);
+            }
+        }
+
+        let qualified: Vec<u32> = self.commitments.keys()
+            .filter(|dealer| !self.disqualified.contains(dealer))
+            .cloned()
+            .collect();
+
+        if qualified.is_empty() {
+            return Err(IndyCryptoError::InvalidState(format!("All participants are disqualified")));
+        }
+
+        let mut share = SecretSharing::index_to_element(0)?;
+        let mut ver_key_point = PointG2::new_inf()?;
+
+        for dealer in qualified.iter() {
+            let dealer_share = self.shares.get(dealer)
+                .ok_or(IndyCryptoError::InvalidState(format!("Share of participant {} is not received", dealer)))?;
+            share = share.add_mod(dealer_share)?;
+            ver_key_point = ver_key_point.add(&self.commitments[dealer][0])?;
+        }
+
+        let mut ver_key_shares: Vec<VerKeyShare> = Vec::new();
+
+        for index in 1..(self.participants_count as u32 + 1) {
+            let mut point = PointG2::new_inf()?;
+            for dealer in qualified.iter() {
+                point = point.add(&self._eval_commitments(*dealer, index)?)?;
+            }
+
+            ver_key_shares.push(VerKeyShare::new(index, VerKey { point, bytes: point.to_bytes()? }));
+        }
+
+        let output = DkgOutput {
+            sign_key_share: SignKeyShare::new(self.index, SignKey { group_order_element: share, bytes: share.to_bytes()? }),
+            ver_key: VerKey { point: ver_key_point, bytes: ver_key_point.to_bytes()? },
+            ver_key_shares,
+            disqualified: self.disqualified.iter().cloned().collect()
+        };
+
+        self.phase = DkgPhase::Finished;
+
+        trace!("DkgParticipant::finalize: <<< ver_key: {:?}, disqualified: {:?}", output.ver_key, output.disqualified);
+
+        Ok(output)
+    }
+
+    fn _verify_share(&self, dealer: u32, index: u32, share: &GroupOrderElement) -> Result<bool, IndyCryptoError> {
+        Ok(self.gen.mul(share)?.to_bytes()? == self._eval_commitments(dealer, index)?.to_bytes()?)
+    }
+
+    /// Computes `gen * f_dealer(index)` from commitments of dealer.
+    fn _eval_commitments(&self, dealer: u32, index: u32) -> Result<PointG2, IndyCryptoError> {
+        let commitments = self.commitments.get(&dealer)
+            .ok_or(IndyCryptoError::InvalidState(format!("Commitments of participant {} are not received", dealer)))?;
+
+        let x = SecretSharing::index_to_element(index)?;
+        let (last, rest) = commitments.split_last()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Commitments of participant {} are empty", dealer)))?;
+
+        let mut res = *last;
+        for commitment in rest.iter().rev() {
+            res = res.mul(&x)?.add(commitment)?;
+        }
+
+        Ok(res)
+    }
+
+    fn _check_phase(&self, phase: DkgPhase) -> Result<(), IndyCryptoError> {
+        if self.phase != phase {
+            return Err(IndyCryptoError::InvalidState(format!("Expected DKG phase {:?}, actual {:?}", phase, self.phase)));
+        }
+        Ok(())
+    }
+
+    fn _check_index(&self, index: u32) -> Result<(), IndyCryptoError> {
+        if index == 0 || index as usize > self.participants_count {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid participant index {}", index)));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bls::Bls;
+    use bls::threshold::{ThresholdBls, PartialSignature};
+
+    fn _run_dkg(participants: &mut Vec<DkgParticipant>, corrupt_share_from: Option<u32>, respond: bool) -> Vec<DkgOutput> {
+        let mut commitments: Vec<DkgCommitments> = Vec::new();
+        let mut shares: Vec<DkgShare> = Vec::new();
+
+        for participant in participants.iter_mut() {
+            let (c, s) = participant.deal().unwrap();
+            commitments.push(c);
+            shares.extend(s);
+        }
+
+        for participant in participants.iter_mut() {
+            for c in commitments.iter() {
+                participant.receive_commitments(c).unwrap();
+            }
+        }
+
+        for share in shares.iter_mut() {
+            if Some(share.from) == corrupt_share_from {
+                share.share = GroupOrderElement::new().unwrap();
+            }
+        }
+
+        for share in shares.iter() {
+            participants[share.to as usize - 1].receive_share(share).unwrap();
+        }
+
+        let mut complaints: Vec<DkgComplaint> = Vec::new();
+        for participant in participants.iter_mut() {
+            complaints.extend(participant.complain().unwrap());
+        }
+
+        let mut responses: Vec<DkgComplaintResponse> = Vec::new();
+        for participant in participants.iter_mut() {
+            for complaint in complaints.iter() {
+                if let Some(response) = participant.receive_complaint(complaint).unwrap() {
+                    responses.push(response);
+                }
+            }
+        }
+
+        if respond {
+            for participant in participants.iter_mut() {
+                for response in responses.iter() {
+                    participant.receive_complaint_response(response).unwrap();
+                }
+            }
+        }
+
+        participants.iter_mut().map(|participant| participant.finalize().unwrap()).collect()
+    }
+
+    fn _participants(gen: &Generator, threshold: usize, count: usize) -> Vec<DkgParticipant> {
+        (1..(count as u32 + 1)).map(|i| DkgParticipant::new(gen, i, threshold, count).unwrap()).collect()
+    }
+
+    fn _check_threshold_signature(gen: &Generator, outputs: &[DkgOutput], signers: &[usize]) -> bool {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let partial_signatures: Vec<PartialSignature> = signers.iter()
+            .map(|&i| ThresholdBls::sign_partial(&message, &outputs[i].sign_key_share).unwrap())
+            .collect();
+
+        for (partial_signature, &i) in partial_signatures.iter().zip(signers.iter()) {
+            assert!(ThresholdBls::verify_partial(partial_signature, &message, &outputs[0].ver_key_shares[i], gen).unwrap());
+        }
+
+        let signature = ThresholdBls::combine(&partial_signatures.iter().collect::<Vec<&PartialSignature>>()).unwrap();
+        Bls::verify(&signature, &message, &outputs[0].ver_key, gen).unwrap()
+    }
+
+    #[test]
+    fn dkg_works() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 3, 5);
+
+        let outputs = _run_dkg(&mut participants, None, true);
+
+        for output in outputs.iter() {
+            assert_eq!(outputs[0].ver_key.as_bytes(), output.ver_key.as_bytes());
+            assert!(output.disqualified.is_empty());
+        }
+
+        assert!(_check_threshold_signature(&gen, &outputs, &[0, 2, 4]));
+        assert!(_check_threshold_signature(&gen, &outputs, &[1, 3, 4]));
+    }
+
+    #[test]
+    fn dkg_works_for_invalid_share_resolved_by_response() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 4);
+
+        let outputs = _run_dkg(&mut participants, Some(2), true);
+
+        for output in outputs.iter() {
+            assert_eq!(outputs[0].ver_key.as_bytes(), output.ver_key.as_bytes());
+            assert!(output.disqualified.is_empty());
+        }
+
+        assert!(_check_threshold_signature(&gen, &outputs, &[0, 3]));
+    }
+
+    #[test]
+    fn dkg_works_for_misbehaving_participant_disqualified() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 4);
+
+        let outputs = _run_dkg(&mut participants, Some(3), false);
+
+        for output in outputs.iter() {
+            assert_eq!(outputs[0].ver_key.as_bytes(), output.ver_key.as_bytes());
+            assert_eq!(vec![3], output.disqualified);
+        }
+
+        assert!(_check_threshold_signature(&gen, &outputs, &[0, 1]));
+    }
+
+    #[test]
+    fn dkg_works_for_malformed_commitments() {
+        let gen = Generator::new().unwrap();
+        let mut participants = _participants(&gen, 2, 3);
+
+        let mut commitments: Vec<DkgCommitments> = Vec::new();
+        let mut shares: Vec<DkgShare> = Vec::new();
+
+        for participant in participants.iter_mut() {
+            let (c, s) = participant.deal().unwrap();
+            commitments.push(c);
+            shares.extend(s);
+        }
+
+        commitments[1].commitments.pop();
+
+        for participant in participants.iter_mut() {
+            for c in commitments.iter() {
+                participant.receive_commitments(c).unwrap();
+            }
+        }
+
+        for share in shares.iter() {
+            participants[share.to as usize - 1].receive_share(share).unwrap();
+        }
+
+        for participant in participants.iter_mut() {
+            assert!(participant.complain().unwrap().is_empty());
+        }
+
+        let outputs: Vec<DkgOutput> = participants.iter_mut().map(|participant| participant.finalize().unwrap()).collect();
+
+        assert_eq!(vec![2], outputs[0].disqualified);
+        assert_eq!(vec![2], outputs[2].disqualified);
+        assert_eq!(outputs[0].ver_key.as_bytes(), outputs[2].ver_key.as_bytes());
+        assert!(_check_threshold_signature(&gen, &outputs, &[0, 2]));
+    }
+
+    #[test]
+    fn dkg_works_for_wrong_phase() {
+        let gen = Generator::new().unwrap();
+        let mut participant = DkgParticipant::new(&gen, 1, 1, 2).unwrap();
+
+        assert!(participant.complain().is_err());
+        participant.deal().unwrap();
+        assert!(participant.deal().is_err());
+    }
+}
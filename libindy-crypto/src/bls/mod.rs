@@ -1,8 +1,207 @@
 use errors::IndyCryptoError;
 use pair::{GroupOrderElement, PointG2, PointG1, Pair};
+use secret_sharing::{SecretSharing, SecretShare, ShareCommitments};
 
 use sha2::{Sha256, Digest};
 
+use std::collections::HashSet;
+
+pub mod dkg;
+pub mod threshold;
+
+/// Domain separation prefix of hash used by proof of possession.
+pub const PROOF_OF_POSSESSION_DOMAIN: &'static [u8] = b"indy_crypto:bls:proof_of_possession:1";
+
+/// Version of the algorithm used to hash messages to the curve before signing.
+/// Signatures are only valid for the scheme they were created with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureScheme {
+    /// Original scheme: SHA-256 of the message mapped to the curve by try-and-increment.
+    /// Used by `Bls::sign` and `Bls::verify`.
+    V1,
+    /// IETF hash-to-curve (RFC 9380) with caller supplied domain separation tag.
+    /// Suite is `pair::HASH_TO_CURVE_SUITE` of the curve selected by `pair_*` feature.
+    V2(Vec<u8>)
+}
+
+impl SignatureScheme {
+    /// Signs the message with this scheme, see `Bls::sign`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let message = vec![1, 2, 3, 4, 5];
+    ///
+    /// let scheme = SignatureScheme::V2(b"MY-APP-V01-CS01".to_vec());
+    /// let signature = scheme.sign(&message, &sign_key).unwrap();
+    ///
+    /// assert!(scheme.verify(&signature, &message, &ver_key, &gen).unwrap());
+    /// assert!(!Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    /// ```
+    pub fn sign(&self, message: &[u8], sign_key: &SignKey) -> Result<Signature, IndyCryptoError> {
+        let point = self._hash(message)?.mul(&sign_key.group_order_element)?;
+        Ok(Signature {
+            point,
+            bytes: point.to_bytes()?
+        })
+    }
+
+    /// Verifies signature created with this scheme, see `Bls::verify`.
+    pub fn verify(&self, signature: &Signature, message: &[u8], ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        let h = self._hash(message)?;
+        Ok(Pair::pair(&signature.point, &gen.point)?.eq(&Pair::pair(&h, &ver_key.point)?))
+    }
+
+    /// Verifies multi signature created with this scheme, see `Bls::verify_multi_sig`.
+    pub fn verify_multi_sig(&self, multi_sig: &MultiSignature, message: &[u8], ver_keys: &[&VerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        let agg_ver_key = AggregatedVerKey::new(ver_keys)?;
+        self.verify_multi_sig_with_aggregated_ver_key(multi_sig, message, &agg_ver_key, gen)
+    }
+
+    /// Verifies multi signature created with this scheme, see `Bls::verify_multi_sig_with_aggregated_ver_key`.
+    pub fn verify_multi_sig_with_aggregated_ver_key(&self, multi_sig: &MultiSignature, message: &[u8], agg_ver_key: &AggregatedVerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        self._verify_multi_sig(&multi_sig.point, message, &agg_ver_key.point, gen)
+    }
+
+    /// Verifies bitmap multi signature created with this scheme, see `Bls::verify_bitmap_multi_sig`.
+    pub fn verify_bitmap_multi_sig(&self, multi_sig: &BitmapMultiSignature, message: &[u8], validators: &[&ProvenVerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if validators.len() != multi_sig.validators_count {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Number of validators {} doesn't match multi signature {}", validators.len(), multi_sig.validators_count)));
+        }
+
+        let mut point = PointG2::new_inf()?;
+
+        for i in multi_sig.get_participants() {
+            point = point.add(&validators[i].point)?;
+        }
+
+        self._verify_multi_sig(&multi_sig.point, message, &point, gen)
+    }
+
+    /// Verifies bitmap multi signature created with this scheme, see `Bls::verify_bitmap_multi_sig_with_quorum`.
+    pub fn verify_bitmap_multi_sig_with_quorum(&self,
+                                               multi_sig: &BitmapMultiSignature,
+                                               message: &[u8],
+                                               validators: &[&ProvenVerKey],
+                                               weights: &[u64],
+                                               quorum: u64,
+                                               gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if weights.len() != validators.len() {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Number of weights {} doesn't match number of validators {}", weights.len(), validators.len())));
+        }
+
+        let weight = multi_sig.get_participants().into_iter()
+            .fold(0u64, |weight, i| weight.saturating_add(weights[i]));
+
+        if weight < quorum {
+            return Ok(false);
+        }
+
+        self.verify_bitmap_multi_sig(multi_sig, message, validators, gen)
+    }
+
+    /// Verifies multi signature created with this scheme, see `Bls::verify_multi_sig_with_pop`.
+    /// Proofs of possession don't depend on signature scheme.
+    pub fn verify_multi_sig_with_pop(&self, multi_sig: &MultiSignature, message: &[u8], ver_keys: &[&ProvenVerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if ver_keys.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Verification keys list is empty")));
+        }
+
+        let mut point = PointG2::new_inf()?;
+
+        for ver_key in ver_keys {
+            point = point.add(&ver_key.point)?;
+        }
+
+        self._verify_multi_sig(&multi_sig.point, message, &point, gen)
+    }
+
+    /// Verifies aggregated signature created with this scheme, see `Bls::verify_aggregate`.
+    pub fn verify_aggregate(&self, agg: &AggregatedSignature, messages_and_keys: &[(&[u8], &VerKey)], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if messages_and_keys.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Messages list is empty")));
+        }
+
+        let mut messages: HashSet<&[u8]> = HashSet::new();
+
+        for &(message, _) in messages_and_keys {
+            if !messages.insert(message) {
+                return Err(IndyCryptoError::InvalidStructure(format!("Aggregated signature contains duplicate messages")));
+            }
+        }
+
+        let mut e = Pair::pair(&self._hash(messages_and_keys[0].0)?, &messages_and_keys[0].1.point)?;
+
+        for &(message, ver_key) in messages_and_keys[1..].iter() {
+            e = e.mul(&Pair::pair(&self._hash(message)?, &ver_key.point)?)?;
+        }
+
+        Ok(Pair::pair(&agg.point, &gen.point)?.eq(&e))
+    }
+
+    /// Verifies batch of signatures created with this scheme, see `Bls::verify_batch`.
+    pub fn verify_batch(&self, batch: &[(&Signature, &[u8], &VerKey)], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if batch.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Batch is empty")));
+        }
+
+        let mut signature = PointG1::new_inf()?;
+        let mut pairs: Vec<(PointG1, PointG2)> = Vec::new();
+
+        for &(sig, message, ver_key) in batch {
+            let r = GroupOrderElement::new()?;
+            signature = signature.add(&sig.point.mul(&r)?)?;
+            pairs.push((self._hash(message)?.mul(&r)?, ver_key.point));
+        }
+
+        Ok(Pair::pair(&signature, &gen.point)?.eq(&Pair::multi_pair(&pairs)?))
+    }
+
+    /// Finds invalid signatures in batch of signatures created with this scheme, see `Bls::find_invalid_in_batch`.
+    pub fn find_invalid_in_batch(&self, batch: &[(&Signature, &[u8], &VerKey)], gen: &Generator) -> Result<Vec<usize>, IndyCryptoError> {
+        if batch.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Batch is empty")));
+        }
+
+        let mut invalid: Vec<usize> = Vec::new();
+        self._bisect_batch(batch, 0, gen, &mut invalid)?;
+        Ok(invalid)
+    }
+
+    fn _bisect_batch(&self, batch: &[(&Signature, &[u8], &VerKey)], offset: usize, gen: &Generator, invalid: &mut Vec<usize>) -> Result<(), IndyCryptoError> {
+        if self.verify_batch(batch, gen)? {
+            return Ok(());
+        }
+
+        if batch.len() == 1 {
+            invalid.push(offset);
+            return Ok(());
+        }
+
+        let middle = batch.len() / 2;
+        self._bisect_batch(&batch[..middle], offset, gen, invalid)?;
+        self._bisect_batch(&batch[middle..], offset + middle, gen, invalid)
+    }
+
+    fn _verify_multi_sig(&self, multi_sig: &PointG1, message: &[u8], ver_key: &PointG2, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        let h = self._hash(message)?;
+        Ok(Pair::pair(multi_sig, &gen.point)?.eq(&Pair::pair(&h, ver_key)?))
+    }
+
+    fn _hash(&self, message: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        match *self {
+            SignatureScheme::V1 => Bls::_hash(message),
+            SignatureScheme::V2(ref dst) => PointG1::hash_to_curve(message, dst)
+        }
+    }
+}
+
 /// BLS generator point.
 /// BLS algorithm requires choosing of generator point that must be known to all parties.
 /// The most of BLS methods require generator to be provided.
@@ -117,6 +316,44 @@ impl SignKey {
             }
         )
     }
+
+    /// Splits BLS sign key into shares so that any `threshold` of them recover sign key.
+    /// Returned commitments allow to detect corrupted share.
+    ///
+    /// # Arguments
+    /// * `threshold` - Number of shares required to recover sign key.
+    /// * `shares_count` - Number of shares.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::SignKey;
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let (shares, commitments) = sign_key.split(2, 3).unwrap();
+    /// let recovered = SignKey::combine(&[&shares[0], &shares[2]], &commitments).unwrap();
+    /// assert_eq!(sign_key.as_bytes(), recovered.as_bytes());
+    /// ```
+    pub fn split(&self, threshold: usize, shares_count: usize) -> Result<(Vec<SecretShare>, ShareCommitments), IndyCryptoError> {
+        SecretSharing::split(&[self.group_order_element], threshold, shares_count)
+    }
+
+    /// Recovers BLS sign key from shares created by `SignKey::split`.
+    ///
+    /// # Arguments
+    /// * `shares` - At least threshold shares.
+    /// * `commitments` - Share commitments.
+    pub fn combine(shares: &[&SecretShare], commitments: &ShareCommitments) -> Result<SignKey, IndyCryptoError> {
+        let secret = SecretSharing::combine(shares, commitments)?;
+
+        if secret.len() != 1 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Shares don't correspond to BLS sign key")));
+        }
+
+        Ok(SignKey {
+            group_order_element: secret[0],
+            bytes: secret[0].to_bytes()?
+        })
+    }
 }
 
 /// BLS verification key.
@@ -173,36 +410,77 @@ impl VerKey {
     }
 }
 
-/// BLS signature.
+/// BLS aggregated verification key of signers set.
+/// Can be cached and reused for verification of many multi signatures of the same signers.
 #[derive(Debug)]
-pub struct Signature {
-    point: PointG1,
-    bytes: Vec<u8>,
+pub struct AggregatedVerKey {
+    point: PointG2,
+    bytes: Vec<u8>
 }
 
-impl Signature {
-    /// Returns BLS signature to bytes representation.
+impl AggregatedVerKey {
+    /// Creates and returns aggregated verification key for provided list of verification keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_keys` - List of verification keys
     ///
     /// # Example
     ///
     /// ```
-    /// //TODO: Provide an example!
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// AggregatedVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+    /// ```
+    pub fn new(ver_keys: &[&VerKey]) -> Result<AggregatedVerKey, IndyCryptoError> {
+        if ver_keys.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Verification keys list is empty")));
+        }
+
+        let mut point = PointG2::new_inf()?;
+
+        for ver_key in ver_keys {
+            point = point.add(&ver_key.point)?;
+        }
+
+        Ok(AggregatedVerKey {
+            point,
+            bytes: point.to_bytes()?
+        })
+    }
+
+    /// Returns BLS aggregated verification key bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let agg_ver_key = AggregatedVerKey::new(&[&ver_key]).unwrap();
+    /// assert!(agg_ver_key.as_bytes().len() > 0);
     /// ```
     pub fn as_bytes(&self) -> &[u8] {
         self.bytes.as_slice()
     }
 
-    /// Creates and returns BLS signature from bytes representation.
+    /// Creates and returns BLS aggregated verification key from bytes representation.
     ///
     /// # Example
     ///
     /// ```
-    /// //TODO: Provide an example!
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+    /// let agg_ver_key = AggregatedVerKey::new(&[&ver_key]).unwrap();
+    /// AggregatedVerKey::from_bytes(agg_ver_key.as_bytes()).unwrap();
     /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, IndyCryptoError> {
-        let point = PointG1::from_bytes(bytes)?;
+    pub fn from_bytes(bytes: &[u8]) -> Result<AggregatedVerKey, IndyCryptoError> {
+        let point = PointG2::from_bytes(bytes)?;
         Ok(
-            Signature {
+            AggregatedVerKey {
                 point,
                 bytes: bytes.to_vec()
             }
@@ -210,53 +488,15 @@ impl Signature {
     }
 }
 
-/// BLS multi signature.
+/// BLS signature.
 #[derive(Debug)]
-pub struct MultiSignature {
+pub struct Signature {
     point: PointG1,
     bytes: Vec<u8>,
 }
 
-impl MultiSignature {
-    /// Creates and returns multi signature for provided list of signatures.
-   ///
-   /// # Arguments
-   ///
-   /// * `signatures` - List of signatures
-   ///
-   /// # Example
-   ///
-   /// ```
-   /// use indy_crypto::bls::*;
-   /// let sign_key1 = SignKey::new(None).unwrap();
-   /// let sign_key2 = SignKey::new(None).unwrap();
-   ///
-   /// let message = vec![1, 2, 3, 4, 5];
-   ///
-   /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
-   /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
-   ///
-   /// let signatures = vec![
-   ///    &signature1,
-   ///    &signature2
-   /// ];
-   ///
-   /// MultiSignature::new(&signatures).unwrap();
-   /// ```
-    pub fn new(signatures: &[&Signature]) -> Result<MultiSignature, IndyCryptoError> {
-        let mut point = PointG1::new_inf()?;
-
-        for signature in signatures {
-            point = point.add(&signature.point)?;
-        }
-
-        Ok(MultiSignature {
-            point,
-            bytes: point.to_bytes()?
-        })
-    }
-
-    /// Returns BLS multi signature bytes representation.
+impl Signature {
+    /// Returns BLS signature to bytes representation.
     ///
     /// # Example
     ///
@@ -267,85 +507,608 @@ impl MultiSignature {
         self.bytes.as_slice()
     }
 
-    /// Creates and returns BLS multi signature from bytes representation.
+    /// Creates and returns BLS signature from bytes representation.
     ///
     /// # Example
     ///
     /// ```
     /// //TODO: Provide an example!
     /// ```
-    pub fn from_bytes(bytes: &[u8]) -> Result<MultiSignature, IndyCryptoError> {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, IndyCryptoError> {
         let point = PointG1::from_bytes(bytes)?;
         Ok(
-            MultiSignature {
-                point: point,
+            Signature {
+                point,
                 bytes: bytes.to_vec()
             }
         )
     }
 }
 
-pub struct Bls {}
-
-impl Bls {
+/// BLS multi signature with bitmap of participants relative to ordered list of validators.
+/// Signatures can be added incrementally by `add_signature` and `merge`: bitmap
+/// guarantees that signature of every validator is aggregated only once.
+///
+/// Bytes representation: validators count (big-endian u32), participants bitmap
+/// (bit `i % 8` of byte `i / 8` is set if validator `i` signed) and multi signature point.
+#[derive(Debug)]
+pub struct BitmapMultiSignature {
+    point: PointG1,
+    validators_count: usize,
+    bitmap: Vec<u8>,
+    bytes: Vec<u8>
+}
 
-    /// Signs the message and returns signature.
+impl BitmapMultiSignature {
+    /// Creates and returns multi signature for provided list of signatures of validators.
     ///
     /// # Arguments
     ///
-    /// * `message` - Message to sign
-    /// * `sign_key` - Sign key
+    /// * `signatures` - List of signatures with indexes of signers in validators list
+    /// * `validators_count` - Number of validators
     ///
     /// # Example
     ///
     /// ```
     /// use indy_crypto::bls::*;
     /// let message = vec![1, 2, 3, 4, 5];
-    /// let sign_key = SignKey::new(None).unwrap();
-    /// Bls::sign(&message, &sign_key).unwrap();
+    ///
+    /// let signature0 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    /// let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let multi_sig = BitmapMultiSignature::new(&[(0, &signature0), (2, &signature2)], 4).unwrap();
+    /// assert_eq!(vec![0, 2], multi_sig.get_participants());
     /// ```
-    pub fn sign(message: &[u8], sign_key: &SignKey) -> Result<Signature, IndyCryptoError> {
-        let point = Bls::_hash(message)?.mul(&sign_key.group_order_element)?;
-        Ok(Signature {
+    pub fn new(signatures: &[(usize, &Signature)], validators_count: usize) -> Result<BitmapMultiSignature, IndyCryptoError> {
+        if validators_count == 0 || validators_count > u32::max_value() as usize {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid validators count {}", validators_count)));
+        }
+
+        if signatures.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Signatures list is empty")));
+        }
+
+        let mut bitmap = vec![0u8; (validators_count + 7) / 8];
+        let mut point = PointG1::new_inf()?;
+
+        for &(index, signature) in signatures {
+            if index >= validators_count {
+                return Err(IndyCryptoError::InvalidStructure(format!("Invalid validator index {}", index)));
+            }
+
+            if bitmap[index / 8] & (1 << (index % 8)) != 0 {
+                return Err(IndyCryptoError::InvalidStructure(format!("Duplicate signature of validator {}", index)));
+            }
+
+            Bls::_check_signature_point(&signature.point)?;
+
+            bitmap[index / 8] |= 1 << (index % 8);
+            point = point.add(&signature.point)?;
+        }
+
+        let bytes = BitmapMultiSignature::_to_bytes(validators_count, &bitmap, &point)?;
+
+        Ok(BitmapMultiSignature {
             point,
-            bytes: point.to_bytes()?
+            validators_count,
+            bitmap,
+            bytes
         })
     }
 
-    /// Verifies the message signature and returns true - if signature valid or false otherwise.
+    /// Adds signature of validator to multi signature.
+    /// Fails if validator already signed, so signatures are never counted twice.
     ///
     /// # Arguments
     ///
-    /// * `signature` - Signature to verify
-    /// * `message` - Message to verify
-    /// * `ver_key` - Verification key
-    /// * `gen` - Generator point
+    /// * `index` - Index of signer in validators list
+    /// * `signature` - Signature of validator
     ///
     /// # Example
     ///
     /// ```
     /// use indy_crypto::bls::*;
-    /// let gen = Generator::new().unwrap();
-    /// let sign_key = SignKey::new(None).unwrap();
-    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
     /// let message = vec![1, 2, 3, 4, 5];
-    /// let signature = Bls::sign(&message, &sign_key).unwrap();
     ///
-    /// let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
-    /// assert!(valid);
+    /// let signature0 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    /// let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let mut multi_sig = BitmapMultiSignature::new(&[(0, &signature0)], 4).unwrap();
+    /// multi_sig.add_signature(2, &signature2).unwrap();
+    /// assert_eq!(vec![0, 2], multi_sig.get_participants());
     /// ```
-    pub fn verify(signature: &Signature, message: &[u8], ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
-        let h = Bls::_hash(message)?;
-        Ok(Pair::pair(&signature.point, &gen.point)?.eq(&Pair::pair(&h, &ver_key.point)?))
+    pub fn add_signature(&mut self, index: usize, signature: &Signature) -> Result<(), IndyCryptoError> {
+        if index >= self.validators_count {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid validator index {}", index)));
+        }
+
+        if self.is_participant(index) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Duplicate signature of validator {}", index)));
+        }
+
+        Bls::_check_signature_point(&signature.point)?;
+
+        self.point = self.point.add(&signature.point)?;
+        self.bitmap[index / 8] |= 1 << (index % 8);
+        self.bytes = BitmapMultiSignature::_to_bytes(self.validators_count, &self.bitmap, &self.point)?;
+
+        Ok(())
     }
 
-    /// Verifies the message multi signature and returns true - if signature valid or false otherwise.
+    /// Merges multi signature of other validators into this multi signature.
+    /// Fails if validators lists differ in size or multi signatures have common participants.
     ///
     /// # Arguments
     ///
-    /// * `multi_sig` - Multi signature to verify
-    /// * `message` - Message to verify
-    /// * `ver_keys` - List of verification keys
+    /// * `other` - Multi signature of the same message by other validators
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    ///
+    /// let signature0 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    /// let signature2 = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+    ///
+    /// let mut multi_sig = BitmapMultiSignature::new(&[(0, &signature0)], 4).unwrap();
+    /// let other = BitmapMultiSignature::new(&[(2, &signature2)], 4).unwrap();
+    /// multi_sig.merge(&other).unwrap();
+    /// assert_eq!(vec![0, 2], multi_sig.get_participants());
+    /// ```
+    pub fn merge(&mut self, other: &BitmapMultiSignature) -> Result<(), IndyCryptoError> {
+        if self.validators_count != other.validators_count {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Validators count {} doesn't match {}", other.validators_count, self.validators_count)));
+        }
+
+        if self.bitmap.iter().zip(other.bitmap.iter()).any(|(a, b)| a & b != 0) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Multi signatures have common participants")));
+        }
+
+        Bls::_check_signature_point(&other.point)?;
+
+        self.point = self.point.add(&other.point)?;
+        for (a, b) in self.bitmap.iter_mut().zip(other.bitmap.iter()) {
+            *a |= *b;
+        }
+        self.bytes = BitmapMultiSignature::_to_bytes(self.validators_count, &self.bitmap, &self.point)?;
+
+        Ok(())
+    }
+
+    /// Returns multi signature without participants bitmap.
+    pub fn to_multi_signature(&self) -> Result<MultiSignature, IndyCryptoError> {
+        Ok(MultiSignature {
+            point: self.point,
+            bytes: self.point.to_bytes()?
+        })
+    }
+
+    /// Returns number of validators.
+    pub fn get_validators_count(&self) -> usize {
+        self.validators_count
+    }
+
+    /// Returns indexes of validators that signed.
+    pub fn get_participants(&self) -> Vec<usize> {
+        (0..self.validators_count).filter(|&i| self.is_participant(i)).collect()
+    }
+
+    /// Returns true if validator with index signed.
+    pub fn is_participant(&self, index: usize) -> bool {
+        index < self.validators_count && self.bitmap[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Returns BLS bitmap multi signature bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&[1, 2, 3], &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[(1, &signature)], 3).unwrap();
+    /// assert!(multi_sig.as_bytes().len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS bitmap multi signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&[1, 2, 3], &SignKey::new(None).unwrap()).unwrap();
+    /// let multi_sig = BitmapMultiSignature::new(&[(1, &signature)], 3).unwrap();
+    /// let multi_sig = BitmapMultiSignature::from_bytes(multi_sig.as_bytes()).unwrap();
+    /// assert_eq!(vec![1], multi_sig.get_participants());
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<BitmapMultiSignature, IndyCryptoError> {
+        if bytes.len() < 4 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid len of bytes representation")));
+        }
+
+        let validators_count = ((bytes[0] as usize) << 24) | ((bytes[1] as usize) << 16) | ((bytes[2] as usize) << 8) | (bytes[3] as usize);
+        let bitmap_len = validators_count / 8 + if validators_count % 8 != 0 { 1 } else { 0 };
+
+        if validators_count == 0 || bytes.len() <= 4 + bitmap_len {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid len of bytes representation")));
+        }
+
+        let bitmap = bytes[4..4 + bitmap_len].to_vec();
+
+        if validators_count % 8 != 0 && bitmap[bitmap_len - 1] >> (validators_count % 8) != 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Bitmap contains bits of unknown validators")));
+        }
+
+        if bitmap.iter().all(|b| *b == 0) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Bitmap is empty")));
+        }
+
+        let point = PointG1::from_bytes(&bytes[4 + bitmap_len..])?;
+
+        Ok(BitmapMultiSignature {
+            point,
+            validators_count,
+            bitmap,
+            bytes: bytes.to_vec()
+        })
+    }
+
+    fn _to_bytes(validators_count: usize, bitmap: &[u8], point: &PointG1) -> Result<Vec<u8>, IndyCryptoError> {
+        let count = validators_count as u32;
+        let mut bytes = vec![(count >> 24) as u8, (count >> 16) as u8, (count >> 8) as u8, count as u8];
+        bytes.extend_from_slice(bitmap);
+        bytes.extend_from_slice(&point.to_bytes()?);
+        Ok(bytes)
+    }
+}
+
+/// Proof of possession of BLS sign key that corresponds to verification key.
+/// Prevents rogue-key attacks on multi signatures: it is signature of verification key bytes
+/// computed with hash domain separated from message signing.
+#[derive(Debug)]
+pub struct ProofOfPossession {
+    point: PointG1,
+    bytes: Vec<u8>,
+}
+
+impl ProofOfPossession {
+    /// Creates and returns proof of possession of sign key that corresponds to verification key.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_key` - Verification key
+    /// * `sign_key` - Sign key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// ```
+    pub fn new(ver_key: &VerKey, sign_key: &SignKey) -> Result<ProofOfPossession, IndyCryptoError> {
+        let point = Bls::_hash_ver_key(ver_key)?.mul(&sign_key.group_order_element)?;
+        Ok(ProofOfPossession {
+            point,
+            bytes: point.to_bytes()?
+        })
+    }
+
+    /// Returns BLS proof of possession bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// assert!(pop.as_bytes().len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS proof of possession from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// ProofOfPossession::from_bytes(pop.as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProofOfPossession, IndyCryptoError> {
+        let point = PointG1::from_bytes(bytes)?;
+        Ok(
+            ProofOfPossession {
+                point,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+}
+
+/// BLS verification key with verified proof of possession of corresponding sign key.
+/// Can be created only by checking of proof of possession, so it can be cached and reused
+/// for verification of many multi signatures without checking of proof again.
+#[derive(Debug)]
+pub struct ProvenVerKey {
+    point: PointG2,
+    bytes: Vec<u8>,
+}
+
+impl ProvenVerKey {
+    /// Verifies proof of possession of sign key and returns verification key marked as proven.
+    /// Returns error if proof of possession is invalid or verification key is identity point.
+    ///
+    /// # Arguments
+    ///
+    /// * `ver_key` - Verification key
+    /// * `pop` - Proof of possession of sign key that corresponds to verification key
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// ProvenVerKey::new(&ver_key, &pop, &gen).unwrap();
+    /// ```
+    pub fn new(ver_key: &VerKey, pop: &ProofOfPossession, gen: &Generator) -> Result<ProvenVerKey, IndyCryptoError> {
+        if !Bls::verify_proof_of_possession(pop, ver_key, gen)? {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid proof of possession of verification key")));
+        }
+
+        Ok(ProvenVerKey {
+            point: ver_key.point,
+            bytes: ver_key.bytes.clone()
+        })
+    }
+
+    /// Returns bytes representation of proven verification key.
+    /// It is the same as bytes representation of original verification key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    /// let proven_ver_key = ProvenVerKey::new(&ver_key, &pop, &gen).unwrap();
+    /// assert_eq!(ver_key.as_bytes(), proven_ver_key.as_bytes());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+/// BLS multi signature.
+/// Use `BitmapMultiSignature` to aggregate signatures incrementally.
+#[derive(Debug)]
+pub struct MultiSignature {
+    point: PointG1,
+    bytes: Vec<u8>,
+}
+
+impl MultiSignature {
+    /// Creates and returns multi signature for provided list of signatures.
+   ///
+   /// # Arguments
+   ///
+   /// * `signatures` - List of signatures
+   ///
+   /// # Example
+   ///
+   /// ```
+   /// use indy_crypto::bls::*;
+   /// let sign_key1 = SignKey::new(None).unwrap();
+   /// let sign_key2 = SignKey::new(None).unwrap();
+   ///
+   /// let message = vec![1, 2, 3, 4, 5];
+   ///
+   /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+   /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+   ///
+   /// let signatures = vec![
+   ///    &signature1,
+   ///    &signature2
+   /// ];
+   ///
+   /// MultiSignature::new(&signatures).unwrap();
+   /// ```
+    pub fn new(signatures: &[&Signature]) -> Result<MultiSignature, IndyCryptoError> {
+        let mut point = PointG1::new_inf()?;
+
+        for signature in signatures {
+            Bls::_check_signature_point(&signature.point)?;
+            point = point.add(&signature.point)?;
+        }
+
+        Ok(MultiSignature {
+            point,
+            bytes: point.to_bytes()?
+        })
+    }
+
+    /// Returns BLS multi signature bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS multi signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// //TODO: Provide an example!
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<MultiSignature, IndyCryptoError> {
+        let point = PointG1::from_bytes(bytes)?;
+        Ok(
+            MultiSignature {
+                point: point,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+}
+
+/// BLS aggregated signature of distinct messages signed by different signers.
+#[derive(Debug)]
+pub struct AggregatedSignature {
+    point: PointG1,
+    bytes: Vec<u8>,
+}
+
+impl AggregatedSignature {
+    /// Creates and returns aggregated signature for provided list of signatures over distinct messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `signatures` - List of signatures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    ///
+    /// let signature1 = Bls::sign(&[1, 2, 3], &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&[4, 5, 6], &sign_key2).unwrap();
+    ///
+    /// AggregatedSignature::new(&[&signature1, &signature2]).unwrap();
+    /// ```
+    pub fn new(signatures: &[&Signature]) -> Result<AggregatedSignature, IndyCryptoError> {
+        if signatures.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Signatures list is empty")));
+        }
+
+        let mut point = PointG1::new_inf()?;
+
+        for signature in signatures {
+            point = point.add(&signature.point)?;
+        }
+
+        Ok(AggregatedSignature {
+            point,
+            bytes: point.to_bytes()?
+        })
+    }
+
+    /// Returns BLS aggregated signature bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&[1, 2, 3], &SignKey::new(None).unwrap()).unwrap();
+    /// let agg = AggregatedSignature::new(&[&signature]).unwrap();
+    /// assert!(agg.as_bytes().len() > 0);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Creates and returns BLS aggregated signature from bytes representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let signature = Bls::sign(&[1, 2, 3], &SignKey::new(None).unwrap()).unwrap();
+    /// let agg = AggregatedSignature::new(&[&signature]).unwrap();
+    /// AggregatedSignature::from_bytes(agg.as_bytes()).unwrap();
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<AggregatedSignature, IndyCryptoError> {
+        let point = PointG1::from_bytes(bytes)?;
+        Ok(
+            AggregatedSignature {
+                point,
+                bytes: bytes.to_vec()
+            }
+        )
+    }
+}
+
+pub struct Bls {}
+
+impl Bls {
+
+    /// Signs the message and returns signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to sign
+    /// * `sign_key` - Sign key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// Bls::sign(&message, &sign_key).unwrap();
+    /// ```
+    pub fn sign(message: &[u8], sign_key: &SignKey) -> Result<Signature, IndyCryptoError> {
+        SignatureScheme::V1.sign(message, sign_key)
+    }
+
+    /// Verifies the message signature and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature = Bls::sign(&message, &sign_key).unwrap();
+    ///
+    /// let valid = Bls::verify(&signature, &message, &ver_key, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify(signature: &Signature, message: &[u8], ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify(signature, message, ver_key, gen)
+    }
+
+    /// Verifies the message multi signature and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - List of verification keys
     /// * `gen` - Generator point
     ///
     /// # Example
@@ -379,18 +1142,324 @@ impl Bls {
     /// assert!(valid)
     /// ```
     pub fn verify_multi_sig(multi_sig: &MultiSignature, message: &[u8], ver_keys: &[&VerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
-        let mut multi_sig_e_list: Vec<Pair> = Vec::new();
-        for ver_key in ver_keys {
-            let h = Bls::_hash(message)?;
-            multi_sig_e_list.push(Pair::pair(&h, &ver_key.point)?);
+        SignatureScheme::V1.verify_multi_sig(multi_sig, message, ver_keys, gen)
+    }
+
+    /// Verifies the message multi signature against aggregated verification key of signers
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `agg_ver_key` - Aggregated verification key of signers
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let agg_ver_key = AggregatedVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    ///
+    /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+    ///
+    /// let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_multi_sig_with_aggregated_ver_key(&multi_sig, &message, &agg_ver_key, &gen).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_multi_sig_with_aggregated_ver_key(multi_sig: &MultiSignature, message: &[u8], agg_ver_key: &AggregatedVerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify_multi_sig_with_aggregated_ver_key(multi_sig, message, agg_ver_key, gen)
+    }
+
+    /// Verifies bitmap multi signature against ordered list of validators
+    /// and returns true - if signature valid or false otherwise.
+    /// Verification keys of validators are proven by proof of possession, so rogue key attack isn't possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Bitmap multi signature to verify
+    /// * `message` - Message to verify
+    /// * `validators` - Ordered list of proven verification keys of all validators
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_keys: Vec<SignKey> = (0..3).map(|_| SignKey::new(None).unwrap()).collect();
+    /// let ver_keys: Vec<ProvenVerKey> = sign_keys.iter()
+    ///     .map(|sign_key| {
+    ///         let ver_key = VerKey::new(&gen, sign_key).unwrap();
+    ///         let pop = ProofOfPossession::new(&ver_key, sign_key).unwrap();
+    ///         ProvenVerKey::new(&ver_key, &pop, &gen).unwrap()
+    ///     })
+    ///     .collect();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature0 = Bls::sign(&message, &sign_keys[0]).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_keys[2]).unwrap();
+    ///
+    /// let multi_sig = BitmapMultiSignature::new(&[(0, &signature0), (2, &signature2)], 3).unwrap();
+    ///
+    /// let validators: Vec<&ProvenVerKey> = ver_keys.iter().collect();
+    /// let valid = Bls::verify_bitmap_multi_sig(&multi_sig, &message, &validators, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_bitmap_multi_sig(multi_sig: &BitmapMultiSignature, message: &[u8], validators: &[&ProvenVerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify_bitmap_multi_sig(multi_sig, message, validators, gen)
+    }
+
+    /// Verifies bitmap multi signature against ordered list of validators and their weights.
+    /// Returns true - if signature valid and total weight of participants reaches quorum or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Bitmap multi signature to verify
+    /// * `message` - Message to verify
+    /// * `validators` - Ordered list of proven verification keys of all validators
+    /// * `weights` - Weights of validators in the same order
+    /// * `quorum` - Minimal total weight of participants
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_keys: Vec<SignKey> = (0..3).map(|_| SignKey::new(None).unwrap()).collect();
+    /// let ver_keys: Vec<ProvenVerKey> = sign_keys.iter()
+    ///     .map(|sign_key| {
+    ///         let ver_key = VerKey::new(&gen, sign_key).unwrap();
+    ///         let pop = ProofOfPossession::new(&ver_key, sign_key).unwrap();
+    ///         ProvenVerKey::new(&ver_key, &pop, &gen).unwrap()
+    ///     })
+    ///     .collect();
+    /// let validators: Vec<&ProvenVerKey> = ver_keys.iter().collect();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    /// let signature0 = Bls::sign(&message, &sign_keys[0]).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_keys[2]).unwrap();
+    ///
+    /// let multi_sig = BitmapMultiSignature::new(&[(0, &signature0), (2, &signature2)], 3).unwrap();
+    ///
+    /// assert!(Bls::verify_bitmap_multi_sig_with_quorum(&multi_sig, &message, &validators, &[1, 1, 1], 2, &gen).unwrap());
+    /// assert!(!Bls::verify_bitmap_multi_sig_with_quorum(&multi_sig, &message, &validators, &[1, 5, 1], 5, &gen).unwrap());
+    /// ```
+    pub fn verify_bitmap_multi_sig_with_quorum(multi_sig: &BitmapMultiSignature,
+                                               message: &[u8],
+                                               validators: &[&ProvenVerKey],
+                                               weights: &[u64],
+                                               quorum: u64,
+                                               gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify_bitmap_multi_sig_with_quorum(multi_sig, message, validators, weights, quorum, gen)
+    }
+
+    /// Verifies proof of possession of sign key and returns true - if proof valid or false otherwise.
+    /// Proof of possession for identity verification key is always invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `pop` - Proof of possession to verify
+    /// * `ver_key` - Verification key
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    /// let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+    ///
+    /// let valid = Bls::verify_proof_of_possession(&pop, &ver_key, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_proof_of_possession(pop: &ProofOfPossession, ver_key: &VerKey, gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if ver_key.point.is_inf()? {
+            return Ok(false);
         }
 
-        let mut multi_sig_e = multi_sig_e_list.get(0).ok_or(IndyCryptoError::InvalidStructure(format!("Element not found")))?.clone();
-        for e in multi_sig_e_list[1..].to_vec() {
-            multi_sig_e = multi_sig_e.mul(&e)?;
+        let h = Bls::_hash_ver_key(ver_key)?;
+        Ok(Pair::pair(&pop.point, &gen.point)?.eq(&Pair::pair(&h, &ver_key.point)?))
+    }
+
+    /// Verifies the message multi signature against verification keys with verified proofs of possession
+    /// and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `multi_sig` - Multi signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_keys` - List of proven verification keys
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let pop1 = ProofOfPossession::new(&ver_key1, &sign_key1).unwrap();
+    /// let proven_ver_key1 = ProvenVerKey::new(&ver_key1, &pop1, &gen).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    /// let pop2 = ProofOfPossession::new(&ver_key2, &sign_key2).unwrap();
+    /// let proven_ver_key2 = ProvenVerKey::new(&ver_key2, &pop2, &gen).unwrap();
+    ///
+    /// let message = vec![1, 2, 3, 4, 5];
+    ///
+    /// let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+    ///
+    /// let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_multi_sig_with_pop(&multi_sig, &message, &[&proven_ver_key1, &proven_ver_key2], &gen).unwrap();
+    /// assert!(valid)
+    /// ```
+    pub fn verify_multi_sig_with_pop(multi_sig: &MultiSignature, message: &[u8], ver_keys: &[&ProvenVerKey], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify_multi_sig_with_pop(multi_sig, message, ver_keys, gen)
+    }
+
+    /// Verifies aggregated signature of distinct messages and returns true - if signature valid or false otherwise.
+    /// Messages must be distinct, otherwise aggregated signature is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `agg` - Aggregated signature to verify
+    /// * `messages_and_keys` - List of signed messages with verification keys of their signers
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let message1 = vec![1, 2, 3];
+    /// let message2 = vec![4, 5, 6];
+    ///
+    /// let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+    ///
+    /// let agg = AggregatedSignature::new(&[&signature1, &signature2]).unwrap();
+    ///
+    /// let valid = Bls::verify_aggregate(&agg, &[(message1.as_slice(), &ver_key1), (message2.as_slice(), &ver_key2)], &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_aggregate(agg: &AggregatedSignature, messages_and_keys: &[(&[u8], &VerKey)], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify_aggregate(agg, messages_and_keys, gen)
+    }
+
+    /// Verifies batch of independent signatures and returns true - if all signatures valid or false otherwise.
+    /// Signatures are combined with random coefficients, so batch takes `n + 1` Miller loops
+    /// and two final exponentiations instead of `2 * n` pairings of separate verifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - List of signatures with signed messages and verification keys of signers
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key1 = SignKey::new(None).unwrap();
+    /// let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+    /// let sign_key2 = SignKey::new(None).unwrap();
+    /// let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+    ///
+    /// let message1 = vec![1, 2, 3];
+    /// let message2 = vec![4, 5, 6];
+    ///
+    /// let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+    /// let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+    ///
+    /// let batch = vec![
+    ///     (&signature1, message1.as_slice(), &ver_key1),
+    ///     (&signature2, message2.as_slice(), &ver_key2)
+    /// ];
+    ///
+    /// let valid = Bls::verify_batch(&batch, &gen).unwrap();
+    /// assert!(valid);
+    /// ```
+    pub fn verify_batch(batch: &[(&Signature, &[u8], &VerKey)], gen: &Generator) -> Result<bool, IndyCryptoError> {
+        SignatureScheme::V1.verify_batch(batch, gen)
+    }
+
+    /// Finds invalid signatures in batch by bisection and returns their indexes in batch.
+    /// Returns empty list if all signatures valid. Each step uses `Bls::verify_batch`,
+    /// so few invalid signatures are found much faster than by separate verifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch` - List of signatures with signed messages and verification keys of signers
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// let gen = Generator::new().unwrap();
+    ///
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+    ///
+    /// let message1 = vec![1, 2, 3];
+    /// let message2 = vec![4, 5, 6];
+    ///
+    /// let signature1 = Bls::sign(&message1, &sign_key).unwrap();
+    /// let signature2 = Bls::sign(&message2, &sign_key).unwrap();
+    ///
+    /// let batch = vec![
+    ///     (&signature1, message1.as_slice(), &ver_key),
+    ///     (&signature2, message1.as_slice(), &ver_key)
+    /// ];
+    ///
+    /// let invalid = Bls::find_invalid_in_batch(&batch, &gen).unwrap();
+    /// assert_eq!(vec![1], invalid);
+    /// ```
+    pub fn find_invalid_in_batch(batch: &[(&Signature, &[u8], &VerKey)], gen: &Generator) -> Result<Vec<usize>, IndyCryptoError> {
+        SignatureScheme::V1.find_invalid_in_batch(batch, gen)
+    }
+
+    fn _hash_ver_key(ver_key: &VerKey) -> Result<PointG1, IndyCryptoError> {
+        let mut hasher = Sha256::default();
+        hasher.input(PROOF_OF_POSSESSION_DOMAIN);
+        hasher.input(ver_key.as_bytes());
+
+        Ok(PointG1::from_hash(hasher.result().as_slice())?)
+    }
+
+    fn _check_signature_point(point: &PointG1) -> Result<(), IndyCryptoError> {
+        if point.is_inf()? || !point.is_in_subgroup()? {
+            return Err(IndyCryptoError::InvalidStructure(format!("Signature point is identity or doesn't belong to G1")));
         }
 
-        Ok(Pair::pair(&multi_sig.point, &gen.point)?.eq(&multi_sig_e))
+        Ok(())
     }
 
     fn _hash(message: &[u8]) -> Result<PointG1, IndyCryptoError> {
@@ -417,10 +1486,20 @@ mod tests {
 
     #[test]
     fn sign_key_new_works_for_seed() {
-        let seed = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 2, 3, 4, 5, 6, 7, 8, 9, 10, 21, 2, 3, 4, 5, 6, 7, 8, 9, 10, 31, 32];
+        let seed: Vec<u8> = (1..(GroupOrderElement::BYTES_REPR_SIZE as u8 + 1)).collect();
         SignKey::new(Some(&seed)).unwrap();
     }
 
+    #[test]
+    fn sign_key_split_combine_works() {
+        let sign_key = SignKey::new(None).unwrap();
+
+        let (shares, commitments) = sign_key.split(3, 5).unwrap();
+        let recovered = SignKey::combine(&[&shares[1], &shares[3], &shares[4]], &commitments).unwrap();
+
+        assert_eq!(sign_key.as_bytes(), recovered.as_bytes());
+    }
+
     #[test]
     fn ver_key_new_works() {
         let gen = Generator::new().unwrap();
@@ -436,6 +1515,119 @@ mod tests {
         Bls::sign(&message, &sign_key).unwrap();
     }
 
+    #[cfg(feature = "pair_amcl")]
+    const SCHEME_V2_DST: &'static [u8] = b"INDY-CRYPTO-V01-CS01-with-BN254G1_XMD:SHA-256_SVDW_RO_NUL_";
+
+    #[cfg(feature = "pair_bls12_381")]
+    const SCHEME_V2_DST: &'static [u8] = b"INDY-CRYPTO-V01-CS01-with-BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+    #[test]
+    fn signature_scheme_sign_works_for_v1() {
+        let sign_key = SignKey::new(None).unwrap();
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature = Bls::sign(&message, &sign_key).unwrap();
+        let signature_v1 = SignatureScheme::V1.sign(&message, &sign_key).unwrap();
+        assert_eq!(signature.as_bytes(), signature_v1.as_bytes());
+    }
+
+    #[test]
+    fn signature_scheme_sign_works_for_v2() {
+        let sign_key = SignKey::new(None).unwrap();
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature = SignatureScheme::V2(SCHEME_V2_DST.to_vec()).sign(&message, &sign_key).unwrap();
+        let expected = PointG1::hash_to_curve(&message, SCHEME_V2_DST).unwrap().mul(&sign_key.group_order_element).unwrap();
+        assert_eq!(expected.to_bytes().unwrap(), signature.as_bytes());
+    }
+
+    #[test]
+    fn signature_scheme_verify_works_for_v2() {
+        let message = vec![1, 2, 3, 4, 5];
+        let scheme = SignatureScheme::V2(SCHEME_V2_DST.to_vec());
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = scheme.sign(&message, &sign_key).unwrap();
+
+        assert!(scheme.verify(&signature, &message, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn signature_scheme_verify_works_for_other_scheme() {
+        let message = vec![1, 2, 3, 4, 5];
+        let scheme = SignatureScheme::V2(SCHEME_V2_DST.to_vec());
+        let other_scheme = SignatureScheme::V2(b"OTHER-APP-V01-CS01".to_vec());
+
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let signature = scheme.sign(&message, &sign_key).unwrap();
+
+        assert!(!other_scheme.verify(&signature, &message, &ver_key, &gen).unwrap());
+        assert!(!Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn signature_scheme_verify_multi_sig_works_for_v2() {
+        let message = vec![1, 2, 3, 4, 5];
+        let scheme = SignatureScheme::V2(SCHEME_V2_DST.to_vec());
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let proven_ver_key1 = ProvenVerKey::new(&ver_key1, &ProofOfPossession::new(&ver_key1, &sign_key1).unwrap(), &gen).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+        let proven_ver_key2 = ProvenVerKey::new(&ver_key2, &ProofOfPossession::new(&ver_key2, &sign_key2).unwrap(), &gen).unwrap();
+
+        let signature1 = scheme.sign(&message, &sign_key1).unwrap();
+        let signature2 = scheme.sign(&message, &sign_key2).unwrap();
+        let multi_sig = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+        let bitmap_multi_sig = BitmapMultiSignature::new(&[(0, &signature1), (1, &signature2)], 2).unwrap();
+
+        assert!(scheme.verify_multi_sig(&multi_sig, &message, &[&ver_key1, &ver_key2], &gen).unwrap());
+        assert!(scheme.verify_bitmap_multi_sig_with_quorum(&bitmap_multi_sig, &message, &[&proven_ver_key1, &proven_ver_key2], &[1, 1], 2, &gen).unwrap());
+        assert!(scheme.verify_multi_sig_with_pop(&multi_sig, &message, &[&proven_ver_key1, &proven_ver_key2], &gen).unwrap());
+
+        assert!(!Bls::verify_multi_sig(&multi_sig, &message, &[&ver_key1, &ver_key2], &gen).unwrap());
+        assert!(!Bls::verify_bitmap_multi_sig(&bitmap_multi_sig, &message, &[&proven_ver_key1, &proven_ver_key2], &gen).unwrap());
+        assert!(!Bls::verify_multi_sig_with_pop(&multi_sig, &message, &[&proven_ver_key1, &proven_ver_key2], &gen).unwrap());
+
+        let mixed_signature2 = Bls::sign(&message, &sign_key2).unwrap();
+        let mixed_multi_sig = MultiSignature::new(&[&signature1, &mixed_signature2]).unwrap();
+        assert!(!scheme.verify_multi_sig(&mixed_multi_sig, &message, &[&ver_key1, &ver_key2], &gen).unwrap());
+    }
+
+    #[test]
+    fn signature_scheme_verify_aggregate_and_batch_works_for_v2() {
+        let message1 = vec![1, 2, 3];
+        let message2 = vec![4, 5, 6];
+        let scheme = SignatureScheme::V2(SCHEME_V2_DST.to_vec());
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let signature1 = scheme.sign(&message1, &sign_key1).unwrap();
+        let signature2 = scheme.sign(&message2, &sign_key2).unwrap();
+        let agg = AggregatedSignature::new(&[&signature1, &signature2]).unwrap();
+        let messages_and_keys = [(message1.as_slice(), &ver_key1), (message2.as_slice(), &ver_key2)];
+
+        assert!(scheme.verify_aggregate(&agg, &messages_and_keys, &gen).unwrap());
+        assert!(!Bls::verify_aggregate(&agg, &messages_and_keys, &gen).unwrap());
+
+        let v1_signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+        let batch = [(&signature1, message1.as_slice(), &ver_key1), (&v1_signature2, message2.as_slice(), &ver_key2)];
+
+        assert!(!scheme.verify_batch(&batch, &gen).unwrap());
+        assert_eq!(vec![1], scheme.find_invalid_in_batch(&batch, &gen).unwrap());
+        assert_eq!(vec![0], Bls::find_invalid_in_batch(&batch, &gen).unwrap());
+    }
+
     #[test]
     fn multi_signature_new_works() {
         let message = vec![1, 2, 3, 4, 5];
@@ -583,4 +1775,370 @@ mod tests {
 
         assert!(!valid)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn proof_of_possession_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+        let pop = ProofOfPossession::from_bytes(pop.as_bytes()).unwrap();
+
+        assert!(Bls::verify_proof_of_possession(&pop, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn proof_of_possession_works_for_other_ver_key() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let other_ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+
+        assert!(!Bls::verify_proof_of_possession(&pop, &other_ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn proof_of_possession_differs_from_signature_of_ver_key() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+        let signature = Bls::sign(ver_key.as_bytes(), &sign_key).unwrap();
+
+        assert_ne!(pop.as_bytes(), signature.as_bytes());
+    }
+
+    #[test]
+    fn proof_of_possession_works_for_identity_ver_key() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let inf = PointG2::new_inf().unwrap();
+        let ver_key = VerKey { point: inf, bytes: inf.to_bytes().unwrap() };
+
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+
+        assert!(!Bls::verify_proof_of_possession(&pop, &ver_key, &gen).unwrap());
+        assert!(ProvenVerKey::new(&ver_key, &pop, &gen).is_err());
+    }
+
+    #[test]
+    fn proven_ver_key_new_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+
+        let proven_ver_key = ProvenVerKey::new(&ver_key, &pop, &gen).unwrap();
+        assert_eq!(ver_key.as_bytes(), proven_ver_key.as_bytes());
+    }
+
+    #[test]
+    fn proven_ver_key_new_works_for_invalid_pop() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let other_ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+        let pop = ProofOfPossession::new(&ver_key, &sign_key).unwrap();
+
+        assert!(ProvenVerKey::new(&other_ver_key, &pop, &gen).is_err());
+    }
+
+    #[test]
+    fn verify_multi_sig_with_pop_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let pop1 = ProofOfPossession::new(&ver_key1, &sign_key1).unwrap();
+        let proven_ver_key1 = ProvenVerKey::new(&ver_key1, &pop1, &gen).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+        let pop2 = ProofOfPossession::new(&ver_key2, &sign_key2).unwrap();
+        let proven_ver_key2 = ProvenVerKey::new(&ver_key2, &pop2, &gen).unwrap();
+
+        let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+        let multi_signature = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+
+        assert!(Bls::verify_multi_sig_with_pop(&multi_signature, &message, &[&proven_ver_key1, &proven_ver_key2], &gen).unwrap());
+        assert!(!Bls::verify_multi_sig_with_pop(&multi_signature, &message, &[&proven_ver_key1], &gen).unwrap());
+        assert!(Bls::verify_multi_sig_with_pop(&multi_signature, &message, &[], &gen).is_err());
+    }
+
+    #[test]
+    fn verify_multi_sig_with_pop_works_for_rogue_key() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+
+        // Attacker chooses ver_key2 = gen * x - ver_key1 without knowing its sign key
+        let attacker_sign_key = SignKey::new(None).unwrap();
+        let rogue_point = gen.point.mul(&attacker_sign_key.group_order_element).unwrap().sub(&ver_key1.point).unwrap();
+        let rogue_ver_key = VerKey { point: rogue_point, bytes: rogue_point.to_bytes().unwrap() };
+        let rogue_pop = ProofOfPossession::new(&rogue_ver_key, &attacker_sign_key).unwrap();
+
+        let forged_signature = Bls::sign(&message, &attacker_sign_key).unwrap();
+        let multi_signature = MultiSignature::new(&[&forged_signature]).unwrap();
+
+        assert!(Bls::verify_multi_sig(&multi_signature, &message, &[&ver_key1, &rogue_ver_key], &gen).unwrap());
+        assert!(ProvenVerKey::new(&rogue_ver_key, &rogue_pop, &gen).is_err());
+    }
+
+    #[test]
+    fn verify_aggregate_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+
+        let agg = AggregatedSignature::new(&[&signature1, &signature2]).unwrap();
+        let agg = AggregatedSignature::from_bytes(agg.as_bytes()).unwrap();
+
+        let valid = Bls::verify_aggregate(&agg, &[(message1.as_slice(), &ver_key1), (message2.as_slice(), &ver_key2)], &gen).unwrap();
+        assert!(valid);
+    }
+
+    #[test]
+    fn verify_aggregate_works_for_swapped_messages() {
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+
+        let agg = AggregatedSignature::new(&[&signature1, &signature2]).unwrap();
+
+        let valid = Bls::verify_aggregate(&agg, &[(message2.as_slice(), &ver_key1), (message1.as_slice(), &ver_key2)], &gen).unwrap();
+        assert!(!valid);
+    }
+
+    #[test]
+    fn verify_aggregate_works_for_duplicate_messages() {
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let message = vec![1, 2, 3, 4, 5];
+
+        let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+
+        let agg = AggregatedSignature::new(&[&signature1, &signature2]).unwrap();
+
+        assert!(Bls::verify_aggregate(&agg, &[(message.as_slice(), &ver_key1), (message.as_slice(), &ver_key2)], &gen).is_err());
+    }
+
+    #[test]
+    fn verify_multi_sig_with_aggregated_ver_key_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let agg_ver_key = AggregatedVerKey::new(&[&ver_key1, &ver_key2]).unwrap();
+        let agg_ver_key = AggregatedVerKey::from_bytes(agg_ver_key.as_bytes()).unwrap();
+
+        let signature1 = Bls::sign(&message, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message, &sign_key2).unwrap();
+        let multi_signature = MultiSignature::new(&[&signature1, &signature2]).unwrap();
+
+        assert!(Bls::verify_multi_sig_with_aggregated_ver_key(&multi_signature, &message, &agg_ver_key, &gen).unwrap());
+
+        let multi_signature_partial = MultiSignature::new(&[&signature1]).unwrap();
+        assert!(!Bls::verify_multi_sig_with_aggregated_ver_key(&multi_signature_partial, &message, &agg_ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn aggregated_ver_key_new_works_for_empty_list() {
+        assert!(AggregatedVerKey::new(&[]).is_err());
+    }
+
+    #[test]
+    fn verify_batch_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key1 = SignKey::new(None).unwrap();
+        let ver_key1 = VerKey::new(&gen, &sign_key1).unwrap();
+        let sign_key2 = SignKey::new(None).unwrap();
+        let ver_key2 = VerKey::new(&gen, &sign_key2).unwrap();
+
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8, 9, 10];
+
+        let signature1 = Bls::sign(&message1, &sign_key1).unwrap();
+        let signature2 = Bls::sign(&message2, &sign_key2).unwrap();
+        let signature3 = Bls::sign(&message1, &sign_key2).unwrap();
+
+        let batch = vec![
+            (&signature1, message1.as_slice(), &ver_key1),
+            (&signature2, message2.as_slice(), &ver_key2),
+            (&signature3, message1.as_slice(), &ver_key2)
+        ];
+
+        assert!(Bls::verify_batch(&batch, &gen).unwrap());
+        assert!(Bls::find_invalid_in_batch(&batch, &gen).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_batch_works_for_invalid_signatures() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+        let other_ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let messages: Vec<Vec<u8>> = (0..7u8).map(|i| vec![i; 5]).collect();
+        let signatures: Vec<Signature> = messages.iter().map(|message| Bls::sign(message, &sign_key).unwrap()).collect();
+
+        let mut batch: Vec<(&Signature, &[u8], &VerKey)> = signatures.iter()
+            .zip(messages.iter())
+            .map(|(signature, message)| (signature, message.as_slice(), &ver_key))
+            .collect();
+
+        batch[2].2 = &other_ver_key;
+        batch[5].1 = messages[0].as_slice();
+
+        assert!(!Bls::verify_batch(&batch, &gen).unwrap());
+        assert_eq!(vec![2, 5], Bls::find_invalid_in_batch(&batch, &gen).unwrap());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_keys: Vec<SignKey> = (0..10).map(|_| SignKey::new(None).unwrap()).collect();
+        let ver_keys: Vec<VerKey> = sign_keys.iter().map(|sign_key| VerKey::new(&gen, sign_key).unwrap()).collect();
+        let proven_ver_keys: Vec<ProvenVerKey> = sign_keys.iter().zip(ver_keys.iter())
+            .map(|(sign_key, ver_key)| ProvenVerKey::new(ver_key, &ProofOfPossession::new(ver_key, sign_key).unwrap(), &gen).unwrap())
+            .collect();
+        let validators: Vec<&ProvenVerKey> = proven_ver_keys.iter().collect();
+
+        let signatures: Vec<(usize, Signature)> = [1, 4, 8, 9].iter()
+            .map(|&i| (i, Bls::sign(&message, &sign_keys[i]).unwrap()))
+            .collect();
+        let signatures: Vec<(usize, &Signature)> = signatures.iter().map(|&(i, ref signature)| (i, signature)).collect();
+
+        let multi_sig = BitmapMultiSignature::new(&signatures, 10).unwrap();
+        let multi_sig = BitmapMultiSignature::from_bytes(multi_sig.as_bytes()).unwrap();
+
+        assert_eq!(vec![1, 4, 8, 9], multi_sig.get_participants());
+        assert_eq!(4 + 2, multi_sig.as_bytes().len() - multi_sig.point.to_bytes().unwrap().len());
+        assert!(Bls::verify_bitmap_multi_sig(&multi_sig, &message, &validators, &gen).unwrap());
+
+        let weights = vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1];
+        assert!(Bls::verify_bitmap_multi_sig_with_quorum(&multi_sig, &message, &validators, &weights, 4, &gen).unwrap());
+        assert!(!Bls::verify_bitmap_multi_sig_with_quorum(&multi_sig, &message, &validators, &weights, 7, &gen).unwrap());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_works_for_wrong_participants() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_keys: Vec<SignKey> = (0..3).map(|_| SignKey::new(None).unwrap()).collect();
+        let ver_keys: Vec<VerKey> = sign_keys.iter().map(|sign_key| VerKey::new(&gen, sign_key).unwrap()).collect();
+        let proven_ver_keys: Vec<ProvenVerKey> = sign_keys.iter().zip(ver_keys.iter())
+            .map(|(sign_key, ver_key)| ProvenVerKey::new(ver_key, &ProofOfPossession::new(ver_key, sign_key).unwrap(), &gen).unwrap())
+            .collect();
+        let validators: Vec<&ProvenVerKey> = proven_ver_keys.iter().collect();
+
+        let signature = Bls::sign(&message, &sign_keys[0]).unwrap();
+
+        let multi_sig = BitmapMultiSignature::new(&[(1, &signature)], 3).unwrap();
+        assert!(!Bls::verify_bitmap_multi_sig(&multi_sig, &message, &validators, &gen).unwrap());
+
+        assert!(BitmapMultiSignature::new(&[(0, &signature), (0, &signature)], 3).is_err());
+        assert!(BitmapMultiSignature::new(&[(3, &signature)], 3).is_err());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_from_bytes_works_for_unknown_validator_bits() {
+        let signature = Bls::sign(&[1, 2, 3], &SignKey::new(None).unwrap()).unwrap();
+        let multi_sig = BitmapMultiSignature::new(&[(0, &signature)], 3).unwrap();
+
+        let mut bytes = multi_sig.as_bytes().to_vec();
+        bytes[4] |= 0x80;
+
+        assert!(BitmapMultiSignature::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_incremental_aggregation_works() {
+        let message = vec![1, 2, 3, 4, 5];
+
+        let gen = Generator::new().unwrap();
+        let sign_keys: Vec<SignKey> = (0..5).map(|_| SignKey::new(None).unwrap()).collect();
+        let ver_keys: Vec<VerKey> = sign_keys.iter().map(|sign_key| VerKey::new(&gen, sign_key).unwrap()).collect();
+        let proven_ver_keys: Vec<ProvenVerKey> = sign_keys.iter().zip(ver_keys.iter())
+            .map(|(sign_key, ver_key)| ProvenVerKey::new(ver_key, &ProofOfPossession::new(ver_key, sign_key).unwrap(), &gen).unwrap())
+            .collect();
+        let validators: Vec<&ProvenVerKey> = proven_ver_keys.iter().collect();
+        let signatures: Vec<Signature> = sign_keys.iter().map(|sign_key| Bls::sign(&message, sign_key).unwrap()).collect();
+
+        let mut multi_sig = BitmapMultiSignature::new(&[(0, &signatures[0])], 5).unwrap();
+        multi_sig.add_signature(3, &signatures[3]).unwrap();
+        assert!(multi_sig.add_signature(3, &signatures[3]).is_err());
+
+        let mut other = BitmapMultiSignature::new(&[(1, &signatures[1])], 5).unwrap();
+        other.add_signature(4, &signatures[4]).unwrap();
+
+        multi_sig.merge(&other).unwrap();
+        assert!(multi_sig.merge(&other).is_err());
+
+        let multi_sig = BitmapMultiSignature::from_bytes(multi_sig.as_bytes()).unwrap();
+        assert_eq!(vec![0, 1, 3, 4], multi_sig.get_participants());
+        assert!(Bls::verify_bitmap_multi_sig(&multi_sig, &message, &validators, &gen).unwrap());
+
+        let participants: Vec<&VerKey> = multi_sig.get_participants().into_iter().map(|i| &ver_keys[i]).collect();
+        assert!(Bls::verify_multi_sig(&multi_sig.to_multi_signature().unwrap(), &message, &participants, &gen).unwrap());
+    }
+
+    #[test]
+    fn bitmap_multi_signature_add_signature_works_for_identity() {
+        let message = vec![1, 2, 3, 4, 5];
+        let signature = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+        let inf = PointG1::new_inf().unwrap();
+        let identity = Signature { point: inf, bytes: inf.to_bytes().unwrap() };
+
+        assert!(BitmapMultiSignature::new(&[(0, &identity)], 2).is_err());
+
+        let mut multi_sig = BitmapMultiSignature::new(&[(0, &signature)], 2).unwrap();
+        assert!(multi_sig.add_signature(1, &identity).is_err());
+        assert_eq!(vec![0], multi_sig.get_participants());
+    }
+
+    #[test]
+    fn multi_signature_new_works_for_identity() {
+        let message = vec![1, 2, 3, 4, 5];
+        let signature = Bls::sign(&message, &SignKey::new(None).unwrap()).unwrap();
+        let inf = PointG1::new_inf().unwrap();
+        let identity = Signature { point: inf, bytes: inf.to_bytes().unwrap() };
+
+        assert!(MultiSignature::new(&[&signature, &identity]).is_err());
+        assert!(MultiSignature::new(&[&identity]).is_err());
+    }
+}
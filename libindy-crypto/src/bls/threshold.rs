@@ -0,0 +1,322 @@
+use bls::{Bls, Generator, SignKey, Signature, VerKey};
+use errors::IndyCryptoError;
+use pair::{GroupOrderElement, PointG1};
+use secret_sharing::SecretSharing;
+
+use std::collections::HashSet;
+
+/// Share of threshold BLS sign key owned by one signer.
+#[derive(Debug)]
+pub struct SignKeyShare {
+    index: u32,
+    sign_key: SignKey
+}
+
+impl SignKeyShare {
+    /// Creates sign key share from index and sign key (for example restored from bytes).
+    pub fn new(index: u32, sign_key: SignKey) -> SignKeyShare {
+        SignKeyShare { index, sign_key }
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn get_sign_key(&self) -> &SignKey {
+        &self.sign_key
+    }
+}
+
+/// Verification key of sign key share. Allows to verify partial signatures of one signer.
+#[derive(Debug)]
+pub struct VerKeyShare {
+    index: u32,
+    ver_key: VerKey
+}
+
+impl VerKeyShare {
+    /// Creates verification key share from index and verification key (for example restored from bytes).
+    pub fn new(index: u32, ver_key: VerKey) -> VerKeyShare {
+        VerKeyShare { index, ver_key }
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn get_ver_key(&self) -> &VerKey {
+        &self.ver_key
+    }
+}
+
+/// Signature created with sign key share.
+#[derive(Debug)]
+pub struct PartialSignature {
+    index: u32,
+    signature: Signature
+}
+
+impl PartialSignature {
+    /// Creates partial signature from index and signature (for example restored from bytes).
+    pub fn new(index: u32, signature: Signature) -> PartialSignature {
+        PartialSignature { index, signature }
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn get_signature(&self) -> &Signature {
+        &self.signature
+    }
+}
+
+/// Threshold (t-of-n) BLS signatures with trusted dealer.
+/// Any `threshold` partial signatures are combined into ordinary signature
+/// that is verified by `Bls::verify` against group verification key.
+pub struct ThresholdBls {}
+
+impl ThresholdBls {
+    /// Splits sign key into `shares_count` indexed shares so that any `threshold` of them can sign.
+    /// Returns group verification key, sign key shares and their verification keys.
+    /// Dealer must deliver every sign key share to its owner and forget sign key.
+    ///
+    /// # Arguments
+    ///
+    /// * `gen` - Generator point
+    /// * `sign_key` - Group sign key
+    /// * `threshold` - Number of partial signatures required to create signature
+    /// * `shares_count` - Number of shares
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let (_ver_key, sign_key_shares, ver_key_shares) = ThresholdBls::split(&gen, &sign_key, 2, 3).unwrap();
+    /// assert_eq!(3, sign_key_shares.len());
+    /// assert_eq!(3, ver_key_shares.len());
+    /// ```
+    pub fn split(gen: &Generator,
+                 sign_key: &SignKey,
+                 threshold: usize,
+                 shares_count: usize) -> Result<(VerKey, Vec<SignKeyShare>, Vec<VerKeyShare>), IndyCryptoError> {
+        trace!("ThresholdBls::split: >>> threshold: {:?}, shares_count: {:?}", threshold, shares_count);
+
+        if threshold == 0 || threshold > shares_count || shares_count >= u32::max_value() as usize {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid threshold {} for {} shares", threshold, shares_count)));
+        }
+
+        let mut coefficients = vec![sign_key.group_order_element];
+        for _ in 1..threshold {
+            coefficients.push(GroupOrderElement::new()?);
+        }
+
+        let mut sign_key_shares: Vec<SignKeyShare> = Vec::new();
+        let mut ver_key_shares: Vec<VerKeyShare> = Vec::new();
+
+        for index in 1..(shares_count as u32 + 1) {
+            let x = SecretSharing::index_to_element(index)?;
+            let share = SecretSharing::eval_polynomial(&coefficients, &x)?;
+
+            let share_sign_key = SignKey {
+                group_order_element: share,
+                bytes: share.to_bytes()?
+            };
+
+            ver_key_shares.push(VerKeyShare { index, ver_key: VerKey::new(gen, &share_sign_key)? });
+            sign_key_shares.push(SignKeyShare { index, sign_key: share_sign_key });
+        }
+
+        let ver_key = VerKey::new(gen, sign_key)?;
+
+        trace!("ThresholdBls::split: <<< ver_key: {:?}, ver_key_shares: {:?}", ver_key, ver_key_shares);
+
+        Ok((ver_key, sign_key_shares, ver_key_shares))
+    }
+
+    /// Signs the message with sign key share and returns partial signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to sign
+    /// * `sign_key_share` - Sign key share
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let (_, sign_key_shares, _) = ThresholdBls::split(&gen, &sign_key, 2, 3).unwrap();
+    /// ThresholdBls::sign_partial(&[1, 2, 3], &sign_key_shares[0]).unwrap();
+    /// ```
+    pub fn sign_partial(message: &[u8], sign_key_share: &SignKeyShare) -> Result<PartialSignature, IndyCryptoError> {
+        Ok(PartialSignature {
+            index: sign_key_share.index,
+            signature: Bls::sign(message, &sign_key_share.sign_key)?
+        })
+    }
+
+    /// Verifies partial signature and returns true - if signature valid or false otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial_signature` - Partial signature to verify
+    /// * `message` - Message to verify
+    /// * `ver_key_share` - Verification key share of signer
+    /// * `gen` - Generator point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let (_, sign_key_shares, ver_key_shares) = ThresholdBls::split(&gen, &sign_key, 2, 3).unwrap();
+    /// let message = vec![1, 2, 3];
+    /// let partial_signature = ThresholdBls::sign_partial(&message, &sign_key_shares[1]).unwrap();
+    /// assert!(ThresholdBls::verify_partial(&partial_signature, &message, &ver_key_shares[1], &gen).unwrap());
+    /// ```
+    pub fn verify_partial(partial_signature: &PartialSignature,
+                          message: &[u8],
+                          ver_key_share: &VerKeyShare,
+                          gen: &Generator) -> Result<bool, IndyCryptoError> {
+        if partial_signature.index != ver_key_share.index {
+            return Ok(false);
+        }
+
+        Bls::verify(&partial_signature.signature, message, &ver_key_share.ver_key, gen)
+    }
+
+    /// Combines partial signatures by Lagrange interpolation and returns signature
+    /// verifiable by `Bls::verify` against group verification key.
+    /// At least threshold partial signatures with distinct indexes are required,
+    /// otherwise resulting signature is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial_signatures` - List of partial signatures
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use indy_crypto::bls::*;
+    /// use indy_crypto::bls::threshold::*;
+    /// let gen = Generator::new().unwrap();
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let (ver_key, sign_key_shares, _) = ThresholdBls::split(&gen, &sign_key, 2, 3).unwrap();
+    ///
+    /// let message = vec![1, 2, 3];
+    /// let partial_signature1 = ThresholdBls::sign_partial(&message, &sign_key_shares[0]).unwrap();
+    /// let partial_signature3 = ThresholdBls::sign_partial(&message, &sign_key_shares[2]).unwrap();
+    ///
+    /// let signature = ThresholdBls::combine(&[&partial_signature1, &partial_signature3]).unwrap();
+    /// assert!(Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    /// ```
+    pub fn combine(partial_signatures: &[&PartialSignature]) -> Result<Signature, IndyCryptoError> {
+        trace!("ThresholdBls::combine: >>> indexes: {:?}", partial_signatures.iter().map(|sig| sig.index).collect::<Vec<u32>>());
+
+        if partial_signatures.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Partial signatures list is empty")));
+        }
+
+        if partial_signatures.iter().any(|sig| sig.index == 0) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Partial signature has invalid index 0")));
+        }
+
+        if partial_signatures.iter().map(|sig| sig.index).collect::<HashSet<u32>>().len() != partial_signatures.len() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Partial signatures contain duplicate indexes")));
+        }
+
+        let xs = partial_signatures.iter()
+            .map(|sig| SecretSharing::index_to_element(sig.index))
+            .collect::<Result<Vec<GroupOrderElement>, IndyCryptoError>>()?;
+
+        let mut point = PointG1::new_inf()?;
+
+        for (k, partial_signature) in partial_signatures.iter().enumerate() {
+            point = point.add(&partial_signature.signature.point.mul(&SecretSharing::lagrange_coefficient(&xs, k)?)?)?;
+        }
+
+        let signature = Signature {
+            point,
+            bytes: point.to_bytes()?
+        };
+
+        trace!("ThresholdBls::combine: <<< signature: {:?}", signature);
+
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn threshold_sign_works() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let (ver_key, sign_key_shares, ver_key_shares) = ThresholdBls::split(&gen, &sign_key, 3, 5).unwrap();
+
+        let message = vec![1, 2, 3, 4, 5];
+
+        let partial_signatures: Vec<PartialSignature> = [4, 0, 2].iter()
+            .map(|&i| ThresholdBls::sign_partial(&message, &sign_key_shares[i]).unwrap())
+            .collect();
+
+        for (partial_signature, &i) in partial_signatures.iter().zip([4, 0, 2].iter()) {
+            assert!(ThresholdBls::verify_partial(partial_signature, &message, &ver_key_shares[i], &gen).unwrap());
+        }
+
+        let signature = ThresholdBls::combine(&partial_signatures.iter().collect::<Vec<&PartialSignature>>()).unwrap();
+
+        assert!(Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+        assert_eq!(Bls::sign(&message, &sign_key).unwrap().as_bytes(), signature.as_bytes());
+    }
+
+    #[test]
+    fn threshold_sign_works_for_not_enough_partial_signatures() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let (ver_key, sign_key_shares, _) = ThresholdBls::split(&gen, &sign_key, 3, 5).unwrap();
+
+        let message = vec![1, 2, 3, 4, 5];
+
+        let partial_signature1 = ThresholdBls::sign_partial(&message, &sign_key_shares[0]).unwrap();
+        let partial_signature2 = ThresholdBls::sign_partial(&message, &sign_key_shares[1]).unwrap();
+
+        let signature = ThresholdBls::combine(&[&partial_signature1, &partial_signature2]).unwrap();
+
+        assert!(!Bls::verify(&signature, &message, &ver_key, &gen).unwrap());
+    }
+
+    #[test]
+    fn verify_partial_works_for_other_share() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let (_, sign_key_shares, ver_key_shares) = ThresholdBls::split(&gen, &sign_key, 2, 3).unwrap();
+
+        let message = vec![1, 2, 3, 4, 5];
+        let partial_signature = ThresholdBls::sign_partial(&message, &sign_key_shares[0]).unwrap();
+
+        assert!(!ThresholdBls::verify_partial(&partial_signature, &message, &ver_key_shares[1], &gen).unwrap());
+    }
+
+    #[test]
+    fn combine_works_for_duplicate_indexes() {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let (_, sign_key_shares, _) = ThresholdBls::split(&gen, &sign_key, 2, 3).unwrap();
+
+        let partial_signature = ThresholdBls::sign_partial(&[1, 2, 3], &sign_key_shares[0]).unwrap();
+
+        assert!(ThresholdBls::combine(&[&partial_signature, &partial_signature]).is_err());
+    }
+}
@@ -13,3 +13,40 @@ pub const ITERATION: usize = 4;
 pub const LARGE_M2_TILDE: usize = 1024;
 pub const LARGE_NONCE: usize = 80;
 pub const LARGE_ALPHATILDE: usize = 2787;
+
+/// Domain separation prefix of signed revocation accumulator statements.
+pub const ACCUMULATOR_STATEMENT_DOMAIN: &'static [u8] = b"indy_crypto:cl:accumulator_statement:1";
+
+/// Maximal allowed clock skew (in seconds) for accumulator statements with timestamp in the future.
+pub const ACCUMULATOR_STATEMENT_MAX_CLOCK_SKEW: u64 = 60;
+
+pub const LARGE_RSA_ACC_R: usize = 2128;
+pub const LARGE_RSA_ACC_R_TILDE: usize = 2464;
+pub const LARGE_RSA_ACC_DELTA_TILDE: usize = 2720;
+
+/// Domain separation prefix of weights of aggregated non-revocation proofs.
+pub const NON_REVOC_PROOF_AGGREGATION_DOMAIN: &'static [u8] = b"indy_crypto:cl:non_revoc_proof_aggregation:1";
+
+/// 2048-bit MODP group modulus (RFC 3526, group 14). It is a safe prime, so pseudonyms
+/// are computed in its subgroup of quadratic residues of prime order `(P - 1) / 2`.
+pub const PSEUDONYM_GROUP_MODULUS: &'static str = "FFFFFFFFFFFFFFFFC90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B139B22514A08798E3404DDEF9519B3CD3A431B302B0A6DF25F14374FE1356D6D51C245E485B576625E7EC6F44C42E9A637ED6B0BFF5CB6F406B7EDEE386BFB5A899FA5AE9F24117C4B1FE649286651ECE45B3DC2007CB8A163BF0598DA48361C55D39A69163FA8FD24CF5F83655D23DCA3AD961C62F356208552BB9ED529077096966D670C354E4ABC9804F1746C08CA18217C32905E462E36CE3BE39E772C180E86039B2783A2EC07A28FB5C55DF06F4C52C9DE2BCBF6955817183995497CEA956AE515D2261898FA051015728E5A8AACAA68FFFFFFFFFFFFFFFF";
+
+/// Domain separation prefix of pseudonym scope hashing.
+pub const PSEUDONYM_SCOPE_DOMAIN: &'static [u8] = b"indy_crypto:cl:pseudonym_scope:1";
+
+/// Number of hash blocks expanded to pseudonym base (9 * 256 bits covers modulus with margin).
+pub const PSEUDONYM_BASE_HASH_BLOCKS: u32 = 9;
+
+/// Domain separation prefix of master secret derivation from seed.
+pub const MASTER_SECRET_SEED_DOMAIN: &'static [u8] = b"indy_crypto:cl:master_secret_seed:1";
+
+/// Minimal length of seed (in bytes) for master secret derivation.
+pub const MIN_MASTER_SECRET_SEED_LEN: usize = 32;
+
+/// Master secret is split by secret sharing as big-endian limbs of 16 bytes,
+/// so every limb is less than group order.
+pub const MASTER_SECRET_SHARE_LIMBS: usize = 2;
+pub const MASTER_SECRET_SHARE_LIMB_LEN: usize = 16;
+
+/// Maximal number of accumulator snapshots kept in public revocation registry.
+pub const MAX_ACCUMULATOR_SNAPSHOTS: usize = 128;
@@ -261,6 +261,19 @@ pub fn transform_u32_to_array_of_u8(x: u32) -> Vec<u8> {
     result
 }
 
+pub fn transform_u64_to_array_of_u8(x: u64) -> Vec<u8> {
+    trace!("Helpers::transform_u64_to_array_of_u8: >>> x: {:?}", x);
+
+    let mut result: Vec<u8> = Vec::new();
+    for i in (0..8).rev() {
+        result.push((x >> i * 8) as u8);
+    }
+
+    trace!("Helpers::transform_u64_to_array_of_u8: <<< res: {:?}", result);
+
+    result
+}
+
 pub fn get_hash_as_int(nums: &mut Vec<Vec<u8>>) -> Result<BigNumber, IndyCryptoError> {
     trace!("Helpers::get_hash_as_int: >>> nums: {:?}", nums);
 
@@ -292,9 +305,9 @@ pub fn get_mtilde(unrevealed_attrs: &HashSet<String>) -> Result<HashMap<String,
 
 pub fn calc_teq(issuer_pub_key: &IssuerPrimaryPublicKey, a_prime: &BigNumber, e: &BigNumber, v: &BigNumber,
                 m_tilde: &HashMap<String, BigNumber>, m1_tilde: &BigNumber, m2tilde: &BigNumber,
-                unrevealed_attrs: &HashSet<String>) -> Result<BigNumber, IndyCryptoError> {
-    trace!("Helpers::calc_teq: >>> issuer_pub_key: {:?}, issuer_pub_key: {:?}, e: {:?}, v: {:?}, m_tilde: {:?}, m1_tilde: {:?}, unrevealed_attrs: {:?}",
-           issuer_pub_key, a_prime, e, v, m_tilde, m1_tilde, unrevealed_attrs);
+                unrevealed_attrs: &HashSet<String>, acc_prime_term: Option<(&BigNumber, &BigNumber)>) -> Result<BigNumber, IndyCryptoError> {
+    trace!("Helpers::calc_teq: >>> issuer_pub_key: {:?}, issuer_pub_key: {:?}, e: {:?}, v: {:?}, m_tilde: {:?}, m1_tilde: {:?}, unrevealed_attrs: {:?}, acc_prime_term: {:?}",
+           issuer_pub_key, a_prime, e, v, m_tilde, m1_tilde, unrevealed_attrs, acc_prime_term);
 
     let mut ctx = BigNumber::new_context()?;
     let mut result: BigNumber = BigNumber::from_dec("1")?;
@@ -318,6 +331,12 @@ pub fn calc_teq(issuer_pub_key: &IssuerPrimaryPublicKey, a_prime: &BigNumber, e:
         .mod_exp(&m2tilde, &issuer_pub_key.n, Some(&mut ctx))?
         .mul(&result, Some(&mut ctx))?;
 
+    if let Some((base, exp)) = acc_prime_term {
+        result = base
+            .mod_exp(&exp, &issuer_pub_key.n, Some(&mut ctx))?
+            .mul(&result, Some(&mut ctx))?;
+    }
+
     result = a_prime
         .mod_exp(&e, &issuer_pub_key.n, Some(&mut ctx))?
         .mul(&result, Some(&mut ctx))?;
@@ -543,6 +562,263 @@ pub fn create_tau_list_values(issuer_r_pub_key: &IssuerRevocationPublicKey, accu
     Ok(non_revoc_proof_tau_list)
 }
 
+/// Derives weights of aggregated non-revocation proofs from hash committing to
+/// claim identifiers and c lists of all proofs. Weighted tau list enters proof challenge,
+/// so tau values can't be chosen after weights are known.
+pub fn non_revoc_proof_aggregation_weights(key_ids: &[String], c_lists: &[&NonRevocProofCList]) -> Result<Vec<GroupOrderElement>, IndyCryptoError> {
+    trace!("Helpers::non_revoc_proof_aggregation_weights: >>> key_ids: {:?}, c_lists: {:?}", key_ids, c_lists);
+
+    if key_ids.len() != c_lists.len() {
+        return Err(IndyCryptoError::InvalidStructure(format!("Number of c lists doesn't correspond to number of claims")));
+    }
+
+    let mut data = NON_REVOC_PROOF_AGGREGATION_DOMAIN.to_vec();
+
+    for (key_id, c_list) in key_ids.iter().zip(c_lists.iter()) {
+        data.extend_from_slice(&transform_u32_to_array_of_u8(key_id.len() as u32));
+        data.extend_from_slice(key_id.as_bytes());
+
+        for c in c_list.as_list()? {
+            data.extend_from_slice(&c);
+        }
+    }
+
+    let seed = BigNumber::hash(&data)?;
+    let mut weights: Vec<GroupOrderElement> = Vec::new();
+
+    for i in 0..key_ids.len() {
+        let mut weight_data = seed.clone();
+        weight_data.extend_from_slice(&transform_u32_to_array_of_u8(i as u32));
+
+        // Seed length depends on the curve, so SHA-256 output is extended by chaining if needed
+        let mut weight_seed = BigNumber::hash(&weight_data)?;
+        while weight_seed.len() < GroupOrderElement::BYTES_REPR_SIZE {
+            let block = BigNumber::hash(&weight_seed)?;
+            weight_seed.extend_from_slice(&block);
+        }
+        weight_seed.truncate(GroupOrderElement::BYTES_REPR_SIZE);
+
+        weights.push(GroupOrderElement::new_from_seed(&weight_seed)?);
+    }
+
+    trace!("Helpers::non_revoc_proof_aggregation_weights: <<< weights: {:?}", weights);
+
+    Ok(weights)
+}
+
+pub fn aggregate_tau_lists(tau_lists: &[&NonRevocProofTauList], weights: &[GroupOrderElement]) -> Result<NonRevocProofTauList, IndyCryptoError> {
+    trace!("Helpers::aggregate_tau_lists: >>> tau_lists: {:?}, weights: {:?}", tau_lists, weights);
+
+    let mut aggregated: Option<NonRevocProofTauList> = None;
+
+    for (tau_list, weight) in tau_lists.iter().zip(weights.iter()) {
+        aggregated = Some(match aggregated {
+            Some(agg) => NonRevocProofTauList {
+                t1: agg.t1.add(&tau_list.t1.mul(weight)?)?,
+                t2: agg.t2.add(&tau_list.t2.mul(weight)?)?,
+                t3: agg.t3.mul(&tau_list.t3.pow(weight)?)?,
+                t4: agg.t4.mul(&tau_list.t4.pow(weight)?)?,
+                t5: agg.t5.add(&tau_list.t5.mul(weight)?)?,
+                t6: agg.t6.add(&tau_list.t6.mul(weight)?)?,
+                t7: agg.t7.mul(&tau_list.t7.pow(weight)?)?,
+                t8: agg.t8.mul(&tau_list.t8.pow(weight)?)?
+            },
+            None => NonRevocProofTauList {
+                t1: tau_list.t1.mul(weight)?,
+                t2: tau_list.t2.mul(weight)?,
+                t3: tau_list.t3.pow(weight)?,
+                t4: tau_list.t4.pow(weight)?,
+                t5: tau_list.t5.mul(weight)?,
+                t6: tau_list.t6.mul(weight)?,
+                t7: tau_list.t7.pow(weight)?,
+                t8: tau_list.t8.pow(weight)?
+            }
+        });
+    }
+
+    let mut aggregated = aggregated
+        .ok_or(IndyCryptoError::InvalidStructure(format!("List of tau lists is empty")))?;
+
+    aggregated.t2 = normalize_inf(aggregated.t2)?;
+    aggregated.t6 = normalize_inf(aggregated.t6)?;
+
+    trace!("Helpers::aggregate_tau_lists: <<< aggregated: {:?}", aggregated);
+
+    Ok(aggregated)
+}
+
+/// Calculates the same values as product of tau lists of given non-revocation proofs
+/// (see `create_tau_list_expected_values` and `create_tau_list_values`) raised to weights,
+/// but combines points before pairing, so only `10 + proofs.len()` pairings are calculated.
+/// All proofs must be created against the same accumulator.
+pub fn create_aggregated_tau_list_values(issuer_r_pub_key: &IssuerRevocationPublicKey, accumulator: &RevocationAccumulator,
+                                         accum_pk: &RevocationAccumulatorPublicKey, c_hash: &GroupOrderElement,
+                                         proofs: &[&NonRevocProof], weights: &[GroupOrderElement]) -> Result<NonRevocProofTauList, IndyCryptoError> {
+    trace!("Helpers::create_aggregated_tau_list_values: >>> issuer_r_pub_key: {:?}, accumulator: {:?}, accum_pk: {:?}, c_hash: {:?}, weights: {:?}",
+           issuer_r_pub_key, accumulator, accum_pk, c_hash, weights);
+
+    let zero = GroupOrderElement::from_bytes(&transform_u32_to_array_of_u8(0))?;
+
+    let (mut w_sum, mut rho, mut r, mut r_prime, mut r_prime_prime_prime) = (zero, zero, zero, zero, zero);
+    let (mut o, mut o_prime, mut m, mut m_prime, mut t, mut t_prime, mut m2, mut s) = (zero, zero, zero, zero, zero, zero, zero, zero);
+
+    let (mut e_c, mut e_x, mut d_c, mut d_x) = (PointG1::new_inf()?, PointG1::new_inf()?, PointG1::new_inf()?, PointG1::new_inf()?);
+    let (mut a_c, mut a_x, mut g_c, mut h0_g_c, mut pk_g_x) = (PointG1::new_inf()?, PointG1::new_inf()?, PointG1::new_inf()?,
+                                                                PointG1::new_inf()?, PointG1::new_inf()?);
+    let (mut w_c, mut u_c) = (PointG2::new_inf()?, PointG2::new_inf()?);
+    let mut pair_s: Option<Pair> = None;
+
+    for (proof, weight) in proofs.iter().zip(weights.iter()) {
+        let x = &proof.x_list;
+        let c = &proof.c_list;
+        let cw = c_hash.mul_mod(weight)?;
+        let pk_g = issuer_r_pub_key.pk.add(&c.g)?;
+
+        w_sum = w_sum.add_mod(weight)?;
+        rho = rho.add_mod(&x.rho.mul_mod(weight)?)?;
+        r = r.add_mod(&x.r.mul_mod(weight)?)?;
+        r_prime = r_prime.add_mod(&x.r_prime.mul_mod(weight)?)?;
+        r_prime_prime_prime = r_prime_prime_prime.add_mod(&x.r_prime_prime_prime.mul_mod(weight)?)?;
+        o = o.add_mod(&x.o.mul_mod(weight)?)?;
+        o_prime = o_prime.add_mod(&x.o_prime.mul_mod(weight)?)?;
+        m = m.add_mod(&x.m.mul_mod(weight)?)?;
+        m_prime = m_prime.add_mod(&x.m_prime.mul_mod(weight)?)?;
+        t = t.add_mod(&x.t.mul_mod(weight)?)?;
+        t_prime = t_prime.add_mod(&x.t_prime.mul_mod(weight)?)?;
+        m2 = m2.add_mod(&x.m2.mul_mod(weight)?)?;
+        s = s.add_mod(&x.s.mul_mod(weight)?)?;
+
+        e_c = e_c.add(&c.e.mul(&cw)?)?;
+        e_x = e_x.add(&c.e.mul(&x.c.mul_mod(weight)?)?)?;
+        d_c = d_c.add(&c.d.mul(&cw)?)?;
+        d_x = d_x.add(&c.d.mul(&x.r_prime_prime.mul_mod(weight)?)?)?;
+        a_c = a_c.add(&c.a.mul(&cw)?)?;
+        a_x = a_x.add(&c.a.mul(&x.c.mul_mod(weight)?)?)?;
+        g_c = g_c.add(&c.g.mul(&cw)?)?;
+        h0_g_c = h0_g_c.add(&issuer_r_pub_key.h0.add(&c.g)?.mul(&cw)?)?;
+        pk_g_x = pk_g_x.add(&pk_g.mul(&x.r_prime_prime.mul_mod(weight)?)?)?;
+        w_c = w_c.add(&c.w.mul(&cw)?)?;
+        u_c = u_c.add(&c.u.mul(&cw)?)?;
+
+        let pair = Pair::pair(&issuer_r_pub_key.htilde.mul(&x.r.mul_mod(weight)?)?.add(&pk_g.mul(&cw)?)?, &c.s)?;
+        pair_s = Some(match pair_s {
+            Some(pair_s) => pair_s.mul(&pair)?,
+            None => pair
+        });
+    }
+
+    let pair_s = pair_s
+        .ok_or(IndyCryptoError::InvalidStructure(format!("List of non-revocation proofs is empty")))?;
+    let c_w_sum = c_hash.mul_mod(&w_sum)?;
+
+    let t1 = e_c
+        .add(&issuer_r_pub_key.h.mul(&rho)?)?
+        .add(&issuer_r_pub_key.htilde.mul(&o)?)?;
+    let t2 = e_x
+        .add(&issuer_r_pub_key.h.mul(&m.mod_neg()?)?)?
+        .add(&issuer_r_pub_key.htilde.mul(&t.mod_neg()?)?)?;
+    let t3 = Pair::pair(
+        &h0_g_c
+            .add(&a_x)?
+            .add(&issuer_r_pub_key.htilde.mul(&r.add_mod(&m.mod_neg()?)?)?)?
+            .add(&issuer_r_pub_key.h1.mul(&m2.mod_neg()?)?)?
+            .add(&issuer_r_pub_key.h2.mul(&s.mod_neg()?)?)?,
+        &issuer_r_pub_key.h_cap
+    )?
+        .mul(&Pair::pair(&a_c.add(&issuer_r_pub_key.htilde.mul(&rho)?)?, &issuer_r_pub_key.y)?.inverse()?)?;
+    let t4 = Pair::pair(&g_c.add(&issuer_r_pub_key.htilde.mul(&r)?)?, &accumulator.acc)?
+        .mul(&Pair::pair(&issuer_r_pub_key.g.neg()?.mul(&r_prime)?, &issuer_r_pub_key.h_cap)?)?
+        .mul(&Pair::pair(&issuer_r_pub_key.g, &w_c)?.mul(&accum_pk.z.pow(&c_w_sum)?)?.inverse()?)?;
+    let t5 = d_c
+        .add(&issuer_r_pub_key.g.mul(&r)?)?
+        .add(&issuer_r_pub_key.htilde.mul(&o_prime)?)?;
+    let t6 = d_x
+        .add(&issuer_r_pub_key.g.mul(&m_prime.mod_neg()?)?)?
+        .add(&issuer_r_pub_key.htilde.mul(&t_prime.mod_neg()?)?)?;
+    let t7 = Pair::pair(&pk_g_x.add(&issuer_r_pub_key.htilde.mul(&m_prime.mod_neg()?)?)?, &issuer_r_pub_key.h_cap)?
+        .mul(&pair_s)?
+        .mul(&Pair::pair(&issuer_r_pub_key.g, &issuer_r_pub_key.g_dash)?.pow(&c_w_sum)?.inverse()?)?;
+    let t8 = Pair::pair(&g_c.add(&issuer_r_pub_key.htilde.mul(&r)?)?, &issuer_r_pub_key.u)?
+        .mul(&Pair::pair(&issuer_r_pub_key.g.neg()?.mul(&r_prime_prime_prime)?, &issuer_r_pub_key.h_cap)?)?
+        .mul(&Pair::pair(&issuer_r_pub_key.g, &u_c)?.inverse()?)?;
+
+    let non_revoc_proof_tau_list = NonRevocProofTauList {
+        t1,
+        t2: normalize_inf(t2)?,
+        t3,
+        t4,
+        t5,
+        t6: normalize_inf(t6)?,
+        t7,
+        t8
+    };
+
+    trace!("Helpers::create_aggregated_tau_list_values: <<< non_revoc_proof_tau_list: {:?}", non_revoc_proof_tau_list);
+
+    Ok(non_revoc_proof_tau_list)
+}
+
+fn normalize_inf(point: PointG1) -> Result<PointG1, IndyCryptoError> {
+    if point.is_inf()? {
+        return PointG1::new_inf();
+    }
+    Ok(point)
+}
+
+pub fn pseudonym_base(scope: &str) -> Result<BigNumber, IndyCryptoError> {
+    trace!("Helpers::pseudonym_base: >>> scope: {:?}", scope);
+
+    let mut ctx = BigNumber::new_context()?;
+    let modulus = BigNumber::from_hex(PSEUDONYM_GROUP_MODULUS)?;
+
+    let mut bytes: Vec<u8> = Vec::new();
+
+    for i in 0..PSEUDONYM_BASE_HASH_BLOCKS {
+        let mut data = PSEUDONYM_SCOPE_DOMAIN.to_vec();
+        data.extend_from_slice(&transform_u32_to_array_of_u8(i));
+        data.extend_from_slice(scope.as_bytes());
+        bytes.extend_from_slice(&BigNumber::hash(&data)?);
+    }
+
+    // Squaring maps hash into subgroup of quadratic residues
+    let base = BigNumber::from_bytes(&bytes)?
+        .modulus(&modulus, Some(&mut ctx))?
+        .sqr(Some(&mut ctx))?
+        .modulus(&modulus, Some(&mut ctx))?;
+
+    trace!("Helpers::pseudonym_base: <<< base: {:?}", base);
+
+    Ok(base)
+}
+
+pub fn calc_pseudonym(scope: &str, exp: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+    trace!("Helpers::calc_pseudonym: >>> scope: {:?}, exp: {:?}", scope, exp);
+
+    let modulus = BigNumber::from_hex(PSEUDONYM_GROUP_MODULUS)?;
+    let pseudonym = pseudonym_base(scope)?.mod_exp(exp, &modulus, None)?;
+
+    trace!("Helpers::calc_pseudonym: <<< pseudonym: {:?}", pseudonym);
+
+    Ok(pseudonym)
+}
+
+pub fn is_pseudonym_group_element(value: &BigNumber) -> Result<bool, IndyCryptoError> {
+    trace!("Helpers::is_pseudonym_group_element: >>> value: {:?}", value);
+
+    let mut ctx = BigNumber::new_context()?;
+    let modulus = BigNumber::from_hex(PSEUDONYM_GROUP_MODULUS)?;
+    let one = BigNumber::from_u32(1)?;
+    let order = modulus.sub(&one)?.div(&BigNumber::from_u32(2)?, Some(&mut ctx))?;
+
+    let res = *value == value.modulus(&modulus, Some(&mut ctx))?
+        && *value != one
+        && value.mod_exp(&order, &modulus, Some(&mut ctx))? == one;
+
+    trace!("Helpers::is_pseudonym_group_element: <<< res: {:?}", res);
+
+    Ok(res)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -626,6 +902,13 @@ mod tests {
         assert_eq!(transform_u32_to_array_of_u8(int), answer)
     }
 
+    #[test]
+    fn transform_u64_to_array_of_u8_works() {
+        let int = 0x0102030474BA7445;
+        let answer = vec![0x01, 0x02, 0x03, 0x04, 0x74, 0xBA, 0x74, 0x45];
+        assert_eq!(transform_u64_to_array_of_u8(int), answer)
+    }
+
     #[test]
     #[ignore]
     fn test_encode_attribute_fail_simple_collision_on_internal_truncate() {
@@ -687,7 +970,7 @@ mod tests {
         let unrevealed_attrs = prover::mocks::unrevealed_attrs();
 
         let res = calc_teq(&pk, &proof.a_prime, &proof.e, &proof.v,
-                           &proof.m, &proof.m1, &proof.m2, &unrevealed_attrs
+                           &proof.m, &proof.m1, &proof.m2, &unrevealed_attrs, None
         );
 
         assert!(res.is_ok());
@@ -695,4 +978,25 @@ mod tests {
         550618350619463078448090619550539984321896578172169128335147589428402324781718901869549112532798652601463746685045212133193174381490524514911738520011287083602890709\
         48079960772659047566794177011062557693924377595447897825427041909910147539820909799749276641294365433984505662572279688664855362124712265751122800305172874190132079584439370708309080764145976583745638279272393589372240070072863129023320369502403543275214865690334236255622586065544807114732455123529", res.unwrap().to_dec().unwrap());
     }
+
+    #[test]
+    fn calc_pseudonym_works() {
+        let exp = BigNumber::from_dec("21578029250517794450984707538122537192839006240802068037273983354680998203845").unwrap();
+
+        let pseudonym = calc_pseudonym("example.com", &exp).unwrap();
+
+        assert!(is_pseudonym_group_element(&pseudonym).unwrap());
+        assert_eq!(pseudonym, calc_pseudonym("example.com", &exp).unwrap());
+        assert_ne!(pseudonym, calc_pseudonym("another.com", &exp).unwrap());
+    }
+
+    #[test]
+    fn is_pseudonym_group_element_works_for_non_residue() {
+        let modulus = BigNumber::from_hex(PSEUDONYM_GROUP_MODULUS).unwrap();
+        let minus_one = modulus.sub(&BigNumber::from_u32(1).unwrap()).unwrap();
+
+        assert!(!is_pseudonym_group_element(&minus_one).unwrap());
+        assert!(!is_pseudonym_group_element(&modulus).unwrap());
+        assert!(!is_pseudonym_group_element(&BigNumber::from_u32(1).unwrap()).unwrap());
+    }
 }
\ No newline at end of file
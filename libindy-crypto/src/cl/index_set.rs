@@ -0,0 +1,331 @@
+use errors::IndyCryptoError;
+
+#[cfg(feature = "serialization")]
+use serde::ser::{Serialize, Serializer};
+#[cfg(feature = "serialization")]
+use serde::de::{Deserialize, Deserializer, Visitor, SeqAccess, MapAccess, Error as DError};
+
+use std::collections::btree_map::{self, BTreeMap};
+use std::fmt;
+use std::iter::FromIterator;
+
+const WORD_BITS: u32 = 64;
+
+/// Set of accumulator indexes stored as sparse bitmap.
+///
+/// Only non-zero 64-bit words are kept, so memory and serialized size don't depend on values of indexes.
+/// Serialized as map of word position to word (bit `j` of word `i` is index `64 * i + j`).
+/// Dense hex bitmaps and JSON arrays of indexes produced by previous versions are accepted on deserialization.
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct IndexSet {
+    words: BTreeMap<u32, u64> /* word position -> non-zero word */
+}
+
+impl IndexSet {
+    pub fn new() -> IndexSet {
+        IndexSet { words: BTreeMap::new() }
+    }
+
+    /// Adds index to the set. Returns false if index was already present.
+    pub fn insert(&mut self, idx: u32) -> bool {
+        let (word, mask) = IndexSet::_position(idx);
+
+        let w = self.words.entry(word).or_insert(0);
+        let present = *w & mask != 0;
+        *w |= mask;
+        !present
+    }
+
+    /// Removes index from the set. Returns false if index wasn't present.
+    pub fn remove(&mut self, idx: u32) -> bool {
+        let (word, mask) = IndexSet::_position(idx);
+
+        let empty = match self.words.get_mut(&word) {
+            Some(w) if *w & mask != 0 => {
+                *w &= !mask;
+                *w == 0
+            }
+            _ => return false
+        };
+
+        if empty {
+            self.words.remove(&word);
+        }
+        true
+    }
+
+    /// Returns true if index is present in the set.
+    pub fn contains(&self, idx: u32) -> bool {
+        let (word, mask) = IndexSet::_position(idx);
+        self.words.get(&word).map(|w| w & mask != 0).unwrap_or(false)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.values().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn iter(&self) -> Iter {
+        Iter { words: self.words.iter(), word: 0, bits: 0 }
+    }
+
+    /// Returns indexes that are in this set but not in `other`. Computed word by word.
+    pub fn difference(&self, other: &IndexSet) -> IndexSet {
+        IndexSet {
+            words: self.words.iter()
+                .map(|(&i, w)| (i, w & !other.words.get(&i).cloned().unwrap_or(0)))
+                .filter(|&(_, w)| w != 0)
+                .collect()
+        }
+    }
+
+    /// Adds all indexes of `other` to this set.
+    pub fn union_with(&mut self, other: &IndexSet) {
+        for (&i, o) in other.words.iter() {
+            *self.words.entry(i).or_insert(0) |= *o;
+        }
+    }
+
+    /// Returns sparse binary representation: 4 bytes big-endian word position
+    /// and 8 bytes big-endian word for every non-zero word in ascending order.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(12 * self.words.len());
+
+        for (&i, &w) in self.words.iter() {
+            bytes.extend((0..4).rev().map(|k| (i >> (8 * k)) as u8));
+            bytes.extend((0..8).rev().map(|k| (w >> (8 * k)) as u8));
+        }
+
+        bytes
+    }
+
+    /// Parses dense hex bitmap (bit `j` of byte `k` is index `8 * k + j`) used by previous versions.
+    pub fn from_hex(hex: &str) -> Result<IndexSet, IndyCryptoError> {
+        if hex.len() % 2 != 0 || !hex.bytes().all(|c| (c as char).is_digit(16)) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid index set bitmap: {}", hex)));
+        }
+
+        let mut res = IndexSet::new();
+
+        for k in 0..hex.len() / 2 {
+            let byte = u8::from_str_radix(&hex[2 * k..2 * k + 2], 16)
+                .map_err(|_| IndyCryptoError::InvalidStructure(format!("Invalid index set bitmap: {}", hex)))?;
+
+            if byte != 0 {
+                *res.words.entry((k / 8) as u32).or_insert(0) |= (byte as u64) << (8 * (k % 8));
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn _position(idx: u32) -> (u32, u64) {
+        (idx / WORD_BITS, 1u64 << (idx % WORD_BITS))
+    }
+}
+
+pub struct Iter<'a> {
+    words: btree_map::Iter<'a, u32, u64>,
+    word: u32,
+    bits: u64,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        while self.bits == 0 {
+            match self.words.next() {
+                Some((&word, &bits)) => {
+                    self.word = word;
+                    self.bits = bits;
+                }
+                None => return None
+            }
+        }
+
+        let bit = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+
+        Some(self.word * WORD_BITS + bit)
+    }
+}
+
+impl<'a> IntoIterator for &'a IndexSet {
+    type Item = u32;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl FromIterator<u32> for IndexSet {
+    fn from_iter<I: IntoIterator<Item=u32>>(iter: I) -> IndexSet {
+        let mut res = IndexSet::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl Extend<u32> for IndexSet {
+    fn extend<I: IntoIterator<Item=u32>>(&mut self, iter: I) {
+        for idx in iter {
+            self.insert(idx);
+        }
+    }
+}
+
+impl fmt::Debug for IndexSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for IndexSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.collect_map(self.words.iter())
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for IndexSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct IndexSetVisitor;
+
+        impl<'a> Visitor<'a> for IndexSetVisitor {
+            type Value = IndexSet;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected map of bitmap words, bitmap hex string or array of indexes")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<IndexSet, E>
+                where E: DError
+            {
+                Ok(IndexSet::from_hex(value).map_err(DError::custom)?)
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<IndexSet, A::Error>
+                where A: MapAccess<'a>
+            {
+                let mut res = IndexSet::new();
+                while let Some((i, w)) = map.next_entry::<u32, u64>()? {
+                    if w != 0 {
+                        *res.words.entry(i).or_insert(0) |= w;
+                    }
+                }
+                Ok(res)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<IndexSet, A::Error>
+                where A: SeqAccess<'a>
+            {
+                let mut res = IndexSet::new();
+                while let Some(idx) = seq.next_element::<u32>()? {
+                    res.insert(idx);
+                }
+                Ok(res)
+            }
+        }
+
+        deserializer.deserialize_any(IndexSetVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn index_set_works() {
+        let mut set = IndexSet::new();
+        assert!(set.is_empty());
+
+        assert!(set.insert(1));
+        assert!(set.insert(64));
+        assert!(set.insert(200));
+        assert!(!set.insert(64));
+
+        assert_eq!(3, set.len());
+        assert!(set.contains(64));
+        assert!(!set.contains(2));
+        assert!(!set.contains(100000));
+        assert_eq!(vec![1, 64, 200], set.iter().collect::<Vec<u32>>());
+
+        assert!(set.remove(200));
+        assert!(!set.remove(200));
+        assert_eq!(IndexSet::from_iter(vec![64, 1]), set);
+    }
+
+    #[test]
+    fn index_set_difference_works() {
+        let a = IndexSet::from_iter(vec![1, 2, 3, 70, 130]);
+        let b = IndexSet::from_iter(vec![2, 70, 500]);
+
+        assert_eq!(IndexSet::from_iter(vec![1, 3, 130]), a.difference(&b));
+        assert_eq!(IndexSet::from_iter(vec![500]), b.difference(&a));
+        assert!(a.difference(&a).is_empty());
+
+        let mut c = a.clone();
+        c.union_with(&b);
+        assert_eq!(IndexSet::from_iter(vec![1, 2, 3, 70, 130, 500]), c);
+    }
+
+    #[test]
+    fn index_set_serialization_works() {
+        let set = IndexSet::from_iter(vec![0, 1, 9, 100]);
+
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(r#"{"0":515,"1":68719476736}"#, json);
+        assert_eq!(set, serde_json::from_str::<IndexSet>(&json).unwrap());
+
+        assert_eq!("{}", serde_json::to_string(&IndexSet::new()).unwrap());
+    }
+
+    #[test]
+    fn index_set_deserialization_works_for_hex_bitmap() {
+        let set: IndexSet = serde_json::from_str("\"03020000000000000000000010\"").unwrap();
+        assert_eq!(IndexSet::from_iter(vec![0, 1, 9, 100]), set);
+
+        let set: IndexSet = serde_json::from_str("\"\"").unwrap();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn index_set_deserialization_works_for_indexes_array() {
+        let set: IndexSet = serde_json::from_str("[100, 0, 9, 1]").unwrap();
+        assert_eq!(IndexSet::from_iter(vec![0, 1, 9, 100]), set);
+
+        let set: IndexSet = serde_json::from_str("[]").unwrap();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn index_set_deserialization_works_for_invalid_bitmap() {
+        assert!(serde_json::from_str::<IndexSet>("\"0\"").is_err());
+        assert!(serde_json::from_str::<IndexSet>("\"zz\"").is_err());
+        assert!(serde_json::from_str::<IndexSet>("\"+f\"").is_err());
+        assert!(serde_json::from_str::<IndexSet>("\"-1\"").is_err());
+    }
+
+    #[test]
+    fn index_set_works_for_large_index() {
+        let set: IndexSet = serde_json::from_str("[4294967295, 3]").unwrap();
+
+        assert_eq!(2, set.words.len());
+        assert!(set.contains(4294967295));
+        assert_eq!(vec![3, 4294967295], set.iter().collect::<Vec<u32>>());
+        assert_eq!(IndexSet::from_iter(vec![3]), set.difference(&IndexSet::from_iter(vec![4294967295])));
+
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(r#"{"0":8,"67108863":9223372036854775808}"#, json);
+        assert_eq!(set, serde_json::from_str::<IndexSet>(&json).unwrap());
+        assert_eq!(24, set.to_bytes().len());
+    }
+}
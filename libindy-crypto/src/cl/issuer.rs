@@ -1,11 +1,19 @@
+use bls::{Bls, SignKey};
 use bn::BigNumber;
 use cl::*;
 use errors::IndyCryptoError;
 use pair::*;
 use cl::constants::*;
 use cl::helpers::*;
+use cl::index_set::IndexSet;
+use cl::rsa_accumulator::{self, RsaRevocationRegistry};
+use cl::tails::{self, TailsReader};
+#[cfg(feature = "serialization")]
+use utils::encrypted_json::EncryptedJson;
+use utils::json::{JsonEncodable, JsonDecodable};
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
+use std::io::Write;
 
 /// Trust source that provides credentials to prover.
 pub struct Issuer {}
@@ -47,22 +55,42 @@ impl Issuer {
     pub fn new_keys(claim_schema: &ClaimSchema, non_revocation_part: bool) -> Result<(IssuerPublicKey, IssuerPrivateKey), IndyCryptoError> {
         trace!("Issuer::new_keys: >>> claim_schema: {:?}, non_revocation_part: {:?}", claim_schema, non_revocation_part);
 
-        let (p_pub_key, p_priv_key) = Issuer::_new_primary_keys(claim_schema)?;
-
-        let (r_pub_key, r_priv_key) = if non_revocation_part {
-            let (r_pub_key, r_priv_key) = Issuer::_new_revocation_keys()?;
-            (Some(r_pub_key), Some(r_priv_key))
-        } else {
-            (None, None)
-        };
+        let revocation_backend = if non_revocation_part { Some(RevocationBackend::Pairing) } else { None };
+        let (issuer_pub_key, issuer_priv_key) = Issuer::_new_keys(claim_schema, revocation_backend)?;
 
-        let issuer_pub_key = IssuerPublicKey { p_key: p_pub_key, r_key: r_pub_key };
-        let issuer_priv_key = IssuerPrivateKey { p_key: p_priv_key, r_key: r_priv_key };
         trace!("Issuer::new_keys: <<< issuer_pub_key: {:?}, issuer_priv_key: {:?}", issuer_pub_key, issuer_priv_key);
 
         Ok((issuer_pub_key, issuer_priv_key))
     }
 
+    /// Creates and returns issuer keys (public and private) entities with non revocation part
+    /// for given revocation backend.
+    ///
+    /// # Arguments
+    /// * `claim_schema` - claim schema entity.
+    /// * `revocation_backend` - Revocation scheme non revocation part of keys is generated for.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::RevocationBackend;
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    /// let (pub_key, _priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+    /// assert_eq!(Some(RevocationBackend::Rsa), pub_key.get_revocation_backend());
+    /// ```
+    pub fn new_keys_with_revocation_backend(claim_schema: &ClaimSchema,
+                                            revocation_backend: RevocationBackend) -> Result<(IssuerPublicKey, IssuerPrivateKey), IndyCryptoError> {
+        trace!("Issuer::new_keys_with_revocation_backend: >>> claim_schema: {:?}, revocation_backend: {:?}", claim_schema, revocation_backend);
+
+        let (issuer_pub_key, issuer_priv_key) = Issuer::_new_keys(claim_schema, Some(revocation_backend))?;
+
+        trace!("Issuer::new_keys_with_revocation_backend: <<< issuer_pub_key: {:?}, issuer_priv_key: {:?}", issuer_pub_key, issuer_priv_key);
+
+        Ok((issuer_pub_key, issuer_priv_key))
+    }
+
     /// Creates and returns revocation registries (public and private) entities.
     ///
     /// # Arguments
@@ -77,11 +105,12 @@ impl Issuer {
     /// claim_schema_builder.add_attr("name").unwrap();
     /// let claim_schema = claim_schema_builder.finalize().unwrap();
     /// let (pub_key, _priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
-    /// let (_rev_reg_pub, _rev_reg_priv) = Issuer::new_revocation_registry(&pub_key, 100).unwrap();
+    /// let (_rev_reg_pub, _rev_reg_priv, _rev_tails) = Issuer::new_revocation_registry(&pub_key, 100).unwrap();
     /// ```
     pub fn new_revocation_registry(issuer_pub_key: &IssuerPublicKey,
                                    max_claim_num: u32) -> Result<(RevocationRegistryPublic,
-                                                                  RevocationRegistryPrivate), IndyCryptoError> {
+                                                                  RevocationRegistryPrivate,
+                                                                  RevocationAccumulatorTails), IndyCryptoError> {
         trace!("Issuer::new_revocation_registry: >>> issuer_pub_key: {:?}, max_claim_num: {:?}", issuer_pub_key, max_claim_num);
 
         let r_pub_key = issuer_pub_key.r_key
@@ -92,39 +121,151 @@ impl Issuer {
         let gamma = GroupOrderElement::new()?;
         let mut g_dash: HashMap<u32, PointG2> = HashMap::new();
 
+        let mut pow = GroupOrderElement::from_bytes(&transform_u32_to_array_of_u8(1))?;
+
         for i in 0..(2 * max_claim_num) {
             if i != max_claim_num + 1 {
-                let i_bytes = transform_u32_to_array_of_u8(i);
-                let mut pow = GroupOrderElement::from_bytes(&i_bytes)?;
-                pow = gamma.pow_mod(&pow)?;
                 g.insert(i, r_pub_key.g.mul(&pow)?);
                 g_dash.insert(i, r_pub_key.g_dash.mul(&pow)?);
             }
+            pow = pow.mul_mod(&gamma)?;
         }
 
-        let mut z = Pair::pair(&r_pub_key.g, &r_pub_key.g_dash)?;
-        let mut pow = GroupOrderElement::from_bytes(&transform_u32_to_array_of_u8(max_claim_num + 1))?;
-        pow = gamma.pow_mod(&pow)?;
-        z = z.pow(&pow)?;
-        let acc = PointG2::new_inf()?;
-        let v: HashSet<u32> = HashSet::new();
-
-        let rev_reg_pub = RevocationRegistryPublic {
-            acc: RevocationAccumulator { acc, v, max_claim_num },
-            key: RevocationAccumulatorPublicKey { z },
-            tails: RevocationAccumulatorTails { tails: g, tails_dash: g_dash },
+        let rev_tails = RevocationAccumulatorTails { tails: g, tails_dash: g_dash };
+        let tails_hash = tails::tails_hash(&rev_tails, max_claim_num)?;
 
+        let rev_reg_priv = RevocationRegistryPrivate {
+            key: RevocationAccumulatorPrivateKey { gamma },
         };
+        let rev_reg_pub = Issuer::_new_revocation_registry(r_pub_key, &rev_reg_priv, max_claim_num, tails_hash)?;
 
+        trace!("Issuer::new_revocation_registry: <<< rev_reg_pub: {:?}, rev_reg_priv: {:?}, rev_tails: {:?}", rev_reg_pub, rev_reg_priv, rev_tails);
+
+        Ok((rev_reg_pub, rev_reg_priv, rev_tails))
+    }
+
+    /// Creates and returns revocation registries (public and private) entities.
+    /// Tails are generated by a pool of worker threads and streamed to writer in format readable
+    /// by `tails::FileTailsReader`, so they are never held in memory all at once.
+    ///
+    /// # Arguments
+    /// * `issuer_pub_key` - Issuer pub key instance pointer.
+    /// * `max_claim_num` - Max claim number in generated registry.
+    /// * `threads` - Number of tails generation threads.
+    /// * `tails_writer` - Tails destination.
+    /// * `progress` - (Optional) Callback that receives numbers of written and total tails records.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    /// let (pub_key, _priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+    /// let mut tails: Vec<u8> = Vec::new();
+    /// let (_rev_reg_pub, _rev_reg_priv) =
+    ///     Issuer::new_revocation_registry_with_tails_writer(&pub_key, 100, 4, &mut tails, None).unwrap();
+    /// ```
+    pub fn new_revocation_registry_with_tails_writer<W: Write>(issuer_pub_key: &IssuerPublicKey,
+                                                               max_claim_num: u32,
+                                                               threads: usize,
+                                                               tails_writer: &mut W,
+                                                               progress: Option<&mut FnMut(u32, u32)>) -> Result<(RevocationRegistryPublic,
+                                                                                                                  RevocationRegistryPrivate), IndyCryptoError> {
+        trace!("Issuer::new_revocation_registry_with_tails_writer: >>> issuer_pub_key: {:?}, max_claim_num: {:?}, threads: {:?}",
+               issuer_pub_key, max_claim_num, threads);
+
+        let r_pub_key = issuer_pub_key.r_key
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("No revocation part present in issuer key.")))?;
+
+        let gamma = GroupOrderElement::new()?;
         let rev_reg_priv = RevocationRegistryPrivate {
             key: RevocationAccumulatorPrivateKey { gamma },
         };
 
-        trace!("Issuer::new_revocation_registry: <<< rev_reg_pub: {:?}, rev_reg_priv: {:?}", rev_reg_pub, rev_reg_priv);
+        let tails_hash = tails::generate_tails(r_pub_key, &rev_reg_priv, max_claim_num, threads, tails_writer, progress)?;
+
+        let rev_reg_pub = Issuer::_new_revocation_registry(r_pub_key, &rev_reg_priv, max_claim_num, tails_hash)?;
+
+        trace!("Issuer::new_revocation_registry_with_tails_writer: <<< rev_reg_pub: {:?}, rev_reg_priv: {:?}", rev_reg_pub, rev_reg_priv);
 
         Ok((rev_reg_pub, rev_reg_priv))
     }
 
+    /// Creates and returns revocation registry manager and private keys of managed registries.
+    ///
+    /// The purpose of revocation registry manager is allocation of revocation indexes,
+    /// tracking of issued and revoked claims and creation of new revocation registry
+    /// when the current one is full.
+    ///
+    /// # Arguments
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `max_claim_num` - Max claim number in each managed revocation registry.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    /// let (pub_key, _priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+    /// let (_rev_reg_manager, _rev_reg_manager_priv) = Issuer::new_revocation_registry_manager(&pub_key, 5).unwrap();
+    /// ```
+    pub fn new_revocation_registry_manager(issuer_pub_key: &IssuerPublicKey,
+                                           max_claim_num: u32) -> Result<(RevocationRegistryManager,
+                                                                          RevocationRegistryManagerPrivate), IndyCryptoError> {
+        trace!("Issuer::new_revocation_registry_manager: >>> issuer_pub_key: {:?}, max_claim_num: {:?}", issuer_pub_key, max_claim_num);
+
+        let mut rev_reg_manager = RevocationRegistryManager {
+            issuer_pub_key: issuer_pub_key.clone()?,
+            max_claim_num,
+            registries: Vec::new()
+        };
+        let mut rev_reg_manager_priv = RevocationRegistryManagerPrivate {
+            registries: Vec::new()
+        };
+        rev_reg_manager._add_registry(&mut rev_reg_manager_priv)?;
+
+        trace!("Issuer::new_revocation_registry_manager: <<< rev_reg_manager: {:?}, rev_reg_manager_priv: {:?}", rev_reg_manager, rev_reg_manager_priv);
+
+        Ok((rev_reg_manager, rev_reg_manager_priv))
+    }
+
+    /// Creates and returns RSA revocation registry entity.
+    ///
+    /// Registry is public and is used instead of revocation registry and tails
+    /// when issuer keys were generated for `RevocationBackend::Rsa`.
+    ///
+    /// # Arguments
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `max_claim_num` - Max claim number in generated registry.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::RevocationBackend;
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    /// let (pub_key, _priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+    /// let _rsa_rev_reg = Issuer::new_rsa_revocation_registry(&pub_key, 100).unwrap();
+    /// ```
+    pub fn new_rsa_revocation_registry(issuer_pub_key: &IssuerPublicKey,
+                                       max_claim_num: u32) -> Result<RsaRevocationRegistry, IndyCryptoError> {
+        trace!("Issuer::new_rsa_revocation_registry: >>> issuer_pub_key: {:?}, max_claim_num: {:?}", issuer_pub_key, max_claim_num);
+
+        let rsa_r_key = issuer_pub_key.rsa_r_key
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in issuer key.")))?;
+
+        let rsa_rev_reg = rsa_accumulator::new_registry(rsa_r_key, max_claim_num)?;
+
+        trace!("Issuer::new_rsa_revocation_registry: <<< rsa_rev_reg: {:?}", rsa_rev_reg);
+
+        Ok(rsa_rev_reg)
+    }
+
     /// Creates and returns claims values entity builder.
     ///
     /// The purpose of claim values builder is building of claim values entity that
@@ -154,6 +295,7 @@ impl Issuer {
     /// * `rev_idx` - (Optional) User index in revocation accumulator. Required for non-revocation claim_signature part generation.
     /// * `rev_reg_pub` - (Optional) Revocation registry public.
     /// * `rev_reg_priv` - (Optional) Revocation registry private.
+    /// * `rev_tails_reader` - (Optional) Revocation accumulator tails reader.
     ///
     /// # Example
     /// ```
@@ -176,7 +318,7 @@ impl Issuer {
     ///                                           &claim_values,
     ///                                           &pub_key,
     ///                                           &priv_key,
-    ///                                           None, None, None).unwrap();
+    ///                                           None, None, None, None).unwrap();
     /// ```
     pub fn sign_claim(prover_id: &str,
                       blinded_ms: &BlindedMasterSecret,
@@ -185,42 +327,123 @@ impl Issuer {
                       issuer_priv_key: &IssuerPrivateKey,
                       rev_idx: Option<u32>,
                       rev_reg_pub: Option<&mut RevocationRegistryPublic>,
-                      rev_reg_priv: Option<&RevocationRegistryPrivate>) -> Result<ClaimSignature, IndyCryptoError> {
+                      rev_reg_priv: Option<&RevocationRegistryPrivate>,
+                      rev_tails_reader: Option<&TailsReader>) -> Result<ClaimSignature, IndyCryptoError> {
         trace!("Issuer::sign_claim: >>> prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, issuer_priv_key: {:?}, rev_idx: {:?}, \
         rev_reg_pub: {:?}, rev_reg_priv: {:?}", prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rev_reg_pub, rev_reg_priv);
 
+        let (claim_signature, rev_reg_delta) = Issuer::sign_claim_with_delta(prover_id,
+                                                                             blinded_ms,
+                                                                             claim_values,
+                                                                             issuer_pub_key,
+                                                                             issuer_priv_key,
+                                                                             rev_idx,
+                                                                             rev_reg_pub.as_ref().map(|r_reg_pub| &**r_reg_pub),
+                                                                             rev_reg_priv,
+                                                                             rev_tails_reader)?;
+
+        if let (Some(r_reg_pub), Some(r_reg_delta)) = (rev_reg_pub, rev_reg_delta) {
+            r_reg_pub.apply_delta(&r_reg_delta)?;
+        }
+
+        trace!("Issuer::sign_claim: <<< claim_signature: {:?}", claim_signature);
+
+        Ok(claim_signature)
+    }
+
+    /// Sign given claim values instance without modification of revocation registry.
+    ///
+    /// Returns claim signature and (if non-revocation part was generated) revocation registry delta
+    /// that must be applied to revocation registry public by `RevocationRegistryPublic::apply_delta`.
+    /// It allows caller to persist claim signature and registry changes atomically.
+    ///
+    /// # Arguments
+    /// * `prover_id` - Prover identifier.
+    /// * `blinded_ms` - Blinded master secret.
+    /// * `claim_values` - Claim values to be signed.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `issuer_priv_key` - Issuer private key.
+    /// * `rev_idx` - (Optional) User index in revocation accumulator. Required for non-revocation claim_signature part generation.
+    /// * `rev_reg_pub` - (Optional) Revocation registry public.
+    /// * `rev_reg_priv` - (Optional) Revocation registry private.
+    /// * `rev_tails_reader` - (Optional) Revocation accumulator tails reader.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// use indy_crypto::cl::prover::Prover;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    ///
+    /// let (pub_key, priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+    /// let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&pub_key, 5).unwrap();
+    /// let master_secret = Prover::new_master_secret().unwrap();
+    /// let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+    ///
+    /// let mut claim_values_builder = Issuer::new_claim_values_builder().unwrap();
+    /// claim_values_builder.add_value("sex", "5944657099558967239210949258394887428692050081607692519917050011144233115103").unwrap();
+    /// let claim_values = claim_values_builder.finalize().unwrap();
+    ///
+    /// let (_claim_signature, rev_reg_delta) = Issuer::sign_claim_with_delta("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW",
+    ///                                                                       &blinded_master_secret,
+    ///                                                                       &claim_values,
+    ///                                                                       &pub_key,
+    ///                                                                       &priv_key,
+    ///                                                                       Some(1), Some(&rev_reg_pub), Some(&rev_reg_priv), Some(&rev_tails)).unwrap();
+    ///
+    /// rev_reg_pub.apply_delta(&rev_reg_delta.unwrap()).unwrap();
+    /// ```
+    pub fn sign_claim_with_delta(prover_id: &str,
+                                 blinded_ms: &BlindedMasterSecret,
+                                 claim_values: &ClaimValues,
+                                 issuer_pub_key: &IssuerPublicKey,
+                                 issuer_priv_key: &IssuerPrivateKey,
+                                 rev_idx: Option<u32>,
+                                 rev_reg_pub: Option<&RevocationRegistryPublic>,
+                                 rev_reg_priv: Option<&RevocationRegistryPrivate>,
+                                 rev_tails_reader: Option<&TailsReader>) -> Result<(ClaimSignature,
+                                                                                    Option<RevocationRegistryDelta>), IndyCryptoError> {
+        trace!("Issuer::sign_claim_with_delta: >>> prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, issuer_priv_key: {:?}, \
+        rev_idx: {:?}, rev_reg_pub: {:?}, rev_reg_priv: {:?}", prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rev_reg_pub, rev_reg_priv);
+
         let m_2 = Issuer::_calc_m2(prover_id, rev_idx)?;
 
         let p_claim = Issuer::_new_primary_claim(&m_2,
                                                  issuer_pub_key,
                                                  issuer_priv_key,
                                                  blinded_ms,
-                                                 claim_values)?;
-
-        let r_claim = if let (Some(rev_idx_2), Some(r_reg_pub), Some(r_reg_priv)) = (rev_idx, rev_reg_pub, rev_reg_priv) {
-            Some(Issuer::_new_non_revocation_claim(rev_idx_2,
-                                                   &m_2,
-                                                   blinded_ms,
-                                                   issuer_pub_key,
-                                                   issuer_priv_key,
-                                                   r_reg_pub,
-                                                   r_reg_priv)?)
+                                                 claim_values,
+                                                 None)?;
+
+        let (r_claim, rev_reg_delta) = if let (Some(rev_idx_2), Some(r_reg_pub), Some(r_reg_priv), Some(r_tails_reader)) =
+            (rev_idx, rev_reg_pub, rev_reg_priv, rev_tails_reader) {
+            let (r_claim, r_reg_delta) = Issuer::_new_non_revocation_claim(rev_idx_2,
+                                                                           &m_2,
+                                                                           blinded_ms,
+                                                                           issuer_pub_key,
+                                                                           issuer_priv_key,
+                                                                           r_reg_pub,
+                                                                           r_reg_priv,
+                                                                           r_tails_reader)?;
+            (Some(r_claim), Some(r_reg_delta))
         } else {
-            None
+            (None, None)
         };
 
-        let claim_signature = ClaimSignature { p_claim, r_claim };
+        let claim_signature = ClaimSignature { p_claim, r_claim, rsa_r_claim: None };
 
-        trace!("Issuer::sign_claim: <<< claim_signature: {:?}", claim_signature);
+        trace!("Issuer::sign_claim_with_delta: <<< claim_signature: {:?}, rev_reg_delta: {:?}", claim_signature, rev_reg_delta);
 
-        Ok(claim_signature)
+        Ok((claim_signature, rev_reg_delta))
     }
 
     /// Revokes a claim by a revoc_id in a given revoc-registry
     ///
     /// # Arguments
     /// * `rev_reg_pub` - Reference that contain revocation registry instance pointer.
-    ///  * rev_idx` - index of the user in the accumulator
+    /// * `rev_idx` - index of the user in the accumulator
+    /// * `rev_tails_reader` - Revocation accumulator tails reader.
     ///
     /// # Example
     /// ```
@@ -231,7 +454,7 @@ impl Issuer {
     /// let claim_schema = claim_schema_builder.finalize().unwrap();
     ///
     /// let (pub_key, priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
-    /// let (mut rev_reg_pub, rev_reg_priv) = Issuer::new_revocation_registry(&pub_key, 1).unwrap();
+    /// let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&pub_key, 1).unwrap();
     /// let master_secret = Prover::new_master_secret().unwrap();
     /// let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
     ///
@@ -244,14 +467,15 @@ impl Issuer {
     ///                                           &claim_values,
     ///                                           &pub_key,
     ///                                           &priv_key,
-    ///                                           Some(1), Some(&mut rev_reg_pub), Some(&rev_reg_priv)).unwrap();
-    /// Issuer::revoke_claim(&mut rev_reg_pub, 1).unwrap();
+    ///                                           Some(1), Some(&mut rev_reg_pub), Some(&rev_reg_priv), Some(&rev_tails)).unwrap();
+    /// Issuer::revoke_claim(&mut rev_reg_pub, 1, &rev_tails).unwrap();
     /// ```
     pub fn revoke_claim(rev_reg_pub: &mut RevocationRegistryPublic,
-                        rev_idx: u32) -> Result<(), IndyCryptoError> {
+                        rev_idx: u32,
+                        rev_tails_reader: &TailsReader) -> Result<(), IndyCryptoError> {
         trace!("Issuer::revoke_claim: >>> rev_reg_pub: {:?}, rev_idx: {:?}", rev_reg_pub, rev_idx);
 
-        if !rev_reg_pub.acc.v.remove(&rev_idx) {
+        if !rev_reg_pub.acc.v.remove(rev_idx) {
             return Err(IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(
                 format!("User index:{} not found in Accumulator", rev_idx))
             );
@@ -259,17 +483,215 @@ impl Issuer {
 
         let index: u32 = rev_reg_pub.acc.max_claim_num + 1 - rev_idx;
 
-        let element = rev_reg_pub.tails.tails_dash
-            .get(&index)
-            .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in g", index)))?;
+        let element = rev_tails_reader.get_tail_dash(index)?;
 
-        rev_reg_pub.acc.acc = rev_reg_pub.acc.acc.sub(element)?;
+        rev_reg_pub.acc.acc = rev_reg_pub.acc.acc.sub(&element)?;
+        rev_reg_pub.version += 1;
 
         trace!("Issuer::revoke_claim: <<<");
 
         Ok(())
     }
 
+    /// Sign given claim values instance and add claim to RSA revocation registry.
+    ///
+    /// Prime assigned to revocation index is signed in primary claim signature as separate
+    /// hidden attribute, so non-revocation proof is bound to the claim. `m2` is bound to prover
+    /// identifier and revocation index as for other claims.
+    ///
+    /// # Arguments
+    /// * `prover_id` - Prover identifier.
+    /// * `blinded_ms` - Blinded master secret.
+    /// * `claim_values` - Claim values to be signed.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `issuer_priv_key` - Issuer private key.
+    /// * `rev_idx` - User index in RSA revocation registry.
+    /// * `rsa_rev_reg` - RSA revocation registry.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::RevocationBackend;
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// use indy_crypto::cl::prover::Prover;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    ///
+    /// let (pub_key, priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+    /// let mut rsa_rev_reg = Issuer::new_rsa_revocation_registry(&pub_key, 5).unwrap();
+    /// let master_secret = Prover::new_master_secret().unwrap();
+    /// let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+    ///
+    /// let mut claim_values_builder = Issuer::new_claim_values_builder().unwrap();
+    /// claim_values_builder.add_value("sex", "5944657099558967239210949258394887428692050081607692519917050011144233115103").unwrap();
+    /// let claim_values = claim_values_builder.finalize().unwrap();
+    ///
+    /// let _claim_signature = Issuer::sign_claim_with_rsa_revocation("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW",
+    ///                                                               &blinded_master_secret,
+    ///                                                               &claim_values,
+    ///                                                               &pub_key,
+    ///                                                               &priv_key,
+    ///                                                               1,
+    ///                                                               &mut rsa_rev_reg).unwrap();
+    /// ```
+    pub fn sign_claim_with_rsa_revocation(prover_id: &str,
+                                          blinded_ms: &BlindedMasterSecret,
+                                          claim_values: &ClaimValues,
+                                          issuer_pub_key: &IssuerPublicKey,
+                                          issuer_priv_key: &IssuerPrivateKey,
+                                          rev_idx: u32,
+                                          rsa_rev_reg: &mut RsaRevocationRegistry) -> Result<ClaimSignature, IndyCryptoError> {
+        trace!("Issuer::sign_claim_with_rsa_revocation: >>> prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, issuer_priv_key: {:?}, \
+        rev_idx: {:?}, rsa_rev_reg: {:?}", prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rsa_rev_reg);
+
+        let rsa_r_key = issuer_pub_key.rsa_r_key
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in issuer key.")))?;
+
+        let m_2 = Issuer::_calc_m2(prover_id, Some(rev_idx))?;
+        let acc_prime_term = rsa_accumulator::primary_signature_term(rsa_r_key, rev_idx)?;
+
+        let p_claim = Issuer::_new_primary_claim(&m_2,
+                                                 issuer_pub_key,
+                                                 issuer_priv_key,
+                                                 blinded_ms,
+                                                 claim_values,
+                                                 Some(&acc_prime_term))?;
+
+        let rsa_r_claim = rsa_accumulator::add(rsa_rev_reg, rsa_r_key, rev_idx)?;
+
+        let claim_signature = ClaimSignature { p_claim, r_claim: None, rsa_r_claim: Some(rsa_r_claim) };
+
+        trace!("Issuer::sign_claim_with_rsa_revocation: <<< claim_signature: {:?}", claim_signature);
+
+        Ok(claim_signature)
+    }
+
+    /// Revokes a claim by a revocation index in a given RSA revocation registry.
+    ///
+    /// # Arguments
+    /// * `rsa_rev_reg` - RSA revocation registry.
+    /// * `rev_idx` - index of the user in the accumulator.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `issuer_priv_key` - Issuer private key.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::RevocationBackend;
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// use indy_crypto::cl::prover::Prover;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    ///
+    /// let (pub_key, priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+    /// let mut rsa_rev_reg = Issuer::new_rsa_revocation_registry(&pub_key, 5).unwrap();
+    /// let master_secret = Prover::new_master_secret().unwrap();
+    /// let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+    ///
+    /// let mut claim_values_builder = Issuer::new_claim_values_builder().unwrap();
+    /// claim_values_builder.add_value("sex", "5944657099558967239210949258394887428692050081607692519917050011144233115103").unwrap();
+    /// let claim_values = claim_values_builder.finalize().unwrap();
+    ///
+    /// let _claim_signature = Issuer::sign_claim_with_rsa_revocation("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", &blinded_master_secret,
+    ///                                                               &claim_values, &pub_key, &priv_key, 1, &mut rsa_rev_reg).unwrap();
+    /// Issuer::revoke_rsa_claim(&mut rsa_rev_reg, 1, &pub_key, &priv_key).unwrap();
+    /// ```
+    pub fn revoke_rsa_claim(rsa_rev_reg: &mut RsaRevocationRegistry,
+                            rev_idx: u32,
+                            issuer_pub_key: &IssuerPublicKey,
+                            issuer_priv_key: &IssuerPrivateKey) -> Result<(), IndyCryptoError> {
+        trace!("Issuer::revoke_rsa_claim: >>> rsa_rev_reg: {:?}, rev_idx: {:?}", rsa_rev_reg, rev_idx);
+
+        let rsa_r_key = issuer_pub_key.rsa_r_key
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in issuer key.")))?;
+
+        rsa_accumulator::remove(rsa_rev_reg, rsa_r_key, &issuer_priv_key.p_key.p, &issuer_priv_key.p_key.q, rev_idx)?;
+
+        trace!("Issuer::revoke_rsa_claim: <<<");
+
+        Ok(())
+    }
+
+    /// Creates accumulator statement signed by Issuer BLS sign key.
+    ///
+    /// Statement announces accumulator value of revocation registry with given id and allows
+    /// verifiers to check that accumulator received from untrusted source is authentic and fresh.
+    ///
+    /// # Arguments
+    /// * `rev_reg_id` - Revocation registry identifier.
+    /// * `seq_no` - Sequence number of accumulator state (should grow with each state change).
+    /// * `timestamp` - Time of statement creation in seconds since unix epoch.
+    /// * `rev_acc` - Revocation accumulator.
+    /// * `sign_key` - Issuer BLS sign key.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::bls::SignKey;
+    /// use indy_crypto::cl::issuer::Issuer;
+    /// let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+    /// claim_schema_builder.add_attr("sex").unwrap();
+    /// let claim_schema = claim_schema_builder.finalize().unwrap();
+    ///
+    /// let (pub_key, _priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+    /// let (rev_reg_pub, _rev_reg_priv, _rev_tails) = Issuer::new_revocation_registry(&pub_key, 1).unwrap();
+    ///
+    /// let sign_key = SignKey::new(None).unwrap();
+    /// let _statement = Issuer::sign_accumulator_statement("rev_reg_id", 1, 1500000000,
+    ///                                                     &rev_reg_pub.get_accumulator().unwrap(), &sign_key).unwrap();
+    /// ```
+    pub fn sign_accumulator_statement(rev_reg_id: &str,
+                                      seq_no: u64,
+                                      timestamp: u64,
+                                      rev_acc: &RevocationAccumulator,
+                                      sign_key: &SignKey) -> Result<RevocationAccumulatorStatement, IndyCryptoError> {
+        trace!("Issuer::sign_accumulator_statement: >>> rev_reg_id: {:?}, seq_no: {:?}, timestamp: {:?}, rev_acc: {:?}",
+               rev_reg_id, seq_no, timestamp, rev_acc);
+
+        let message = RevocationAccumulatorStatement::_signed_bytes(rev_reg_id, seq_no, timestamp, rev_acc)?;
+        let signature = Bls::sign(&message, sign_key)?;
+
+        let statement = RevocationAccumulatorStatement {
+            rev_reg_id: rev_reg_id.to_owned(),
+            seq_no,
+            timestamp,
+            acc: rev_acc.clone(),
+            signature: signature.as_bytes().to_vec()
+        };
+
+        trace!("Issuer::sign_accumulator_statement: <<< statement: {:?}", statement);
+
+        Ok(statement)
+    }
+
+    fn _new_keys(claim_schema: &ClaimSchema,
+                 revocation_backend: Option<RevocationBackend>) -> Result<(IssuerPublicKey, IssuerPrivateKey), IndyCryptoError> {
+        trace!("Issuer::_new_keys: >>> claim_schema: {:?}, revocation_backend: {:?}", claim_schema, revocation_backend);
+
+        let (p_pub_key, p_priv_key) = Issuer::_new_primary_keys(claim_schema)?;
+
+        let (r_pub_key, r_priv_key) = if revocation_backend == Some(RevocationBackend::Pairing) {
+            let (r_pub_key, r_priv_key) = Issuer::_new_revocation_keys()?;
+            (Some(r_pub_key), Some(r_priv_key))
+        } else {
+            (None, None)
+        };
+
+        let rsa_r_key = if revocation_backend == Some(RevocationBackend::Rsa) {
+            Some(rsa_accumulator::new_key(&p_pub_key.n, &p_pub_key.s, &p_priv_key.p, &p_priv_key.q)?)
+        } else {
+            None
+        };
+
+        let issuer_pub_key = IssuerPublicKey { p_key: p_pub_key, r_key: r_pub_key, rsa_r_key };
+        let issuer_priv_key = IssuerPrivateKey { p_key: p_priv_key, r_key: r_priv_key };
+
+        trace!("Issuer::_new_keys: <<< issuer_pub_key: {:?}, issuer_priv_key: {:?}", issuer_pub_key, issuer_priv_key);
+
+        Ok((issuer_pub_key, issuer_priv_key))
+    }
+
     fn _new_primary_keys(claim_schema: &ClaimSchema) -> Result<(IssuerPrimaryPublicKey,
                                                                 IssuerPrimaryPrivateKey), IndyCryptoError> {
         trace!("Issuer::_new_primary_keys: >>> claim_schema: {:?}", claim_schema);
@@ -365,9 +787,10 @@ impl Issuer {
                           issuer_pub_key: &IssuerPublicKey,
                           issuer_priv_key: &IssuerPrivateKey,
                           blnd_ms: &BlindedMasterSecret,
-                          claim_values: &ClaimValues) -> Result<PrimaryClaimSignature, IndyCryptoError> {
-        trace!("Issuer::_new_primary_claim: >>> m_2: {:?}, issuer_pub_key: {:?}, issuer_priv_key: {:?}, blnd_ms: {:?}, claim_values: {:?}",
-               m_2, issuer_pub_key, issuer_priv_key, blnd_ms, claim_values);
+                          claim_values: &ClaimValues,
+                          acc_prime_term: Option<&BigNumber>) -> Result<PrimaryClaimSignature, IndyCryptoError> {
+        trace!("Issuer::_new_primary_claim: >>> m_2: {:?}, issuer_pub_key: {:?}, issuer_priv_key: {:?}, blnd_ms: {:?}, claim_values: {:?}, acc_prime_term: {:?}",
+               m_2, issuer_pub_key, issuer_priv_key, blnd_ms, claim_values, acc_prime_term);
 
         let v = generate_v_prime_prime()?;
 
@@ -377,7 +800,7 @@ impl Issuer {
             .add(&e_start)?;
 
         let e = generate_prime_in_range(&e_start, &e_end)?;
-        let a = Issuer::_sign_primary_claim(issuer_pub_key, issuer_priv_key, &m_2, &claim_values, &v, blnd_ms, &e)?;
+        let a = Issuer::_sign_primary_claim(issuer_pub_key, issuer_priv_key, &m_2, &claim_values, &v, blnd_ms, &e, acc_prime_term)?;
 
         let pr_claim_signature = PrimaryClaimSignature { m_2: m_2.clone()?, a, e, v };
 
@@ -392,9 +815,10 @@ impl Issuer {
                            claim_values: &ClaimValues,
                            v: &BigNumber,
                            blnd_ms: &BlindedMasterSecret,
-                           e: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
-        trace!("Issuer::_sign_primary_claim: >>> p_pub_key: {:?}, p_priv_key: {:?}, m_2: {:?}, claim_values: {:?}, v: {:?}, blnd_ms: {:?}, e: {:?}",
-               p_pub_key, p_priv_key, m_2, claim_values, v, blnd_ms, e);
+                           e: &BigNumber,
+                           acc_prime_term: Option<&BigNumber>) -> Result<BigNumber, IndyCryptoError> {
+        trace!("Issuer::_sign_primary_claim: >>> p_pub_key: {:?}, p_priv_key: {:?}, m_2: {:?}, claim_values: {:?}, v: {:?}, blnd_ms: {:?}, e: {:?}, acc_prime_term: {:?}",
+               p_pub_key, p_priv_key, m_2, claim_values, v, blnd_ms, e, acc_prime_term);
 
         let p_pub_key = &p_pub_key.p_key;
         let p_priv_key = &p_priv_key.p_key;
@@ -416,6 +840,10 @@ impl Issuer {
         rx = p_pub_key.rctxt.mod_exp(&m_2, &p_pub_key.n, Some(&mut context))?
             .mul(&rx, Some(&mut context))?;
 
+        if let Some(acc_prime_term) = acc_prime_term {
+            rx = acc_prime_term.mul(&rx, Some(&mut context))?;
+        }
+
         if blnd_ms.u != BigNumber::from_u32(0)? {
             rx = blnd_ms.u.modulus(&p_pub_key.n, Some(&mut context))?
                 .mul(&rx, Some(&mut context))?;
@@ -437,13 +865,41 @@ impl Issuer {
         Ok(a)
     }
 
+    fn _new_revocation_registry(r_pub_key: &IssuerRevocationPublicKey,
+                                rev_reg_priv: &RevocationRegistryPrivate,
+                                max_claim_num: u32,
+                                tails_hash: Vec<u8>) -> Result<RevocationRegistryPublic, IndyCryptoError> {
+        trace!("Issuer::_new_revocation_registry: >>> r_pub_key: {:?}, max_claim_num: {:?}, tails_hash: {:?}", r_pub_key, max_claim_num, tails_hash);
+
+        let mut z = Pair::pair(&r_pub_key.g, &r_pub_key.g_dash)?;
+        let mut pow = GroupOrderElement::from_bytes(&transform_u32_to_array_of_u8(max_claim_num + 1))?;
+        pow = rev_reg_priv.key.gamma.pow_mod(&pow)?;
+        z = z.pow(&pow)?;
+        let acc = PointG2::new_inf()?;
+        let v = IndexSet::new();
+
+        let rev_reg_pub = RevocationRegistryPublic {
+            acc: RevocationAccumulator { acc, v, max_claim_num },
+            key: RevocationAccumulatorPublicKey { z },
+            version: 0,
+            tails_hash,
+            snapshots: Vec::new()
+        };
+
+        trace!("Issuer::_new_revocation_registry: <<< rev_reg_pub: {:?}", rev_reg_pub);
+
+        Ok(rev_reg_pub)
+    }
+
     fn _new_non_revocation_claim(rev_idx: u32,
                                  m_2: &BigNumber,
                                  blnd_ms: &BlindedMasterSecret,
                                  issuer_pub_key: &IssuerPublicKey,
                                  issuer_priv_key: &IssuerPrivateKey,
-                                 rev_reg_pub: &mut RevocationRegistryPublic,
-                                 rev_reg_priv: &RevocationRegistryPrivate) -> Result<NonRevocationClaimSignature, IndyCryptoError> {
+                                 rev_reg_pub: &RevocationRegistryPublic,
+                                 rev_reg_priv: &RevocationRegistryPrivate,
+                                 rev_tails_reader: &TailsReader) -> Result<(NonRevocationClaimSignature,
+                                                                            RevocationRegistryDelta), IndyCryptoError> {
         trace!("Issuer::_new_non_revocation_claim: >>> rev_idx: {:?}, m_2: {:?}, blnd_ms: {:?}, issuer_pub_key: {:?}, issuer_priv_key: {:?}, rev_reg_pub: {:?}, rev_reg_priv: {:?}",
                rev_idx, m_2, blnd_ms, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
 
@@ -458,8 +914,7 @@ impl Issuer {
             .as_ref()
             .ok_or(IndyCryptoError::InvalidStructure(format!("No revocation part present in issuer private key.")))?;
 
-        let r_acc: &mut RevocationAccumulator = &mut rev_reg_pub.acc;
-        let r_acc_tails: &mut RevocationAccumulatorTails = &mut rev_reg_pub.tails;
+        let r_acc: &RevocationAccumulator = &rev_reg_pub.acc;
         let r_acc_priv_key: &RevocationAccumulatorPrivateKey = &rev_reg_priv.key;
 
         if r_acc.is_full() {
@@ -467,7 +922,7 @@ impl Issuer {
         }
 
         if r_acc.is_idx_used(rev_idx) {
-            return Err(IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(format!("Revocation index is already used."))); //TODO Is it correct error?
+            return Err(IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(format!("Revocation index {} is already used.", rev_idx)));
         }
 
         let i = rev_idx;
@@ -476,14 +931,12 @@ impl Issuer {
         let c = GroupOrderElement::new()?;
         let m2 = GroupOrderElement::from_bytes(&m_2.to_bytes()?)?;
 
-        let g_i = r_acc_tails.tails
-            .get(&i)
-            .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in tails.g", i)))?;
+        let g_i = rev_tails_reader.get_tail(i)?;
 
         let sigma =
             r_pub_key.h0.add(&r_pub_key.h1.mul(&m2)?)?
                 .add(&ur)?
-                .add(g_i)?
+                .add(&g_i)?
                 .add(&r_pub_key.h2.mul(&vr_prime_prime)?)?
                 .mul(&r_priv_key.x.add_mod(&c)?.inverse()?)?;
 
@@ -491,9 +944,7 @@ impl Issuer {
 
         for j in &r_acc.v {
             let index = r_acc.max_claim_num + 1 - j + i;
-            omega = omega.add(r_acc_tails.tails_dash
-                .get(&index)
-                .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in g", index)))?)?;
+            omega = omega.add(&rev_tails_reader.get_tail_dash(index)?)?;
         }
 
         let sigma_i = r_pub_key.g_dash
@@ -507,35 +958,336 @@ impl Issuer {
 
         let index = r_acc.max_claim_num + 1 - i;
 
-        r_acc.acc = r_acc.acc
-            .add(r_acc_tails.tails_dash
-                .get(&index)
-                .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in g", index)))?)?;
+        let acc = r_acc.acc.add(&rev_tails_reader.get_tail_dash(index)?)?;
 
-        r_acc.v.insert(i);
+        let mut v = r_acc.v.clone();
+        v.insert(i);
 
         let witness = Witness {
             sigma_i,
             u_i,
             g_i: g_i.clone(),
             omega,
-            v: r_acc.v.clone()
+            v
         };
 
         let non_revocation_claim_sig = NonRevocationClaimSignature { sigma, c, vr_prime_prime, witness, g_i: g_i.clone(), i, m2 };
 
-        trace!("Issuer::_new_non_revocation_claim: <<< non_revocation_claim_sig: {:?}", non_revocation_claim_sig);
+        let mut issued = IndexSet::new();
+        issued.insert(i);
+
+        let rev_reg_delta = RevocationRegistryDelta { prev_version: rev_reg_pub.version, prev_acc: r_acc.acc, acc, issued };
+
+        trace!("Issuer::_new_non_revocation_claim: <<< non_revocation_claim_sig: {:?}, rev_reg_delta: {:?}", non_revocation_claim_sig, rev_reg_delta);
+
+        Ok((non_revocation_claim_sig, rev_reg_delta))
+    }
+}
+
+/// Issuer side manager of revocation registries.
+///
+/// Allocates revocation indexes, tracks revoked claims and rolls over to a new revocation
+/// registry when all indexes of the current one are consumed. Revoked indexes are never allocated again.
+///
+/// Contains only public data. Private keys of managed registries are kept in
+/// `RevocationRegistryManagerPrivate` and accumulator tails aren't serialized;
+/// after deserialization tails must be attached by `set_registry_tails`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevocationRegistryManager {
+    issuer_pub_key: IssuerPublicKey,
+    max_claim_num: u32,
+    registries: Vec<ManagedRevocationRegistry>,
+}
+
+/// Private keys of revocation registries created by `RevocationRegistryManager`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevocationRegistryManagerPrivate {
+    registries: Vec<RevocationRegistryPrivate>,
+}
+
+impl JsonEncodable for RevocationRegistryManagerPrivate {}
+
+impl<'a> JsonDecodable<'a> for RevocationRegistryManagerPrivate {}
+
+#[cfg(feature = "serialization")]
+impl EncryptedJson for RevocationRegistryManagerPrivate {
+    const KIND: &'static str = "RevocationRegistryManagerPrivate";
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ManagedRevocationRegistry {
+    rev_reg_pub: RevocationRegistryPublic,
+    revoked: IndexSet,
+    next_idx: u32 /* no free index below */,
+    #[serde(skip_serializing, skip_deserializing)]
+    rev_tails: Option<RevocationAccumulatorTails>,
+}
+
+impl ManagedRevocationRegistry {
+    fn is_idx_consumed(&self, idx: u32) -> bool {
+        self.rev_reg_pub.acc.is_idx_used(idx) || self.revoked.contains(idx)
+    }
+
+    fn next_free_idx(&mut self, max_claim_num: u32) -> Option<u32> {
+        while self.next_idx <= max_claim_num && self.is_idx_consumed(self.next_idx) {
+            self.next_idx += 1;
+        }
+
+        if self.next_idx <= max_claim_num { Some(self.next_idx) } else { None }
+    }
+}
+
+impl RevocationRegistryManager {
+    /// Returns identifier of revocation registry that is currently used for new claims.
+    pub fn get_current_registry_id(&self) -> Result<usize, IndyCryptoError> {
+        if self.registries.is_empty() {
+            return Err(IndyCryptoError::InvalidState(format!("Revocation registry manager has no registries")));
+        }
+        Ok(self.registries.len() - 1)
+    }
+
+    /// Returns revocation registry public by its identifier.
+    pub fn get_registry_public(&self, rev_reg_id: usize) -> Result<&RevocationRegistryPublic, IndyCryptoError> {
+        Ok(&self._get_registry(rev_reg_id)?.rev_reg_pub)
+    }
+
+    /// Returns accumulator tails of revocation registry by its identifier.
+    pub fn get_registry_tails(&self, rev_reg_id: usize) -> Result<&RevocationAccumulatorTails, IndyCryptoError> {
+        RevocationRegistryManager::_tails(self._get_registry(rev_reg_id)?, rev_reg_id)
+    }
+
+    /// Attaches accumulator tails to revocation registry after manager deserialization.
+    /// Tails are checked against tails hash of the registry.
+    ///
+    /// # Arguments
+    /// * `rev_reg_id` - Revocation registry identifier.
+    /// * `rev_tails` - Accumulator tails of the registry.
+    pub fn set_registry_tails(&mut self, rev_reg_id: usize, rev_tails: RevocationAccumulatorTails) -> Result<(), IndyCryptoError> {
+        let registry = self._get_registry_mut(rev_reg_id)?;
+        tails::verify_tails(&rev_tails, &registry.rev_reg_pub)?;
+        registry.rev_tails = Some(rev_tails);
+        Ok(())
+    }
+
+    /// Returns true if claim with given index was issued in given registry and isn't revoked.
+    pub fn is_issued(&self, rev_reg_id: usize, rev_idx: u32) -> Result<bool, IndyCryptoError> {
+        Ok(self._get_registry(rev_reg_id)?.rev_reg_pub.acc.is_idx_used(rev_idx))
+    }
+
+    /// Returns true if claim with given index was revoked in given registry.
+    pub fn is_revoked(&self, rev_reg_id: usize, rev_idx: u32) -> Result<bool, IndyCryptoError> {
+        Ok(self._get_registry(rev_reg_id)?.revoked.contains(rev_idx))
+    }
+
+    /// Signs claim with the next free revocation index.
+    /// New revocation registry will be created if the current one is full,
+    /// its private key is added to `rev_reg_manager_priv`.
+    ///
+    /// Returns identifier of revocation registry, allocated revocation index and claim signature.
+    ///
+    /// # Arguments
+    /// * `prover_id` - Prover identifier.
+    /// * `blinded_ms` - Blinded master secret.
+    /// * `claim_values` - Claim values to be signed.
+    /// * `issuer_priv_key` - Issuer private key.
+    /// * `rev_reg_manager_priv` - Private keys of managed revocation registries.
+    pub fn sign_claim(&mut self,
+                      prover_id: &str,
+                      blinded_ms: &BlindedMasterSecret,
+                      claim_values: &ClaimValues,
+                      issuer_priv_key: &IssuerPrivateKey,
+                      rev_reg_manager_priv: &mut RevocationRegistryManagerPrivate) -> Result<(usize, u32, ClaimSignature), IndyCryptoError> {
+        trace!("RevocationRegistryManager::sign_claim: >>> prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_priv_key: {:?}",
+               prover_id, blinded_ms, claim_values, issuer_priv_key);
+
+        self._check_private(rev_reg_manager_priv)?;
+
+        let (rev_reg_id, rev_idx) = self._allocate_idx(rev_reg_manager_priv)?;
+        let claim_signature = self._sign_claim(rev_reg_id, rev_idx, prover_id, blinded_ms, claim_values, issuer_priv_key, rev_reg_manager_priv)?;
+
+        trace!("RevocationRegistryManager::sign_claim: <<< rev_reg_id: {:?}, rev_idx: {:?}, claim_signature: {:?}", rev_reg_id, rev_idx, claim_signature);
+
+        Ok((rev_reg_id, rev_idx, claim_signature))
+    }
+
+    /// Signs claim with explicitly chosen revocation index.
+    ///
+    /// Returns `AnoncredsRevocationAccumulatorIndexAlreadyUsed` error if index was already issued or revoked.
+    ///
+    /// # Arguments
+    /// * `rev_reg_id` - Revocation registry identifier.
+    /// * `rev_idx` - User index in revocation accumulator.
+    /// * `prover_id` - Prover identifier.
+    /// * `blinded_ms` - Blinded master secret.
+    /// * `claim_values` - Claim values to be signed.
+    /// * `issuer_priv_key` - Issuer private key.
+    /// * `rev_reg_manager_priv` - Private keys of managed revocation registries.
+    pub fn sign_claim_with_idx(&mut self,
+                               rev_reg_id: usize,
+                               rev_idx: u32,
+                               prover_id: &str,
+                               blinded_ms: &BlindedMasterSecret,
+                               claim_values: &ClaimValues,
+                               issuer_priv_key: &IssuerPrivateKey,
+                               rev_reg_manager_priv: &RevocationRegistryManagerPrivate) -> Result<ClaimSignature, IndyCryptoError> {
+        trace!("RevocationRegistryManager::sign_claim_with_idx: >>> rev_reg_id: {:?}, rev_idx: {:?}, prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, \
+        issuer_priv_key: {:?}", rev_reg_id, rev_idx, prover_id, blinded_ms, claim_values, issuer_priv_key);
+
+        self._check_private(rev_reg_manager_priv)?;
+        self._check_idx(rev_idx)?;
+
+        if self._get_registry(rev_reg_id)?.is_idx_consumed(rev_idx) {
+            return Err(IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(
+                format!("Revocation index {} is already used in registry {}", rev_idx, rev_reg_id)));
+        }
+
+        let claim_signature = self._sign_claim(rev_reg_id, rev_idx, prover_id, blinded_ms, claim_values, issuer_priv_key, rev_reg_manager_priv)?;
+
+        trace!("RevocationRegistryManager::sign_claim_with_idx: <<< claim_signature: {:?}", claim_signature);
+
+        Ok(claim_signature)
+    }
+
+    /// Revokes claim with given index in given revocation registry.
+    ///
+    /// # Arguments
+    /// * `rev_reg_id` - Revocation registry identifier.
+    /// * `rev_idx` - User index in revocation accumulator.
+    pub fn revoke_claim(&mut self, rev_reg_id: usize, rev_idx: u32) -> Result<(), IndyCryptoError> {
+        trace!("RevocationRegistryManager::revoke_claim: >>> rev_reg_id: {:?}, rev_idx: {:?}", rev_reg_id, rev_idx);
+
+        let registry = self._get_registry_mut(rev_reg_id)?;
+
+        if !registry.rev_reg_pub.acc.is_idx_used(rev_idx) {
+            return Err(IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(
+                format!("Claim with index {} wasn't issued or is already revoked in registry {}", rev_idx, rev_reg_id)));
+        }
+
+        let rev_tails = registry.rev_tails
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidState(format!("Tails of revocation registry {} aren't loaded", rev_reg_id)))?;
+
+        Issuer::revoke_claim(&mut registry.rev_reg_pub, rev_idx, rev_tails)?;
+
+        registry.revoked.insert(rev_idx);
+
+        trace!("RevocationRegistryManager::revoke_claim: <<<");
+
+        Ok(())
+    }
+
+    fn _sign_claim(&mut self,
+                   rev_reg_id: usize,
+                   rev_idx: u32,
+                   prover_id: &str,
+                   blinded_ms: &BlindedMasterSecret,
+                   claim_values: &ClaimValues,
+                   issuer_priv_key: &IssuerPrivateKey,
+                   rev_reg_manager_priv: &RevocationRegistryManagerPrivate) -> Result<ClaimSignature, IndyCryptoError> {
+        let issuer_pub_key = &self.issuer_pub_key;
+        let registry = self.registries
+            .get_mut(rev_reg_id)
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Revocation registry {} not found", rev_reg_id)))?;
+
+        let rev_tails = registry.rev_tails
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidState(format!("Tails of revocation registry {} aren't loaded", rev_reg_id)))?;
+
+        Issuer::sign_claim(prover_id,
+                           blinded_ms,
+                           claim_values,
+                           issuer_pub_key,
+                           issuer_priv_key,
+                           Some(rev_idx),
+                           Some(&mut registry.rev_reg_pub),
+                           Some(&rev_reg_manager_priv.registries[rev_reg_id]),
+                           Some(rev_tails))
+    }
+
+    fn _allocate_idx(&mut self, rev_reg_manager_priv: &mut RevocationRegistryManagerPrivate) -> Result<(usize, u32), IndyCryptoError> {
+        let rev_reg_id = self.get_current_registry_id()?;
+
+        if let Some(rev_idx) = self.registries[rev_reg_id].next_free_idx(self.max_claim_num) {
+            return Ok((rev_reg_id, rev_idx));
+        }
+
+        self._add_registry(rev_reg_manager_priv)?;
+        let rev_reg_id = self.get_current_registry_id()?;
+
+        let rev_idx = self.registries[rev_reg_id]
+            .next_free_idx(self.max_claim_num)
+            .ok_or(IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(format!("Revocation registry {} has no free indexes", rev_reg_id)))?;
+
+        Ok((rev_reg_id, rev_idx))
+    }
+
+    fn _add_registry(&mut self, rev_reg_manager_priv: &mut RevocationRegistryManagerPrivate) -> Result<(), IndyCryptoError> {
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&self.issuer_pub_key, self.max_claim_num)?;
+
+        self.registries.push(ManagedRevocationRegistry {
+            rev_reg_pub,
+            revoked: IndexSet::new(),
+            next_idx: 1,
+            rev_tails: Some(rev_tails)
+        });
+        rev_reg_manager_priv.registries.push(rev_reg_priv);
+
+        Ok(())
+    }
+
+    fn _check_private(&self, rev_reg_manager_priv: &RevocationRegistryManagerPrivate) -> Result<(), IndyCryptoError> {
+        if rev_reg_manager_priv.registries.len() != self.registries.len() {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Revocation registry manager private doesn't correspond to revocation registry manager")));
+        }
+        Ok(())
+    }
+
+    fn _check_idx(&self, rev_idx: u32) -> Result<(), IndyCryptoError> {
+        if rev_idx == 0 || rev_idx > self.max_claim_num {
+            return Err(IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(
+                format!("Revocation index {} is out of range 1..{}", rev_idx, self.max_claim_num)));
+        }
+        Ok(())
+    }
 
-        Ok(non_revocation_claim_sig)
+    fn _tails(registry: &ManagedRevocationRegistry, rev_reg_id: usize) -> Result<&RevocationAccumulatorTails, IndyCryptoError> {
+        registry.rev_tails
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidState(format!("Tails of revocation registry {} aren't loaded", rev_reg_id)))
+    }
+
+    fn _get_registry(&self, rev_reg_id: usize) -> Result<&ManagedRevocationRegistry, IndyCryptoError> {
+        self.registries
+            .get(rev_reg_id)
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Revocation registry {} not found", rev_reg_id)))
+    }
+
+    fn _get_registry_mut(&mut self, rev_reg_id: usize) -> Result<&mut ManagedRevocationRegistry, IndyCryptoError> {
+        self.registries
+            .get_mut(rev_reg_id)
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Revocation registry {} not found", rev_reg_id)))
     }
 }
 
+impl JsonEncodable for RevocationRegistryManager {}
+
+impl<'a> JsonDecodable<'a> for RevocationRegistryManager {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use cl::issuer::{Issuer, mocks};
     use cl::prover::Prover;
+    use cl::prover::mocks::PROVER_DID;
     use cl::helpers::MockHelper;
+    use errors::ToErrorCode;
+    use std::collections::HashSet;
+    use ffi::ErrorCode;
+
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
 
     #[test]
     fn generate_context_attribute_works() {
@@ -606,7 +1358,24 @@ mod tests {
         MockHelper::inject();
 
         let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
-        let (_, _) = Issuer::new_revocation_registry(&pub_key, 100).unwrap();
+        let (_, _, _) = Issuer::new_revocation_registry(&pub_key, 100).unwrap();
+    }
+
+    #[test]
+    fn issuer_new_revocation_registry_with_tails_writer_works() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+
+        let mut tails: Vec<u8> = Vec::new();
+        let mut calls = 0;
+        let (rev_reg_pub, _) =
+            Issuer::new_revocation_registry_with_tails_writer(&pub_key, 600, 2, &mut tails, Some(&mut |_, _| calls += 1)).unwrap();
+
+        assert_eq!(2, calls);
+
+        let path = env::temp_dir().join("indy_crypto_issuer_new_revocation_registry_with_tails_writer_works.tails");
+        File::create(&path).unwrap().write_all(&tails).unwrap();
+        tails::FileTailsReader::open(&path, &rev_reg_pub).unwrap();
+        fs::remove_file(&path).unwrap();
     }
 
     #[test]
@@ -629,7 +1398,7 @@ mod tests {
         let e = BigNumber::from_dec("259344723055062059907025491480697571938277889515152306249728583105665800713306759149981690559193987143012367913206299323899696942213235956742930214202955935602153431795703076242907").unwrap();
         let result = BigNumber::from_dec("28748151213526235356806559302394713234708919908503693283861771311017778909029307989059154007823711057388221409308121224597301914007508580498985253922086489241065285193059997346332076248684330624957067344016446755572964815456056930278425883796750731908534333384959509746585564275501093362841366335955561237226624645170675067095743367895186059835073250297480315430811087601896371266213408739927940580173817412189118678276094925364341985978659550229327835510932814819830163166484857629278032552734675432915303389204079219287453130354714417551011163735621955266079226631695289893390164242695387374962452897413162593627569").unwrap();
 
-        assert_eq!(result, Issuer::_sign_primary_claim(&pub_key, &secret_key, &context_attribute, &claim_values, &v, &BlindedMasterSecret { u: u, ur: None }, &e).unwrap());
+        assert_eq!(result, Issuer::_sign_primary_claim(&pub_key, &secret_key, &context_attribute, &claim_values, &v, &BlindedMasterSecret { u: u, ur: None }, &e, None).unwrap());
     }
 
     #[test]
@@ -646,19 +1415,215 @@ mod tests {
                                                  &mocks::claim_values(),
                                                  &pub_key,
                                                  &priv_key,
-                                                 Some(1), None, None).unwrap();
+                                                 Some(1), None, None, None).unwrap();
 
         assert_eq!(mocks::primary_claim(), claim_signature.p_claim);
     }
+
+    #[test]
+    fn sign_claim_with_delta_works() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&pub_key, 5).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) =
+            Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        let (claim_signature, rev_reg_delta) = Issuer::sign_claim_with_delta("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW",
+                                                                             &blinded_master_secret,
+                                                                             &mocks::claim_values(),
+                                                                             &pub_key,
+                                                                             &priv_key,
+                                                                             Some(1),
+                                                                             Some(&rev_reg_pub),
+                                                                             Some(&rev_reg_priv),
+                                                                             Some(&rev_tails)).unwrap();
+        assert!(claim_signature.r_claim.is_some());
+        assert!(!rev_reg_pub.acc.is_idx_used(1));
+
+        let rev_reg_delta = rev_reg_delta.unwrap();
+        rev_reg_pub.apply_delta(&rev_reg_delta).unwrap();
+        assert!(rev_reg_pub.acc.is_idx_used(1));
+
+        assert!(rev_reg_pub.apply_delta(&rev_reg_delta).is_err());
+    }
+
+    #[test]
+    fn apply_delta_works_for_replay_after_revocation() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&pub_key, 5).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) =
+            Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        let (_, rev_reg_delta) = Issuer::sign_claim_with_delta("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW",
+                                                               &blinded_master_secret,
+                                                               &mocks::claim_values(),
+                                                               &pub_key,
+                                                               &priv_key,
+                                                               Some(1),
+                                                               Some(&rev_reg_pub),
+                                                               Some(&rev_reg_priv),
+                                                               Some(&rev_tails)).unwrap();
+        let rev_reg_delta = rev_reg_delta.unwrap();
+
+        rev_reg_pub.apply_delta(&rev_reg_delta).unwrap();
+        Issuer::revoke_claim(&mut rev_reg_pub, 1, &rev_tails).unwrap();
+        assert!(rev_reg_pub.acc.acc.is_inf().unwrap());
+        assert_eq!(2, rev_reg_pub.get_version());
+
+        assert!(rev_reg_pub.apply_delta(&rev_reg_delta).is_err());
+        assert!(!rev_reg_pub.acc.is_idx_used(1));
+    }
+
+    #[test]
+    fn sign_claim_works_for_used_rev_idx() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&pub_key, 5).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        Issuer::sign_claim("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", &blinded_master_secret, &mocks::claim_values(),
+                           &pub_key, &priv_key, Some(1), Some(&mut rev_reg_pub), Some(&rev_reg_priv), Some(&rev_tails)).unwrap();
+
+        let res = Issuer::sign_claim("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", &blinded_master_secret, &mocks::claim_values(),
+                                     &pub_key, &priv_key, Some(1), Some(&mut rev_reg_pub), Some(&rev_reg_priv), Some(&rev_tails));
+        assert_eq!(ErrorCode::AnoncredsRevocationAccumulatorIndexAlreadyUsed, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn revocation_registry_manager_sign_claim_works() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        let (mut rev_reg_manager, mut rev_reg_manager_priv) = Issuer::new_revocation_registry_manager(&pub_key, 3).unwrap();
+
+        let (rev_reg_id, rev_idx, _) = rev_reg_manager.sign_claim(PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &mut rev_reg_manager_priv).unwrap();
+        assert_eq!((0, 1), (rev_reg_id, rev_idx));
+
+        let (rev_reg_id, rev_idx, _) = rev_reg_manager.sign_claim(PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &mut rev_reg_manager_priv).unwrap();
+        assert_eq!((0, 2), (rev_reg_id, rev_idx));
+        assert!(rev_reg_manager.is_issued(0, 2).unwrap());
+    }
+
+    #[test]
+    fn revocation_registry_manager_sign_claim_works_for_full_registry() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        let (mut rev_reg_manager, mut rev_reg_manager_priv) = Issuer::new_revocation_registry_manager(&pub_key, 1).unwrap();
+
+        rev_reg_manager.sign_claim(PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &mut rev_reg_manager_priv).unwrap();
+        rev_reg_manager.revoke_claim(0, 1).unwrap();
+        assert!(rev_reg_manager.is_revoked(0, 1).unwrap());
+
+        let (rev_reg_id, rev_idx, _) = rev_reg_manager.sign_claim(PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &mut rev_reg_manager_priv).unwrap();
+        assert_eq!((1, 1), (rev_reg_id, rev_idx));
+        assert_eq!(1, rev_reg_manager.get_current_registry_id().unwrap());
+    }
+
+    #[test]
+    fn revocation_registry_manager_sign_claim_with_idx_works_for_used_idx() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        let (mut rev_reg_manager, rev_reg_manager_priv) = Issuer::new_revocation_registry_manager(&pub_key, 5).unwrap();
+
+        rev_reg_manager.sign_claim_with_idx(0, 3, PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &rev_reg_manager_priv).unwrap();
+        rev_reg_manager.revoke_claim(0, 3).unwrap();
+
+        let res = rev_reg_manager.sign_claim_with_idx(0, 3, PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &rev_reg_manager_priv);
+        assert_eq!(ErrorCode::AnoncredsRevocationAccumulatorIndexAlreadyUsed, res.unwrap_err().to_error_code());
+
+        let res = rev_reg_manager.sign_claim_with_idx(0, 6, PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &rev_reg_manager_priv);
+        assert_eq!(ErrorCode::AnoncredsInvalidRevocationAccumulatorIndex, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn revocation_registry_manager_to_json_works_without_private_data() {
+        let (pub_key, priv_key) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_master_secret, _) = Prover::blind_master_secret(&pub_key, &master_secret).unwrap();
+
+        let (mut rev_reg_manager, mut rev_reg_manager_priv) = Issuer::new_revocation_registry_manager(&pub_key, 5).unwrap();
+        rev_reg_manager.sign_claim(PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &mut rev_reg_manager_priv).unwrap();
+
+        let rev_tails = rev_reg_manager.get_registry_tails(0).unwrap().clone();
+        let rev_reg_manager_json = rev_reg_manager.to_json().unwrap();
+        assert!(!rev_reg_manager_json.contains("gamma"));
+        assert!(!rev_reg_manager_json.contains("tails_dash"));
+
+        let mut rev_reg_manager = RevocationRegistryManager::from_json(&rev_reg_manager_json).unwrap();
+        let rev_reg_manager_priv = RevocationRegistryManagerPrivate::from_json(&rev_reg_manager_priv.to_json().unwrap()).unwrap();
+        assert!(rev_reg_manager.is_issued(0, 1).unwrap());
+
+        let res = rev_reg_manager.sign_claim_with_idx(0, 2, PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &rev_reg_manager_priv);
+        assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err().to_error_code());
+
+        rev_reg_manager.set_registry_tails(0, rev_tails).unwrap();
+        rev_reg_manager.sign_claim_with_idx(0, 2, PROVER_DID, &blinded_master_secret, &mocks::claim_values(), &priv_key, &rev_reg_manager_priv).unwrap();
+        assert!(rev_reg_manager.is_issued(0, 2).unwrap());
+    }
+
+    #[test]
+    fn revocation_registry_manager_get_current_registry_id_works_for_empty_manager() {
+        let rev_reg_manager = RevocationRegistryManager {
+            issuer_pub_key: mocks::issuer_public_key(),
+            max_claim_num: 5,
+            registries: Vec::new()
+        };
+
+        let res = rev_reg_manager.get_current_registry_id();
+        assert_eq!(ErrorCode::CommonInvalidState, res.unwrap_err().to_error_code());
+    }
 }
 
 pub mod mocks {
     use cl::*;
+    use super::Issuer;
+
+    // Encodings of pairing values depend on the curve selected by `pair_*` feature,
+    // so revocation mocks are generated once and shared to stay consistent with each other.
+    struct RevocationMocks {
+        r_pub_key: IssuerRevocationPublicKey,
+        r_claim: NonRevocationClaimSignature,
+        rev_reg_pub: RevocationRegistryPublic,
+        rev_tails: RevocationAccumulatorTails
+    }
+
+    lazy_static! {
+        static ref REVOCATION_MOCKS: RevocationMocks = {
+            let (r_pub_key, r_priv_key) = Issuer::_new_revocation_keys().unwrap();
+
+            let issuer_pub_key = IssuerPublicKey {
+                p_key: issuer_primary_public_key(),
+                r_key: Some(r_pub_key.clone()),
+                rsa_r_key: None
+            };
+            let issuer_priv_key = IssuerPrivateKey {
+                p_key: issuer_primary_private_key(),
+                r_key: Some(r_priv_key)
+            };
+
+            let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+            let blinded_ms = BlindedMasterSecret { u: BigNumber::new().unwrap(), ur: Some(PointG1::new_inf().unwrap()) };
+            let (r_claim, rev_reg_delta) = Issuer::_new_non_revocation_claim(1, &r_cnxt_m2(), &blinded_ms, &issuer_pub_key,
+                                                                             &issuer_priv_key, &rev_reg_pub, &rev_reg_priv,
+                                                                             &rev_tails).unwrap();
+            rev_reg_pub.apply_delta(&rev_reg_delta).unwrap();
+
+            RevocationMocks { r_pub_key, r_claim, rev_reg_pub, rev_tails }
+        };
+    }
 
     pub fn issuer_public_key() -> IssuerPublicKey {
         IssuerPublicKey {
             p_key: issuer_primary_public_key(),
-            r_key: Some(revocation_pub_key())
+            r_key: Some(revocation_pub_key()),
+            rsa_r_key: None
         }
     }
 
@@ -714,7 +1679,8 @@ pub mod mocks {
     pub fn claim() -> ClaimSignature {
         ClaimSignature {
             p_claim: primary_claim(),
-            r_claim: Some(revocation_claim())
+            r_claim: Some(revocation_claim()),
+            rsa_r_claim: None
         }
     }
 
@@ -728,45 +1694,11 @@ pub mod mocks {
     }
 
     pub fn revocation_claim() -> NonRevocationClaimSignature {
-        NonRevocationClaimSignature {
-            sigma: PointG1::from_string("false C8C7213101C60F F625A22E65736C 695A1F398B4787 D087ABB966C5BC 1EA63E37 7895832C96B02C 60C7E086DFA7AF 1518CD71A957F3 C1BED176429FB9 11DD23B3 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            c: GroupOrderElement::from_string("4CF57E7A173E6 27720818863F49 D72801BCE5CBE9 7C8C588E2A8B3B 3642B08").unwrap(),
-            vr_prime_prime: GroupOrderElement::from_string("2BC52B6D8B5F4B 26E57208D0DB35 D0411E4BE49639 18A8BC10BF946E 1F8689A5").unwrap(),
-            witness: witness(),
-            g_i: PointG1::from_string("false 1A5D92950F9D1C 82DB5D4BF49AB8 FBFF5E631AD221 9B89F534C2AC04 165F1606 2E5EE0ECDBB554 F4C238315ACC2 57CAA2D6085FA6 CCE1970A4628E9 119D86E1 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            i: 1,
-            m2: GroupOrderElement::from_string("7219C82BC1A5C5 2E958256CDE0D B6FBB94E62AC37 4DAA41B3F577 74DDF3F3").unwrap()
-        }
-    }
-
-    fn witness() -> Witness {
-        let mut v: HashSet<u32> = HashSet::new();
-        v.insert(1);
-
-        Witness {
-            sigma_i: PointG2::from_string("false D75D129A90AC7C E980CE49738692 E81F6656B7EC8B 5CB508713E5514 1C8D263D 277F296ED2870 DD07D7557B996C 3E3A4CBE72B433 CE6A5B3F49DCF0 12760A8D 794C7329844D36 5F061EF8268D0B 6931F242E445A2 941EE07805B105 112CCA EA8F2154379FFC E347F4C23152D6 81B0FD797DECC 99649EAE531C52 306F627 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-            u_i: PointG2::from_string("false 5BDC53BAF81A3F 161769B604A474 B7D29413291CFF 339D755F2188BC 33CD0CE D67B914F2755B3 9753565047A4C7 A431380FD96DC BDC9CF432D6969 167143C2 E8C107037A2973 9D6DC89136F5CD 24A92213C2C956 5B52182802ADB 23673530 237EC2A2AE67B4 B2680968AA2A 52E5202656A6A6 CB2696283382AE 251DD0E6 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-            g_i: PointG1::from_string("false 1A5D92950F9D1C 82DB5D4BF49AB8 FBFF5E631AD221 9B89F534C2AC04 165F1606 2E5EE0ECDBB554 F4C238315ACC2 57CAA2D6085FA6 CCE1970A4628E9 119D86E1 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            omega: PointG2::from_string("true 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0").unwrap(),
-            v
-        }
+        REVOCATION_MOCKS.r_claim.clone()
     }
 
     pub fn revocation_pub_key() -> IssuerRevocationPublicKey {
-        IssuerRevocationPublicKey {
-            g: PointG1::from_string("false F7061FFC5D86BD FEA559D709EBB4 184F0E83E83C7F 77518EACC28D21 1B2D4E76 86D88DDE8770D0 5034DD68624C0 CA409B38BD8B6A EC15B842470D5B 2188CB11 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            g_dash: PointG2::from_string("false B689CDF1EEE6B9 7879E722A8927E 7A5F92EB847EF4 CB7D2B559A4B7A 94DFCE9 F353AFC74815B1 DF1DB4459153A0 FEBB4F1B0DC6CB 375723BC12026E 3A02BC0 CA432D2B712EBE 28310825C67A82 FCD05276543D75 4A06A4C1A05435 1DA19E B02395BAE668B8 10BD3BCD5F1CC9 92FC516611102D CC2E8568C3C687 1A06AE72 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-            h: PointG1::from_string("false E6C4969BF7F6E4 D2FF8C24B9EAA4 88F6451A20353E E46C8775910036 1F23D6AB E7E72372E2006B 20894EC16703C2 E97C22B6CFA42A A7EAD87F1E74BE 242E67D3 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            h0: PointG1::from_string("false BEBA02E97255CC CB4AA09F541688 7AF29F26C85B42 6F3597F4EF2844 205788E5 8A078274BC3198 ADAC33647064BA 7E67D45EF39249 58FD2A0AFE6250 18010994 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            h1: PointG1::from_string("false 9661FB704E366 51836F20094702 27521DB454AEB9 801E3DA189E912 A37E50F 367705F353C794 515F17102321FC 9A7D613710C121 84DCCCAA671F2D 10CF357E FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            h2: PointG1::from_string("false C242FAE6B2465C 952C5CFEB62F09 CAA6A61B8238EA 67C2CE0F7EFB6A 190433AA FFA4D1B5551EEA A35F5F43E84616 B92A757ABD51D6 8110F04280410A 664C174 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            htilde: PointG1::from_string("false 1AE71B910A158E 330457779B6C85 F1C907A134D795 19A9DB2FD49411 D3B769E 6015F30C57A0E7 310B6007D23DBD C1B8D416480847 85C92B35A32A25 18E8F8A2 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            h_cap: PointG2::from_string("false 1A1C59765D49BE BCE6181A3B736A BAB72551F8F462 5F99B2715F2A25 1FEFD0C7 8A6472CB371D3E C4F6C33AA80CB 86EAAE909EA09 197B4EC78A3B09 1333E51F 2DC7441E28B2D4 C388A79FA4961A 79058DBBCF4C8A 8B2F97E23D4342 FBC67FF 52B82226D2995D 34D053907E3850 D965050D54F027 A4E7CF8D2A5220 8C29822 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-            u: PointG2::from_string("false 9B1FCFE75F79F8 73D1BC00D107C7 577D8010343FC0 35533CFE595E1F 13CEE3F8 8F38C7640C9982 F42092066D94BB D309384A81D943 340E1CCB6D1788 22A6CB75 43CC781E5A6C8F 4BD67F27D8C58D 884C09DE7F5DD6 5687B2A7047663 1441F423 313BC475C70F5B 36957040BCE3BA D9E78497E2F745 48902ADC4EC27B 853A4A3 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-            pk: PointG1::from_string("false 14A982E1F1C801 30A3F71F937305 E9780E68F9B8A3 11A7A4ECED7FF2 1D873FD7 24632538A440FA E77FAD44B8C9A 78854503568CAC 1A0BC5C8E7F1B9 8EAF176 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap(),
-            y: PointG2::from_string("false 8B7A8A72F510A 72414040912D7 760941DAB9E957 4D95E546557B75 6025130 7A9DB180AD2CA9 4FB0C15F96EB6E D753A5F9284015 D75962552FE3E5 1682A17B 30DA4BF8C17A8A 5DA41C9E4F7BED 4F01B812A1E5E1 7F5151E1A47E99 1358AD80 4E75A24CC7339 AC18180F5D570A BE9C1DA918469B 578E1909A8B1F C7EDD39 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-
-        }
+        REVOCATION_MOCKS.r_pub_key.clone()
     }
 
     pub fn revocation_private_key() -> IssuerRevocationPrivateKey {
@@ -777,58 +1709,19 @@ pub mod mocks {
     }
 
     pub fn accumulator_pub_key() -> RevocationAccumulatorPublicKey {
-        RevocationAccumulatorPublicKey {
-            z: Pair::from_string("B0C52EBB799E8 6FC6F7D6883390 BC4244EDBC1787 FDEA974C84C1F1 234FA3A6 F411BCC525581F B238C8B10BBACB 8536CC797D203D DEFEAA1B1DBC5B 736EAC 529F008C0398B9 CD0B30B71A1F14 2D332E37CEBF1B A3D9B3319DCDAD CA1AAD2 E5B506C98D6F95 575329E5789B3B CA3A9AB8CED863 BB16612D7EDFC9 241D0C39 810C5FA05825E3 C8A863BA7721CD DCCCB939E4BC22 1817F872AA9906 E423204 C38DCA6D9C80D6 5DE52EA7CFE23E FB41FA284C112E E438D18C192C1D 88A018F EF8569C86B3916 119FE81D359A09 6D5A0088955ED3 6904F412A28BD4 11F6C539 29AD474B03EE99 D0353A66812CA7 C9763FC9EEB4A3 217160B2B8982E 10983B69 7F67C0FCFD4244 45C9665E75EC5B 4A23D9F0D1182F 3A8C685A922F6 20A176A9 883FF71EB14569 5030243F2B2B79 95A67EF0922D07 A6D74310BFE00A F8BBB21 476E55B2836798 16B49B2120D6EB 68EABD968A44DE E8DF358500A99A 15A3F96B 28749CC7A07F60 F82B17A0CA933F EE4166241C77F2 9BE2BB4B802250 19F0D85E").unwrap(),
-        }
+        REVOCATION_MOCKS.rev_reg_pub.key.clone()
     }
 
     pub fn accumulator() -> RevocationAccumulator {
-        let mut v: HashSet<u32> = HashSet::new();
-        v.insert(1);
-
-        RevocationAccumulator {
-            acc: PointG2::from_string("false 1348A2A978E0DB 34007FF6AF40CE 6D0587A6FB0664 5C7BE100A9A5F0 195FD169 A8C3298C4E3638 F93A75199C097D F3659F1FB6AE4A A03EC27AEB629 2435D86 4DA6C9C1917365 866CCF7C293373 216DF40B2F9E81 19F44DEEC2C748 170C3B8A DDEA4569FCEEC7 1685AB7B80F94F 5BB29412B2822D 3FE85A96139673 109B08B8 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap(),
-            v,
-            max_claim_num: 5
-        }
+        REVOCATION_MOCKS.rev_reg_pub.acc.clone()
     }
 
     pub fn tails() -> RevocationAccumulatorTails {
-        let mut tails: HashMap<u32, PointG1> = HashMap::new();
-        tails.insert(7, PointG1::from_string("false 6497C5436F096B 47B5497245ECBD FAFB1DB54701E4 20D8BA8C0BF17 70C77C4 88287D43F7A4B4 8BB4E20D6F44B7 E2AA930F85AC1D E2EFAE9BD7510F 8E58412 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(0, PointG1::from_string("false F7061FFC5D86BD FEA559D709EBB4 184F0E83E83C7F 77518EACC28D21 1B2D4E76 86D88DDE8770D0 5034DD68624C0 CA409B38BD8B6A EC15B842470D5B 2188CB11 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(2, PointG1::from_string("false 7F09E0BD7D3690 6479A9EC6FA073 64573936BF650B BE36F8593539B1 B171A77 904E5FF1902F37 CFB9E9C4DF1C84 3D02EE7915746B 68D700E062F81D 1DD4A216 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(8, PointG1::from_string("false D279E0F66FC8DA E287C065D3AAAB 4CE6A253645D30 433B6C8EF0CDA8 11CCBF5D 37B2100A28726B 4D9F49CF3A19B9 CC2CB102DC087D 7796535BC28B35 F520BB6 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(9, PointG1::from_string("false 2D436D106648F2 14A694AE410D8 E1F0468B1CC344 A11933E6D0C2F3 22590BB3 9DF2B5CE005F33 E39339635A02FA A890D5AFE6D251 1743F88BE1D067 D39B2C9 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(4, PointG1::from_string("false D2B947E76DC768 9544E27827014B A849C431714D5F D89704800BD2FD 5ABD852 AF2F1718B1CEE6 49242AF192601A CC13E78DA62DC7 6CC9B9161777FE 59A1C57 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(5, PointG1::from_string("false 41E6423FDE4D3 ED26FCE2D16487 2D297CA2796CEE 759B69CD067458 204F1642 AB82F9318A595F B574DF93832227 E792C9B54537BB 993558762A4619 967B9E7 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(3, PointG1::from_string("false 15720DF0F49B7F 34C04EC737127C BB4CA2E80916C7 A49E082782FC2C 51F62B5 E578481BE30FED 2885D24B4D01C4 A47BE2CEA0074F 849210EFC63320 6B01000 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-        tails.insert(1, PointG1::from_string("false 1A5D92950F9D1C 82DB5D4BF49AB8 FBFF5E631AD221 9B89F534C2AC04 165F1606 2E5EE0ECDBB554 F4C238315ACC2 57CAA2D6085FA6 CCE1970A4628E9 119D86E1 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD").unwrap());
-
-        let mut tails_dash: HashMap<u32, PointG2> = HashMap::new();
-        tails_dash.insert(8, PointG2::from_string("false 390D1709376721 98245AABF4362B AC1FACB6BBD05E E4C3386AE5503A 128CD835 A982F82EC38AA9 C4D509C50A5739 147B931F0BAAB 9D2E2891090067 1E093A4 77F9E4C8101F59 BB5F319909D7D4 564B8C47AA666C CC8C97E732D170 220116B8 3A10DE9707FCB6 52542617716EA EB57185B59742D D01BF217533C5C 193BC78B FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(1, PointG2::from_string("false CD975AFB9C5067 8FA21CC4E37FC3 CCAFBC2CB2CED2 AF24B72B23046E 1085AD0B 29B067BCCC5E0D D1A5FFD58150B E4DBB40838690C 84910B94ED77BE 135D66D F45FCF475FBB9C 63A1CF9D8DBDD2 F55CB07F8233A2 279087BA842772 B93978E 18C43310CDAA0 421A062395787F 96D1D9787FC4FD E2ABEB6A00F0A1 1140BA54 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(4, PointG2::from_string("false 78E66848A7F5F9 132AB1C7553E01 4F277AB53DEFD0 EAAFD109BA7934 2E796AA A9CF83C15175AD 53D69FF43FC5D2 54883C1D7E9981 9C4D4BD276C31E D8DB152 F9457FB683C672 73873FEB68A789 DD761945B7C230 55BB9B119F2376 1D5BD4A1 419BCA31048242 607694D34D4010 203516C8CBF5E5 A1D5CC2ABBA257 6937824 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(0, PointG2::from_string("false B689CDF1EEE6B9 7879E722A8927E 7A5F92EB847EF4 CB7D2B559A4B7A 94DFCE9 F353AFC74815B1 DF1DB4459153A0 FEBB4F1B0DC6CB 375723BC12026E 3A02BC0 CA432D2B712EBE 28310825C67A82 FCD05276543D75 4A06A4C1A05435 1DA19E B02395BAE668B8 10BD3BCD5F1CC9 92FC516611102D CC2E8568C3C687 1A06AE72 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(7, PointG2::from_string("false 16AF65D69D2077 43BFBF1F03B4DB 1F875B833E17A2 70C749CF644BE2 9776105 D8F3E86EDF958C 213C8D6A15CB6 8032FB837CC91D 3D1F0AEAD45AB2 1D9225F9 824C05CC2BC42B E3CC4DF213DF1A 847C5444BB681 F9023211E70E70 1283B8EA E34FE320EFB7E1 9578E301C12C66 F498C7A87F0B99 E77B86E8244F00 10FDA472 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(9, PointG2::from_string("false 77BA09D7BA300E 40B8FD86701B8E 2F74A578990E71 B28868F9A10567 1D23EFFC 6192F7E92893E2 8ECD622E98521 4E911BCF1ED8FB B77137674C6EFB 9CB358A 636339738BC01D 6AB860F1085F6D B53E0479005A69 7D3D559B98792E 239C96A E4468AC977895F 946CC6D42E40A7 EFACABFF178BF E74E1D503F3454 486C83C FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(5, PointG2::from_string("false 1348A2A978E0DB 34007FF6AF40CE 6D0587A6FB0664 5C7BE100A9A5F0 195FD169 A8C3298C4E3638 F93A75199C097D F3659F1FB6AE4A A03EC27AEB629 2435D86 4DA6C9C1917365 866CCF7C293373 216DF40B2F9E81 19F44DEEC2C748 170C3B8A DDEA4569FCEEC7 1685AB7B80F94F 5BB29412B2822D 3FE85A96139673 109B08B8 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(2, PointG2::from_string("false A833A7FEE1899C DDB240BB9D5C70 B12225E6BC6BC8 7214770046E216 11C7AF4B C673E87B0E3CA7 D402059952151C C78BAC6AE271F4 B767948A4CC721 16EDBCA9 9A898AF8DDF47C 2AA64013B834B4 643EFBCBFA5FCA F7FA0B6D3ADC92 1BA2FD5D 7C664B888E3A8F FC90E07B7CF85 A57C697325853C A7383E71F41985 227D1F8B FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-        tails_dash.insert(3, PointG2::from_string("false 5E2B71E59F20F1 CDDC6CB91B3D78 9B3130684357E5 A9160132CEDE21 59E3FD8 CBE453D80A803A A96D59D7F885ED 1FAF1E7D84D77D 6703AB31EDF74E 1197DD1B EEEC3499BFBBDC 6B17FABA999A2D 9BE155AB7EFC66 BC4CBFC73D258E 15027EDA 31FDA448757FB7 E4B6213FD3BD56 BCC89B065F8BFF 3775B8DA93E846 107B0CE9 FFFFFF7D07A8A8 FFFF7888802F07 FFC63D474548B7 F417D05FB10933 95E45DD 0 0 0 0 0").unwrap());
-
-
-        RevocationAccumulatorTails {
-            tails,
-            tails_dash
-        }
+        REVOCATION_MOCKS.rev_tails.clone()
     }
 
     pub fn revocation_reg_public() -> RevocationRegistryPublic {
-        RevocationRegistryPublic {
-            key: accumulator_pub_key(),
-            acc: accumulator(),
-            tails: tails()
-        }
+        REVOCATION_MOCKS.rev_reg_pub.clone()
     }
 
     pub fn r_cnxt_m2() -> BigNumber {
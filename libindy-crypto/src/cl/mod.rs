@@ -2,13 +2,21 @@ extern crate serde_json;
 
 mod constants;
 mod helpers;
+pub mod index_set;
 pub mod issuer;
 pub mod prover;
+pub mod rsa_accumulator;
+pub mod tails;
 pub mod verifier;
 
 use bn::BigNumber;
+use cl::index_set::IndexSet;
+use cl::rsa_accumulator::{RsaAccumulatorPublicKey, RsaNonRevocationClaimSignature, RsaNonRevocInitProof, RsaNonRevocProof};
 use errors::IndyCryptoError;
 use pair::*;
+use sha2::{Sha256, Digest};
+#[cfg(feature = "serialization")]
+use utils::encrypted_json::EncryptedJson;
 use utils::json::{JsonEncodable, JsonDecodable};
 
 use std::collections::{HashMap, HashSet};
@@ -84,6 +92,15 @@ impl ClaimValuesBuilder {
     }
 }
 
+/// Revocation scheme non-revocation part of Issuer keys is generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RevocationBackend {
+    /// Pairing based accumulator. Requires tails.
+    Pairing,
+    /// Strong RSA accumulator (see `rsa_accumulator`). Doesn't require tails.
+    Rsa
+}
+
 /// `Issuer Public Key` contains 2 internal parts.
 /// One for signing primary claims and second for signing non-revocation claims.
 /// These keys are used to proof that claim was issued and doesn’t revoked by this issuer.
@@ -92,13 +109,19 @@ impl ClaimValuesBuilder {
 pub struct IssuerPublicKey {
     p_key: IssuerPrimaryPublicKey,
     r_key: Option<IssuerRevocationPublicKey>,
+    #[serde(default)]
+    rsa_r_key: Option<RsaAccumulatorPublicKey>,
 }
 
 impl IssuerPublicKey {
     pub fn clone(&self) -> Result<IssuerPublicKey, IndyCryptoError> {
         Ok(IssuerPublicKey {
             p_key: self.p_key.clone()?,
-            r_key: self.r_key.clone()
+            r_key: self.r_key.clone(),
+            rsa_r_key: match self.rsa_r_key {
+                Some(ref rsa_r_key) => Some(rsa_r_key.clone()?),
+                None => None
+            }
         })
     }
 
@@ -110,10 +133,28 @@ impl IssuerPublicKey {
         Ok(self.r_key.clone())
     }
 
+    pub fn get_rsa_revocation_key(&self) -> Result<Option<RsaAccumulatorPublicKey>, IndyCryptoError> {
+        match self.rsa_r_key {
+            Some(ref rsa_r_key) => Ok(Some(rsa_r_key.clone()?)),
+            None => Ok(None)
+        }
+    }
+
+    pub fn get_revocation_backend(&self) -> Option<RevocationBackend> {
+        if self.r_key.is_some() {
+            Some(RevocationBackend::Pairing)
+        } else if self.rsa_r_key.is_some() {
+            Some(RevocationBackend::Rsa)
+        } else {
+            None
+        }
+    }
+
     pub fn build_from_parts(p_key: &IssuerPrimaryPublicKey, r_key: Option<&IssuerRevocationPublicKey>) -> Result<IssuerPublicKey, IndyCryptoError> {
         Ok(IssuerPublicKey {
             p_key: p_key.clone()?,
-            r_key: r_key.map(|key| key.clone())
+            r_key: r_key.map(|key| key.clone()),
+            rsa_r_key: None
         })
     }
 }
@@ -134,6 +175,11 @@ impl JsonEncodable for IssuerPrivateKey {}
 
 impl<'a> JsonDecodable<'a> for IssuerPrivateKey {}
 
+#[cfg(feature = "serialization")]
+impl EncryptedJson for IssuerPrivateKey {
+    const KIND: &'static str = "IssuerPrivateKey";
+}
+
 /// `Primary Public Key` is used to prove that claim was issued and satisfy the proof request.
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub struct IssuerPrimaryPublicKey {
@@ -188,14 +234,24 @@ pub struct IssuerRevocationPrivateKey {
     sk: GroupOrderElement
 }
 
-/// `Revocation Registry Public` contain revocation keys, accumulator and accumulator tails.
+/// `Revocation Registry Public` contain revocation keys, accumulator and tails file hash.
 /// Must be shared by Issuer in trusted place
 /// Can be used to proof that concrete claim wasn’t revoked.
+/// Accumulator tails are distributed separately (see `tails::TailsReader`),
+/// `tails_hash` allows to check them by `tails::TailsReader::verify`.
+/// Historical accumulator states can be kept as snapshots (see `take_snapshot`).
+/// `version` is increased by every issuance or revocation, so registry state is identified
+/// by it even if accumulator value repeats (for example after revocation of just issued claim).
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RevocationRegistryPublic {
     key: RevocationAccumulatorPublicKey,
     acc: RevocationAccumulator,
-    tails: RevocationAccumulatorTails,
+    #[serde(default)]
+    version: u64,
+    #[serde(default)]
+    tails_hash: Vec<u8>,
+    #[serde(default)]
+    snapshots: Vec<RevocationAccumulatorSnapshot> /* ordered by id */,
 }
 
 impl RevocationRegistryPublic {
@@ -207,23 +263,140 @@ impl RevocationRegistryPublic {
         Ok(self.acc.clone())
     }
 
-    pub fn get_tails(&self) -> Result<RevocationAccumulatorTails, IndyCryptoError> {
-        Ok(self.tails.clone())
+    pub fn get_tails_hash(&self) -> &[u8] {
+        &self.tails_hash
+    }
+
+    pub fn get_version(&self) -> u64 {
+        self.version
     }
 
-    pub fn build_from_parts(key: &RevocationAccumulatorPublicKey, acc: &RevocationAccumulator, tails: &RevocationAccumulatorTails) -> Result<RevocationRegistryPublic, IndyCryptoError> {
+    pub fn build_from_parts(key: &RevocationAccumulatorPublicKey, acc: &RevocationAccumulator, tails_hash: &[u8]) -> Result<RevocationRegistryPublic, IndyCryptoError> {
         Ok(RevocationRegistryPublic {
             key: key.clone(),
             acc: acc.clone(),
-            tails: tails.clone()
+            version: 0,
+            tails_hash: tails_hash.to_vec(),
+            snapshots: Vec::new()
+        })
+    }
+
+    /// Stores current accumulator value as snapshot with given id (for example unix timestamp).
+    /// Only accumulator value and hash of used indexes are kept, used indexes of the snapshot moment
+    /// must be kept by Prover (see `ProofBuilder::add_sub_proof_request_for_snapshot`).
+    /// At most `constants::MAX_ACCUMULATOR_SNAPSHOTS` latest snapshots are kept, oldest ones are dropped.
+    ///
+    /// Snapshot ids must increase, otherwise `InvalidState` error will be returned.
+    pub fn take_snapshot(&mut self, snapshot_id: u64) -> Result<(), IndyCryptoError> {
+        if let Some(last) = self.snapshots.last() {
+            if last.id >= snapshot_id {
+                return Err(IndyCryptoError::InvalidState(
+                    format!("Accumulator snapshot id {} isn't greater than last snapshot id {}", snapshot_id, last.id)));
+            }
+        }
+
+        self.snapshots.push(RevocationAccumulatorSnapshot { id: snapshot_id, acc: self.acc.acc, v_hash: self.acc._v_hash() });
+
+        if self.snapshots.len() > constants::MAX_ACCUMULATOR_SNAPSHOTS {
+            let excess = self.snapshots.len() - constants::MAX_ACCUMULATOR_SNAPSHOTS;
+            self.snapshots.drain(..excess);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_snapshot(&self, snapshot_id: u64) -> Result<RevocationAccumulatorSnapshot, IndyCryptoError> {
+        self._get_snapshot(snapshot_id).map(|snapshot| snapshot.clone())
+    }
+
+    pub fn get_snapshot_ids(&self) -> Vec<u64> {
+        self.snapshots.iter().map(|snapshot| snapshot.id).collect()
+    }
+
+    fn _get_snapshot(&self, snapshot_id: u64) -> Result<&RevocationAccumulatorSnapshot, IndyCryptoError> {
+        self.snapshots
+            .binary_search_by_key(&snapshot_id, |snapshot| snapshot.id)
+            .map(|pos| &self.snapshots[pos])
+            .map_err(|_| IndyCryptoError::InvalidStructure(format!("Accumulator snapshot {} not found", snapshot_id)))
+    }
+
+    /// Returns accumulator with value of snapshot with given id.
+    /// Used indexes aren't kept in snapshots, so returned accumulator can be used only for proof verification.
+    fn _get_snapshot_accumulator(&self, snapshot_id: u64) -> Result<RevocationAccumulator, IndyCryptoError> {
+        let snapshot = self._get_snapshot(snapshot_id)?;
+
+        Ok(RevocationAccumulator {
+            acc: snapshot.acc,
+            v: IndexSet::new(),
+            max_claim_num: self.acc.max_claim_num
         })
     }
+
+    /// Checks that accumulator kept by Prover has the same value and used indexes as snapshot with given id.
+    fn _check_snapshot_accumulator(&self, snapshot_id: u64, acc: &RevocationAccumulator) -> Result<(), IndyCryptoError> {
+        let snapshot = self._get_snapshot(snapshot_id)?;
+
+        if snapshot.acc != acc.acc || snapshot.v_hash != acc._v_hash() || self.acc.max_claim_num != acc.max_claim_num {
+            return Err(IndyCryptoError::InvalidStructure(format!("Accumulator doesn't correspond to snapshot {}", snapshot_id)));
+        }
+
+        Ok(())
+    }
+
+    /// Applies changes described by `Revocation Registry Delta` to this registry.
+    ///
+    /// Delta can be applied only to the registry state it was created for (same registry version
+    /// and accumulator value), otherwise `InvalidState` error will be returned.
+    /// So delta can't be replayed after later changes of registry, for example to restore revoked claim.
+    pub fn apply_delta(&mut self, delta: &RevocationRegistryDelta) -> Result<(), IndyCryptoError> {
+        if self.version != delta.prev_version {
+            return Err(IndyCryptoError::InvalidState(
+                format!("Revocation registry delta was created for registry version {}, current version is {}", delta.prev_version, self.version)));
+        }
+
+        if self.acc.acc != delta.prev_acc {
+            return Err(IndyCryptoError::InvalidState(format!("Revocation registry delta doesn't correspond to current accumulator value")));
+        }
+
+        if let Some(idx) = delta.issued.iter().find(|idx| self.acc.is_idx_used(*idx)) {
+            return Err(IndyCryptoError::InvalidState(format!("Revocation index {} from delta is already used", idx)));
+        }
+
+        self.acc.acc = delta.acc;
+        self.acc.v.union_with(&delta.issued);
+        self.version += 1;
+
+        Ok(())
+    }
 }
 
 impl JsonEncodable for RevocationRegistryPublic {}
 
 impl<'a> JsonDecodable<'a> for RevocationRegistryPublic {}
 
+/// Value of `Revocation Accumulator` at some moment identified by snapshot id.
+/// Keeps SHA-256 hash of used indexes instead of indexes themselves.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevocationAccumulatorSnapshot {
+    id: u64,
+    acc: PointG2,
+    v_hash: Vec<u8>,
+}
+
+impl RevocationAccumulatorSnapshot {
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get_accumulator_value(&self) -> PointG2 {
+        self.acc
+    }
+
+    pub fn get_used_indexes_hash(&self) -> &[u8] {
+        &self.v_hash
+    }
+}
+
 /// `Revocation Registry Private` used for adding claims in the accumulator.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RevocationRegistryPrivate {
@@ -234,10 +407,15 @@ impl JsonEncodable for RevocationRegistryPrivate {}
 
 impl<'a> JsonDecodable<'a> for RevocationRegistryPrivate {}
 
+#[cfg(feature = "serialization")]
+impl EncryptedJson for RevocationRegistryPrivate {
+    const KIND: &'static str = "RevocationRegistryPrivate";
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RevocationAccumulator {
     acc: PointG2,
-    v: HashSet<u32> /* used indexes */,
+    v: IndexSet /* used indexes */,
     max_claim_num: u32,
 }
 
@@ -246,10 +424,91 @@ impl RevocationAccumulator {
         self.v.len() >= self.max_claim_num as usize
     }
     pub fn is_idx_used(&self, idx: u32) -> bool {
-        self.v.contains(&idx)
+        self.v.contains(idx)
+    }
+
+    fn _v_hash(&self) -> Vec<u8> {
+        let mut hasher = Sha256::default();
+        hasher.input(&self.v.to_bytes());
+        hasher.result().to_vec()
+    }
+}
+
+impl JsonEncodable for RevocationAccumulator {}
+
+impl<'a> JsonDecodable<'a> for RevocationAccumulator {}
+
+/// `Revocation Accumulator Statement` is announcement of accumulator state made by Issuer.
+/// Binds accumulator to revocation registry id, sequence number and timestamp and is signed
+/// by Issuer BLS sign key (see `Issuer::sign_accumulator_statement`), so verifiers can get
+/// accumulator from untrusted source and check it by `Verifier::check_accumulator_statement`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevocationAccumulatorStatement {
+    rev_reg_id: String,
+    seq_no: u64,
+    timestamp: u64 /* seconds since unix epoch */,
+    acc: RevocationAccumulator,
+    signature: Vec<u8> /* BLS signature bytes */
+}
+
+impl RevocationAccumulatorStatement {
+    pub fn get_rev_reg_id(&self) -> &str {
+        &self.rev_reg_id
+    }
+
+    pub fn get_seq_no(&self) -> u64 {
+        self.seq_no
+    }
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn get_accumulator(&self) -> Result<RevocationAccumulator, IndyCryptoError> {
+        Ok(self.acc.clone())
+    }
+
+    /// Returns bytes covered by statement signature.
+    fn _signed_bytes(rev_reg_id: &str, seq_no: u64, timestamp: u64, acc: &RevocationAccumulator) -> Result<Vec<u8>, IndyCryptoError> {
+        let mut bytes: Vec<u8> = Vec::new();
+
+        bytes.extend_from_slice(constants::ACCUMULATOR_STATEMENT_DOMAIN);
+        bytes.extend_from_slice(&helpers::transform_u32_to_array_of_u8(rev_reg_id.len() as u32));
+        bytes.extend_from_slice(rev_reg_id.as_bytes());
+        bytes.extend_from_slice(&helpers::transform_u64_to_array_of_u8(seq_no));
+        bytes.extend_from_slice(&helpers::transform_u64_to_array_of_u8(timestamp));
+        bytes.extend_from_slice(&acc.acc.to_bytes()?);
+        bytes.extend_from_slice(&helpers::transform_u32_to_array_of_u8(acc.max_claim_num));
+        bytes.extend_from_slice(&acc.v.to_bytes());
+
+        Ok(bytes)
+    }
+}
+
+impl JsonEncodable for RevocationAccumulatorStatement {}
+
+impl<'a> JsonDecodable<'a> for RevocationAccumulatorStatement {}
+
+/// `Revocation Registry Delta` contains changes of `Revocation Registry Public` made by claims issuance.
+/// Allows Issuer to sign claims without mutation of registry and apply (and persist) changes later.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RevocationRegistryDelta {
+    prev_version: u64,
+    prev_acc: PointG2,
+    acc: PointG2,
+    issued: IndexSet /* indexes added to accumulator */,
+}
+
+impl RevocationRegistryDelta {
+    pub fn get_issued(&self) -> Result<IndexSet, IndyCryptoError> {
+        Ok(self.issued.clone())
     }
 }
 
+impl JsonEncodable for RevocationRegistryDelta {}
+
+impl<'a> JsonDecodable<'a> for RevocationRegistryDelta {}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RevocationAccumulatorPrivateKey {
     gamma: GroupOrderElement
@@ -260,17 +519,29 @@ pub struct RevocationAccumulatorPublicKey {
     z: Pair
 }
 
+impl JsonEncodable for RevocationAccumulatorPublicKey {}
+
+impl<'a> JsonDecodable<'a> for RevocationAccumulatorPublicKey {}
+
+/// In-memory accumulator tails. Can be stored to file by `tails::write_tails_file`
+/// and read on demand by `tails::FileTailsReader`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RevocationAccumulatorTails {
     tails: HashMap<u32 /* index in acc */, PointG1>,
     tails_dash: HashMap<u32 /* index in acc */, PointG2>,
 }
 
+impl JsonEncodable for RevocationAccumulatorTails {}
+
+impl<'a> JsonDecodable<'a> for RevocationAccumulatorTails {}
+
 /// Signed by the Issuer part of the Claim.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ClaimSignature {
     p_claim: PrimaryClaimSignature,
     r_claim: Option<NonRevocationClaimSignature> /* will be used to proof is claim revoked preparation */,
+    #[serde(default)]
+    rsa_r_claim: Option<RsaNonRevocationClaimSignature>,
 }
 
 impl JsonEncodable for ClaimSignature {}
@@ -302,7 +573,7 @@ pub struct Witness {
     u_i: PointG2,
     g_i: PointG1,
     omega: PointG2,
-    v: HashSet<u32>
+    v: IndexSet
 }
 
 /// Secret prover data that is used to proof that prover owns the claim.
@@ -319,6 +590,41 @@ impl JsonEncodable for MasterSecret {}
 
 impl<'a> JsonDecodable<'a> for MasterSecret {}
 
+#[cfg(feature = "serialization")]
+impl EncryptedJson for MasterSecret {
+    const KIND: &'static str = "MasterSecret";
+}
+
+/// Scope-exclusive pseudonym of Prover derived from master secret and scope string provided by Verifier.
+/// The same master secret always gives the same pseudonym within one scope,
+/// while pseudonyms of different scopes are unlinkable.
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Pseudonym {
+    scope: String,
+    value: BigNumber
+}
+
+impl Pseudonym {
+    pub fn get_scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn get_value(&self) -> Result<BigNumber, IndyCryptoError> {
+        self.value.clone()
+    }
+
+    pub fn clone(&self) -> Result<Pseudonym, IndyCryptoError> {
+        Ok(Pseudonym {
+            scope: self.scope.clone(),
+            value: self.value.clone()?
+        })
+    }
+}
+
+impl JsonEncodable for Pseudonym {}
+
+impl<'a> JsonDecodable<'a> for Pseudonym {}
+
 /// `Blinded Master Secret` uses by Issuer in claim creation.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct BlindedMasterSecret {
@@ -425,6 +731,17 @@ pub enum PredicateType {
 pub struct Proof {
     proofs: HashMap<String /* issuer pub key id */, SubProof>,
     aggregated_proof: AggregatedProof,
+    #[serde(default)]
+    aggregated_non_revoc_proofs: Vec<AggregatedNonRevocProof>,
+    #[serde(default)]
+    pseudonym: Option<Pseudonym>,
+}
+
+impl Proof {
+    /// Returns pseudonym of Prover if it was requested for proof.
+    pub fn get_pseudonym(&self) -> Option<&Pseudonym> {
+        self.pseudonym.as_ref()
+    }
 }
 
 impl JsonEncodable for Proof {}
@@ -434,7 +751,19 @@ impl<'a> JsonDecodable<'a> for Proof {}
 #[derive(Debug, Deserialize, Serialize)]
 pub struct SubProof {
     primary_proof: PrimaryProof,
-    non_revoc_proof: Option<NonRevocProof>
+    non_revoc_proof: Option<NonRevocProof>,
+    #[serde(default)]
+    rsa_non_revoc_proof: Option<RsaNonRevocProof>
+}
+
+/// Group of non-revocation proofs of claims issued under the same revocation registry.
+/// Only claim identifiers are sent: proof challenge contains single tau list of the group weighted
+/// by hash of claim identifiers and c lists, and Verifier recomputes it by single set of pairings
+/// (see `ProofBuilder::aggregate_non_revocation_proofs`). Aggregation reduces Verifier pairings,
+/// proof isn't smaller than with separate non-revocation proofs.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AggregatedNonRevocProof {
+    key_ids: Vec<String>
 }
 
 #[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -473,13 +802,16 @@ pub struct PrimaryPredicateGEProof {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct NonRevocProof {
     x_list: NonRevocProofXList,
-    c_list: NonRevocProofCList
+    c_list: NonRevocProofCList,
+    #[serde(default)]
+    acc_snapshot_id: Option<u64> /* None if proof is built against current accumulator */
 }
 
 #[derive(Debug)]
 pub struct InitProof {
     primary_init_proof: PrimaryInitProof,
     non_revoc_init_proof: Option<NonRevocInitProof>,
+    rsa_non_revoc_init_proof: Option<RsaNonRevocInitProof>,
     claim_values: ClaimValues,
     sub_proof_request: SubProofRequest,
     claim_schema: ClaimSchema
@@ -515,7 +847,8 @@ pub struct NonRevocInitProof {
     c_list_params: NonRevocProofXList,
     tau_list_params: NonRevocProofXList,
     c_list: NonRevocProofCList,
-    tau_list: NonRevocProofTauList
+    tau_list: NonRevocProofTauList,
+    acc_snapshot_id: Option<u64>
 }
 
 impl NonRevocInitProof {
@@ -639,7 +972,7 @@ impl NonRevocProofCList {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct NonRevocProofTauList {
     t1: PointG1,
     t2: PointG1,
@@ -668,7 +1001,10 @@ impl<'a> JsonDecodable<'a> for Nonce {}
 #[derive(Debug)]
 pub struct VerifyClaim {
     pub_key: IssuerPublicKey,
-    r_reg: Option<RevocationRegistryPublic>,
+    r_acc: Option<RevocationAccumulator>,
+    r_acc_pub_key: Option<RevocationAccumulatorPublicKey>,
+    acc_snapshot_id: Option<u64>,
+    rsa_acc: Option<BigNumber>,
     sub_proof_request: SubProofRequest,
     claim_schema: ClaimSchema
 }
@@ -752,7 +1088,7 @@ mod test {
                                                      &claim_values,
                                                      &issuer_pub_key,
                                                      &issuer_priv_key,
-                                                     Some(1), None, None).unwrap();
+                                                     Some(1), None, None, None).unwrap();
         Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
 
         let mut sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
@@ -766,6 +1102,7 @@ mod test {
                                             &claim_signature,
                                             &claim_values,
                                             &issuer_pub_key,
+                                            None,
                                             None).unwrap();
         let nonce = Verifier::new_nonce().unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
@@ -774,4 +1111,89 @@ mod test {
         proof_verifier.add_sub_proof_request("issuer_key_id_1", &sub_proof_request, &claim_schema, &issuer_pub_key, None).unwrap();
         assert_eq!(true, proof_verifier.verify(&proof, &nonce).unwrap());
     }
+
+    #[test]
+    fn demo_works_for_aggregated_non_revocation_proof_without_aggregation_info() {
+        let mut claim_schema_builder = Issuer::new_claim_schema_builder().unwrap();
+        claim_schema_builder.add_attr("name").unwrap();
+        claim_schema_builder.add_attr("age").unwrap();
+        let claim_schema = claim_schema_builder.finalize().unwrap();
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+        let master_secret = Prover::new_master_secret().unwrap();
+        let mut claim_values_builder = Issuer::new_claim_values_builder().unwrap();
+        claim_values_builder.add_value("name", "1139481716457488690172217916278103335").unwrap();
+        claim_values_builder.add_value("age", "28").unwrap();
+        let claim_values = claim_values_builder.finalize().unwrap();
+
+        let mut claim_signatures = Vec::new();
+        for rev_idx in 1..3 {
+            let (blinded_master_secret, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+            let mut claim_signature = Issuer::sign_claim("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", &blinded_master_secret,
+                                                         &claim_values,
+                                                         &issuer_pub_key,
+                                                         &issuer_priv_key,
+                                                         Some(rev_idx),
+                                                         Some(&mut rev_reg_pub),
+                                                         Some(&rev_reg_priv),
+                                                         Some(&rev_tails)).unwrap();
+            Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+            claim_signatures.push(claim_signature);
+        }
+
+        let mut sub_proof_request_builder = Verifier::new_sub_proof_request_builder().unwrap();
+        sub_proof_request_builder.add_revealed_attr("name").unwrap();
+        let sub_proof_request = sub_proof_request_builder.finalize().unwrap();
+
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        for (key_id, claim_signature) in ["key_id_1", "key_id_2"].iter().zip(claim_signatures.iter()) {
+            proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, claim_signature, &claim_values,
+                                                &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
+        }
+        proof_builder.aggregate_non_revocation_proofs(&["key_id_1", "key_id_2"]).unwrap();
+        let nonce = Verifier::new_nonce().unwrap();
+        let mut proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // Challenge contains only weighted tau list of the group, so separate verification fails
+        proof.aggregated_non_revoc_proofs.clear();
+
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request("key_id_1", &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        proof_verifier.add_sub_proof_request("key_id_2", &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        assert_eq!(false, proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn revocation_registry_public_take_snapshot_works_for_max_snapshots() {
+        let (pub_key, _) = Issuer::new_keys(&issuer::mocks::claim_schema(), true).unwrap();
+        let (mut rev_reg_pub, _, _) = Issuer::new_revocation_registry(&pub_key, 5).unwrap();
+
+        for id in 0..(constants::MAX_ACCUMULATOR_SNAPSHOTS as u64 + 2) {
+            rev_reg_pub.take_snapshot(id).unwrap();
+        }
+
+        let snapshot_ids = rev_reg_pub.get_snapshot_ids();
+        assert_eq!(constants::MAX_ACCUMULATOR_SNAPSHOTS, snapshot_ids.len());
+        assert_eq!(2, snapshot_ids[0]);
+        assert!(rev_reg_pub.get_snapshot(0).is_err());
+        assert!(rev_reg_pub.take_snapshot(2).is_err());
+
+        let snapshot = rev_reg_pub.get_snapshot(2).unwrap();
+        assert_eq!(rev_reg_pub.acc.acc, snapshot.get_accumulator_value());
+        assert!(rev_reg_pub._check_snapshot_accumulator(2, &rev_reg_pub.acc).is_ok());
+    }
+
+    #[test]
+    fn check_snapshot_accumulator_works_for_other_used_indexes() {
+        let (pub_key, _) = Issuer::new_keys(&issuer::mocks::claim_schema(), true).unwrap();
+        let (mut rev_reg_pub, _, _) = Issuer::new_revocation_registry(&pub_key, 5).unwrap();
+        rev_reg_pub.take_snapshot(1).unwrap();
+
+        let mut acc = rev_reg_pub.get_accumulator().unwrap();
+        acc.v.insert(3);
+
+        assert!(rev_reg_pub._check_snapshot_accumulator(1, &acc).is_err());
+        assert!(rev_reg_pub._check_snapshot_accumulator(2, &rev_reg_pub.acc).is_err());
+    }
 }
@@ -1,8 +1,11 @@
 use bn::BigNumber;
 use cl::*;
 use cl::constants::*;
+use cl::rsa_accumulator::{self, RsaRevocationRegistry};
+use cl::tails::TailsReader;
 use errors::IndyCryptoError;
 use pair::*;
+use secret_sharing::{SecretSharing, SecretShare, ShareCommitments};
 use super::helpers::*;
 
 use std::collections::{HashMap, HashSet};
@@ -25,6 +28,167 @@ impl Prover {
         })
     }
 
+    /// Creates a master secret deterministically derived from seed,
+    /// so master secret can be recovered from the same seed.
+    ///
+    /// Derivation: SHA-256 blocks `H(MASTER_SECRET_SEED_DOMAIN || i || seed)` for `i = 0, 1, ...`
+    /// (`i` is big-endian u32) are concatenated and truncated to `LARGE_MASTER_SECRET` bits.
+    ///
+    /// # Arguments
+    /// * `seed` - High-entropy seed of at least `MIN_MASTER_SECRET_SEED_LEN` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::prover::Prover;
+    /// let _master_secret = Prover::new_master_secret_from_seed(&[1u8; 32]).unwrap();
+    /// ```
+    pub fn new_master_secret_from_seed(seed: &[u8]) -> Result<MasterSecret, IndyCryptoError> {
+        trace!("Prover::new_master_secret_from_seed: >>> seed len: {:?}", seed.len());
+
+        if seed.len() < MIN_MASTER_SECRET_SEED_LEN {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid len of seed: expected at least {}, actual {}", MIN_MASTER_SECRET_SEED_LEN, seed.len())));
+        }
+
+        let len = (LARGE_MASTER_SECRET + 7) / 8;
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut i = 0;
+
+        while bytes.len() < len {
+            let mut data = MASTER_SECRET_SEED_DOMAIN.to_vec();
+            data.extend_from_slice(&transform_u32_to_array_of_u8(i));
+            data.extend_from_slice(seed);
+            bytes.extend_from_slice(&BigNumber::hash(&data)?);
+            i += 1;
+        }
+
+        bytes.truncate(len);
+
+        if LARGE_MASTER_SECRET % 8 != 0 {
+            bytes[0] &= (1u8 << (LARGE_MASTER_SECRET % 8)) - 1;
+        }
+
+        let master_secret = MasterSecret {
+            ms: BigNumber::from_bytes(&bytes)?
+        };
+
+        trace!("Prover::new_master_secret_from_seed: <<<");
+
+        Ok(master_secret)
+    }
+
+    /// Splits master secret into shares so that any `threshold` of them recover master secret.
+    /// Master secret is split as two 128-bit limbs, returned commitments allow to detect corrupted share.
+    ///
+    /// # Arguments
+    /// * `master_secret` - Master secret.
+    /// * `threshold` - Number of shares required to recover master secret.
+    /// * `shares_count` - Number of shares.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::prover::Prover;
+    /// let master_secret = Prover::new_master_secret().unwrap();
+    /// let (shares, commitments) = Prover::split_master_secret(&master_secret, 2, 3).unwrap();
+    /// let _master_secret = Prover::combine_master_secret(&[&shares[0], &shares[1]], &commitments).unwrap();
+    /// ```
+    pub fn split_master_secret(master_secret: &MasterSecret,
+                               threshold: usize,
+                               shares_count: usize) -> Result<(Vec<SecretShare>, ShareCommitments), IndyCryptoError> {
+        trace!("Prover::split_master_secret: >>> master_secret: {:?}, threshold: {:?}, shares_count: {:?}", master_secret, threshold, shares_count);
+
+        let len = MASTER_SECRET_SHARE_LIMBS * MASTER_SECRET_SHARE_LIMB_LEN;
+        let ms_bytes = master_secret.ms.to_bytes()?;
+
+        if ms_bytes.len() > len {
+            return Err(IndyCryptoError::InvalidStructure(format!("Master secret is too large to be split")));
+        }
+
+        let mut bytes = vec![0u8; len - ms_bytes.len()];
+        bytes.extend_from_slice(&ms_bytes);
+
+        let limbs = bytes.chunks(MASTER_SECRET_SHARE_LIMB_LEN)
+            .map(|limb| GroupOrderElement::from_bytes(limb))
+            .collect::<Result<Vec<GroupOrderElement>, IndyCryptoError>>()?;
+
+        let (shares, commitments) = SecretSharing::split(&limbs, threshold, shares_count)?;
+
+        trace!("Prover::split_master_secret: <<< commitments: {:?}", commitments);
+
+        Ok((shares, commitments))
+    }
+
+    /// Recovers master secret from shares created by `Prover::split_master_secret`.
+    ///
+    /// # Arguments
+    /// * `shares` - At least threshold shares.
+    /// * `commitments` - Share commitments.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::prover::Prover;
+    /// let master_secret = Prover::new_master_secret().unwrap();
+    /// let (shares, commitments) = Prover::split_master_secret(&master_secret, 2, 3).unwrap();
+    /// let _master_secret = Prover::combine_master_secret(&[&shares[2], &shares[0]], &commitments).unwrap();
+    /// ```
+    pub fn combine_master_secret(shares: &[&SecretShare], commitments: &ShareCommitments) -> Result<MasterSecret, IndyCryptoError> {
+        trace!("Prover::combine_master_secret: >>> commitments: {:?}", commitments);
+
+        let limbs = SecretSharing::combine(shares, commitments)?;
+
+        if limbs.len() != MASTER_SECRET_SHARE_LIMBS {
+            return Err(IndyCryptoError::InvalidStructure(format!("Shares don't correspond to master secret")));
+        }
+
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for limb in limbs {
+            let limb_bytes = limb.to_bytes()?;
+            let (high, low) = limb_bytes.split_at(limb_bytes.len() - MASTER_SECRET_SHARE_LIMB_LEN);
+
+            if high.iter().any(|b| *b != 0) {
+                return Err(IndyCryptoError::InvalidStructure(format!("Shares don't correspond to master secret")));
+            }
+
+            bytes.extend_from_slice(low);
+        }
+
+        let master_secret = MasterSecret {
+            ms: BigNumber::from_bytes(&bytes)?
+        };
+
+        trace!("Prover::combine_master_secret: <<<");
+
+        Ok(master_secret)
+    }
+
+    /// Creates pseudonym of Prover for given scope.
+    /// The same master secret always gives the same pseudonym for the same scope,
+    /// but pseudonyms of different scopes are unlinkable.
+    ///
+    /// # Arguments
+    /// * `master_secret` - Master secret.
+    /// * `scope` - Scope string provided by Verifier.
+    ///
+    /// # Example
+    /// ```
+    /// use indy_crypto::cl::prover::Prover;
+    /// let master_secret = Prover::new_master_secret().unwrap();
+    /// let _pseudonym = Prover::new_pseudonym(&master_secret, "example.com").unwrap();
+    /// ```
+    pub fn new_pseudonym(master_secret: &MasterSecret, scope: &str) -> Result<Pseudonym, IndyCryptoError> {
+        trace!("Prover::new_pseudonym: >>> master_secret: {:?}, scope: {:?}", master_secret, scope);
+
+        let pseudonym = Pseudonym {
+            scope: scope.to_owned(),
+            value: calc_pseudonym(scope, &master_secret.ms)?
+        };
+
+        trace!("Prover::new_pseudonym: <<< pseudonym: {:?}", pseudonym);
+
+        Ok(pseudonym)
+    }
+
     /// Creates blinded master secret for given issuer key and master secret.
     ///
     /// # Arguments
@@ -99,7 +263,7 @@ impl Prover {
     ///                                           &claim_values,
     ///                                           &pub_key,
     ///                                           &priv_key,
-    ///                                           None, None, None).unwrap();
+    ///                                           None, None, None, None).unwrap();
     /// Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &pub_key, None).unwrap();
     /// ```
     pub fn process_claim_signature(claim_signature: &mut ClaimSignature,
@@ -126,6 +290,34 @@ impl Prover {
         Ok(())
     }
 
+    /// Updates witness of RSA non-revocation part of the claim signature to the current state
+    /// of RSA revocation registry. Only public registry data is used.
+    ///
+    /// # Arguments
+    /// * `claim_signature` - Claim signature with RSA non-revocation part
+    /// * `issuer_pub_key` - Issuer public key
+    /// * `rsa_rev_reg` - RSA revocation registry
+    pub fn update_rsa_non_revocation_witness(claim_signature: &mut ClaimSignature,
+                                             issuer_pub_key: &IssuerPublicKey,
+                                             rsa_rev_reg: &RsaRevocationRegistry) -> Result<(), IndyCryptoError> {
+        trace!("Prover::update_rsa_non_revocation_witness: >>> claim_signature: {:?}, issuer_pub_key: {:?}, rsa_rev_reg: {:?}",
+               claim_signature, issuer_pub_key, rsa_rev_reg);
+
+        let rsa_r_key = issuer_pub_key.rsa_r_key
+            .as_ref()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in issuer key.")))?;
+
+        let rsa_r_claim = claim_signature.rsa_r_claim
+            .as_mut()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in claim signature.")))?;
+
+        rsa_accumulator::update_witness(rsa_r_claim, rsa_rev_reg, rsa_r_key)?;
+
+        trace!("Prover::update_rsa_non_revocation_witness: <<<");
+
+        Ok(())
+    }
+
     /// Creates and returns proof builder.
     ///
     /// The purpose of proof builder is building of proof entity according to the given request .
@@ -138,7 +330,9 @@ impl Prover {
             m1_tilde: bn_rand(LARGE_M2_TILDE)?,
             init_proofs: HashMap::new(),
             c_list: Vec::new(),
-            tau_list: Vec::new()
+            tau_list: Vec::new(),
+            aggregated_non_revoc_proofs: Vec::new(),
+            pseudonym_scope: None
         })
     }
 
@@ -246,6 +440,8 @@ pub struct ProofBuilder {
     pub init_proofs: HashMap<String, InitProof>,
     pub c_list: Vec<Vec<u8>>,
     pub tau_list: Vec<Vec<u8>>,
+    pub aggregated_non_revoc_proofs: Vec<AggregatedNonRevocProof>,
+    pub pseudonym_scope: Option<String>,
 }
 
 impl ProofBuilder {
@@ -261,35 +457,219 @@ impl ProofBuilder {
     /// * `claim_values` - Claim values.
     /// * `issuer_pub_key` - Issuer public key.
     /// * `rev_reg_pub` - (Optional) Revocation registry public.
+    /// * `rev_tails_reader` - (Optional) Revocation accumulator tails reader. Must correspond to tails hash of `rev_reg_pub`.
     pub fn add_sub_proof_request(&mut self, key_id: &str, sub_proof_request: &SubProofRequest, claim_schema: &ClaimSchema, claim_signature: &ClaimSignature,
-                                 claim_values: &ClaimValues, issuer_pub_key: &IssuerPublicKey, rev_reg_pub: Option<&RevocationRegistryPublic>) -> Result<(), IndyCryptoError> {
+                                 claim_values: &ClaimValues, issuer_pub_key: &IssuerPublicKey, rev_reg_pub: Option<&RevocationRegistryPublic>,
+                                 rev_tails_reader: Option<&TailsReader>) -> Result<(), IndyCryptoError> {
         trace!("ProofBuilder::add_sub_proof_request: >>> key_id: {:?}, claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, \
         rev_reg_pub: {:?}, sub_proof_request: {:?}, claim_schema: {:?}",
                key_id, claim_signature, claim_values, issuer_pub_key, rev_reg_pub, sub_proof_request, claim_schema);
 
+        self._add_sub_proof_request(key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key,
+                                    rev_reg_pub, rev_tails_reader, None, None)?;
+
+        trace!("ProofBuilder::add_sub_proof_request: <<<");
+
+        Ok(())
+    }
+
+    /// Add sub proof request to proof builder which will prove that claim wasn't revoked
+    /// at the moment of accumulator snapshot with given id (see `RevocationRegistryPublic::take_snapshot`).
+    /// Registry keeps only accumulator values of snapshots, so accumulator of the snapshot moment
+    /// (see `RevocationRegistryPublic::get_accumulator`) must be kept by Prover and is checked against the snapshot.
+    /// Snapshot id is recorded in the non revocation part of the proof.
+    ///
+    /// # Arguments
+    /// * `proof_builder` - Proof builder.
+    /// * `key_id` - unique claim identifier.
+    /// * `sub_proof_request` -Requested attributes and predicates.
+    /// * `claim_schema` - Claim schema.
+    /// * `claim_signature` - Claim signature.
+    /// * `claim_values` - Claim values.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `rev_reg_pub` - Revocation registry public containing snapshot.
+    /// * `rev_tails_reader` - Revocation accumulator tails reader. Must correspond to tails hash of `rev_reg_pub`.
+    /// * `acc_snapshot` - Accumulator of the snapshot moment.
+    /// * `acc_snapshot_id` - Accumulator snapshot id.
+    pub fn add_sub_proof_request_for_snapshot(&mut self, key_id: &str, sub_proof_request: &SubProofRequest, claim_schema: &ClaimSchema,
+                                              claim_signature: &ClaimSignature, claim_values: &ClaimValues, issuer_pub_key: &IssuerPublicKey,
+                                              rev_reg_pub: &RevocationRegistryPublic, rev_tails_reader: &TailsReader,
+                                              acc_snapshot: &RevocationAccumulator, acc_snapshot_id: u64) -> Result<(), IndyCryptoError> {
+        trace!("ProofBuilder::add_sub_proof_request_for_snapshot: >>> key_id: {:?}, claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, \
+        rev_reg_pub: {:?}, sub_proof_request: {:?}, claim_schema: {:?}, acc_snapshot: {:?}, acc_snapshot_id: {:?}",
+               key_id, claim_signature, claim_values, issuer_pub_key, rev_reg_pub, sub_proof_request, claim_schema, acc_snapshot, acc_snapshot_id);
+
+        if claim_signature.r_claim.is_none() || issuer_pub_key.r_key.is_none() {
+            return Err(IndyCryptoError::InvalidStructure(format!("No revocation part present in claim signature or issuer public key.")));
+        }
+
+        self._add_sub_proof_request(key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key,
+                                    Some(rev_reg_pub), Some(rev_tails_reader), Some((acc_snapshot_id, acc_snapshot)), None)?;
+
+        trace!("ProofBuilder::add_sub_proof_request_for_snapshot: <<<");
+
+        Ok(())
+    }
+
+    /// Add sub proof request to proof builder which will prove that claim is accumulated
+    /// in RSA revocation registry (see `RevocationBackend::Rsa`).
+    ///
+    /// # Arguments
+    /// * `proof_builder` - Proof builder.
+    /// * `key_id` - unique claim identifier.
+    /// * `sub_proof_request` -Requested attributes and predicates.
+    /// * `claim_schema` - Claim schema.
+    /// * `claim_signature` - Claim signature.
+    /// * `claim_values` - Claim values.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `rsa_rev_reg` - RSA revocation registry.
+    pub fn add_sub_proof_request_with_rsa_accumulator(&mut self, key_id: &str, sub_proof_request: &SubProofRequest, claim_schema: &ClaimSchema,
+                                                      claim_signature: &ClaimSignature, claim_values: &ClaimValues, issuer_pub_key: &IssuerPublicKey,
+                                                      rsa_rev_reg: &RsaRevocationRegistry) -> Result<(), IndyCryptoError> {
+        trace!("ProofBuilder::add_sub_proof_request_with_rsa_accumulator: >>> key_id: {:?}, claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, \
+        rsa_rev_reg: {:?}, sub_proof_request: {:?}, claim_schema: {:?}",
+               key_id, claim_signature, claim_values, issuer_pub_key, rsa_rev_reg, sub_proof_request, claim_schema);
+
+        if claim_signature.rsa_r_claim.is_none() || issuer_pub_key.rsa_r_key.is_none() {
+            return Err(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in claim signature or issuer public key.")));
+        }
+
+        self._add_sub_proof_request(key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key,
+                                    None, None, None, Some(rsa_rev_reg))?;
+
+        trace!("ProofBuilder::add_sub_proof_request_with_rsa_accumulator: <<<");
+
+        Ok(())
+    }
+
+    /// Request Prover pseudonym for given scope to be included in proof.
+    /// Proof will contain pseudonym and prove that it is derived from the same
+    /// master secret as claims of sub proofs.
+    ///
+    /// # Arguments
+    /// * `proof_builder` - Proof builder.
+    /// * `scope` - Scope string provided by Verifier.
+    pub fn add_pseudonym_request(&mut self, scope: &str) -> Result<(), IndyCryptoError> {
+        trace!("ProofBuilder::add_pseudonym_request: >>> scope: {:?}", scope);
+
+        if self.pseudonym_scope.is_some() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Pseudonym is already requested")));
+        }
+
+        self.pseudonym_scope = Some(scope.to_owned());
+
+        trace!("ProofBuilder::add_pseudonym_request: <<<");
+
+        Ok(())
+    }
+
+    /// Aggregate non-revocation proofs of claims issued under the same revocation registry.
+    /// Proof challenge contains single weighted tau list of the group instead of tau list of every claim,
+    /// so Verifier checks the whole group by single set of pairings. Proof size stays the same:
+    /// c lists and responses are still sent for every claim.
+    /// Must be called after all sub proof requests of the group were added and before `finalize`.
+    ///
+    /// # Arguments
+    /// * `proof_builder` - Proof builder.
+    /// * `key_ids` - Identifiers of claims (at least two) added to proof builder against the same revocation registry and accumulator.
+    pub fn aggregate_non_revocation_proofs(&mut self, key_ids: &[&str]) -> Result<(), IndyCryptoError> {
+        trace!("ProofBuilder::aggregate_non_revocation_proofs: >>> key_ids: {:?}", key_ids);
+
+        if key_ids.len() < 2 {
+            return Err(IndyCryptoError::InvalidStructure(format!("At least two claims are required for aggregation")));
+        }
+
+        let key_ids: Vec<String> = key_ids.iter().map(|key_id| key_id.to_string()).collect();
+
+        if HashSet::<&String>::from_iter(key_ids.iter()).len() != key_ids.len() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Claims for aggregation contain duplicates")));
+        }
+
+        if self.aggregated_non_revoc_proofs.iter()
+            .any(|aggregated| aggregated.key_ids.iter().any(|key_id| key_ids.contains(key_id))) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Claim non-revocation proof is already aggregated")));
+        }
+
+        let mut non_revoc_init_proofs: Vec<&NonRevocInitProof> = Vec::new();
+
+        for key_id in key_ids.iter() {
+            let non_revoc_init_proof = self.init_proofs.get(key_id)
+                .ok_or(IndyCryptoError::InvalidStructure(format!("Claim with key id {} isn't added to proof builder", key_id)))?
+                .non_revoc_init_proof.as_ref()
+                .ok_or(IndyCryptoError::InvalidStructure(format!("Claim with key id {} doesn't contain non-revocation proof", key_id)))?;
+
+            if non_revoc_init_proof.acc_snapshot_id != non_revoc_init_proofs.first().map_or(non_revoc_init_proof.acc_snapshot_id, |first| first.acc_snapshot_id) {
+                return Err(IndyCryptoError::InvalidStructure(format!("Claims for aggregation are proved against different accumulators")));
+            }
+
+            non_revoc_init_proofs.push(non_revoc_init_proof);
+        }
+
+        self.aggregated_non_revoc_proofs.push(AggregatedNonRevocProof { key_ids });
+
+        trace!("ProofBuilder::aggregate_non_revocation_proofs: <<<");
+
+        Ok(())
+    }
+
+    fn _add_sub_proof_request(&mut self, key_id: &str, sub_proof_request: &SubProofRequest, claim_schema: &ClaimSchema, claim_signature: &ClaimSignature,
+                              claim_values: &ClaimValues, issuer_pub_key: &IssuerPublicKey, rev_reg_pub: Option<&RevocationRegistryPublic>,
+                              rev_tails_reader: Option<&TailsReader>, acc_snapshot: Option<(u64, &RevocationAccumulator)>,
+                              rsa_rev_reg: Option<&RsaRevocationRegistry>) -> Result<(), IndyCryptoError> {
         ProofBuilder::_check_add_sub_proof_request_params_consistency(claim_values, sub_proof_request, claim_schema)?;
 
         let mut non_revoc_init_proof = None;
+        let mut rsa_non_revoc_init_proof = None;
         let mut m2_tilde: Option<BigNumber> = None;
 
-        if let (&Some(ref r_claim), &Some(ref r_reg), &Some(ref r_pub_key)) = (&claim_signature.r_claim,
-                                                                               &rev_reg_pub,
-                                                                               &issuer_pub_key.r_key) {
-            let proof = ProofBuilder::_init_non_revocation_proof(&mut r_claim.clone(), &r_reg, &r_pub_key)?;//TODO:FIXME
+        if let (&Some(ref r_claim), &Some(ref r_reg), &Some(ref r_pub_key), &Some(ref r_tails_reader)) = (&claim_signature.r_claim,
+                                                                                                         &rev_reg_pub,
+                                                                                                         &issuer_pub_key.r_key,
+                                                                                                         &rev_tails_reader) {
+            r_tails_reader.verify(r_reg)?;
+
+            let (accum, acc_snapshot_id) = match acc_snapshot {
+                Some((id, acc)) => {
+                    r_reg._check_snapshot_accumulator(id, acc)?;
+                    (acc, Some(id))
+                }
+                None => (&r_reg.acc, None)
+            };
+
+            let proof = ProofBuilder::_init_non_revocation_proof(&mut r_claim.clone(), accum, &r_pub_key, *r_tails_reader, acc_snapshot_id)?;//TODO:FIXME
 
             self.c_list.extend_from_slice(&proof.as_c_list()?);
-            self.tau_list.extend_from_slice(&proof.as_tau_list()?);
             m2_tilde = Some(group_element_to_bignum(&proof.tau_list_params.m2)?);
             non_revoc_init_proof = Some(proof);
         }
 
+        if let (&Some(ref rsa_r_claim), Some(rsa_r_reg), &Some(ref rsa_r_key)) = (&claim_signature.rsa_r_claim,
+                                                                               rsa_rev_reg,
+                                                                               &issuer_pub_key.rsa_r_key) {
+            let mut rsa_r_claim = rsa_r_claim.clone()?;
+            rsa_accumulator::update_witness(&mut rsa_r_claim, rsa_r_reg, rsa_r_key)?;
+
+            let e_t = bn_rand(LARGE_MVECT)?;
+            let proof = rsa_accumulator::init_proof(&rsa_r_claim, rsa_r_reg, rsa_r_key, &e_t)?;
+
+            self.c_list.extend_from_slice(&proof.as_c_list()?);
+            self.tau_list.extend_from_slice(&proof.as_tau_list()?);
+            rsa_non_revoc_init_proof = Some(proof);
+        }
+
+        let acc_prime_term = match (&rsa_non_revoc_init_proof, &issuer_pub_key.rsa_r_key) {
+            (&Some(ref proof), &Some(ref rsa_r_key)) => Some(rsa_accumulator::primary_init_proof_term(proof, rsa_r_key)),
+            _ => None
+        };
+
         let primary_init_proof = ProofBuilder::_init_primary_proof(&issuer_pub_key.p_key,
                                                                    &claim_signature.p_claim,
                                                                    &claim_values,
                                                                    &claim_schema,
                                                                    &sub_proof_request,
                                                                    &self.m1_tilde,
-                                                                   m2_tilde)?;
+                                                                   m2_tilde,
+                                                                   acc_prime_term)?;
 
         self.c_list.extend_from_slice(&primary_init_proof.as_c_list()?);
         self.tau_list.extend_from_slice(&primary_init_proof.as_tau_list()?);
@@ -297,14 +677,13 @@ impl ProofBuilder {
         let init_proof = InitProof {
             primary_init_proof,
             non_revoc_init_proof,
+            rsa_non_revoc_init_proof,
             claim_values: claim_values.clone()?,
             sub_proof_request: sub_proof_request.clone(),
             claim_schema: claim_schema.clone()
         };
         self.init_proofs.insert(key_id.to_owned(), init_proof);
 
-        trace!("ProofBuilder::add_sub_proof_request: <<<");
-
         Ok(())
     }
 
@@ -319,9 +698,24 @@ impl ProofBuilder {
 
         let mut values: Vec<Vec<u8>> = Vec::new();
         values.extend_from_slice(&self.tau_list);
+        values.extend_from_slice(&self._non_revoc_tau_list()?);
         values.extend_from_slice(&self.c_list);
         values.push(nonce.to_bytes()?);
 
+        let mut pseudonym: Option<Pseudonym> = None;
+
+        if let Some(ref scope) = self.pseudonym_scope {
+            if self.init_proofs.is_empty() {
+                return Err(IndyCryptoError::InvalidStructure(format!("Pseudonym can't be proved without sub proofs")));
+            }
+
+            let p = Prover::new_pseudonym(master_secret, scope)?;
+
+            values.push(calc_pseudonym(scope, &self.m1_tilde)?.to_bytes()?);
+            values.push(p.value.to_bytes()?);
+            pseudonym = Some(p);
+        }
+
         let c_h = get_hash_as_int(&mut values)?;
 
         let mut proofs: HashMap<String, SubProof> = HashMap::new();
@@ -332,6 +726,11 @@ impl ProofBuilder {
                 non_revoc_proof = Some(ProofBuilder::_finalize_non_revocation_proof(&non_revoc_init_proof, &c_h)?);
             }
 
+            let mut rsa_non_revoc_proof = None;
+            if let Some(ref rsa_non_revoc_init_proof) = init_proof.rsa_non_revoc_init_proof {
+                rsa_non_revoc_proof = Some(rsa_accumulator::finalize_proof(rsa_non_revoc_init_proof, &c_h)?);
+            }
+
             let primary_proof = ProofBuilder::_finalize_primary_proof(&master_secret.ms,
                                                                       &init_proof.primary_init_proof,
                                                                       &c_h,
@@ -339,19 +738,62 @@ impl ProofBuilder {
                                                                       &init_proof.claim_values,
                                                                       &init_proof.sub_proof_request)?;
 
-            let proof = SubProof { primary_proof, non_revoc_proof };
+            let proof = SubProof { primary_proof, non_revoc_proof, rsa_non_revoc_proof };
             proofs.insert(proof_claim_uuid.to_owned(), proof);
         }
 
         let aggregated_proof = AggregatedProof { c_hash: c_h, c_list: self.c_list.clone() };
 
-        let proof = Proof { proofs, aggregated_proof };
+        let proof = Proof {
+            proofs,
+            aggregated_proof,
+            aggregated_non_revoc_proofs: self.aggregated_non_revoc_proofs.clone(),
+            pseudonym
+        };
 
         trace!("ProofBuilder::finalize: <<< proof: {:?}", proof);
 
         Ok(proof)
     }
 
+    /// Tau lists of non-revocation proofs for proof challenge. Tau lists of aggregated proofs
+    /// are replaced by single weighted tau list of every group.
+    fn _non_revoc_tau_list(&self) -> Result<Vec<Vec<u8>>, IndyCryptoError> {
+        trace!("ProofBuilder::_non_revoc_tau_list: >>>");
+
+        let aggregated_key_ids: HashSet<&String> = self.aggregated_non_revoc_proofs.iter()
+            .flat_map(|aggregated| aggregated.key_ids.iter())
+            .collect();
+
+        let mut tau_list: Vec<Vec<u8>> = Vec::new();
+
+        for (key_id, init_proof) in self.init_proofs.iter() {
+            if let Some(ref non_revoc_init_proof) = init_proof.non_revoc_init_proof {
+                if !aggregated_key_ids.contains(key_id) {
+                    tau_list.extend_from_slice(&non_revoc_init_proof.as_tau_list()?);
+                }
+            }
+        }
+
+        for aggregated in self.aggregated_non_revoc_proofs.iter() {
+            let non_revoc_init_proofs = aggregated.key_ids.iter()
+                .map(|key_id| self.init_proofs.get(key_id)
+                    .and_then(|init_proof| init_proof.non_revoc_init_proof.as_ref())
+                    .ok_or(IndyCryptoError::InvalidStructure(format!("Claim with key id {} doesn't contain non-revocation proof", key_id))))
+                .collect::<Result<Vec<&NonRevocInitProof>, IndyCryptoError>>()?;
+
+            let c_lists: Vec<&NonRevocProofCList> = non_revoc_init_proofs.iter().map(|proof| &proof.c_list).collect();
+            let tau_lists: Vec<&NonRevocProofTauList> = non_revoc_init_proofs.iter().map(|proof| &proof.tau_list).collect();
+            let weights = non_revoc_proof_aggregation_weights(&aggregated.key_ids, &c_lists)?;
+
+            tau_list.extend_from_slice(&aggregate_tau_lists(&tau_lists, &weights)?.as_slice()?);
+        }
+
+        trace!("ProofBuilder::_non_revoc_tau_list: <<< tau_list: {:?}", tau_list);
+
+        Ok(tau_list)
+    }
+
     fn _check_add_sub_proof_request_params_consistency(claim_values: &ClaimValues, sub_proof_request: &SubProofRequest, claim_schema: &ClaimSchema) -> Result<(), IndyCryptoError> {
         trace!("ProofBuilder::_check_add_sub_proof_request_params_consistency: >>> claim_values: {:?}, sub_proof_request: {:?}, claim_schema: {:?}",
                claim_values, sub_proof_request, claim_schema);
@@ -382,11 +824,11 @@ impl ProofBuilder {
 
     fn _init_primary_proof(issuer_pub_key: &IssuerPrimaryPublicKey, c1: &PrimaryClaimSignature, claim_values: &ClaimValues, claim_schema: &ClaimSchema,
                            sub_proof_request: &SubProofRequest, m1_t: &BigNumber,
-                           m2_t: Option<BigNumber>) -> Result<PrimaryInitProof, IndyCryptoError> {
-        trace!("ProofBuilder::_init_primary_proof: >>> issuer_pub_key: {:?}, c1: {:?}, claim_values: {:?}, claim_schema: {:?}, sub_proof_request: {:?}, m1_t: {:?}, m2_t: {:?}",
-               issuer_pub_key, c1, claim_values, claim_schema, sub_proof_request, m1_t, m2_t);
+                           m2_t: Option<BigNumber>, acc_prime_term: Option<(&BigNumber, &BigNumber)>) -> Result<PrimaryInitProof, IndyCryptoError> {
+        trace!("ProofBuilder::_init_primary_proof: >>> issuer_pub_key: {:?}, c1: {:?}, claim_values: {:?}, claim_schema: {:?}, sub_proof_request: {:?}, m1_t: {:?}, m2_t: {:?}, \
+        acc_prime_term: {:?}", issuer_pub_key, c1, claim_values, claim_schema, sub_proof_request, m1_t, m2_t, acc_prime_term);
 
-        let eq_proof = ProofBuilder::_init_eq_proof(&issuer_pub_key, c1, claim_schema, sub_proof_request, m1_t, m2_t)?;
+        let eq_proof = ProofBuilder::_init_eq_proof(&issuer_pub_key, c1, claim_schema, sub_proof_request, m1_t, m2_t, acc_prime_term)?;
 
         let mut ge_proofs: Vec<PrimaryPredicateGEInitProof> = Vec::new();
         for predicate in sub_proof_request.predicates.iter() {
@@ -401,23 +843,25 @@ impl ProofBuilder {
         Ok(primary_init_proof)
     }
 
-    fn _init_non_revocation_proof(r_claim: &mut NonRevocationClaimSignature, rev_reg_pub: &RevocationRegistryPublic, issuer_rev_pub_key: &IssuerRevocationPublicKey)
-                                  -> Result<NonRevocInitProof, IndyCryptoError> {
-        trace!("ProofBuilder::_init_non_revocation_proof: >>> r_claim: {:?}, rev_reg_pub: {:?}, issuer_rev_pub_key: {:?}", r_claim, rev_reg_pub, issuer_rev_pub_key);
+    fn _init_non_revocation_proof(r_claim: &mut NonRevocationClaimSignature, accum: &RevocationAccumulator, issuer_rev_pub_key: &IssuerRevocationPublicKey,
+                                  rev_tails_reader: &TailsReader, acc_snapshot_id: Option<u64>) -> Result<NonRevocInitProof, IndyCryptoError> {
+        trace!("ProofBuilder::_init_non_revocation_proof: >>> r_claim: {:?}, accum: {:?}, issuer_rev_pub_key: {:?}, acc_snapshot_id: {:?}",
+               r_claim, accum, issuer_rev_pub_key, acc_snapshot_id);
 
-        ProofBuilder::_update_non_revocation_claim(r_claim, &rev_reg_pub.acc, &rev_reg_pub.tails.tails_dash)?;
+        ProofBuilder::_update_non_revocation_claim(r_claim, accum, rev_tails_reader)?;
 
         let c_list_params = ProofBuilder::_gen_c_list_params(&r_claim)?;
         let proof_c_list = ProofBuilder::_create_c_list_values(&r_claim, &c_list_params, &issuer_rev_pub_key)?;
 
         let tau_list_params = ProofBuilder::_gen_tau_list_params()?;
-        let proof_tau_list = create_tau_list_values(&issuer_rev_pub_key, &rev_reg_pub.acc, &tau_list_params, &proof_c_list)?;
+        let proof_tau_list = create_tau_list_values(&issuer_rev_pub_key, accum, &tau_list_params, &proof_c_list)?;
 
         let r_init_proof = NonRevocInitProof {
             c_list_params,
             tau_list_params,
             c_list: proof_c_list,
-            tau_list: proof_tau_list
+            tau_list: proof_tau_list,
+            acc_snapshot_id
         };
 
         trace!("ProofBuilder::_init_non_revocation_proof: <<< r_init_proof: {:?}", r_init_proof);
@@ -426,31 +870,22 @@ impl ProofBuilder {
     }
 
     fn _update_non_revocation_claim(r_claim: &mut NonRevocationClaimSignature, accum: &RevocationAccumulator,
-                                    tails_dash: &HashMap<u32, PointG2>) -> Result<(), IndyCryptoError> {
-        trace!("ProofBuilder::_update_non_revocation_claim: >>> r_claim: {:?}, accum: {:?}, tails_dash: {:?}", r_claim, accum, tails_dash);
+                                    rev_tails_reader: &TailsReader) -> Result<(), IndyCryptoError> {
+        trace!("ProofBuilder::_update_non_revocation_claim: >>> r_claim: {:?}, accum: {:?}", r_claim, accum);
 
-        if !accum.v.contains(&r_claim.i) {
+        if !accum.v.contains(r_claim.i) {
             return Err(IndyCryptoError::AnoncredsClaimRevoked("Can not update Witness. Claim revoked.".to_string()));
         }
 
         if r_claim.witness.v != accum.v {
-            let v_old_minus_new: HashSet<u32> =
-                r_claim.witness.v.difference(&accum.v).cloned().collect();
-            let mut omega_denom = PointG2::new_inf()?;
-            for j in v_old_minus_new.iter() {
-                omega_denom = omega_denom.add(
-                    tails_dash.get(&(accum.max_claim_num + 1 - j + r_claim.i))
-                        .ok_or(IndyCryptoError::InvalidStructure(format!("Key not found {} in tails_dash", accum.max_claim_num + 1 - j + r_claim.i)))?)?;
-            }
-            let mut omega_num = PointG2::new_inf()?;
             let mut new_omega: PointG2 = r_claim.witness.omega.clone();
-            for j in v_old_minus_new.iter() {
-                omega_num = omega_num.add(
-                    tails_dash.get(&(accum.max_claim_num + 1 - j + r_claim.i))
-                        .ok_or(IndyCryptoError::InvalidStructure(format!("Key not found {} in tails_dash", accum.max_claim_num + 1 - j + r_claim.i)))?)?;
-                new_omega = new_omega.add(
-                    &omega_num.sub(&omega_denom)?
-                )?;
+
+            for j in accum.v.difference(&r_claim.witness.v).iter() {
+                new_omega = new_omega.add(&rev_tails_reader.get_tail_dash(accum.max_claim_num + 1 - j + r_claim.i)?)?;
+            }
+
+            for j in r_claim.witness.v.difference(&accum.v).iter() {
+                new_omega = new_omega.sub(&rev_tails_reader.get_tail_dash(accum.max_claim_num + 1 - j + r_claim.i)?)?;
             }
 
             r_claim.witness.v = accum.v.clone();
@@ -463,9 +898,10 @@ impl ProofBuilder {
     }
 
     fn _init_eq_proof(issuer_pub_key: &IssuerPrimaryPublicKey, c1: &PrimaryClaimSignature, claim_schema: &ClaimSchema, sub_proof_request: &SubProofRequest,
-                      m1_tilde: &BigNumber, m2_t: Option<BigNumber>) -> Result<PrimaryEqualInitProof, IndyCryptoError> {
-        trace!("ProofBuilder::_init_eq_proof: >>> issuer_pub_key: {:?}, c1: {:?}, claim_schema: {:?}, sub_proof_request: {:?}, m1_tilde: {:?}, m2_t: {:?}",
-               issuer_pub_key, c1, claim_schema, sub_proof_request, m1_tilde, m2_t);
+                      m1_tilde: &BigNumber, m2_t: Option<BigNumber>,
+                      acc_prime_term: Option<(&BigNumber, &BigNumber)>) -> Result<PrimaryEqualInitProof, IndyCryptoError> {
+        trace!("ProofBuilder::_init_eq_proof: >>> issuer_pub_key: {:?}, c1: {:?}, claim_schema: {:?}, sub_proof_request: {:?}, m1_tilde: {:?}, m2_t: {:?}, acc_prime_term: {:?}",
+               issuer_pub_key, c1, claim_schema, sub_proof_request, m1_tilde, m2_t, acc_prime_term);
 
         let mut ctx = BigNumber::new_context()?;
 
@@ -499,7 +935,7 @@ impl ProofBuilder {
         )?;
 
         let t = calc_teq(&issuer_pub_key, &a_prime, &e_tilde, &v_tilde, &m_tilde, &m1_tilde,
-                         &m2_tilde, &unrevealed_attrs)?;
+                         &m2_tilde, &unrevealed_attrs, acc_prime_term)?;
 
         let primary_equal_init_proof = PrimaryEqualInitProof {
             a_prime,
@@ -908,7 +1344,8 @@ impl ProofBuilder {
 
         let non_revoc_proof = NonRevocProof {
             x_list: NonRevocProofXList::from_list(x_list),
-            c_list: init_proof.c_list.clone()
+            c_list: init_proof.c_list.clone(),
+            acc_snapshot_id: init_proof.acc_snapshot_id
         };
 
         trace!("ProofBuilder::_finalize_non_revocation_proof: <<< non_revoc_proof: {:?}", non_revoc_proof);
@@ -921,6 +1358,8 @@ impl ProofBuilder {
 mod tests {
     use super::*;
     use cl::issuer;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
 
     #[test]
     fn generate_master_secret_works() {
@@ -930,6 +1369,36 @@ mod tests {
         assert_eq!(ms.ms.to_dec().unwrap(), mocks::master_secret().ms.to_dec().unwrap());
     }
 
+    #[test]
+    fn new_master_secret_from_seed_works() {
+        let seed: Vec<u8> = (0..32).collect();
+
+        let master_secret = Prover::new_master_secret_from_seed(&seed).unwrap();
+
+        assert_eq!(BigNumber::from_dec("105103058045870293335188813948753684802643482214185770995446658821989067571322").unwrap(), master_secret.ms);
+        assert_eq!(master_secret.ms, Prover::new_master_secret_from_seed(&seed).unwrap().ms);
+        assert_ne!(master_secret.ms, Prover::new_master_secret_from_seed(&[1u8; 32]).unwrap().ms);
+    }
+
+    #[test]
+    fn new_master_secret_from_seed_works_for_short_seed() {
+        let res = Prover::new_master_secret_from_seed(&[1u8; 16]);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn split_combine_master_secret_works() {
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        let (shares, commitments) = Prover::split_master_secret(&master_secret, 3, 5).unwrap();
+
+        let recovered = Prover::combine_master_secret(&[&shares[4], &shares[1], &shares[2]], &commitments).unwrap();
+        assert_eq!(master_secret.ms, recovered.ms);
+
+        let res = Prover::combine_master_secret(&[&shares[0], &shares[1]], &commitments);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+    }
+
     #[test]
     fn generate_blinded_primary_master_secret_works() {
         MockHelper::inject();
@@ -1004,6 +1473,7 @@ mod tests {
                                                          &claim_schema,
                                                          &sub_proof_request,
                                                          &m1_t,
+                                                         None,
                                                          None).unwrap();
 
         assert_eq!(mocks::primary_equal_init_proof(), init_eq_proof);
@@ -1043,6 +1513,7 @@ mod tests {
                                                            &claim_schema,
                                                            &sub_proof_request,
                                                            &m1_t,
+                                                           None,
                                                            None).unwrap();
         assert_eq!(mocks::primary_init_proof(), init_proof);
     }
@@ -1052,7 +1523,7 @@ mod tests {
         MockHelper::inject();
 
         let ms = mocks::master_secret();
-        let c_h = mocks::aggregated_proof().c_hash;
+        let c_h = mocks::c_hash();
         let init_proof = mocks::primary_equal_init_proof();
         let claim_values = issuer::mocks::claim_values();
         let claim_schema = issuer::mocks::claim_schema();
@@ -1072,7 +1543,7 @@ mod tests {
     fn finalize_ge_proof_works() {
         MockHelper::inject();
 
-        let c_h = mocks::aggregated_proof().c_hash;
+        let c_h = mocks::c_hash();
         let ge_proof = mocks::primary_ge_init_proof();
         let eq_proof = mocks::eq_proof();
 
@@ -1088,7 +1559,7 @@ mod tests {
 
         let proof = mocks::primary_init_proof();
         let ms = mocks::master_secret();
-        let c_h = mocks::aggregated_proof().c_hash;
+        let c_h = mocks::c_hash();
         let claim_schema = issuer::mocks::claim_schema();
         let claim_values = issuer::mocks::claim_values();
         let sub_proof_request = mocks::sub_proof_request();
@@ -1133,6 +1604,42 @@ mod tests {
 
         assert_eq!(proof_tau_list.as_slice().unwrap(), proof_tau_list_calc.as_slice().unwrap());
     }
+
+    #[test]
+    fn test_aggregated_tau_list() {
+        let r_claim = issuer::mocks::revocation_claim();
+        let r_key = issuer::mocks::revocation_pub_key();
+        let pub_rev_reg = issuer::mocks::revocation_reg_public();
+
+        let c_list_params = ProofBuilder::_gen_c_list_params(&r_claim).unwrap();
+        let proof_c_list = ProofBuilder::_create_c_list_values(&r_claim, &c_list_params, &r_key).unwrap();
+
+        let non_revoc_proof = NonRevocProof {
+            x_list: c_list_params,
+            c_list: proof_c_list.clone(),
+            acc_snapshot_id: None
+        };
+
+        let proof_tau_list_expected = create_tau_list_expected_values(&r_key,
+                                                                      &pub_rev_reg.acc,
+                                                                      &pub_rev_reg.key,
+                                                                      &proof_c_list).unwrap();
+
+        let key_ids = vec!["key_id_1".to_string(), "key_id_2".to_string()];
+        let weights = non_revoc_proof_aggregation_weights(&key_ids,
+                                                          &[&proof_c_list, &proof_c_list]).unwrap();
+        let proof_tau_list = aggregate_tau_lists(&[&proof_tau_list_expected, &proof_tau_list_expected], &weights).unwrap();
+
+        let zero = GroupOrderElement::from_bytes(&transform_u32_to_array_of_u8(0)).unwrap();
+        let proof_tau_list_calc = create_aggregated_tau_list_values(&r_key,
+                                                                    &pub_rev_reg.acc,
+                                                                    &pub_rev_reg.key,
+                                                                    &zero,
+                                                                    &[&non_revoc_proof, &non_revoc_proof],
+                                                                    &weights).unwrap();
+
+        assert_eq!(proof_tau_list.as_slice().unwrap(), proof_tau_list_calc.as_slice().unwrap());
+    }
 }
 
 pub mod mocks {
@@ -1164,7 +1671,8 @@ pub mod mocks {
     pub fn claim() -> ClaimSignature {
         ClaimSignature {
             p_claim: primary_claim(),
-            r_claim: Some(issuer::mocks::revocation_claim())
+            r_claim: Some(issuer::mocks::revocation_claim()),
+            rsa_r_claim: None
         }
     }
 
@@ -1325,10 +1833,14 @@ pub mod mocks {
                          vec![1, 111, 80, 91, 53, 214, 139, 10, 197, 79, 134, 183, 50, 233, 244, 130, 80, 173, 167, 5, 130, 151, 183, 162, 97, 134, 246, 146, 37, 151, 103, 45, 68, 33, 204, 18, 157, 21, 98, 230, 225, 30, 162, 172, 75, 159, 115, 94, 72, 113, 153, 155, 117, 233, 95, 251, 29, 1, 149, 38, 117, 63, 112, 213, 48, 29, 3, 131, 238, 120, 48, 141, 105, 31, 127, 51, 176, 32, 203, 191, 155, 159, 91, 29, 87, 223, 30, 92, 146, 250, 182, 181, 155, 67, 253, 33, 165, 142, 195, 146, 180, 221, 83, 62, 46, 74, 29, 83, 175, 218, 132, 93, 42, 93, 105, 173, 189, 254, 193, 230, 113, 39, 45, 137, 143, 124, 190, 42, 19, 77, 13, 220, 137, 202, 128, 170, 10, 22, 37, 177, 200, 186, 3, 73, 171, 232, 81, 144, 36, 46, 70, 237, 208, 26, 84, 26, 141, 19, 37, 200, 83, 60, 27, 175, 96, 233, 246, 144, 137, 178, 140, 213, 13, 36, 137, 82, 107, 0, 239, 192, 187, 126, 20, 205, 40, 203, 33, 238, 88, 121, 132, 31, 87, 91, 65, 207, 144, 15, 249, 66, 58, 98, 64, 61, 236, 103, 203, 207, 20, 205, 48, 202, 247, 22, 248, 197, 188, 21, 178, 187, 193, 152, 164, 247, 53, 15, 33, 170, 145, 3, 213, 63, 205, 55, 158, 170, 62, 157, 207, 162, 117, 157, 215, 125, 94, 77, 251, 251, 25, 209, 207, 119, 16, 186, 210, 190, 83],
                          vec![1, 111, 80, 91, 53, 214, 139, 10, 197, 79, 134, 183, 50, 233, 244, 130, 80, 173, 167, 5, 130, 151, 183, 162, 97, 134, 246, 146, 37, 151, 103, 45, 68, 33, 204, 18, 157, 21, 98, 230, 225, 30, 162, 172, 75, 159, 115, 94, 72, 113, 153, 155, 117, 233, 95, 251, 29, 1, 149, 38, 117, 63, 112, 213, 48, 29, 3, 131, 238, 120, 48, 141, 105, 31, 127, 51, 176, 32, 203, 191, 155, 159, 91, 29, 87, 223, 30, 92, 146, 250, 182, 181, 155, 67, 253, 33, 165, 142, 195, 146, 180, 221, 83, 62, 46, 74, 29, 83, 175, 218, 132, 93, 42, 93, 105, 173, 189, 254, 193, 230, 113, 39, 45, 137, 143, 124, 190, 42, 19, 77, 13, 220, 137, 202, 128, 170, 10, 22, 37, 177, 200, 186, 3, 73, 171, 232, 81, 144, 36, 46, 70, 237, 208, 26, 84, 26, 141, 19, 37, 200, 83, 60, 27, 175, 96, 233, 246, 144, 137, 178, 140, 213, 13, 36, 137, 82, 107, 0, 239, 192, 187, 126, 20, 205, 40, 203, 33, 238, 88, 121, 132, 31, 87, 91, 65, 207, 144, 15, 249, 66, 58, 98, 64, 61, 236, 103, 203, 207, 20, 205, 48, 202, 247, 22, 248, 197, 188, 21, 178, 187, 193, 152, 164, 247, 53, 15, 33, 170, 145, 3, 213, 63, 205, 55, 158, 170, 62, 157, 207, 162, 117, 157, 215, 125, 94, 77, 251, 251, 25, 209, 207, 119, 16, 186, 210, 190, 83],
                          vec![1, 185, 37, 77, 23, 245, 214, 239, 127, 18, 101, 63, 229, 201, 171, 193, 32, 182, 124, 45, 15, 127, 58, 172, 226, 30, 246, 70, 33, 19, 117, 183, 29, 157, 209, 237, 41, 58, 208, 4, 105, 26, 73, 26, 69, 72, 21, 78, 106, 28, 72, 117, 102, 144, 199, 148, 3, 98, 81, 251, 246, 106, 50, 235, 129, 14, 186, 108, 216, 29, 41, 207, 233, 7, 179, 86, 224, 230, 187, 138, 125, 62, 68, 31, 66, 147, 205, 93, 100, 9, 134, 225, 210, 57, 36, 71, 134, 26, 179, 85, 37, 194, 32, 137, 91, 4, 91, 214, 220, 134, 173, 148, 14, 95, 209, 232, 79, 87, 12, 180, 217, 148, 240, 242, 190, 36, 229, 189, 16, 208, 75, 176, 153, 239, 212, 255, 45, 42, 250, 234, 139, 40, 104, 74, 21, 30, 184, 221, 126, 185, 23, 69, 114, 104, 249, 242, 248, 210, 97, 100, 141, 61, 176, 93, 200, 148, 152, 138, 31, 66, 99, 61, 237, 210, 42, 205, 60, 241, 92, 247, 1, 146, 203, 116, 237, 0, 171, 235, 250, 128, 74, 56, 223, 65, 189, 176, 91, 243, 174, 2, 111, 216, 233, 227, 28, 22, 41, 102, 225, 1, 21, 156, 212, 16, 243, 9, 94, 61, 246, 153, 193, 243, 188, 187, 154, 109, 168, 36, 89, 48, 236, 113, 74, 179, 158, 103, 51, 38, 15, 148, 18, 89, 218, 144, 71, 198, 8, 144, 104, 135, 160, 224, 98, 243, 106, 228, 198]],
-            c_hash: BigNumber::from_dec("63841489063440422591549130255324272391231497635167479821265935688468807059914").unwrap()
+            c_hash: c_hash()
         }
     }
 
+    pub fn c_hash() -> BigNumber {
+        BigNumber::from_dec("63841489063440422591549130255324272391231497635167479821265935688468807059914").unwrap()
+    }
+
     pub fn ge_proof() -> PrimaryPredicateGEProof {
         let mut m: HashMap<String, BigNumber> = HashMap::new();
         m.insert("age".to_string(), BigNumber::from_dec("6461691768834933403326572830814516653957231030793837560544354737855803497655300429843454445497126569555048377863338051254460267053606356944162460437192812434232786788496640641930").unwrap());
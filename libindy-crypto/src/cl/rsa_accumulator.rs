@@ -0,0 +1,640 @@
+use bn::{BigNumber, BigNumberContext};
+use cl::constants::*;
+use cl::helpers::*;
+use cl::index_set::IndexSet;
+use errors::IndyCryptoError;
+use utils::json::{JsonEncodable, JsonDecodable};
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Domain separation prefix of hashes mapped to accumulated primes.
+const INDEX_PRIME_DOMAIN: &'static [u8] = b"indy_crypto:cl:rsa_accumulator:index_prime:1";
+
+/// Bit size of primes accumulated for revocation indexes.
+const INDEX_PRIME_BITS: i32 = 256;
+
+lazy_static! {
+    /// Primes already found for revocation indexes (search of prime is the most expensive part).
+    static ref INDEX_PRIMES: Mutex<HashMap<u32, Vec<u8>>> = Mutex::new(HashMap::new());
+}
+
+/// `RSA Accumulator Public Key` is the non-revocation part of Issuer public key
+/// when strong RSA accumulator is chosen as revocation backend.
+///
+/// Works in quadratic residues group of Issuer primary key modulus, so Issuer uses
+/// primary private key to remove elements from accumulator and no tails are required.
+/// Accumulated prime is signed in primary Claim signature as separate hidden attribute with base `r_acc`.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct RsaAccumulatorPublicKey {
+    n: BigNumber,
+    g: BigNumber,
+    h: BigNumber,
+    u: BigNumber /* initial value of accumulator */,
+    r_acc: BigNumber /* base of accumulated prime in primary claim signature */
+}
+
+impl RsaAccumulatorPublicKey {
+    pub fn clone(&self) -> Result<RsaAccumulatorPublicKey, IndyCryptoError> {
+        Ok(RsaAccumulatorPublicKey {
+            n: self.n.clone()?,
+            g: self.g.clone()?,
+            h: self.h.clone()?,
+            u: self.u.clone()?,
+            r_acc: self.r_acc.clone()?
+        })
+    }
+}
+
+impl JsonEncodable for RsaAccumulatorPublicKey {}
+
+impl<'a> JsonDecodable<'a> for RsaAccumulatorPublicKey {}
+
+/// `RSA Revocation Registry` contains accumulator value and set of accumulated indexes.
+///
+/// Accumulator value is `u^(e_1 * ... * e_k)` where `e_i` is prime assigned to index `i`
+/// (see `index_prime`), so registry contains everything provers and verifiers need.
+/// Revoked indexes are kept in `issued` set, so they can't be reissued.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct RsaRevocationRegistry {
+    acc: BigNumber,
+    v: IndexSet /* accumulated indexes */,
+    #[serde(default)]
+    issued: IndexSet /* ever accumulated indexes including revoked ones */,
+    max_claim_num: u32
+}
+
+impl RsaRevocationRegistry {
+    pub fn clone(&self) -> Result<RsaRevocationRegistry, IndyCryptoError> {
+        Ok(RsaRevocationRegistry {
+            acc: self.acc.clone()?,
+            v: self.v.clone(),
+            issued: self.issued.clone(),
+            max_claim_num: self.max_claim_num
+        })
+    }
+
+    pub fn get_accumulator(&self) -> Result<BigNumber, IndyCryptoError> {
+        Ok(self.acc.clone()?)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.v.len() >= self.max_claim_num as usize || self.issued.len() >= self.max_claim_num as usize
+    }
+
+    /// Checks if index was ever used, revoked indexes are used too.
+    pub fn is_idx_used(&self, idx: u32) -> bool {
+        self.v.contains(idx) || self.issued.contains(idx)
+    }
+}
+
+impl JsonEncodable for RsaRevocationRegistry {}
+
+impl<'a> JsonDecodable<'a> for RsaRevocationRegistry {}
+
+/// Non-revocation part of Claim signature for RSA accumulator.
+/// Contains witness `w` such that `w^e_i = acc` and set of indexes witness corresponds to.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct RsaNonRevocationClaimSignature {
+    i: u32,
+    witness: BigNumber,
+    v: IndexSet
+}
+
+impl RsaNonRevocationClaimSignature {
+    pub fn clone(&self) -> Result<RsaNonRevocationClaimSignature, IndyCryptoError> {
+        Ok(RsaNonRevocationClaimSignature {
+            i: self.i,
+            witness: self.witness.clone()?,
+            v: self.v.clone()
+        })
+    }
+
+    pub fn get_idx(&self) -> u32 {
+        self.i
+    }
+}
+
+#[derive(Debug)]
+pub struct RsaNonRevocInitProof {
+    c_u: BigNumber,
+    c_r: BigNumber,
+    e: BigNumber,
+    e_tilde: BigNumber,
+    r: BigNumber,
+    r_prime: BigNumber,
+    delta: BigNumber,
+    gamma: BigNumber,
+    r_tilde: BigNumber,
+    r_prime_tilde: BigNumber,
+    delta_tilde: BigNumber,
+    gamma_tilde: BigNumber,
+    t_r: BigNumber,
+    t_delta: BigNumber,
+    t_acc: BigNumber
+}
+
+impl RsaNonRevocInitProof {
+    pub fn as_c_list(&self) -> Result<Vec<Vec<u8>>, IndyCryptoError> {
+        Ok(vec![self.c_u.to_bytes()?, self.c_r.to_bytes()?])
+    }
+
+    pub fn as_tau_list(&self) -> Result<Vec<Vec<u8>>, IndyCryptoError> {
+        Ok(vec![self.t_r.to_bytes()?, self.t_delta.to_bytes()?, self.t_acc.to_bytes()?])
+    }
+}
+
+/// Zero-knowledge proof of knowledge of witness for accumulated prime `e`.
+///
+/// Prime `e` is signed in primary Claim signature, so response `s_e` is used
+/// by primary equality proof too and links both proofs.
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RsaNonRevocProof {
+    c_u: BigNumber,
+    c_r: BigNumber,
+    s_e: BigNumber,
+    s_r: BigNumber,
+    s_r_prime: BigNumber,
+    s_delta: BigNumber,
+    s_gamma: BigNumber
+}
+
+/// Creates RSA accumulator public key in quadratic residues group modulo `n`.
+///
+/// # Arguments
+/// * `n` - Issuer primary public key modulus.
+/// * `s` - Issuer primary public key generator `S`.
+/// * `p` - First factor of quadratic residues group order (`(p_safe - 1) / 2`).
+/// * `q` - Second factor of quadratic residues group order (`(q_safe - 1) / 2`).
+pub fn new_key(n: &BigNumber, s: &BigNumber, p: &BigNumber, q: &BigNumber) -> Result<RsaAccumulatorPublicKey, IndyCryptoError> {
+    trace!("rsa_accumulator::new_key: >>> n: {:?}, s: {:?}", n, s);
+
+    let key = RsaAccumulatorPublicKey {
+        n: n.clone()?,
+        g: random_qr(n)?,
+        h: random_qr(n)?,
+        u: random_qr(n)?,
+        r_acc: s.mod_exp(&gen_x(p, q)?, n, None)?
+    };
+
+    trace!("rsa_accumulator::new_key: <<< key: {:?}", key);
+
+    Ok(key)
+}
+
+/// Returns prime accumulated for revocation index.
+///
+/// Prime is the first one starting from 256 bit hash of index, so it can be
+/// recalculated by everyone who knows index. Found primes are cached.
+///
+/// # Arguments
+/// * `idx` - Revocation index.
+pub fn index_prime(idx: u32) -> Result<BigNumber, IndyCryptoError> {
+    trace!("rsa_accumulator::index_prime: >>> idx: {:?}", idx);
+
+    if let Ok(primes) = INDEX_PRIMES.lock() {
+        if let Some(prime) = primes.get(&idx) {
+            let prime = BigNumber::from_bytes(prime)?;
+            trace!("rsa_accumulator::index_prime: <<< prime: {:?}", prime);
+            return Ok(prime);
+        }
+    }
+
+    let mut ctx = BigNumber::new_context()?;
+
+    let mut data = INDEX_PRIME_DOMAIN.to_vec();
+    data.extend_from_slice(&transform_u32_to_array_of_u8(idx));
+
+    let mut prime = BigNumber::from_bytes(&BigNumber::hash(&data)?)?;
+    prime.set_bit(INDEX_PRIME_BITS - 1)?;
+    prime.set_bit(0)?;
+
+    while !prime.is_prime(Some(&mut ctx))? {
+        prime.add_word(2)?;
+    }
+
+    if let Ok(mut primes) = INDEX_PRIMES.lock() {
+        primes.insert(idx, prime.to_bytes()?);
+    }
+
+    trace!("rsa_accumulator::index_prime: <<< prime: {:?}", prime);
+
+    Ok(prime)
+}
+
+/// Creates empty RSA revocation registry.
+///
+/// # Arguments
+/// * `key` - RSA accumulator public key.
+/// * `max_claim_num` - Max claim number in registry.
+pub fn new_registry(key: &RsaAccumulatorPublicKey, max_claim_num: u32) -> Result<RsaRevocationRegistry, IndyCryptoError> {
+    trace!("rsa_accumulator::new_registry: >>> key: {:?}, max_claim_num: {:?}", key, max_claim_num);
+
+    let rev_reg = RsaRevocationRegistry {
+        acc: key.u.clone()?,
+        v: IndexSet::new(),
+        issued: IndexSet::new(),
+        max_claim_num
+    };
+
+    trace!("rsa_accumulator::new_registry: <<< rev_reg: {:?}", rev_reg);
+
+    Ok(rev_reg)
+}
+
+/// Adds prime of index to accumulator.
+/// Returns non-revocation claim signature with witness for the index.
+///
+/// # Arguments
+/// * `rev_reg` - RSA revocation registry.
+/// * `key` - RSA accumulator public key.
+/// * `idx` - Revocation index.
+pub fn add(rev_reg: &mut RsaRevocationRegistry,
+           key: &RsaAccumulatorPublicKey,
+           idx: u32) -> Result<RsaNonRevocationClaimSignature, IndyCryptoError> {
+    trace!("rsa_accumulator::add: >>> rev_reg: {:?}, key: {:?}, idx: {:?}", rev_reg, key, idx);
+
+    if rev_reg.is_full() {
+        return Err(IndyCryptoError::AnoncredsRevocationAccumulatorIsFull(format!("Revocation accumulator is full")));
+    }
+
+    if idx == 0 || idx > rev_reg.max_claim_num {
+        return Err(IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(
+            format!("Index {} is out of accumulator range", idx)));
+    }
+
+    if rev_reg.is_idx_used(idx) {
+        return Err(IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(
+            format!("Revocation index {} is already used", idx)));
+    }
+
+    let e = index_prime(idx)?;
+    let witness = rev_reg.acc.clone()?;
+
+    rev_reg.acc = witness.mod_exp(&e, &key.n, None)?;
+    rev_reg.v.insert(idx);
+    rev_reg.issued.insert(idx);
+
+    let r_claim = RsaNonRevocationClaimSignature {
+        i: idx,
+        witness,
+        v: rev_reg.v.clone()
+    };
+
+    trace!("rsa_accumulator::add: <<< r_claim: {:?}", r_claim);
+
+    Ok(r_claim)
+}
+
+/// Removes prime of index from accumulator.
+/// Removed index stays used and can't be added again.
+///
+/// # Arguments
+/// * `rev_reg` - RSA revocation registry.
+/// * `key` - RSA accumulator public key.
+/// * `p` - First factor of quadratic residues group order (`(p_safe - 1) / 2`).
+/// * `q` - Second factor of quadratic residues group order (`(q_safe - 1) / 2`).
+/// * `idx` - Revocation index.
+pub fn remove(rev_reg: &mut RsaRevocationRegistry,
+              key: &RsaAccumulatorPublicKey,
+              p: &BigNumber,
+              q: &BigNumber,
+              idx: u32) -> Result<(), IndyCryptoError> {
+    trace!("rsa_accumulator::remove: >>> rev_reg: {:?}, key: {:?}, idx: {:?}", rev_reg, key, idx);
+
+    if !rev_reg.v.contains(idx) {
+        return Err(IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(
+            format!("User index:{} not found in Accumulator", idx)));
+    }
+
+    let mut ctx = BigNumber::new_context()?;
+
+    let order = p.mul(q, Some(&mut ctx))?;
+    let e_inverse = index_prime(idx)?.inverse(&order, Some(&mut ctx))?;
+
+    rev_reg.acc = rev_reg.acc.mod_exp(&e_inverse, &key.n, Some(&mut ctx))?;
+    rev_reg.v.remove(idx);
+
+    trace!("rsa_accumulator::remove: <<<");
+
+    Ok(())
+}
+
+/// Updates witness of non-revocation claim signature to the current registry state.
+///
+/// Witness is raised to product of primes added since last update. Removed primes
+/// are excluded by Shamir's trick, so only public data is used.
+///
+/// # Arguments
+/// * `r_claim` - RSA non-revocation claim signature.
+/// * `rev_reg` - RSA revocation registry.
+/// * `key` - RSA accumulator public key.
+pub fn update_witness(r_claim: &mut RsaNonRevocationClaimSignature,
+                      rev_reg: &RsaRevocationRegistry,
+                      key: &RsaAccumulatorPublicKey) -> Result<(), IndyCryptoError> {
+    trace!("rsa_accumulator::update_witness: >>> r_claim: {:?}, rev_reg: {:?}, key: {:?}", r_claim, rev_reg, key);
+
+    if !rev_reg.v.contains(r_claim.i) {
+        return Err(IndyCryptoError::AnoncredsClaimRevoked(format!("Claim is revoked")));
+    }
+
+    let mut ctx = BigNumber::new_context()?;
+    let e = index_prime(r_claim.i)?;
+
+    let added = _primes_product(&rev_reg.v.difference(&r_claim.v), &mut ctx)?;
+    let mut witness = r_claim.witness.mod_exp(&added, &key.n, Some(&mut ctx))?;
+
+    let removed = r_claim.v.difference(&rev_reg.v);
+
+    if !removed.is_empty() {
+        let removed = _primes_product(&removed, &mut ctx)?;
+
+        let a = e.inverse(&removed, Some(&mut ctx))?;
+        let mut k = a.mul(&e, Some(&mut ctx))?;
+        k.sub_word(1)?;
+        let k = k.div(&removed, Some(&mut ctx))?;
+
+        witness = rev_reg.acc
+            .mod_exp(&a, &key.n, Some(&mut ctx))?
+            .mul(
+                &witness.inverse(&key.n, Some(&mut ctx))?.mod_exp(&k, &key.n, Some(&mut ctx))?,
+                Some(&mut ctx)
+            )?
+            .modulus(&key.n, Some(&mut ctx))?;
+    }
+
+    if witness.mod_exp(&e, &key.n, Some(&mut ctx))? != rev_reg.acc {
+        return Err(IndyCryptoError::InvalidStructure(format!("Witness doesn't correspond to accumulator")));
+    }
+
+    r_claim.witness = witness;
+    r_claim.v = rev_reg.v.clone();
+
+    trace!("rsa_accumulator::update_witness: <<<");
+
+    Ok(())
+}
+
+/// Creates initial part of proof of knowledge of witness for prime `e`.
+///
+/// For commitments `C_u = w * h^r` and `C_r = g^r * h^r'` proves knowledge of
+/// `e, r, r', delta = e * r, gamma = e * r'` such that
+/// `C_r = g^r * h^r'`, `C_r^e = g^delta * h^gamma` and `C_u^e = acc * h^delta`.
+///
+/// # Arguments
+/// * `r_claim` - RSA non-revocation claim signature with updated witness.
+/// * `rev_reg` - RSA revocation registry.
+/// * `key` - RSA accumulator public key.
+/// * `e_tilde` - Blinding factor of `e` used in primary proof too.
+pub fn init_proof(r_claim: &RsaNonRevocationClaimSignature,
+                  rev_reg: &RsaRevocationRegistry,
+                  key: &RsaAccumulatorPublicKey,
+                  e_tilde: &BigNumber) -> Result<RsaNonRevocInitProof, IndyCryptoError> {
+    trace!("rsa_accumulator::init_proof: >>> r_claim: {:?}, rev_reg: {:?}, key: {:?}, e_tilde: {:?}", r_claim, rev_reg, key, e_tilde);
+
+    if r_claim.v != rev_reg.v {
+        return Err(IndyCryptoError::InvalidStructure(format!("Witness doesn't correspond to accumulator")));
+    }
+
+    let mut ctx = BigNumber::new_context()?;
+    let e = index_prime(r_claim.i)?;
+
+    let r = bn_rand(LARGE_RSA_ACC_R)?;
+    let r_prime = bn_rand(LARGE_RSA_ACC_R)?;
+    let delta = e.mul(&r, Some(&mut ctx))?;
+    let gamma = e.mul(&r_prime, Some(&mut ctx))?;
+
+    let r_tilde = bn_rand(LARGE_RSA_ACC_R_TILDE)?;
+    let r_prime_tilde = bn_rand(LARGE_RSA_ACC_R_TILDE)?;
+    let delta_tilde = bn_rand(LARGE_RSA_ACC_DELTA_TILDE)?;
+    let gamma_tilde = bn_rand(LARGE_RSA_ACC_DELTA_TILDE)?;
+
+    let c_u = r_claim.witness
+        .mul(&key.h.mod_exp(&r, &key.n, Some(&mut ctx))?, Some(&mut ctx))?
+        .modulus(&key.n, Some(&mut ctx))?;
+    let c_r = _commit(key, &r, &r_prime, &mut ctx)?;
+
+    let t_r = _commit(key, &r_tilde, &r_prime_tilde, &mut ctx)?;
+    let t_delta = c_r
+        .mod_exp(e_tilde, &key.n, Some(&mut ctx))?
+        .mul(&_commit(key, &delta_tilde, &gamma_tilde, &mut ctx)?.inverse(&key.n, Some(&mut ctx))?, Some(&mut ctx))?
+        .modulus(&key.n, Some(&mut ctx))?;
+    let t_acc = c_u
+        .mod_exp(e_tilde, &key.n, Some(&mut ctx))?
+        .mul(&key.h.mod_exp(&delta_tilde, &key.n, Some(&mut ctx))?.inverse(&key.n, Some(&mut ctx))?, Some(&mut ctx))?
+        .modulus(&key.n, Some(&mut ctx))?;
+
+    let init_proof = RsaNonRevocInitProof {
+        c_u, c_r, e, e_tilde: e_tilde.clone()?, r, r_prime, delta, gamma,
+        r_tilde, r_prime_tilde, delta_tilde, gamma_tilde,
+        t_r, t_delta, t_acc
+    };
+
+    trace!("rsa_accumulator::init_proof: <<< init_proof: {:?}", init_proof);
+
+    Ok(init_proof)
+}
+
+/// Finalizes proof of knowledge of witness by challenge.
+///
+/// # Arguments
+/// * `init_proof` - Initial part of proof.
+/// * `c_h` - Challenge.
+pub fn finalize_proof(init_proof: &RsaNonRevocInitProof, c_h: &BigNumber) -> Result<RsaNonRevocProof, IndyCryptoError> {
+    trace!("rsa_accumulator::finalize_proof: >>> init_proof: {:?}, c_h: {:?}", init_proof, c_h);
+
+    let mut ctx = BigNumber::new_context()?;
+
+    let proof = RsaNonRevocProof {
+        c_u: init_proof.c_u.clone()?,
+        c_r: init_proof.c_r.clone()?,
+        s_e: c_h.mul(&init_proof.e, Some(&mut ctx))?.add(&init_proof.e_tilde)?,
+        s_r: c_h.mul(&init_proof.r, Some(&mut ctx))?.add(&init_proof.r_tilde)?,
+        s_r_prime: c_h.mul(&init_proof.r_prime, Some(&mut ctx))?.add(&init_proof.r_prime_tilde)?,
+        s_delta: c_h.mul(&init_proof.delta, Some(&mut ctx))?.add(&init_proof.delta_tilde)?,
+        s_gamma: c_h.mul(&init_proof.gamma, Some(&mut ctx))?.add(&init_proof.gamma_tilde)?
+    };
+
+    trace!("rsa_accumulator::finalize_proof: <<< proof: {:?}", proof);
+
+    Ok(proof)
+}
+
+/// Recalculates tau list of proof of knowledge of witness.
+/// Proof is valid if recalculated values hash to challenge.
+///
+/// # Arguments
+/// * `proof` - Proof of knowledge of witness.
+/// * `key` - RSA accumulator public key.
+/// * `acc` - Accumulator value proof is checked against.
+/// * `c_h` - Challenge.
+pub fn verify_proof(proof: &RsaNonRevocProof,
+                    key: &RsaAccumulatorPublicKey,
+                    acc: &BigNumber,
+                    c_h: &BigNumber) -> Result<Vec<Vec<u8>>, IndyCryptoError> {
+    trace!("rsa_accumulator::verify_proof: >>> proof: {:?}, key: {:?}, acc: {:?}, c_h: {:?}", proof, key, acc, c_h);
+
+    let mut ctx = BigNumber::new_context()?;
+
+    let t_r = proof.c_r
+        .inverse(&key.n, Some(&mut ctx))?
+        .mod_exp(c_h, &key.n, Some(&mut ctx))?
+        .mul(&_commit(key, &proof.s_r, &proof.s_r_prime, &mut ctx)?, Some(&mut ctx))?
+        .modulus(&key.n, Some(&mut ctx))?;
+    let t_delta = proof.c_r
+        .mod_exp(&proof.s_e, &key.n, Some(&mut ctx))?
+        .mul(&_commit(key, &proof.s_delta, &proof.s_gamma, &mut ctx)?.inverse(&key.n, Some(&mut ctx))?, Some(&mut ctx))?
+        .modulus(&key.n, Some(&mut ctx))?;
+    let t_acc = acc
+        .inverse(&key.n, Some(&mut ctx))?
+        .mod_exp(c_h, &key.n, Some(&mut ctx))?
+        .mul(&proof.c_u.mod_exp(&proof.s_e, &key.n, Some(&mut ctx))?, Some(&mut ctx))?
+        .mul(&key.h.mod_exp(&proof.s_delta, &key.n, Some(&mut ctx))?.inverse(&key.n, Some(&mut ctx))?, Some(&mut ctx))?
+        .modulus(&key.n, Some(&mut ctx))?;
+
+    let tau_list = vec![t_r.to_bytes()?, t_delta.to_bytes()?, t_acc.to_bytes()?];
+
+    trace!("rsa_accumulator::verify_proof: <<< tau_list: {:?}", tau_list);
+
+    Ok(tau_list)
+}
+
+/// Returns `r_acc^e` for prime `e` of index, the factor of accumulated prime in primary Claim signature.
+///
+/// # Arguments
+/// * `key` - RSA accumulator public key.
+/// * `idx` - Revocation index.
+pub fn primary_signature_term(key: &RsaAccumulatorPublicKey, idx: u32) -> Result<BigNumber, IndyCryptoError> {
+    Ok(key.r_acc.mod_exp(&index_prime(idx)?, &key.n, None)?)
+}
+
+/// Returns base and blinding factor of accumulated prime for primary equality proof.
+pub fn primary_init_proof_term<'a>(init_proof: &'a RsaNonRevocInitProof, key: &'a RsaAccumulatorPublicKey) -> (&'a BigNumber, &'a BigNumber) {
+    (&key.r_acc, &init_proof.e_tilde)
+}
+
+/// Returns base and response of accumulated prime for primary equality proof verification.
+pub fn primary_proof_term<'a>(proof: &'a RsaNonRevocProof, key: &'a RsaAccumulatorPublicKey) -> (&'a BigNumber, &'a BigNumber) {
+    (&key.r_acc, &proof.s_e)
+}
+
+fn _commit(key: &RsaAccumulatorPublicKey, a: &BigNumber, b: &BigNumber, ctx: &mut BigNumberContext) -> Result<BigNumber, IndyCryptoError> {
+    Ok(key.g
+        .mod_exp(a, &key.n, Some(ctx))?
+        .mul(&key.h.mod_exp(b, &key.n, Some(ctx))?, Some(ctx))?
+        .modulus(&key.n, Some(ctx))?)
+}
+
+fn _primes_product(indexes: &IndexSet, ctx: &mut BigNumberContext) -> Result<BigNumber, IndyCryptoError> {
+    let mut product = BigNumber::from_u32(1)?;
+
+    for idx in indexes.iter() {
+        product = product.mul(&index_prime(idx)?, Some(ctx))?;
+    }
+
+    Ok(product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
+
+    fn _key() -> (RsaAccumulatorPublicKey, BigNumber, BigNumber) {
+        let p_safe = BigNumber::generate_safe_prime(512).unwrap();
+        let q_safe = BigNumber::generate_safe_prime(512).unwrap();
+        let n = p_safe.mul(&q_safe, None).unwrap();
+
+        let mut p = p_safe.sub(&BigNumber::from_u32(1).unwrap()).unwrap();
+        p.div_word(2).unwrap();
+        let mut q = q_safe.sub(&BigNumber::from_u32(1).unwrap()).unwrap();
+        q.div_word(2).unwrap();
+
+        let s = random_qr(&n).unwrap();
+
+        (new_key(&n, &s, &p, &q).unwrap(), p, q)
+    }
+
+    #[test]
+    fn index_prime_works() {
+        let prime = index_prime(1).unwrap();
+
+        assert!(prime.is_prime(None).unwrap());
+        assert_eq!(INDEX_PRIME_BITS, prime.num_bits().unwrap());
+        assert_eq!(prime, index_prime(1).unwrap());
+        assert_ne!(prime, index_prime(2).unwrap());
+    }
+
+    #[test]
+    fn add_works_for_used_idx() {
+        let (key, _, _) = _key();
+        let mut rev_reg = new_registry(&key, 5).unwrap();
+        add(&mut rev_reg, &key, 1).unwrap();
+
+        let res = add(&mut rev_reg, &key, 1);
+        assert_eq!(ErrorCode::AnoncredsRevocationAccumulatorIndexAlreadyUsed, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn add_works_for_revoked_idx() {
+        let (key, p, q) = _key();
+        let mut rev_reg = new_registry(&key, 5).unwrap();
+        add(&mut rev_reg, &key, 1).unwrap();
+        remove(&mut rev_reg, &key, &p, &q, 1).unwrap();
+
+        let res = add(&mut rev_reg, &key, 1);
+        assert_eq!(ErrorCode::AnoncredsRevocationAccumulatorIndexAlreadyUsed, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn update_witness_works() {
+        let (key, p, q) = _key();
+        let mut rev_reg = new_registry(&key, 5).unwrap();
+
+        let mut r_claim = add(&mut rev_reg, &key, 1).unwrap();
+        add(&mut rev_reg, &key, 2).unwrap();
+        add(&mut rev_reg, &key, 3).unwrap();
+        remove(&mut rev_reg, &key, &p, &q, 2).unwrap();
+        add(&mut rev_reg, &key, 4).unwrap();
+
+        update_witness(&mut r_claim, &rev_reg, &key).unwrap();
+
+        assert_eq!(r_claim.witness.mod_exp(&index_prime(1).unwrap(), &key.n, None).unwrap(), rev_reg.acc);
+    }
+
+    #[test]
+    fn update_witness_works_for_revoked_claim() {
+        let (key, p, q) = _key();
+        let mut rev_reg = new_registry(&key, 5).unwrap();
+
+        let mut r_claim = add(&mut rev_reg, &key, 1).unwrap();
+        remove(&mut rev_reg, &key, &p, &q, 1).unwrap();
+
+        let res = update_witness(&mut r_claim, &rev_reg, &key);
+        assert_eq!(ErrorCode::AnoncredsClaimRevoked, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn proof_works() {
+        let (key, _, _) = _key();
+        let mut rev_reg = new_registry(&key, 5).unwrap();
+
+        let mut r_claim = add(&mut rev_reg, &key, 1).unwrap();
+        add(&mut rev_reg, &key, 2).unwrap();
+        update_witness(&mut r_claim, &rev_reg, &key).unwrap();
+
+        let e = index_prime(1).unwrap();
+        let e_tilde = bn_rand(LARGE_MVECT).unwrap();
+        let c_h = bn_rand(LARGE_MASTER_SECRET).unwrap();
+
+        let init_proof = init_proof(&r_claim, &rev_reg, &key, &e_tilde).unwrap();
+        let mut proof = finalize_proof(&init_proof, &c_h).unwrap();
+
+        assert_eq!(c_h.mul(&e, None).unwrap().add(&e_tilde).unwrap(), proof.s_e);
+        assert_eq!(init_proof.as_tau_list().unwrap(), verify_proof(&proof, &key, &rev_reg.acc, &c_h).unwrap());
+
+        proof.s_e = proof.s_e.add(&BigNumber::from_u32(1).unwrap()).unwrap();
+        assert_ne!(init_proof.as_tau_list().unwrap(), verify_proof(&proof, &key, &rev_reg.acc, &c_h).unwrap());
+    }
+}
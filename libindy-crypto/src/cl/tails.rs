@@ -0,0 +1,650 @@
+use cl::*;
+use cl::helpers::transform_u32_to_array_of_u8;
+use errors::IndyCryptoError;
+use pair::{GroupOrderElement, PointG1, PointG2};
+
+use sha2::{Sha256, Digest};
+
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+
+/// Version of tails file format written by `write_tails`.
+pub const TAILS_FILE_VERSION: u8 = 1;
+
+const TAIL_SIZE: usize = PointG1::BYTES_REPR_SIZE;
+const TAIL_DASH_SIZE: usize = PointG2::BYTES_REPR_SIZE;
+const RECORD_SIZE: usize = TAIL_SIZE + TAIL_DASH_SIZE;
+const HEADER_SIZE: usize = 5;
+const NODE_HASH_SIZE: usize = 32;
+const GENERATION_CHUNK_SIZE: u32 = 1024;
+
+/// Provides access to revocation accumulator tails by index.
+///
+/// Tails are `g^(gamma^i)` and `g_dash^(gamma^i)` points for i in 0..2*max_claim_num
+/// (except max_claim_num + 1). Issuer, prover and verifier fetch only the points they need.
+pub trait TailsReader {
+    /// Returns `g^(gamma^idx)` point.
+    fn get_tail(&self, idx: u32) -> Result<PointG1, IndyCryptoError>;
+
+    /// Returns `g_dash^(gamma^idx)` point.
+    fn get_tail_dash(&self, idx: u32) -> Result<PointG2, IndyCryptoError>;
+
+    /// Checks that tails correspond to tails hash of revocation registry.
+    fn verify(&self, rev_reg_pub: &RevocationRegistryPublic) -> Result<(), IndyCryptoError>;
+}
+
+impl TailsReader for RevocationAccumulatorTails {
+    fn get_tail(&self, idx: u32) -> Result<PointG1, IndyCryptoError> {
+        self.tails
+            .get(&idx)
+            .cloned()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in tails", idx)))
+    }
+
+    fn get_tail_dash(&self, idx: u32) -> Result<PointG2, IndyCryptoError> {
+        self.tails_dash
+            .get(&idx)
+            .cloned()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in tails_dash", idx)))
+    }
+
+    fn verify(&self, rev_reg_pub: &RevocationRegistryPublic) -> Result<(), IndyCryptoError> {
+        verify_tails(self, rev_reg_pub)
+    }
+}
+
+/// Tails reader that reads points from file on demand.
+///
+/// File starts with header: format version byte and max_claim_num as 4 bytes big-endian.
+/// Header is followed by fixed-width records. Record i contains `g^(gamma^i)` and
+/// `g_dash^(gamma^i)` points bytes representations. Record max_claim_num + 1 is zero filled.
+/// Records are followed by Merkle tree over them: all tree levels from leaves to root,
+/// each node is 32 bytes SHA-256 hash. Tails hash is SHA-256 hash of header and tree root.
+///
+/// Only header and tree root are checked on open. Each read record is checked against
+/// the root by its Merkle path, so tampered records are rejected when they are used.
+pub struct FileTailsReader {
+    file: Mutex<File>,
+    max_claim_num: u32,
+    tails_hash: Vec<u8>,
+    root: Vec<u8>,
+}
+
+impl FileTailsReader {
+    /// Opens tails file and checks that its header and Merkle root match tails hash of revocation registry.
+    ///
+    /// # Arguments
+    /// * `path` - Path to tails file.
+    /// * `rev_reg_pub` - Revocation registry public the tails file belongs to.
+    pub fn open<P: AsRef<Path>>(path: P, rev_reg_pub: &RevocationRegistryPublic) -> Result<FileTailsReader, IndyCryptoError> {
+        trace!("FileTailsReader::open: >>> rev_reg_pub: {:?}", rev_reg_pub);
+
+        let mut file = File::open(path)?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        file.read_exact(&mut header)?;
+
+        if header[0] != TAILS_FILE_VERSION {
+            return Err(IndyCryptoError::InvalidStructure(format!("Unsupported tails file version: {}", header[0])));
+        }
+
+        let max_claim_num = bytes_to_u32(&header[1..]);
+
+        if max_claim_num != rev_reg_pub.acc.max_claim_num {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Tails file max claim number {} doesn't correspond to revocation registry {}",
+                        max_claim_num, rev_reg_pub.acc.max_claim_num)));
+        }
+
+        if max_claim_num == 0 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Tails file doesn't contain records")));
+        }
+
+        let records_num = 2 * max_claim_num as usize;
+        let tree_size: usize = merkle_level_sizes(records_num).iter().sum();
+        let expected_len = (HEADER_SIZE + RECORD_SIZE * records_num + NODE_HASH_SIZE * tree_size) as u64;
+        let actual_len = file.metadata()?.len();
+
+        if actual_len != expected_len {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid tails file length: expected {}, actual {}", expected_len, actual_len)));
+        }
+
+        let mut root = vec![0u8; NODE_HASH_SIZE];
+        file.seek(SeekFrom::Start(expected_len - NODE_HASH_SIZE as u64))?;
+        file.read_exact(&mut root)?;
+
+        if header_and_root_hash(&header, &root).as_slice() != rev_reg_pub.tails_hash.as_slice() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Tails file hash doesn't correspond to revocation registry")));
+        }
+
+        let tails_reader = FileTailsReader { file: Mutex::new(file), max_claim_num, tails_hash: rev_reg_pub.tails_hash.clone(), root };
+
+        trace!("FileTailsReader::open: <<< tails_reader: {:?}", tails_reader);
+
+        Ok(tails_reader)
+    }
+
+    pub fn get_max_claim_num(&self) -> u32 {
+        self.max_claim_num
+    }
+
+    fn _read(&self, idx: u32, offset: usize, len: usize) -> Result<Vec<u8>, IndyCryptoError> {
+        if idx >= 2 * self.max_claim_num || idx == self.max_claim_num + 1 {
+            return Err(IndyCryptoError::InvalidStructure(format!("Value by key '{}' not found in tails file", idx)));
+        }
+
+        let mut file = self.file.lock()
+            .map_err(|_| IndyCryptoError::InvalidState(format!("Tails file lock is poisoned")))?;
+
+        let records_num = 2 * self.max_claim_num as usize;
+
+        let mut record = vec![0u8; RECORD_SIZE];
+        _read_at(&mut file, HEADER_SIZE + RECORD_SIZE * idx as usize, &mut record)?;
+
+        let mut node = leaf_hash(&record);
+        let mut node_idx = idx as usize;
+        let mut level_start = HEADER_SIZE + RECORD_SIZE * records_num;
+        let mut sibling = vec![0u8; NODE_HASH_SIZE];
+
+        for level_size in merkle_level_sizes(records_num) {
+            if level_size == 1 {
+                break;
+            }
+
+            let sibling_idx = node_idx ^ 1;
+
+            if sibling_idx < level_size {
+                _read_at(&mut file, level_start + NODE_HASH_SIZE * sibling_idx, &mut sibling)?;
+                node = if node_idx % 2 == 0 { node_hash(&node, &sibling) } else { node_hash(&sibling, &node) };
+            }
+
+            node_idx /= 2;
+            level_start += NODE_HASH_SIZE * level_size;
+        }
+
+        if node != self.root {
+            return Err(IndyCryptoError::InvalidStructure(format!("Tails file record {} doesn't correspond to tails hash", idx)));
+        }
+
+        Ok(record[offset..offset + len].to_vec())
+    }
+}
+
+impl TailsReader for FileTailsReader {
+    fn get_tail(&self, idx: u32) -> Result<PointG1, IndyCryptoError> {
+        PointG1::from_bytes(&self._read(idx, 0, TAIL_SIZE)?)
+    }
+
+    fn get_tail_dash(&self, idx: u32) -> Result<PointG2, IndyCryptoError> {
+        PointG2::from_bytes(&self._read(idx, TAIL_SIZE, TAIL_DASH_SIZE)?)
+    }
+
+    fn verify(&self, rev_reg_pub: &RevocationRegistryPublic) -> Result<(), IndyCryptoError> {
+        if self.max_claim_num != rev_reg_pub.acc.max_claim_num || self.tails_hash != rev_reg_pub.tails_hash {
+            return Err(IndyCryptoError::InvalidStructure(format!("Tails file doesn't correspond to revocation registry")));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FileTailsReader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileTailsReader")
+            .field("file", &self.file)
+            .field("max_claim_num", &self.max_claim_num)
+            .field("tails_hash", &self.tails_hash)
+            .finish()
+    }
+}
+
+fn _read_at(file: &mut File, pos: usize, buf: &mut [u8]) -> Result<(), IndyCryptoError> {
+    file.seek(SeekFrom::Start(pos as u64))?;
+    file.read_exact(buf)?;
+    Ok(())
+}
+
+/// Writes tails to writer in format readable by `FileTailsReader`.
+/// Returns tails hash of written data.
+///
+/// # Arguments
+/// * `tails_reader` - Source of tails.
+/// * `max_claim_num` - Max claim number in revocation registry.
+/// * `writer` - Tails destination.
+pub fn write_tails<W: Write>(tails_reader: &TailsReader, max_claim_num: u32, writer: &mut W) -> Result<Vec<u8>, IndyCryptoError> {
+    trace!("write_tails: >>> max_claim_num: {:?}", max_claim_num);
+
+    let mut writer = TailsFileWriter::new(writer, max_claim_num)?;
+
+    for i in 0..(2 * max_claim_num) {
+        if i == max_claim_num + 1 {
+            writer.write_records(&vec![0u8; RECORD_SIZE])?;
+        } else {
+            let mut record = tails_reader.get_tail(i)?.to_bytes()?;
+            record.extend_from_slice(&tails_reader.get_tail_dash(i)?.to_bytes()?);
+            writer.write_records(&record)?;
+        }
+    }
+
+    let tails_hash = writer.finish()?;
+
+    trace!("write_tails: <<< tails_hash: {:?}", tails_hash);
+
+    Ok(tails_hash)
+}
+
+/// Writes tails to file in format readable by `FileTailsReader`.
+///
+/// # Arguments
+/// * `tails_reader` - Source of tails.
+/// * `max_claim_num` - Max claim number in revocation registry.
+/// * `path` - Path to tails file.
+pub fn write_tails_file<P: AsRef<Path>>(tails_reader: &TailsReader, max_claim_num: u32, path: P) -> Result<Vec<u8>, IndyCryptoError> {
+    let mut file = File::create(path)?;
+    write_tails(tails_reader, max_claim_num, &mut file)
+}
+
+/// Generates tails of revocation registry and writes them to writer in format readable by `FileTailsReader`.
+/// Returns tails hash of written data.
+///
+/// Tails are never held in memory all at once: indexes are split into chunks, a pool of worker threads
+/// computes chunks using running powers of gamma and chunks are written in order as they are ready.
+/// At most two chunks per worker are scheduled or waiting to be written at any moment.
+/// Only Merkle tree leaves (32 bytes per record) are kept until the tree is written.
+///
+/// # Arguments
+/// * `r_pub_key` - Issuer revocation public key.
+/// * `rev_reg_priv` - Revocation registry private.
+/// * `max_claim_num` - Max claim number in revocation registry.
+/// * `threads` - Number of worker threads.
+/// * `writer` - Tails destination.
+/// * `progress` - (Optional) Callback that receives numbers of written and total tails records.
+pub fn generate_tails<W: Write>(r_pub_key: &IssuerRevocationPublicKey,
+                                rev_reg_priv: &RevocationRegistryPrivate,
+                                max_claim_num: u32,
+                                threads: usize,
+                                writer: &mut W,
+                                mut progress: Option<&mut FnMut(u32, u32)>) -> Result<Vec<u8>, IndyCryptoError> {
+    trace!("generate_tails: >>> max_claim_num: {:?}, threads: {:?}", max_claim_num, threads);
+
+    let threads = cmp::max(threads, 1);
+
+    let mut writer = TailsFileWriter::new(writer, max_claim_num)?;
+
+    let (job_sender, job_receiver) = mpsc::channel::<(u32, u32)>();
+    let job_receiver = Arc::new(Mutex::new(job_receiver));
+    let (chunk_sender, chunk_receiver) = mpsc::channel::<(u32, Result<Vec<u8>, IndyCryptoError>)>();
+
+    let workers: Vec<thread::JoinHandle<()>> = (0..threads)
+        .map(|_| {
+            let job_receiver = job_receiver.clone();
+            let chunk_sender = chunk_sender.clone();
+            let (g, g_dash, gamma) = (r_pub_key.g, r_pub_key.g_dash, rev_reg_priv.key.gamma);
+
+            thread::spawn(move || {
+                loop {
+                    let job = match job_receiver.lock() {
+                        Ok(job_receiver) => job_receiver.recv(),
+                        Err(_) => break
+                    };
+
+                    let (start, end) = match job {
+                        Ok(job) => job,
+                        Err(_) => break /* all jobs are sent */
+                    };
+
+                    let chunk = _generate_tails_chunk(&g, &g_dash, &gamma, max_claim_num, start, end);
+
+                    if chunk_sender.send((start, chunk)).is_err() {
+                        break;
+                    }
+                }
+            })
+        })
+        .collect();
+    drop(chunk_sender);
+
+    let res = _write_tails_chunks(&mut writer, &job_sender, &chunk_receiver, threads as u32, max_claim_num, &mut progress);
+
+    drop(job_sender);
+
+    for worker in workers {
+        worker.join()
+            .map_err(|_| IndyCryptoError::InvalidState(format!("Tails generation thread panicked")))?;
+    }
+
+    res?;
+
+    let tails_hash = writer.finish()?;
+
+    trace!("generate_tails: <<< tails_hash: {:?}", tails_hash);
+
+    Ok(tails_hash)
+}
+
+fn _write_tails_chunks<W: Write>(writer: &mut TailsFileWriter<W>,
+                                 job_sender: &mpsc::Sender<(u32, u32)>,
+                                 chunk_receiver: &mpsc::Receiver<(u32, Result<Vec<u8>, IndyCryptoError>)>,
+                                 threads: u32,
+                                 max_claim_num: u32,
+                                 progress: &mut Option<&mut FnMut(u32, u32)>) -> Result<(), IndyCryptoError> {
+    let total = 2 * max_claim_num;
+    let max_pending = 2 * threads;
+
+    let mut scheduled = 0;
+    let mut written = 0;
+    let mut pending = 0;
+    let mut ready: HashMap<u32, Vec<u8>> = HashMap::new();
+
+    while written < total {
+        while pending < max_pending && scheduled < total {
+            let end = cmp::min(scheduled + GENERATION_CHUNK_SIZE, total);
+            job_sender.send((scheduled, end))
+                .map_err(|_| IndyCryptoError::InvalidState(format!("Tails generation threads stopped")))?;
+            scheduled = end;
+            pending += 1;
+        }
+
+        let (start, chunk) = chunk_receiver.recv()
+            .map_err(|_| IndyCryptoError::InvalidState(format!("Tails generation threads stopped")))?;
+        ready.insert(start, chunk?);
+
+        while let Some(chunk) = ready.remove(&written) {
+            writer.write_records(&chunk)?;
+            written += (chunk.len() / RECORD_SIZE) as u32;
+            pending -= 1;
+
+            if let Some(ref mut progress) = *progress {
+                progress(written, total);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn _generate_tails_chunk(g: &PointG1, g_dash: &PointG2, gamma: &GroupOrderElement,
+                         max_claim_num: u32, start: u32, end: u32) -> Result<Vec<u8>, IndyCryptoError> {
+    let mut chunk: Vec<u8> = Vec::with_capacity(RECORD_SIZE * (end - start) as usize);
+    let mut pow = gamma.pow_mod(&GroupOrderElement::from_bytes(&transform_u32_to_array_of_u8(start))?)?;
+
+    for i in start..end {
+        if i == max_claim_num + 1 {
+            chunk.extend_from_slice(&vec![0u8; RECORD_SIZE]);
+        } else {
+            chunk.extend_from_slice(&g.mul(&pow)?.to_bytes()?);
+            chunk.extend_from_slice(&g_dash.mul(&pow)?.to_bytes()?);
+        }
+        pow = pow.mul_mod(gamma)?;
+    }
+
+    Ok(chunk)
+}
+
+/// Returns tails hash of tails file content without writing it.
+///
+/// # Arguments
+/// * `tails_reader` - Source of tails.
+/// * `max_claim_num` - Max claim number in revocation registry.
+pub fn tails_hash(tails_reader: &TailsReader, max_claim_num: u32) -> Result<Vec<u8>, IndyCryptoError> {
+    write_tails(tails_reader, max_claim_num, &mut io::sink())
+}
+
+/// Checks that tails correspond to tails hash of revocation registry.
+///
+/// # Arguments
+/// * `tails_reader` - Tails to check.
+/// * `rev_reg_pub` - Revocation registry public the tails belong to.
+pub fn verify_tails(tails_reader: &TailsReader, rev_reg_pub: &RevocationRegistryPublic) -> Result<(), IndyCryptoError> {
+    if tails_hash(tails_reader, rev_reg_pub.acc.max_claim_num)? != rev_reg_pub.tails_hash {
+        return Err(IndyCryptoError::InvalidStructure(format!("Tails hash doesn't correspond to revocation registry")));
+    }
+    Ok(())
+}
+
+/// Writes header and records of tails file, collects Merkle tree leaves
+/// and writes the tree after the last record.
+struct TailsFileWriter<'a, W: Write + 'a> {
+    inner: &'a mut W,
+    header: Vec<u8>,
+    leaves: Vec<u8> /* NODE_HASH_SIZE bytes per record */,
+}
+
+impl<'a, W: Write> TailsFileWriter<'a, W> {
+    fn new(inner: &'a mut W, max_claim_num: u32) -> Result<TailsFileWriter<'a, W>, IndyCryptoError> {
+        let mut header = vec![TAILS_FILE_VERSION];
+        header.extend_from_slice(&transform_u32_to_array_of_u8(max_claim_num));
+
+        inner.write_all(&header)?;
+
+        Ok(TailsFileWriter { inner, header, leaves: Vec::with_capacity(NODE_HASH_SIZE * 2 * max_claim_num as usize) })
+    }
+
+    fn write_records(&mut self, records: &[u8]) -> Result<(), IndyCryptoError> {
+        self.inner.write_all(records)?;
+
+        for record in records.chunks(RECORD_SIZE) {
+            self.leaves.extend_from_slice(&leaf_hash(record));
+        }
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Vec<u8>, IndyCryptoError> {
+        let mut level = self.leaves;
+
+        loop {
+            self.inner.write_all(&level)?;
+
+            if level.len() <= NODE_HASH_SIZE {
+                break;
+            }
+
+            level = level
+                .chunks(2 * NODE_HASH_SIZE)
+                .flat_map(|pair| if pair.len() == 2 * NODE_HASH_SIZE {
+                    node_hash(&pair[..NODE_HASH_SIZE], &pair[NODE_HASH_SIZE..])
+                } else {
+                    pair.to_vec()
+                })
+                .collect();
+        }
+
+        self.inner.flush()?;
+
+        Ok(header_and_root_hash(&self.header, &level))
+    }
+}
+
+/// Returns numbers of nodes on Merkle tree levels from leaves to root.
+/// Last node of level without pair is moved to the next level as is.
+fn merkle_level_sizes(leaves_num: usize) -> Vec<usize> {
+    let mut sizes = vec![leaves_num];
+    let mut size = leaves_num;
+
+    while size > 1 {
+        size = (size + 1) / 2;
+        sizes.push(size);
+    }
+
+    sizes
+}
+
+fn leaf_hash(record: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::default();
+    hasher.input(&[0u8]);
+    hasher.input(record);
+    hasher.result().to_vec()
+}
+
+fn node_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::default();
+    hasher.input(&[1u8]);
+    hasher.input(left);
+    hasher.input(right);
+    hasher.result().to_vec()
+}
+
+fn header_and_root_hash(header: &[u8], root: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::default();
+    hasher.input(header);
+    hasher.input(root);
+    hasher.result().to_vec()
+}
+
+fn bytes_to_u32(bytes: &[u8]) -> u32 {
+    ((bytes[0] as u32) << 24) | ((bytes[1] as u32) << 16) | ((bytes[2] as u32) << 8) | (bytes[3] as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cl::issuer::Issuer;
+    use cl::issuer::mocks;
+
+    use std::env;
+    use std::fs;
+
+    #[test]
+    fn file_tails_reader_works() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&pub_key, 3).unwrap();
+
+        let path = env::temp_dir().join("indy_crypto_file_tails_reader_works.tails");
+        write_tails_file(&rev_tails, 3, &path).unwrap();
+
+        let file_tails_reader = FileTailsReader::open(&path, &rev_reg_pub).unwrap();
+        file_tails_reader.verify(&rev_reg_pub).unwrap();
+        assert_eq!(3, file_tails_reader.get_max_claim_num());
+
+        for i in 0..6 {
+            if i == 4 {
+                assert!(file_tails_reader.get_tail(i).is_err());
+                continue;
+            }
+            assert_eq!(rev_tails.get_tail(i).unwrap(), file_tails_reader.get_tail(i).unwrap());
+            assert_eq!(rev_tails.get_tail_dash(i).unwrap(), file_tails_reader.get_tail_dash(i).unwrap());
+        }
+        assert!(file_tails_reader.get_tail_dash(6).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_tails_reader_works_for_truncated_file() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&pub_key, 2).unwrap();
+
+        let mut bytes: Vec<u8> = Vec::new();
+        write_tails(&rev_tails, 2, &mut bytes).unwrap();
+        bytes.pop();
+
+        let path = env::temp_dir().join("indy_crypto_file_tails_reader_works_for_truncated_file.tails");
+        File::create(&path).unwrap().write_all(&bytes).unwrap();
+
+        assert!(FileTailsReader::open(&path, &rev_reg_pub).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_tails_reader_open_works_for_tails_hash() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&pub_key, 2).unwrap();
+
+        let path = env::temp_dir().join("indy_crypto_file_tails_reader_open_works_for_tails_hash.tails");
+        let tails_hash = write_tails_file(&rev_tails, 2, &path).unwrap();
+        assert_eq!(rev_reg_pub.get_tails_hash(), tails_hash.as_slice());
+
+        let file_tails_reader = FileTailsReader::open(&path, &rev_reg_pub).unwrap();
+        assert_eq!(rev_tails.get_tail_dash(2).unwrap(), file_tails_reader.get_tail_dash(2).unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn generate_tails_works() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&pub_key, 3).unwrap();
+
+        let mut expected: Vec<u8> = Vec::new();
+        let expected_hash = write_tails(&rev_tails, 3, &mut expected).unwrap();
+
+        let mut reported: Vec<(u32, u32)> = Vec::new();
+        let mut bytes: Vec<u8> = Vec::new();
+        let tails_hash = generate_tails(pub_key.r_key.as_ref().unwrap(), &rev_reg_priv, 3, 4, &mut bytes,
+                                        Some(&mut |done, total| reported.push((done, total)))).unwrap();
+
+        assert_eq!(expected, bytes);
+        assert_eq!(expected_hash, tails_hash);
+        assert_eq!(rev_reg_pub.get_tails_hash(), tails_hash.as_slice());
+        assert_eq!(Some(&(6, 6)), reported.last());
+    }
+
+    #[test]
+    fn file_tails_reader_open_works_for_tampered_file() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&pub_key, 2).unwrap();
+        let (_, _, other_rev_tails) = Issuer::new_revocation_registry(&pub_key, 2).unwrap();
+
+        let path = env::temp_dir().join("indy_crypto_file_tails_reader_open_works_for_tampered_file.tails");
+        write_tails_file(&other_rev_tails, 2, &path).unwrap();
+
+        assert!(FileTailsReader::open(&path, &rev_reg_pub).is_err());
+
+        verify_tails(&rev_tails, &rev_reg_pub).unwrap();
+        assert!(verify_tails(&other_rev_tails, &rev_reg_pub).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_tails_reader_works_for_file_modified_after_open() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&pub_key, 2).unwrap();
+        let (other_rev_reg_pub, _, other_rev_tails) = Issuer::new_revocation_registry(&pub_key, 2).unwrap();
+
+        let path = env::temp_dir().join("indy_crypto_file_tails_reader_works_for_file_modified_after_open.tails");
+        write_tails_file(&rev_tails, 2, &path).unwrap();
+
+        let file_tails_reader = FileTailsReader::open(&path, &rev_reg_pub).unwrap();
+        assert!(file_tails_reader.verify(&other_rev_reg_pub).is_err());
+
+        let mut bytes: Vec<u8> = Vec::new();
+        write_tails(&other_rev_tails, 2, &mut bytes).unwrap();
+        fs::OpenOptions::new().write(true).open(&path).unwrap().write_all(&bytes).unwrap();
+
+        assert!(file_tails_reader.get_tail(0).is_err());
+        assert!(file_tails_reader.get_tail_dash(3).is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_tails_reader_works_for_tampered_record() {
+        let (pub_key, _) = Issuer::new_keys(&mocks::claim_schema(), true).unwrap();
+        let (rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&pub_key, 3).unwrap();
+
+        let mut bytes: Vec<u8> = Vec::new();
+        write_tails(&rev_tails, 3, &mut bytes).unwrap();
+        bytes[HEADER_SIZE + RECORD_SIZE * 2] ^= 1;
+
+        let path = env::temp_dir().join("indy_crypto_file_tails_reader_works_for_tampered_record.tails");
+        File::create(&path).unwrap().write_all(&bytes).unwrap();
+
+        let file_tails_reader = FileTailsReader::open(&path, &rev_reg_pub).unwrap();
+
+        assert!(file_tails_reader.get_tail(2).is_err());
+        assert_eq!(rev_tails.get_tail(3).unwrap(), file_tails_reader.get_tail(3).unwrap());
+        assert_eq!(rev_tails.get_tail_dash(5).unwrap(), file_tails_reader.get_tail_dash(5).unwrap());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
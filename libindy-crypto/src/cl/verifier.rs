@@ -1,12 +1,16 @@
+use bls::{Bls, Generator, Signature, VerKey};
 use bn::BigNumber;
 use cl::*;
-use cl::constants::{LARGE_E_START, ITERATION, LARGE_NONCE};
+use cl::constants::{LARGE_E_START, ITERATION, LARGE_NONCE, PSEUDONYM_GROUP_MODULUS, ACCUMULATOR_STATEMENT_MAX_CLOCK_SKEW};
 use cl::helpers::*;
+use cl::rsa_accumulator;
 use errors::IndyCryptoError;
 
 use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 
+use time;
+
 /// Party that wants to check that prover has some credentials provided by issuer.
 pub struct Verifier {}
 
@@ -53,14 +57,76 @@ impl Verifier {
     pub fn new_proof_verifier() -> Result<ProofVerifier, IndyCryptoError> {
         Ok(ProofVerifier {
             claims: HashMap::new(),
+            pseudonym_scope: None,
         })
     }
+
+    /// Checks accumulator statement and returns announced accumulator.
+    ///
+    /// Statement is accepted only if it is issued for given revocation registry, signed by
+    /// Issuer key corresponding to `ver_key`, its sequence number isn't less than `min_seq_no`,
+    /// it is no more than `max_age` seconds old and its timestamp is ahead of current time
+    /// no more than on `ACCUMULATOR_STATEMENT_MAX_CLOCK_SKEW` seconds.
+    /// Otherwise `AnoncredsProofRejected` error is returned.
+    ///
+    /// # Arguments
+    /// * `statement` - Revocation accumulator statement.
+    /// * `rev_reg_id` - Expected revocation registry identifier.
+    /// * `ver_key` - Issuer BLS verification key.
+    /// * `gen` - BLS generator.
+    /// * `min_seq_no` - Minimal accepted sequence number (for example last seen one).
+    /// * `max_age` - Maximal allowed age of statement in seconds.
+    pub fn check_accumulator_statement(statement: &RevocationAccumulatorStatement,
+                                       rev_reg_id: &str,
+                                       ver_key: &VerKey,
+                                       gen: &Generator,
+                                       min_seq_no: u64,
+                                       max_age: u64) -> Result<RevocationAccumulator, IndyCryptoError> {
+        trace!("Verifier::check_accumulator_statement: >>> statement: {:?}, rev_reg_id: {:?}, min_seq_no: {:?}, max_age: {:?}",
+               statement, rev_reg_id, min_seq_no, max_age);
+
+        if statement.rev_reg_id != rev_reg_id {
+            return Err(IndyCryptoError::AnoncredsProofRejected(
+                format!("Accumulator statement is issued for revocation registry {}", statement.rev_reg_id)));
+        }
+
+        let message = RevocationAccumulatorStatement::_signed_bytes(&statement.rev_reg_id, statement.seq_no, statement.timestamp, &statement.acc)?;
+        let signature = Signature::from_bytes(&statement.signature)?;
+
+        if !Bls::verify(&signature, &message, ver_key, gen)? {
+            return Err(IndyCryptoError::AnoncredsProofRejected(format!("Accumulator statement signature is invalid")));
+        }
+
+        if statement.seq_no < min_seq_no {
+            return Err(IndyCryptoError::AnoncredsProofRejected(
+                format!("Accumulator statement sequence number {} is less than {}", statement.seq_no, min_seq_no)));
+        }
+
+        let now = time::get_time().sec as u64;
+
+        if statement.timestamp > now.saturating_add(ACCUMULATOR_STATEMENT_MAX_CLOCK_SKEW) {
+            return Err(IndyCryptoError::AnoncredsProofRejected(
+                format!("Accumulator statement timestamp {} is in the future, current time: {}", statement.timestamp, now)));
+        }
+
+        if now.saturating_sub(statement.timestamp) > max_age {
+            return Err(IndyCryptoError::AnoncredsProofRejected(
+                format!("Accumulator statement timestamp {} isn't fresh, current time: {}", statement.timestamp, now)));
+        }
+
+        let rev_acc = statement.acc.clone();
+
+        trace!("Verifier::check_accumulator_statement: <<< rev_acc: {:?}", rev_acc);
+
+        Ok(rev_acc)
+    }
 }
 
 
 #[derive(Debug)]
 pub struct ProofVerifier {
     claims: HashMap<String, VerifyClaim>,
+    pseudonym_scope: Option<String>,
 }
 
 impl ProofVerifier {
@@ -79,11 +145,161 @@ impl ProofVerifier {
                                  claim_schema: &ClaimSchema,
                                  issuer_pub_key: &IssuerPublicKey,
                                  rev_reg_pub: Option<&RevocationRegistryPublic>) -> Result<(), IndyCryptoError> {
+        self._add_sub_proof_request(key_id,
+                                    sub_proof_request,
+                                    claim_schema,
+                                    issuer_pub_key,
+                                    rev_reg_pub.map(|r_reg| (&r_reg.acc, &r_reg.key)),
+                                    None,
+                                    None)
+    }
+
+    /// Add sub proof request to proof verifier which requires proof that claim wasn't revoked
+    /// at the moment of accumulator snapshot with given id.
+    /// Proofs built against another snapshot or current accumulator will be rejected.
+    ///
+    /// # Arguments
+    /// * `proof_verifier` - Proof verifier.
+    /// * `key_id` - unique claim identifier.
+    /// * `claim_schema` - Claim schema.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `rev_reg_pub` - Public revocation registry containing snapshot.
+    /// * `sub_proof_request` - Requested attributes and predicates instance pointer.
+    /// * `acc_snapshot_id` - Accumulator snapshot id.
+    pub fn add_sub_proof_request_for_snapshot(&mut self,
+                                              key_id: &str,
+                                              sub_proof_request: &SubProofRequest,
+                                              claim_schema: &ClaimSchema,
+                                              issuer_pub_key: &IssuerPublicKey,
+                                              rev_reg_pub: &RevocationRegistryPublic,
+                                              acc_snapshot_id: u64) -> Result<(), IndyCryptoError> {
+        let r_acc = rev_reg_pub._get_snapshot_accumulator(acc_snapshot_id)?;
+
+        self._add_sub_proof_request(key_id,
+                                    sub_proof_request,
+                                    claim_schema,
+                                    issuer_pub_key,
+                                    Some((&r_acc, &rev_reg_pub.key)),
+                                    Some(acc_snapshot_id),
+                                    None)
+    }
+
+    /// Add sub proof request to proof verifier using only revocation accumulator and its public key
+    /// instead of whole public revocation registry.
+    ///
+    /// # Arguments
+    /// * `proof_verifier` - Proof verifier.
+    /// * `key_id` - unique claim identifier.
+    /// * `claim_schema` - Claim schema.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `rev_acc` - Revocation accumulator.
+    /// * `rev_acc_pub_key` - Revocation accumulator public key.
+    /// * `acc_snapshot_id` - (Optional) Accumulator snapshot id if `rev_acc` is taken from snapshot.
+    /// * `sub_proof_request` - Requested attributes and predicates instance pointer.
+    pub fn add_sub_proof_request_with_accumulator(&mut self,
+                                                  key_id: &str,
+                                                  sub_proof_request: &SubProofRequest,
+                                                  claim_schema: &ClaimSchema,
+                                                  issuer_pub_key: &IssuerPublicKey,
+                                                  rev_acc: &RevocationAccumulator,
+                                                  rev_acc_pub_key: &RevocationAccumulatorPublicKey,
+                                                  acc_snapshot_id: Option<u64>) -> Result<(), IndyCryptoError> {
+        self._add_sub_proof_request(key_id,
+                                    sub_proof_request,
+                                    claim_schema,
+                                    issuer_pub_key,
+                                    Some((rev_acc, rev_acc_pub_key)),
+                                    acc_snapshot_id,
+                                    None)
+    }
+
+    /// Add sub proof request to proof verifier using accumulator from Issuer signed statement.
+    /// Statement is checked by `Verifier::check_accumulator_statement`.
+    ///
+    /// # Arguments
+    /// * `proof_verifier` - Proof verifier.
+    /// * `key_id` - unique claim identifier.
+    /// * `claim_schema` - Claim schema.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `statement` - Revocation accumulator statement.
+    /// * `rev_acc_pub_key` - Revocation accumulator public key.
+    /// * `rev_reg_id` - Expected revocation registry identifier.
+    /// * `ver_key` - Issuer BLS verification key.
+    /// * `gen` - BLS generator.
+    /// * `min_seq_no` - Minimal accepted sequence number of statement.
+    /// * `max_age` - Maximal allowed age of statement in seconds.
+    /// * `sub_proof_request` - Requested attributes and predicates instance pointer.
+    pub fn add_sub_proof_request_with_accumulator_statement(&mut self,
+                                                            key_id: &str,
+                                                            sub_proof_request: &SubProofRequest,
+                                                            claim_schema: &ClaimSchema,
+                                                            issuer_pub_key: &IssuerPublicKey,
+                                                            statement: &RevocationAccumulatorStatement,
+                                                            rev_acc_pub_key: &RevocationAccumulatorPublicKey,
+                                                            rev_reg_id: &str,
+                                                            ver_key: &VerKey,
+                                                            gen: &Generator,
+                                                            min_seq_no: u64,
+                                                            max_age: u64) -> Result<(), IndyCryptoError> {
+        let rev_acc = Verifier::check_accumulator_statement(statement, rev_reg_id, ver_key, gen, min_seq_no, max_age)?;
+
+        self._add_sub_proof_request(key_id,
+                                    sub_proof_request,
+                                    claim_schema,
+                                    issuer_pub_key,
+                                    Some((&rev_acc, rev_acc_pub_key)),
+                                    None,
+                                    None)
+    }
+
+    /// Add sub proof request to proof verifier which requires proof that claim is accumulated
+    /// in RSA revocation registry (see `RevocationBackend::Rsa`).
+    ///
+    /// # Arguments
+    /// * `proof_verifier` - Proof verifier.
+    /// * `key_id` - unique claim identifier.
+    /// * `claim_schema` - Claim schema.
+    /// * `issuer_pub_key` - Issuer public key.
+    /// * `rsa_acc` - RSA accumulator value (see `RsaRevocationRegistry::get_accumulator`).
+    /// * `sub_proof_request` - Requested attributes and predicates instance pointer.
+    pub fn add_sub_proof_request_with_rsa_accumulator(&mut self,
+                                                      key_id: &str,
+                                                      sub_proof_request: &SubProofRequest,
+                                                      claim_schema: &ClaimSchema,
+                                                      issuer_pub_key: &IssuerPublicKey,
+                                                      rsa_acc: &BigNumber) -> Result<(), IndyCryptoError> {
+        if issuer_pub_key.rsa_r_key.is_none() {
+            return Err(IndyCryptoError::InvalidStructure(format!("No RSA revocation part present in issuer key.")));
+        }
+
+        self._add_sub_proof_request(key_id,
+                                    sub_proof_request,
+                                    claim_schema,
+                                    issuer_pub_key,
+                                    None,
+                                    None,
+                                    Some(rsa_acc))
+    }
+
+    fn _add_sub_proof_request(&mut self,
+                              key_id: &str,
+                              sub_proof_request: &SubProofRequest,
+                              claim_schema: &ClaimSchema,
+                              issuer_pub_key: &IssuerPublicKey,
+                              rev_acc: Option<(&RevocationAccumulator, &RevocationAccumulatorPublicKey)>,
+                              acc_snapshot_id: Option<u64>,
+                              rsa_acc: Option<&BigNumber>) -> Result<(), IndyCryptoError> {
         ProofVerifier::_check_add_sub_proof_request_params_consistency(sub_proof_request, claim_schema)?;
 
         self.claims.insert(key_id.to_string(), VerifyClaim {
             pub_key: issuer_pub_key.clone()?,
-            r_reg: rev_reg_pub.map(Clone::clone),
+            r_acc: rev_acc.map(|(acc, _)| acc.clone()),
+            r_acc_pub_key: rev_acc.map(|(_, key)| key.clone()),
+            acc_snapshot_id,
+            rsa_acc: match rsa_acc {
+                Some(acc) => Some(acc.clone()?),
+                None => None
+            },
             sub_proof_request: sub_proof_request.clone(),
             claim_schema: claim_schema.clone(),
         });
@@ -111,6 +327,27 @@ impl ProofVerifier {
         Ok(())
     }
 
+    /// Request Prover pseudonym for given scope.
+    /// Proof will be accepted only if it contains pseudonym for this scope derived
+    /// from the same master secret as claims of sub proofs.
+    ///
+    /// # Arguments
+    /// * `proof_verifier` - Proof verifier.
+    /// * `scope` - Scope string (e.g. Verifier domain).
+    pub fn add_pseudonym_request(&mut self, scope: &str) -> Result<(), IndyCryptoError> {
+        trace!("ProofVerifier::add_pseudonym_request: >>> scope: {:?}", scope);
+
+        if self.pseudonym_scope.is_some() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Pseudonym is already requested")));
+        }
+
+        self.pseudonym_scope = Some(scope.to_owned());
+
+        trace!("ProofVerifier::add_pseudonym_request: <<<");
+
+        Ok(())
+    }
+
     /// Verifies proof.
     ///
     /// # Arguments
@@ -124,31 +361,80 @@ impl ProofVerifier {
 
         ProofVerifier::_check_verify_params_consistency(&self.claims, proof)?;
 
+        if let Some(ref scope) = self.pseudonym_scope {
+            let pseudonym = proof.pseudonym.as_ref()
+                .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Proof doesn't contain requested pseudonym")))?;
+
+            if pseudonym.scope != *scope {
+                return Err(IndyCryptoError::AnoncredsProofRejected(format!("Proof pseudonym scope not correspond to requested scope")));
+            }
+        }
+
         let mut tau_list: Vec<Vec<u8>> = Vec::new();
 
+        let aggregated_key_ids: HashSet<&String> = proof.aggregated_non_revoc_proofs.iter()
+            .flat_map(|aggregated| aggregated.key_ids.iter())
+            .collect();
+
+        if aggregated_key_ids.len() != proof.aggregated_non_revoc_proofs.iter().map(|aggregated| aggregated.key_ids.len()).sum::<usize>() {
+            return Err(IndyCryptoError::AnoncredsProofRejected(format!("Non-revocation proof is aggregated more than once")));
+        }
+
+        for aggregated_non_revoc_proof in proof.aggregated_non_revoc_proofs.iter() {
+            tau_list.extend_from_slice(
+                &ProofVerifier::_verify_aggregated_non_revocation_proof(&self.claims, proof, aggregated_non_revoc_proof)?.as_slice()?
+            );
+        }
+
         for (issuer_key_id, proof_item) in &proof.proofs {
             let claim = self.claims.get(issuer_key_id)
                 .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Schema is not found")))?;
 
-            if let (Some(non_revocation_proof), Some(pkr), Some(revoc_reg)) = (proof_item.non_revoc_proof.as_ref(),
-                                                                               claim.pub_key.r_key.as_ref(),
-                                                                               claim.r_reg.as_ref()) {
+            if let (Some(non_revocation_proof), Some(pkr), Some(r_acc), Some(r_acc_pub_key)) = (proof_item.non_revoc_proof.as_ref(),
+                                                                                                claim.pub_key.r_key.as_ref(),
+                                                                                                claim.r_acc.as_ref(),
+                                                                                                claim.r_acc_pub_key.as_ref()) {
+                if non_revocation_proof.acc_snapshot_id != claim.acc_snapshot_id {
+                    return Err(IndyCryptoError::AnoncredsProofRejected(format!("Proof accumulator snapshot not correspond to requested snapshot")));
+                }
+
+                if !aggregated_key_ids.contains(issuer_key_id) {
+                    tau_list.extend_from_slice(
+                        &ProofVerifier::_verify_non_revocation_proof(
+                            &pkr,
+                            r_acc,
+                            r_acc_pub_key,
+                            &proof.aggregated_proof.c_hash,
+                            &non_revocation_proof)?.as_slice()?
+                    );
+                }
+            };
+
+            let mut acc_prime_term = None;
+
+            if let (Some(rsa_r_key), Some(rsa_acc)) = (claim.pub_key.rsa_r_key.as_ref(), claim.rsa_acc.as_ref()) {
+                let rsa_non_revocation_proof = proof_item.rsa_non_revoc_proof.as_ref()
+                    .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Proof doesn't contain RSA non revocation part")))?;
+
                 tau_list.extend_from_slice(
-                    &ProofVerifier::_verify_non_revocation_proof(
-                        &pkr,
-                        &revoc_reg.acc,
-                        &revoc_reg.key,
-                        &proof.aggregated_proof.c_hash,
-                        &non_revocation_proof)?.as_slice()?
+                    &rsa_accumulator::verify_proof(rsa_non_revocation_proof,
+                                                   rsa_r_key,
+                                                   rsa_acc,
+                                                   &proof.aggregated_proof.c_hash)?
                 );
-            };
+                acc_prime_term = Some(rsa_accumulator::primary_proof_term(rsa_non_revocation_proof, rsa_r_key));
+            } else if proof_item.rsa_non_revoc_proof.is_some() {
+                return Err(IndyCryptoError::AnoncredsProofRejected(
+                    format!("Proof contains RSA non revocation part, but sub proof request was added without RSA accumulator")));
+            }
 
             tau_list.append_vec(
                 &ProofVerifier::_verify_primary_proof(&claim.pub_key.p_key,
                                                       &proof.aggregated_proof.c_hash,
                                                       &proof_item.primary_proof,
                                                       &claim.claim_schema,
-                                                      &claim.sub_proof_request)?
+                                                      &claim.sub_proof_request,
+                                                      acc_prime_term)?
             )?;
         }
 
@@ -158,6 +444,11 @@ impl ProofVerifier {
         values.extend_from_slice(&proof.aggregated_proof.c_list);
         values.push(nonce.to_bytes()?);
 
+        if let Some(ref pseudonym) = proof.pseudonym {
+            values.push(ProofVerifier::_verify_pseudonym(pseudonym, proof)?);
+            values.push(pseudonym.value.to_bytes()?);
+        }
+
         let c_hver = get_hash_as_int(&mut values)?;
 
         info!(target: "anoncreds_service", "Verifier verify proof -> done");
@@ -198,11 +489,13 @@ impl ProofVerifier {
     }
 
     fn _verify_primary_proof(issuer_pub_key: &IssuerPrimaryPublicKey, c_hash: &BigNumber,
-                             primary_proof: &PrimaryProof, claim_schema: &ClaimSchema, sub_proof_request: &SubProofRequest) -> Result<Vec<BigNumber>, IndyCryptoError> {
-        trace!("ProofVerifier::_verify_primary_proof: >>> issuer_pub_key: {:?}, c_hash: {:?}, primary_proof: {:?}, sub_proof_request: {:?}",
-               issuer_pub_key, c_hash, primary_proof, sub_proof_request);
+                             primary_proof: &PrimaryProof, claim_schema: &ClaimSchema, sub_proof_request: &SubProofRequest,
+                             acc_prime_term: Option<(&BigNumber, &BigNumber)>) -> Result<Vec<BigNumber>, IndyCryptoError> {
+        trace!("ProofVerifier::_verify_primary_proof: >>> issuer_pub_key: {:?}, c_hash: {:?}, primary_proof: {:?}, sub_proof_request: {:?}, acc_prime_term: {:?}",
+               issuer_pub_key, c_hash, primary_proof, sub_proof_request, acc_prime_term);
 
-        let mut t_hat: Vec<BigNumber> = ProofVerifier::_verify_equality(issuer_pub_key, &primary_proof.eq_proof, c_hash, claim_schema, sub_proof_request)?;
+        let mut t_hat: Vec<BigNumber> = ProofVerifier::_verify_equality(issuer_pub_key, &primary_proof.eq_proof, c_hash, claim_schema, sub_proof_request,
+                                                                         acc_prime_term)?;
 
         for ge_proof in primary_proof.ge_proofs.iter() {
             t_hat.append(&mut ProofVerifier::_verify_ge_predicate(issuer_pub_key, ge_proof, c_hash)?)
@@ -214,9 +507,10 @@ impl ProofVerifier {
     }
 
     fn _verify_equality(issuer_pub_key: &IssuerPrimaryPublicKey, proof: &PrimaryEqualProof, c_hash: &BigNumber,
-                        claim_schema: &ClaimSchema, sub_proof_request: &SubProofRequest) -> Result<Vec<BigNumber>, IndyCryptoError> {
-        trace!("ProofVerifier::_verify_equality: >>> issuer_pub_key: {:?}, proof: {:?}, c_hash: {:?}, claim_schema: {:?}, sub_proof_request: {:?}",
-               issuer_pub_key, proof, c_hash, claim_schema, sub_proof_request);
+                        claim_schema: &ClaimSchema, sub_proof_request: &SubProofRequest,
+                        acc_prime_term: Option<(&BigNumber, &BigNumber)>) -> Result<Vec<BigNumber>, IndyCryptoError> {
+        trace!("ProofVerifier::_verify_equality: >>> issuer_pub_key: {:?}, proof: {:?}, c_hash: {:?}, claim_schema: {:?}, sub_proof_request: {:?}, acc_prime_term: {:?}",
+               issuer_pub_key, proof, c_hash, claim_schema, sub_proof_request, acc_prime_term);
 
         let unrevealed_attrs: HashSet<String> =
             claim_schema.attrs
@@ -225,7 +519,7 @@ impl ProofVerifier {
                 .collect::<HashSet<String>>();
 
         let t1: BigNumber = calc_teq(&issuer_pub_key, &proof.a_prime, &proof.e, &proof.v, &proof.m,
-                                     &proof.m1, &proof.m2, &unrevealed_attrs)?;
+                                     &proof.m1, &proof.m2, &unrevealed_attrs, acc_prime_term)?;
 
         let mut ctx = BigNumber::new_context()?;
         let mut rar = BigNumber::from_dec("1")?;
@@ -306,6 +600,94 @@ impl ProofVerifier {
         Ok(tau_list)
     }
 
+    fn _verify_pseudonym(pseudonym: &Pseudonym, proof: &Proof) -> Result<Vec<u8>, IndyCryptoError> {
+        trace!("ProofVerifier::_verify_pseudonym: >>> pseudonym: {:?}, proof: {:?}", pseudonym, proof);
+
+        if !is_pseudonym_group_element(&pseudonym.value)? {
+            return Err(IndyCryptoError::AnoncredsProofRejected(format!("Pseudonym is not valid group element")));
+        }
+
+        let mut m1_values = proof.proofs.values().map(|sub_proof| &sub_proof.primary_proof.eq_proof.m1);
+
+        let m1 = m1_values.next()
+            .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Pseudonym can't be verified without sub proofs")))?;
+
+        if m1_values.any(|other| other != m1) {
+            return Err(IndyCryptoError::AnoncredsProofRejected(format!("Sub proofs are built with different master secrets")));
+        }
+
+        let modulus = BigNumber::from_hex(PSEUDONYM_GROUP_MODULUS)?;
+
+        let t_hat = calc_pseudonym(&pseudonym.scope, m1)?
+            .mod_div(&pseudonym.value.mod_exp(&proof.aggregated_proof.c_hash, &modulus, None)?, &modulus)?;
+
+        trace!("ProofVerifier::_verify_pseudonym: <<< t_hat: {:?}", t_hat);
+
+        t_hat.to_bytes()
+    }
+
+    fn _verify_aggregated_non_revocation_proof(claims: &HashMap<String, VerifyClaim>,
+                                               proof: &Proof,
+                                               aggregated_non_revoc_proof: &AggregatedNonRevocProof) -> Result<NonRevocProofTauList, IndyCryptoError> {
+        trace!("ProofVerifier::_verify_aggregated_non_revocation_proof: >>> claims: {:?}, proof: {:?}, aggregated_non_revoc_proof: {:?}",
+               claims, proof, aggregated_non_revoc_proof);
+
+        let key_ids = &aggregated_non_revoc_proof.key_ids;
+
+        if key_ids.len() < 2 {
+            return Err(IndyCryptoError::AnoncredsProofRejected(format!("Aggregated non-revocation proof contains less than two claims")));
+        }
+
+        let mut non_revoc_proofs: Vec<&NonRevocProof> = Vec::new();
+        let mut revocation_params: Option<(&IssuerRevocationPublicKey, &RevocationAccumulator, &RevocationAccumulatorPublicKey)> = None;
+
+        for key_id in key_ids.iter() {
+            let claim = claims.get(key_id)
+                .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Schema is not found")))?;
+
+            let non_revoc_proof = proof.proofs.get(key_id)
+                .and_then(|proof_item| proof_item.non_revoc_proof.as_ref())
+                .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Proof doesn't contain non revocation part")))?;
+
+            let (r_key, r_acc, r_acc_pub_key) = match (claim.pub_key.r_key.as_ref(), claim.r_acc.as_ref(), claim.r_acc_pub_key.as_ref()) {
+                (Some(r_key), Some(r_acc), Some(r_acc_pub_key)) => (r_key, r_acc, r_acc_pub_key),
+                _ => return Err(IndyCryptoError::AnoncredsProofRejected(format!("Revocation registry is not found")))
+            };
+
+            if let Some((first_r_key, first_r_acc, first_r_acc_pub_key)) = revocation_params {
+                if *first_r_key != *r_key || first_r_acc.acc != r_acc.acc || first_r_acc_pub_key.z != r_acc_pub_key.z {
+                    return Err(IndyCryptoError::AnoncredsProofRejected(format!("Aggregated claims are issued under different revocation registries")));
+                }
+            } else {
+                revocation_params = Some((r_key, r_acc, r_acc_pub_key));
+            }
+
+            if non_revoc_proof.acc_snapshot_id != claim.acc_snapshot_id
+                || non_revoc_proof.acc_snapshot_id != non_revoc_proofs.first().map_or(non_revoc_proof.acc_snapshot_id, |first| first.acc_snapshot_id) {
+                return Err(IndyCryptoError::AnoncredsProofRejected(format!("Proof accumulator snapshot not correspond to requested snapshot")));
+            }
+
+            non_revoc_proofs.push(non_revoc_proof);
+        }
+
+        let (r_key, r_acc, r_acc_pub_key) = revocation_params
+            .ok_or(IndyCryptoError::AnoncredsProofRejected(format!("Revocation registry is not found")))?;
+
+        let c_lists: Vec<&NonRevocProofCList> = non_revoc_proofs.iter().map(|proof| &proof.c_list).collect();
+        let weights = non_revoc_proof_aggregation_weights(key_ids, &c_lists)?;
+
+        let aggregated_tau_list = create_aggregated_tau_list_values(r_key,
+                                                                    r_acc,
+                                                                    r_acc_pub_key,
+                                                                    &bignum_to_group_element(&proof.aggregated_proof.c_hash)?,
+                                                                    &non_revoc_proofs,
+                                                                    &weights)?;
+
+        trace!("ProofVerifier::_verify_aggregated_non_revocation_proof: <<< aggregated_tau_list: {:?}", aggregated_tau_list);
+
+        Ok(aggregated_tau_list)
+    }
+
     pub fn _verify_non_revocation_proof(issuer_r_pub_key: &IssuerRevocationPublicKey,
                                         accum: &RevocationAccumulator,
                                         accum_pk: &RevocationAccumulatorPublicKey,
@@ -344,6 +726,9 @@ mod tests {
     use cl::issuer;
     use cl::helpers::MockHelper;
     use cl::prover::mocks::*;
+    use bls::SignKey;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
 
     #[test]
     fn sub_proof_request_builder_works() {
@@ -362,7 +747,7 @@ mod tests {
 
         let proof = prover::mocks::eq_proof();
         let pk = issuer::mocks::issuer_primary_public_key();
-        let c_h = prover::mocks::aggregated_proof().c_hash;
+        let c_h = prover::mocks::c_hash();
         let claim_schema = issuer::mocks::claim_schema();
 
         let mut sub_proof_request_builder = SubProofRequestBuilder::new().unwrap();
@@ -373,7 +758,8 @@ mod tests {
                                                                   &proof,
                                                                   &c_h,
                                                                   &claim_schema,
-                                                                  &sub_proof_request).unwrap();
+                                                                  &sub_proof_request,
+                                                                  None).unwrap();
 
         assert_eq!("5726715933634154184237442341903521921929637766411345954184987907936686738874379427010785278205728337008490886334546986256977911094895352684252668401039\
         0734822547748045548230392972760963518121157019709701625242723461454152432542778593173172718989724831707206802011046039600739512848642063504936248491468813901985203\
@@ -386,7 +772,7 @@ mod tests {
         MockHelper::inject();
 
         let proof = prover::mocks::ge_proof();
-        let c_h = prover::mocks::aggregated_proof().c_hash;
+        let c_h = prover::mocks::c_hash();
         let pk = issuer::mocks::issuer_primary_public_key();
 
         let res = ProofVerifier::_verify_ge_predicate(&pk, &proof, &c_h);
@@ -409,4 +795,88 @@ mod tests {
         1864273991033137371106324132550175224820164581900030456410773386740196083471393997554706544523739752281900419801521207994038554809091738654313973079882387597672518908535\
         80982844825639097363091181044515877489450972963624109587697097258041963985607958610791800500711857115582406526050626576194", res_data[5].to_dec().unwrap());
     }
+
+    fn _accumulator() -> RevocationAccumulator {
+        RevocationAccumulator {
+            acc: PointG2::new().unwrap(),
+            v: IndexSet::new(),
+            max_claim_num: 5
+        }
+    }
+
+    fn _accumulator_statement(rev_acc: &RevocationAccumulator, timestamp: u64) -> (RevocationAccumulatorStatement, VerKey, Generator) {
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        let statement = issuer::Issuer::sign_accumulator_statement("rev_reg_id", 1, timestamp, rev_acc, &sign_key).unwrap();
+
+        (statement, ver_key, gen)
+    }
+
+    #[test]
+    fn check_accumulator_statement_works() {
+        let now = time::get_time().sec as u64;
+        let expected = _accumulator();
+        let (statement, ver_key, gen) = _accumulator_statement(&expected, now);
+
+        let rev_acc = Verifier::check_accumulator_statement(&statement, "rev_reg_id", &ver_key, &gen, 1, 60).unwrap();
+        assert_eq!(expected.acc, rev_acc.acc);
+    }
+
+    #[test]
+    fn check_accumulator_statement_works_for_other_registry() {
+        let now = time::get_time().sec as u64;
+        let (statement, ver_key, gen) = _accumulator_statement(&_accumulator(), now);
+
+        let res = Verifier::check_accumulator_statement(&statement, "other_rev_reg_id", &ver_key, &gen, 1, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn check_accumulator_statement_works_for_modified_statement() {
+        let now = time::get_time().sec as u64;
+        let (mut statement, ver_key, gen) = _accumulator_statement(&_accumulator(), now);
+        statement.seq_no = 2;
+
+        let res = Verifier::check_accumulator_statement(&statement, "rev_reg_id", &ver_key, &gen, 1, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn check_accumulator_statement_works_for_other_ver_key() {
+        let now = time::get_time().sec as u64;
+        let (statement, _, gen) = _accumulator_statement(&_accumulator(), now);
+        let ver_key = VerKey::new(&gen, &SignKey::new(None).unwrap()).unwrap();
+
+        let res = Verifier::check_accumulator_statement(&statement, "rev_reg_id", &ver_key, &gen, 1, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn check_accumulator_statement_works_for_stale_statement() {
+        let now = time::get_time().sec as u64;
+        let (statement, ver_key, gen) = _accumulator_statement(&_accumulator(), now - 3600);
+
+        let res = Verifier::check_accumulator_statement(&statement, "rev_reg_id", &ver_key, &gen, 1, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn check_accumulator_statement_works_for_future_statement() {
+        let now = time::get_time().sec as u64;
+        let (statement, ver_key, gen) = _accumulator_statement(&_accumulator(), now + 3600);
+
+        let res = Verifier::check_accumulator_statement(&statement, "rev_reg_id", &ver_key, &gen, 1, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn check_accumulator_statement_works_for_old_seq_no() {
+        let now = time::get_time().sec as u64;
+        let (statement, ver_key, gen) = _accumulator_statement(&_accumulator(), now);
+
+        let res = Verifier::check_accumulator_statement(&statement, "rev_reg_id", &ver_key, &gen, 2, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
 }
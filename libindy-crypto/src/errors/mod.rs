@@ -27,6 +27,8 @@ pub enum IndyCryptoError {
     AnoncredsInvalidRevocationAccumulatorIndex(String),
     AnoncredsClaimRevoked(String),
     AnoncredsProofRejected(String),
+    AnoncredsRevocationAccumulatorIndexAlreadyUsed(String),
+    DecryptionFailed(String),
 }
 
 impl fmt::Display for IndyCryptoError {
@@ -48,6 +50,8 @@ impl fmt::Display for IndyCryptoError {
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(ref description) => write!(f, "Invalid revocation accumulator index: {}", description),
             IndyCryptoError::AnoncredsClaimRevoked(ref description) => write!(f, "Claim revoked: {}", description),
             IndyCryptoError::AnoncredsProofRejected(ref description) => write!(f, "Proof rejected: {}", description),
+            IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(ref description) => write!(f, "Revocation accumulator index already used: {}", description),
+            IndyCryptoError::DecryptionFailed(ref description) => write!(f, "Decryption failed: {}", description),
         }
     }
 }
@@ -71,6 +75,8 @@ impl Error for IndyCryptoError {
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(ref description) => description,
             IndyCryptoError::AnoncredsClaimRevoked(ref description) => description,
             IndyCryptoError::AnoncredsProofRejected(ref description) => description,
+            IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(ref description) => description,
+            IndyCryptoError::DecryptionFailed(ref description) => description,
         }
     }
 
@@ -92,6 +98,8 @@ impl Error for IndyCryptoError {
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(_) => None,
             IndyCryptoError::AnoncredsClaimRevoked(_) => None,
             IndyCryptoError::AnoncredsProofRejected(_) => None,
+            IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(_) => None,
+            IndyCryptoError::DecryptionFailed(_) => None,
         }
     }
 }
@@ -115,6 +123,8 @@ impl ToErrorCode for IndyCryptoError {
             IndyCryptoError::AnoncredsInvalidRevocationAccumulatorIndex(_) => ErrorCode::AnoncredsInvalidRevocationAccumulatorIndex,
             IndyCryptoError::AnoncredsClaimRevoked(_) => ErrorCode::AnoncredsClaimRevoked,
             IndyCryptoError::AnoncredsProofRejected(_) => ErrorCode::AnoncredsProofRejected,
+            IndyCryptoError::AnoncredsRevocationAccumulatorIndexAlreadyUsed(_) => ErrorCode::AnoncredsRevocationAccumulatorIndexAlreadyUsed,
+            IndyCryptoError::DecryptionFailed(_) => ErrorCode::CommonDecryptionFailed,
         }
     }
 }
@@ -123,4 +133,10 @@ impl From<serde_json::Error> for IndyCryptoError {
     fn from(err: serde_json::Error) -> IndyCryptoError {
         IndyCryptoError::InvalidStructure(err.description().to_string())
     }
-}
\ No newline at end of file
+}
+
+impl From<io::Error> for IndyCryptoError {
+    fn from(err: io::Error) -> IndyCryptoError {
+        IndyCryptoError::IOError(err)
+    }
+}
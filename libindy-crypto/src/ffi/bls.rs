@@ -352,6 +352,123 @@ pub extern fn indy_crypto_bls_ver_key_free(ver_key: *const c_void) -> ErrorCode
     res
 }
 
+/// Creates and returns aggregated verification key for provided list of verification keys.
+///
+/// Note: Aggregated verification key instance deallocation must be performed by calling indy_crypto_bls_aggregated_ver_key_free.
+///
+/// # Arguments
+/// * `ver_keys` - Verification key instance pointers array
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `agg_ver_key_p` - Reference that will contain aggregated verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_ver_key_new(ver_keys: *const *const c_void,
+                                                     ver_keys_len: usize,
+                                                     agg_ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregated_ver_key_new: >>> ver_keys: {:?}, ver_keys_len: {:?}, agg_ver_key_p: {:?}", ver_keys, ver_keys_len, agg_ver_key_p);
+
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(agg_ver_key_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregated_ver_key_new: ver_keys: {:?}", ver_keys);
+
+    let res = match AggregatedVerKey::new(&ver_keys) {
+        Ok(agg_ver_key) => {
+            trace!("indy_crypto_bls_aggregated_ver_key_new: agg_ver_key: {:?}", agg_ver_key);
+            unsafe {
+                *agg_ver_key_p = Box::into_raw(Box::new(agg_ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_aggregated_ver_key_new: *agg_ver_key_p: {:?}", *agg_ver_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregated_ver_key_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns aggregated verification key from bytes representation.
+///
+/// Note: Aggregated verification key instance deallocation must be performed by calling indy_crypto_bls_aggregated_ver_key_free.
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `agg_ver_key_p` - Reference that will contain aggregated verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_ver_key_from_bytes(bytes: *const u8, bytes_len: usize,
+                                                            agg_ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregated_ver_key_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, agg_ver_key_p: {:?}", bytes, bytes_len, agg_ver_key_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(agg_ver_key_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregated_ver_key_from_bytes: bytes: {:?}", bytes);
+
+    let res = match AggregatedVerKey::from_bytes(bytes) {
+        Ok(agg_ver_key) => {
+            trace!("indy_crypto_bls_aggregated_ver_key_from_bytes: agg_ver_key: {:?}", agg_ver_key);
+            unsafe {
+                *agg_ver_key_p = Box::into_raw(Box::new(agg_ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_aggregated_ver_key_from_bytes: *agg_ver_key_p: {:?}", *agg_ver_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregated_ver_key_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of aggregated verification key.
+///
+/// Note: Returned buffer lifetime is the same as aggregated verification key instance.
+///
+/// # Arguments
+/// * `agg_ver_key` - Aggregated verification key instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_ver_key_as_bytes(agg_ver_key: *const c_void,
+                                                          bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregated_ver_key_as_bytes: >>> agg_ver_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", agg_ver_key, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(agg_ver_key, AggregatedVerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregated_ver_key_as_bytes: agg_ver_key: {:?}", agg_ver_key);
+
+    unsafe {
+        *bytes_p = agg_ver_key.as_bytes().as_ptr();
+        *bytes_len_p = agg_ver_key.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregated_ver_key_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates aggregated verification key instance.
+///
+/// # Arguments
+/// * `agg_ver_key` - Aggregated verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_ver_key_free(agg_ver_key: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(agg_ver_key, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_aggregated_ver_key_free: >>> agg_ver_key: {:?}", agg_ver_key);
+
+    unsafe { Box::from_raw(agg_ver_key as *mut AggregatedVerKey); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregated_ver_key_free: <<< res: {:?}", res);
+    res
+}
+
 /// Creates and returns signature from bytes representation.
 ///
 /// Note: Signature instance deallocation must be performed by calling indy_crypto_bls_signature_free
@@ -552,205 +669,958 @@ pub extern fn indy_crypto_bls_multi_signature_free(multi_sig: *const c_void) ->
     res
 }
 
-/// Signs the message and returns signature.
+/// Creates and returns multi signature with participants bitmap for provided list of signatures of validators.
 ///
-/// Note: allocated buffer referenced by (signature_p, signature_len_p) must be
-/// deallocated by calling indy_crypto_bls_free_array.
+/// Note: Bitmap multi signature instance deallocation must be performed by calling indy_crypto_bls_bitmap_multi_signature_free.
 ///
 /// # Arguments
-///
-/// * `message` - Message to sign buffer pointer
-/// * `message_len` - Message to sign buffer len
-/// * `sign_key` - Pointer to Sign Key instance
-/// * `signature_p` - Reference that will contain Signture Instance pointer
+/// * `indexes` - Array of indexes of signers in validators list
+/// * `signatures` - Signature instance pointers array in the same order as indexes
+/// * `signatures_len` - Number of signatures
+/// * `validators_count` - Number of validators
+/// * `multi_sig_p` - Reference that will contain bitmap multi signature instance pointer
 #[no_mangle]
-pub extern fn indy_crypto_bls_sign(message: *const u8,
-                                   message_len: usize,
-                                   sign_key: *const c_void,
-                                   signature_p: *mut *const c_void) -> ErrorCode {
-    trace!("indy_crypto_bls_sign: >>> message: {:?}, message_len: {:?}, sign_key: {:?}, signature_p: {:?}", message, message_len, sign_key, signature_p);
+pub extern fn indy_crypto_bls_bitmap_multi_signature_new(indexes: *const usize,
+                                                         signatures: *const *const c_void,
+                                                         signatures_len: usize,
+                                                         validators_count: usize,
+                                                         multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_new: >>> indexes: {:?}, signatures: {:?}, signatures_len: {:?}, validators_count: {:?}, multi_sig_p: {:?}",
+           indexes, signatures, signatures_len, validators_count, multi_sig_p);
 
-    check_useful_c_byte_array!(message, message_len,
-                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
-    check_useful_c_reference!(sign_key, SignKey, ErrorCode::CommonInvalidParam3);
-    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam5);
+    check_useful_c_byte_array!(indexes, signatures_len, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference_array!(signatures, signatures_len, Signature, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam5);
 
-    trace!("indy_crypto_bls_sign: message: {:?}, sign_key: {:?}", message, sign_key);
+    let signatures: Vec<(usize, &Signature)> = indexes.iter().cloned().zip(signatures.into_iter()).collect();
 
-    let res = match Bls::sign(message, sign_key) {
-        Ok(signature) => {
+    trace!("indy_crypto_bls_bitmap_multi_signature_new: signatures: {:?}, validators_count: {:?}", signatures, validators_count);
+
+    let res = match BitmapMultiSignature::new(&signatures, validators_count) {
+        Ok(multi_sig) => {
+            trace!("indy_crypto_bls_bitmap_multi_signature_new: multi_sig: {:?}", multi_sig);
             unsafe {
-                trace!("indy_crypto_bls_sign: signature: {:?}", signature);
-                *signature_p = Box::into_raw(Box::new(signature)) as *const c_void;
-                trace!("indy_crypto_bls_sign: *signature_p: {:?}", *signature_p);
+                *multi_sig_p = Box::into_raw(Box::new(multi_sig)) as *const c_void;
+                trace!("indy_crypto_bls_bitmap_multi_signature_new: *multi_sig_p: {:?}", *multi_sig_p);
             }
             ErrorCode::Success
         }
         Err(err) => err.to_error_code()
     };
 
-    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    trace!("indy_crypto_bls_bitmap_multi_signature_new: <<< res: {:?}", res);
     res
 }
 
-/// Verifies the message signature and returns true - if signature valid or false otherwise.
+/// Adds signature of validator to bitmap multi signature.
+/// Fails if validator already signed.
 ///
 /// # Arguments
-///
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `index` - Index of signer in validators list
 /// * `signature` - Signature instance pointer
-/// * `message` - Message to verify buffer pointer
-/// * `message_len` - Message to verify buffer len
-/// * `ver_key` - Verification key instance pinter
-/// * `gen` - Generator instance pointer
-/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
 #[no_mangle]
-pub extern fn indy_crypto_bsl_verify(signature: *const c_void,
-                                     message: *const u8,
-                                     message_len: usize,
-                                     ver_key: *const c_void,
-                                     gen: *const c_void,
-                                     valid_p: *mut bool) -> ErrorCode {
-    trace!("indy_crypto_bsl_verify: >>> signature: {:?}, message: {:?}, message_len: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}", signature, message, message_len, ver_key, gen, valid_p);
+pub extern fn indy_crypto_bls_bitmap_multi_signature_add_signature(multi_sig: *const c_void,
+                                                                   index: usize,
+                                                                   signature: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_add_signature: >>> multi_sig: {:?}, index: {:?}, signature: {:?}", multi_sig, index, signature);
 
-    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam1);
-    check_useful_c_byte_array!(message, message_len,
-                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
-    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam4);
-    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam5);
-    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam6);
+    check_useful_mut_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam3);
 
-    trace!("indy_crypto_bsl_verify: signature: {:?}, message: {:?}, ver_key: {:?}, gen: {:?}", signature, message, ver_key, gen);
+    trace!("indy_crypto_bls_bitmap_multi_signature_add_signature: multi_sig: {:?}, index: {:?}, signature: {:?}", multi_sig, index, signature);
 
-    let res = match Bls::verify(signature, message, ver_key, gen) {
-        Ok(valid) => {
-            trace!("indy_crypto_bsl_verify: valid: {:?}", valid);
-            unsafe { *valid_p = valid; }
-            ErrorCode::Success
-        }
+    let res = match multi_sig.add_signature(index, signature) {
+        Ok(()) => ErrorCode::Success,
         Err(err) => err.to_error_code()
     };
 
-    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    trace!("indy_crypto_bls_bitmap_multi_signature_add_signature: <<< res: {:?}", res);
     res
 }
 
-/// Verifies the message multi signature and returns true - if signature valid or false otherwise.
+/// Merges bitmap multi signature of other validators into bitmap multi signature.
+/// Fails if multi signatures have common participants.
 ///
 /// # Arguments
-///
-/// * `multi_sig` - Multi signature instance pointer
-/// * `message` - Message to verify buffer pointer
-/// * `message_len` - Message to verify buffer len
-/// * `ver_keys` - Verification key instance pointers array
-/// * `ver_keys_len` - Verification keys instance pointers array len
-/// * `gen` - Generator point instance
-/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `other` - Other bitmap multi signature instance pointer
 #[no_mangle]
-pub extern fn indy_crypto_bls_verify_multi_sig(multi_sig: *const c_void,
-                                               message: *const u8,
-                                               message_len: usize,
-                                               ver_keys: *const *const c_void,
-                                               ver_keys_len: usize,
-                                               gen: *const c_void,
-                                               valid_p: *mut bool) -> ErrorCode {
-    trace!("indy_crypto_bls_verify_multi_sig: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}", multi_sig, message, message_len, ver_keys, ver_keys_len, gen, valid_p);
+pub extern fn indy_crypto_bls_bitmap_multi_signature_merge(multi_sig: *const c_void,
+                                                           other: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_merge: >>> multi_sig: {:?}, other: {:?}", multi_sig, other);
 
-    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
-    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
-    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
-    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam6);
-    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+    if multi_sig == other {
+        return ErrorCode::CommonInvalidParam2;
+    }
 
-    trace!("indy_crypto_bls_verify_multi_sig: multi_sig: {:?}, message: {:?}, ver_keys: {:?}, gen: {:?}", multi_sig, message, ver_keys, gen);
+    check_useful_mut_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(other, BitmapMultiSignature, ErrorCode::CommonInvalidParam2);
 
-    let res = match Bls::verify_multi_sig(multi_sig, message, &ver_keys, gen) {
-        Ok(valid) => {
-            trace!("indy_crypto_bls_verify_multi_sig: valid: {:?}", valid);
-            unsafe { *valid_p = valid; }
-            ErrorCode::Success
-        }
+    trace!("indy_crypto_bls_bitmap_multi_signature_merge: multi_sig: {:?}, other: {:?}", multi_sig, other);
+
+    let res = match multi_sig.merge(other) {
+        Ok(()) => ErrorCode::Success,
         Err(err) => err.to_error_code()
     };
 
-    trace!("indy_crypto_bls_verify_multi_sig: <<< res: {:?}", res);
+    trace!("indy_crypto_bls_bitmap_multi_signature_merge: <<< res: {:?}", res);
     res
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::ptr;
+/// Checks whether validator signed bitmap multi signature.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `index` - Index of validator in validators list
+/// * `is_participant_p` - Reference that will be filled with true - if validator signed or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_is_participant(multi_sig: *const c_void,
+                                                                    index: usize,
+                                                                    is_participant_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_is_participant: >>> multi_sig: {:?}, index: {:?}, is_participant_p: {:?}", multi_sig, index, is_participant_p);
 
-    #[test]
-    fn indy_crypto_bls_generator_new_works() {
-        let mut gen: *const c_void = ptr::null();
+    check_useful_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(is_participant_p, ErrorCode::CommonInvalidParam3);
 
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
-        assert_eq!(err_code, ErrorCode::Success);
-        assert!(!gen.is_null());
+    let is_participant = multi_sig.is_participant(index);
+    trace!("indy_crypto_bls_bitmap_multi_signature_is_participant: is_participant: {:?}", is_participant);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
-        assert_eq!(err_code, ErrorCode::Success);
-    }
+    unsafe { *is_participant_p = is_participant; }
+    let res = ErrorCode::Success;
 
-    #[test]
-    fn indy_crypto_bls_generator_as_bytes_works() {
-        let mut gen: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
-        assert_eq!(err_code, ErrorCode::Success);
+    trace!("indy_crypto_bls_bitmap_multi_signature_is_participant: <<< res: {:?}", res);
+    res
+}
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
-        assert_eq!(err_code, ErrorCode::Success);
-        assert!(!gen.is_null());
-        assert!(bytes_len > 0);
+/// Creates and returns bitmap multi signature from bytes representation.
+///
+/// Note: Bitmap multi signature instance deallocation must be performed by calling indy_crypto_bls_bitmap_multi_signature_free.
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `multi_sig_p` - Reference that will contain bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_from_bytes(bytes: *const u8, bytes_len: usize,
+                                                                multi_sig_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, multi_sig_p: {:?}", bytes, bytes_len, multi_sig_p);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
-        assert_eq!(err_code, ErrorCode::Success);
-    }
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(multi_sig_p, ErrorCode::CommonInvalidParam3);
 
-    #[test]
-    fn indy_crypto_bls_generator_from_bytes_works() {
-        let mut gen: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
-        assert_eq!(err_code, ErrorCode::Success);
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: bytes: {:?}", bytes);
 
-        let mut bytes: *const u8 = ptr::null();
-        let mut bytes_len: usize = 0;
-        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
-        assert_eq!(err_code, ErrorCode::Success);
+    let res = match BitmapMultiSignature::from_bytes(bytes) {
+        Ok(multi_sig) => {
+            trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: multi_sig: {:?}", multi_sig);
+            unsafe {
+                *multi_sig_p = Box::into_raw(Box::new(multi_sig)) as *const c_void;
+                trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: *multi_sig_p: {:?}", *multi_sig_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
 
-        let mut gen2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_generator_from_bytes(bytes, bytes_len, &mut gen2);
-        assert_eq!(err_code, ErrorCode::Success);
+    trace!("indy_crypto_bls_bitmap_multi_signature_from_bytes: <<< res: {:?}", res);
+    res
+}
 
-        let err_code = indy_crypto_bls_generator_free(gen);
-        assert_eq!(err_code, ErrorCode::Success);
+/// Returns bytes representation of bitmap multi signature.
+///
+/// Note: Returned buffer lifetime is the same as bitmap multi signature instance
+/// and buffer is invalidated by adding signature or merging.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_as_bytes(multi_sig: *const c_void,
+                                                              bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bytes: >>> multi_sig: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", multi_sig, bytes_p, bytes_len_p);
 
-        let err_code = indy_crypto_bls_generator_free(gen2);
-        assert_eq!(err_code, ErrorCode::Success);
-    }
+    check_useful_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
 
-    #[test]
-    fn indy_crypto_bls_generator_free_works() {
-        let mut gen: *const c_void = ptr::null();
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bytes: multi_sig: {:?}", multi_sig);
 
-        let err_code = indy_crypto_bls_generator_new(&mut gen);
-        assert_eq!(err_code, ErrorCode::Success);
-        assert!(!gen.is_null());
+    unsafe {
+        *bytes_p = multi_sig.as_bytes().as_ptr();
+        *bytes_len_p = multi_sig.as_bytes().len();
+    };
 
-        let err_code = indy_crypto_bls_generator_free(gen);
-        assert_eq!(err_code, ErrorCode::Success);
-    }
+    let res = ErrorCode::Success;
 
-    #[test]
-    fn indy_crypto_bls_sign_key_new_works() {
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
+    trace!("indy_crypto_bls_bitmap_multi_signature_as_bytes: <<< res: {:?}", res);
+    res
+}
 
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+/// Deallocates bitmap multi signature instance.
+///
+/// # Arguments
+/// * `multi_sig` - Bitmap multi signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_bitmap_multi_signature_free(multi_sig: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(multi_sig, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_free: >>> multi_sig: {:?}", multi_sig);
+
+    unsafe { Box::from_raw(multi_sig as *mut BitmapMultiSignature); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_bitmap_multi_signature_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns aggregated signature for provided list of signatures over distinct messages.
+///
+/// Note: Aggregated signature instance deallocation must be performed by calling indy_crypto_bls_aggregated_signature_free.
+///
+/// # Arguments
+/// * `signatures` - Signature instance pointers array
+/// * `signatures_len` - Signature instance pointers array len
+/// * `agg_p` - Reference that will contain aggregated signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_signature_new(signatures: *const *const c_void,
+                                                       signatures_len: usize,
+                                                       agg_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregated_signature_new: >>> signatures: {:?}, signatures_len: {:?}, agg_p: {:?}", signatures, signatures_len, agg_p);
+
+    check_useful_c_reference_array!(signatures, signatures_len, Signature, ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(agg_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregated_signature_new: signatures: {:?}", signatures);
+
+    let res = match AggregatedSignature::new(&signatures) {
+        Ok(agg) => {
+            trace!("indy_crypto_bls_aggregated_signature_new: agg: {:?}", agg);
+            unsafe {
+                *agg_p = Box::into_raw(Box::new(agg)) as *const c_void;
+                trace!("indy_crypto_bls_aggregated_signature_new: *agg_p: {:?}", *agg_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregated_signature_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns aggregated signature from bytes representation.
+///
+/// Note: Aggregated signature instance deallocation must be performed by calling indy_crypto_bls_aggregated_signature_free.
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `agg_p` - Reference that will contain aggregated signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_signature_from_bytes(bytes: *const u8, bytes_len: usize,
+                                                              agg_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregated_signature_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, agg_p: {:?}", bytes, bytes_len, agg_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(agg_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregated_signature_from_bytes: bytes: {:?}", bytes);
+
+    let res = match AggregatedSignature::from_bytes(bytes) {
+        Ok(agg) => {
+            trace!("indy_crypto_bls_aggregated_signature_from_bytes: agg: {:?}", agg);
+            unsafe {
+                *agg_p = Box::into_raw(Box::new(agg)) as *const c_void;
+                trace!("indy_crypto_bls_aggregated_signature_from_bytes: *agg_p: {:?}", *agg_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_aggregated_signature_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of aggregated signature.
+///
+/// Note: Returned buffer lifetime is the same as aggregated signature instance.
+///
+/// # Arguments
+/// * `agg` - Aggregated signature instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_signature_as_bytes(agg: *const c_void,
+                                                            bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_aggregated_signature_as_bytes: >>> agg: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", agg, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(agg, AggregatedSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_aggregated_signature_as_bytes: agg: {:?}", agg);
+
+    unsafe {
+        *bytes_p = agg.as_bytes().as_ptr();
+        *bytes_len_p = agg.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregated_signature_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates aggregated signature instance.
+///
+/// # Arguments
+/// * `agg` - Aggregated signature instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_aggregated_signature_free(agg: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(agg, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_aggregated_signature_free: >>> agg: {:?}", agg);
+
+    unsafe { Box::from_raw(agg as *mut AggregatedSignature); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_aggregated_signature_free: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns proof of possession of sign key that corresponds to verification key.
+///
+/// Note: Proof of possession instance deallocation must be performed by calling indy_crypto_bls_pop_free.
+///
+/// # Arguments
+/// * `ver_key` - Verification key instance pointer
+/// * `sign_key` - Sign key instance pointer
+/// * `pop_p` - Reference that will contain proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_new(ver_key: *const c_void,
+                                      sign_key: *const c_void,
+                                      pop_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_new: >>> ver_key: {:?}, sign_key: {:?}, pop_p: {:?}", ver_key, sign_key, pop_p);
+
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(sign_key, SignKey, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(pop_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_new: ver_key: {:?}, sign_key: {:?}", ver_key, sign_key);
+
+    let res = match ProofOfPossession::new(ver_key, sign_key) {
+        Ok(pop) => {
+            trace!("indy_crypto_bls_pop_new: pop: {:?}", pop);
+            unsafe {
+                *pop_p = Box::into_raw(Box::new(pop)) as *const c_void;
+                trace!("indy_crypto_bls_pop_new: *pop_p: {:?}", *pop_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_pop_new: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns proof of possession from bytes representation.
+///
+/// Note: Proof of possession instance deallocation must be performed by calling indy_crypto_bls_pop_free.
+///
+/// # Arguments
+/// * `bytes` - Bytes buffer pointer
+/// * `bytes_len` - Bytes buffer len
+/// * `pop_p` - Reference that will contain proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_from_bytes(bytes: *const u8, bytes_len: usize,
+                                             pop_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_from_bytes: >>> bytes: {:?}, bytes_len: {:?}, pop_p: {:?}", bytes, bytes_len, pop_p);
+
+    check_useful_c_byte_array!(bytes, bytes_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(pop_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_from_bytes: bytes: {:?}", bytes);
+
+    let res = match ProofOfPossession::from_bytes(bytes) {
+        Ok(pop) => {
+            trace!("indy_crypto_bls_pop_from_bytes: pop: {:?}", pop);
+            unsafe {
+                *pop_p = Box::into_raw(Box::new(pop)) as *const c_void;
+                trace!("indy_crypto_bls_pop_from_bytes: *pop_p: {:?}", *pop_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_pop_from_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of proof of possession.
+///
+/// Note: Returned buffer lifetime is the same as proof of possession instance.
+///
+/// # Arguments
+/// * `pop` - Proof of possession instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_as_bytes(pop: *const c_void,
+                                           bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_pop_as_bytes: >>> pop: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", pop, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(pop, ProofOfPossession, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_pop_as_bytes: pop: {:?}", pop);
+
+    unsafe {
+        *bytes_p = pop.as_bytes().as_ptr();
+        *bytes_len_p = pop.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_pop_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates proof of possession instance.
+///
+/// # Arguments
+/// * `pop` - Proof of possession instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_pop_free(pop: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(pop, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_pop_free: >>> pop: {:?}", pop);
+
+    unsafe { Box::from_raw(pop as *mut ProofOfPossession); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_pop_free: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies proof of possession of sign key and returns verification key marked as proven.
+/// Returns error if proof of possession is invalid or verification key is identity point.
+///
+/// Note: Proven verification key instance deallocation must be performed by calling indy_crypto_bls_proven_ver_key_free.
+///
+/// # Arguments
+/// * `ver_key` - Verification key instance pointer
+/// * `pop` - Proof of possession instance pointer
+/// * `gen` - Generator instance pointer
+/// * `proven_ver_key_p` - Reference that will contain proven verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_proven_ver_key_new(ver_key: *const c_void,
+                                                 pop: *const c_void,
+                                                 gen: *const c_void,
+                                                 proven_ver_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_proven_ver_key_new: >>> ver_key: {:?}, pop: {:?}, gen: {:?}, proven_ver_key_p: {:?}", ver_key, pop, gen, proven_ver_key_p);
+
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(pop, ProofOfPossession, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(proven_ver_key_p, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_bls_proven_ver_key_new: ver_key: {:?}, pop: {:?}, gen: {:?}", ver_key, pop, gen);
+
+    let res = match ProvenVerKey::new(ver_key, pop, gen) {
+        Ok(proven_ver_key) => {
+            trace!("indy_crypto_bls_proven_ver_key_new: proven_ver_key: {:?}", proven_ver_key);
+            unsafe {
+                *proven_ver_key_p = Box::into_raw(Box::new(proven_ver_key)) as *const c_void;
+                trace!("indy_crypto_bls_proven_ver_key_new: *proven_ver_key_p: {:?}", *proven_ver_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_proven_ver_key_new: <<< res: {:?}", res);
+    res
+}
+
+/// Returns bytes representation of proven verification key.
+///
+/// Note: Returned buffer lifetime is the same as proven verification key instance.
+///
+/// # Arguments
+/// * `proven_ver_key` - Proven verification key instance pointer
+/// * `bytes_p` - Pointer that will contains bytes buffer
+/// * `bytes_len_p` - Pointer that will contains bytes buffer len
+#[no_mangle]
+pub extern fn indy_crypto_bls_proven_ver_key_as_bytes(proven_ver_key: *const c_void,
+                                                      bytes_p: *mut *const u8, bytes_len_p: *mut usize) -> ErrorCode {
+    trace!("indy_crypto_bls_proven_ver_key_as_bytes: >>> proven_ver_key: {:?}, bytes_p: {:?}, bytes_len_p: {:?}", proven_ver_key, bytes_p, bytes_len_p);
+
+    check_useful_c_reference!(proven_ver_key, ProvenVerKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(bytes_p, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(bytes_len_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_bls_proven_ver_key_as_bytes: proven_ver_key: {:?}", proven_ver_key);
+
+    unsafe {
+        *bytes_p = proven_ver_key.as_bytes().as_ptr();
+        *bytes_len_p = proven_ver_key.as_bytes().len();
+    };
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_proven_ver_key_as_bytes: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates proven verification key instance.
+///
+/// # Arguments
+/// * `proven_ver_key` - Proven verification key instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_proven_ver_key_free(proven_ver_key: *const c_void) -> ErrorCode {
+    check_useful_c_ptr!(proven_ver_key, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_bls_proven_ver_key_free: >>> proven_ver_key: {:?}", proven_ver_key);
+
+    unsafe { Box::from_raw(proven_ver_key as *mut ProvenVerKey); }
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_bls_proven_ver_key_free: <<< res: {:?}", res);
+    res
+}
+
+/// Signs the message and returns signature.
+///
+/// Note: allocated buffer referenced by (signature_p, signature_len_p) must be
+/// deallocated by calling indy_crypto_bls_free_array.
+///
+/// # Arguments
+///
+/// * `message` - Message to sign buffer pointer
+/// * `message_len` - Message to sign buffer len
+/// * `sign_key` - Pointer to Sign Key instance
+/// * `signature_p` - Reference that will contain Signture Instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_bls_sign(message: *const u8,
+                                   message_len: usize,
+                                   sign_key: *const c_void,
+                                   signature_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_bls_sign: >>> message: {:?}, message_len: {:?}, sign_key: {:?}, signature_p: {:?}", message, message_len, sign_key, signature_p);
+
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(sign_key, SignKey, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(signature_p, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_crypto_bls_sign: message: {:?}, sign_key: {:?}", message, sign_key);
+
+    let res = match Bls::sign(message, sign_key) {
+        Ok(signature) => {
+            unsafe {
+                trace!("indy_crypto_bls_sign: signature: {:?}", signature);
+                *signature_p = Box::into_raw(Box::new(signature)) as *const c_void;
+                trace!("indy_crypto_bls_sign: *signature_p: {:?}", *signature_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message signature and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `signature` - Signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_key` - Verification key instance pinter
+/// * `gen` - Generator instance pointer
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bsl_verify(signature: *const c_void,
+                                     message: *const u8,
+                                     message_len: usize,
+                                     ver_key: *const c_void,
+                                     gen: *const c_void,
+                                     valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bsl_verify: >>> signature: {:?}, message: {:?}, message_len: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}", signature, message, message_len, ver_key, gen, valid_p);
+
+    check_useful_c_reference!(signature, Signature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len,
+                               ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam5);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_bsl_verify: signature: {:?}, message: {:?}, ver_key: {:?}, gen: {:?}", signature, message, ver_key, gen);
+
+    let res = match Bls::verify(signature, message, ver_key, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bsl_verify: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_sign: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message multi signature and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_keys` - Verification key instance pointers array
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_multi_sig(multi_sig: *const c_void,
+                                               message: *const u8,
+                                               message_len: usize,
+                                               ver_keys: *const *const c_void,
+                                               ver_keys_len: usize,
+                                               gen: *const c_void,
+                                               valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_multi_sig: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}", multi_sig, message, message_len, ver_keys, ver_keys_len, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam6);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_crypto_bls_verify_multi_sig: multi_sig: {:?}, message: {:?}, ver_keys: {:?}, gen: {:?}", multi_sig, message, ver_keys, gen);
+
+    let res = match Bls::verify_multi_sig(multi_sig, message, &ver_keys, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_multi_sig: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_multi_sig: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message multi signature against aggregated verification key of signers
+/// and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `agg_ver_key` - Aggregated verification key instance pointer
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key(multi_sig: *const c_void,
+                                                                       message: *const u8,
+                                                                       message_len: usize,
+                                                                       agg_ver_key: *const c_void,
+                                                                       gen: *const c_void,
+                                                                       valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, agg_ver_key: {:?}, gen: {:?}, valid_p: {:?}",
+           multi_sig, message, message_len, agg_ver_key, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(agg_ver_key, AggregatedVerKey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam5);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key: multi_sig: {:?}, message: {:?}, agg_ver_key: {:?}, gen: {:?}", multi_sig, message, agg_ver_key, gen);
+
+    let res = match Bls::verify_multi_sig_with_aggregated_ver_key(multi_sig, message, agg_ver_key, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies bitmap multi signature against ordered list of validators
+/// and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Bitmap multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `validators` - Ordered proven verification key instance pointers array of all validators
+/// * `validators_len` - Proven verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_bitmap_multi_sig(multi_sig: *const c_void,
+                                                      message: *const u8,
+                                                      message_len: usize,
+                                                      validators: *const *const c_void,
+                                                      validators_len: usize,
+                                                      gen: *const c_void,
+                                                      valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_bitmap_multi_sig: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, validators: {:?}, validators_len: {:?}, gen: {:?}, valid_p: {:?}",
+           multi_sig, message, message_len, validators, validators_len, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, BitmapMultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference_array!(validators, validators_len, ProvenVerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam6);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_crypto_bls_verify_bitmap_multi_sig: multi_sig: {:?}, message: {:?}, validators: {:?}, gen: {:?}", multi_sig, message, validators, gen);
+
+    let res = match Bls::verify_bitmap_multi_sig(multi_sig, message, &validators, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_bitmap_multi_sig: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_bitmap_multi_sig: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies proof of possession and returns true - if proof valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `pop` - Proof of possession instance pointer
+/// * `ver_key` - Verification key instance pointer
+/// * `gen` - Generator instance pointer
+/// * `valid_p` - Reference that will be filled with true - if proof valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_pop(pop: *const c_void,
+                                         ver_key: *const c_void,
+                                         gen: *const c_void,
+                                         valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_pop: >>> pop: {:?}, ver_key: {:?}, gen: {:?}, valid_p: {:?}", pop, ver_key, gen, valid_p);
+
+    check_useful_c_reference!(pop, ProofOfPossession, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(ver_key, VerKey, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam3);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_crypto_bls_verify_pop: pop: {:?}, ver_key: {:?}, gen: {:?}", pop, ver_key, gen);
+
+    let res = match Bls::verify_proof_of_possession(pop, ver_key, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_pop: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_pop: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies the message multi signature against verification keys with verified proofs of possession
+/// and returns true - if signature valid or false otherwise.
+///
+/// # Arguments
+///
+/// * `multi_sig` - Multi signature instance pointer
+/// * `message` - Message to verify buffer pointer
+/// * `message_len` - Message to verify buffer len
+/// * `ver_keys` - Proven verification key instance pointers array
+/// * `ver_keys_len` - Proven verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_multi_sig_with_pop(multi_sig: *const c_void,
+                                                        message: *const u8,
+                                                        message_len: usize,
+                                                        ver_keys: *const *const c_void,
+                                                        ver_keys_len: usize,
+                                                        gen: *const c_void,
+                                                        valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_multi_sig_with_pop: >>> multi_sig: {:?}, message: {:?}, message_len: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}",
+           multi_sig, message, message_len, ver_keys, ver_keys_len, gen, valid_p);
+
+    check_useful_c_reference!(multi_sig, MultiSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, ProvenVerKey, ErrorCode::CommonInvalidParam4, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam6);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_crypto_bls_verify_multi_sig_with_pop: multi_sig: {:?}, message: {:?}, ver_keys: {:?}, gen: {:?}", multi_sig, message, ver_keys, gen);
+
+    let res = match Bls::verify_multi_sig_with_pop(multi_sig, message, &ver_keys, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_multi_sig_with_pop: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_multi_sig_with_pop: <<< res: {:?}", res);
+    res
+}
+
+/// Verifies aggregated signature of distinct messages and returns true - if signature valid or false otherwise.
+/// Messages must be distinct, otherwise aggregated signature is rejected.
+///
+/// # Arguments
+///
+/// * `agg` - Aggregated signature instance pointer
+/// * `messages` - Array of message buffer pointers
+/// * `messages_lens` - Array of message buffer lens
+/// * `messages_count` - Number of messages
+/// * `ver_keys` - Verification key instance pointers array in the same order as messages
+/// * `ver_keys_len` - Verification keys instance pointers array len
+/// * `gen` - Generator point instance
+/// * `valid_p` - Reference that will be filled with true - if signature valid or false otherwise.
+#[no_mangle]
+pub extern fn indy_crypto_bls_verify_aggregate(agg: *const c_void,
+                                               messages: *const *const u8,
+                                               messages_lens: *const usize,
+                                               messages_count: usize,
+                                               ver_keys: *const *const c_void,
+                                               ver_keys_len: usize,
+                                               gen: *const c_void,
+                                               valid_p: *mut bool) -> ErrorCode {
+    trace!("indy_crypto_bls_verify_aggregate: >>> agg: {:?}, messages: {:?}, messages_lens: {:?}, messages_count: {:?}, ver_keys: {:?}, ver_keys_len: {:?}, gen: {:?}, valid_p: {:?}",
+           agg, messages, messages_lens, messages_count, ver_keys, ver_keys_len, gen, valid_p);
+
+    check_useful_c_reference!(agg, AggregatedSignature, ErrorCode::CommonInvalidParam1);
+    check_useful_c_byte_array!(messages, messages_count, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam4);
+    check_useful_c_byte_array!(messages_lens, messages_count, ErrorCode::CommonInvalidParam3, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference_array!(ver_keys, ver_keys_len, VerKey, ErrorCode::CommonInvalidParam5, ErrorCode::CommonInvalidParam6);
+    check_useful_c_reference!(gen, Generator, ErrorCode::CommonInvalidParam7);
+    check_useful_c_ptr!(valid_p, ErrorCode::CommonInvalidParam8);
+
+    if ver_keys.len() != messages_count {
+        return ErrorCode::CommonInvalidParam6;
+    }
+
+    let mut messages_and_keys: Vec<(&[u8], &VerKey)> = Vec::new();
+
+    for ((message, message_len), ver_key) in messages.iter().zip(messages_lens.iter()).zip(ver_keys.iter()) {
+        let (message, message_len) = (*message, *message_len);
+        check_useful_c_byte_array!(message, message_len, ErrorCode::CommonInvalidParam2, ErrorCode::CommonInvalidParam3);
+        messages_and_keys.push((message, *ver_key));
+    }
+
+    trace!("indy_crypto_bls_verify_aggregate: agg: {:?}, messages_and_keys: {:?}, gen: {:?}", agg, messages_and_keys, gen);
+
+    let res = match Bls::verify_aggregate(agg, &messages_and_keys, gen) {
+        Ok(valid) => {
+            trace!("indy_crypto_bls_verify_aggregate: valid: {:?}", valid);
+            unsafe { *valid_p = valid; }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_bls_verify_aggregate: <<< res: {:?}", res);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn indy_crypto_bls_generator_new_works() {
+        let mut gen: *const c_void = ptr::null();
+
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!gen.is_null());
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_generator_as_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!gen.is_null());
+        assert!(bytes_len > 0);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_generator_from_bytes_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_generator_as_bytes(gen, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut gen2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_from_bytes(bytes, bytes_len, &mut gen2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_generator_free(gen2);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_generator_free_works() {
+        let mut gen: *const c_void = ptr::null();
+
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!gen.is_null());
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_sign_key_new_works() {
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(!sign_key.is_null());
 
@@ -1223,10 +2093,250 @@ mod tests {
         let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key2: *const c_void = ptr::null();
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+
+    #[test]
+    fn indy_crypto_bsl_verify_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut valid = false;
+
+        let err_code = indy_crypto_bsl_verify(signature,
+                                              message, message_len,
+                                              ver_key,
+                                              gen, &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bsl_verify_works_for_invalid() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut valid = false;
+
+        let err_code = indy_crypto_bsl_verify(signature,
+                                              message, message_len,
+                                              ver_key,
+                                              gen, &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_verify_multi_sig_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key1: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let signatures = [signature1, signature2];
+
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let ver_keys = [ver_key1, ver_key2];
+        let mut valid = false;
+
+        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
+                                                        message, message_len,
+                                                        ver_keys.as_ptr(), ver_keys.len(),
+                                                        gen,
+                                                        &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_signature_free(signature2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    #[test]
+    fn indy_crypto_bls_verify_multi_sig_works_for_invalid() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key1: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key2: *const c_void = ptr::null();
+        let seed: *const u8 = ptr::null();
+        let seed_len: usize = 0;
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut sign_key3: *const c_void = ptr::null();
         let seed: *const u8 = ptr::null();
         let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key3);
         assert_eq!(err_code, ErrorCode::Success);
 
         let message_v = vec![1, 2, 3, 4, 5];
@@ -1247,12 +2357,43 @@ mod tests {
         let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key3, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let ver_keys = [ver_key1, ver_key2];
+        let mut valid = false;
+
+        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
+                                                        message, message_len,
+                                                        ver_keys.as_ptr(), ver_keys.len(),
+                                                        gen,
+                                                        &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let err_code = indy_crypto_bls_sign_key_free(sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let err_code = indy_crypto_bls_sign_key_free(sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let err_code = indy_crypto_bls_sign_key_free(sign_key3);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
         let err_code = indy_crypto_bls_signature_free(signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
@@ -1263,37 +2404,37 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
     }
 
-
     #[test]
-    fn indy_crypto_bsl_verify_works() {
+    fn indy_crypto_bls_pop_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut ver_key: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let mut pop: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_new(ver_key, sign_key, &mut pop);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!pop.is_null());
 
-        let mut signature: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_pop_as_bytes(pop, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(bytes_len > 0);
 
-        let mut valid = false;
+        let mut pop2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_from_bytes(bytes, bytes_len, &mut pop2);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bsl_verify(signature,
-                                              message, message_len,
-                                              ver_key,
-                                              gen, &mut valid);
+        let mut valid = false;
+        let err_code = indy_crypto_bls_verify_pop(pop2, ver_key, gen, &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(valid);
 
@@ -1306,114 +2447,171 @@ mod tests {
         let err_code = indy_crypto_bls_ver_key_free(ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature);
+        let err_code = indy_crypto_bls_pop_free(pop);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_bls_pop_free(pop2);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bsl_verify_works_for_invalid() {
+    fn indy_crypto_bls_verify_multi_sig_with_pop_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key);
+        let mut sign_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key2: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut pop1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_new(ver_key1, sign_key1, &mut pop1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut pop2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_pop_new(ver_key2, sign_key2, &mut pop2);
         assert_eq!(err_code, ErrorCode::Success);
 
         let message_v = vec![1, 2, 3, 4, 5];
         let message = message_v.as_ptr();
         let message_len = message_v.len();
 
-        let mut signature: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+        let mut signature1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut valid = false;
+        let mut signature2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bsl_verify(signature,
-                                              message, message_len,
-                                              ver_key,
-                                              gen, &mut valid);
+        let signatures = [signature1, signature2];
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!valid);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
+        let mut proven_ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_proven_ver_key_new(ver_key1, pop1, gen, &mut proven_ver_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key);
+        let mut proven_ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_proven_ver_key_new(ver_key2, pop2, gen, &mut proven_ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+        let mut proven_ver_key_invalid: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_proven_ver_key_new(ver_key1, pop2, gen, &mut proven_ver_key_invalid);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+
+        let ver_keys = [ver_key1, ver_key2];
+        let pops = [pop1, pop2];
+        let proven_ver_keys = [proven_ver_key1, proven_ver_key2];
+        let mut valid = false;
+
+        let err_code = indy_crypto_bls_verify_multi_sig_with_pop(multi_sig,
+                                                                 message, message_len,
+                                                                 proven_ver_keys.as_ptr(), proven_ver_keys.len(),
+                                                                 gen,
+                                                                 &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key);
+        for proven_ver_key in proven_ver_keys.iter() {
+            let err_code = indy_crypto_bls_proven_ver_key_free(*proven_ver_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature);
+        for sign_key in [sign_key1, sign_key2].iter() {
+            let err_code = indy_crypto_bls_sign_key_free(*sign_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        for ver_key in ver_keys.iter() {
+            let err_code = indy_crypto_bls_ver_key_free(*ver_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        for pop in pops.iter() {
+            let err_code = indy_crypto_bls_pop_free(*pop);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        for signature in signatures.iter() {
+            let err_code = indy_crypto_bls_signature_free(*signature);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_verify_multi_sig_works() {
+    fn indy_crypto_bls_verify_aggregate_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key1: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key2: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let message_v = vec![1, 2, 3, 4, 5];
-        let message = message_v.as_ptr();
-        let message_len = message_v.len();
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let message1 = vec![1, 2, 3, 4, 5];
+        let message2 = vec![6, 7, 8];
 
         let mut signature1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key1, &mut signature1);
+        let err_code = indy_crypto_bls_sign(message1.as_ptr(), message1.len(), sign_key1, &mut signature1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut signature2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_sign(message, message_len, sign_key2, &mut signature2);
+        let err_code = indy_crypto_bls_sign(message2.as_ptr(), message2.len(), sign_key2, &mut signature2);
         assert_eq!(err_code, ErrorCode::Success);
 
         let signatures = [signature1, signature2];
-
-        let mut multi_sig: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
+        let mut agg: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregated_signature_new(signatures.as_ptr(), signatures.len(), &mut agg);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_aggregated_signature_as_bytes(agg, &mut bytes, &mut bytes_len);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
+        let mut agg2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregated_signature_from_bytes(bytes, bytes_len, &mut agg2);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let messages = [message1.as_ptr(), message2.as_ptr()];
+        let messages_lens = [message1.len(), message2.len()];
         let ver_keys = [ver_key1, ver_key2];
         let mut valid = false;
 
-        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
-                                                        message, message_len,
+        let err_code = indy_crypto_bls_verify_aggregate(agg2,
+                                                        messages.as_ptr(), messages_lens.as_ptr(), messages.len(),
                                                         ver_keys.as_ptr(), ver_keys.len(),
                                                         gen,
                                                         &mut valid);
@@ -1423,50 +2621,62 @@ mod tests {
         let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
-        assert_eq!(err_code, ErrorCode::Success);
-
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
-        assert_eq!(err_code, ErrorCode::Success);
-
-        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
-        assert_eq!(err_code, ErrorCode::Success);
+        for sign_key in [sign_key1, sign_key2].iter() {
+            let err_code = indy_crypto_bls_sign_key_free(*sign_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
-        assert_eq!(err_code, ErrorCode::Success);
+        for ver_key in ver_keys.iter() {
+            let err_code = indy_crypto_bls_ver_key_free(*ver_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
 
-        let err_code = indy_crypto_bls_signature_free(signature1);
-        assert_eq!(err_code, ErrorCode::Success);
+        for signature in signatures.iter() {
+            let err_code = indy_crypto_bls_signature_free(*signature);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let err_code = indy_crypto_bls_aggregated_signature_free(agg);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let err_code = indy_crypto_bls_aggregated_signature_free(agg2);
         assert_eq!(err_code, ErrorCode::Success);
     }
 
     #[test]
-    fn indy_crypto_bls_verify_multi_sig_works_for_invalid() {
+    fn indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key_works() {
         let mut gen: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key1: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key1);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key1);
         assert_eq!(err_code, ErrorCode::Success);
 
         let mut sign_key2: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key2);
+        let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut sign_key3: *const c_void = ptr::null();
-        let seed: *const u8 = ptr::null();
-        let seed_len: usize = 0;
-        let err_code = indy_crypto_bls_sign_key_new(seed, seed_len, &mut sign_key3);
+        let mut ver_key1: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key2, &mut ver_key2);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let ver_keys = [ver_key1, ver_key2];
+        let mut agg_ver_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregated_ver_key_new(ver_keys.as_ptr(), ver_keys.len(), &mut agg_ver_key);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_aggregated_ver_key_as_bytes(agg_ver_key, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut agg_ver_key2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_aggregated_ver_key_from_bytes(bytes, bytes_len, &mut agg_ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
 
         let message_v = vec![1, 2, 3, 4, 5];
@@ -1482,55 +2692,156 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         let signatures = [signature1, signature2];
-
         let mut multi_sig: *const c_void = ptr::null();
         let err_code = indy_crypto_bls_multi_signature_new(signatures.as_ptr(), signatures.len(), &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let mut ver_key1: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key1, &mut ver_key1);
+        let mut valid = false;
+        let err_code = indy_crypto_bls_verify_multi_sig_with_aggregated_ver_key(multi_sig,
+                                                                                message, message_len,
+                                                                                agg_ver_key2,
+                                                                                gen,
+                                                                                &mut valid);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
 
-        let mut ver_key2: *const c_void = ptr::null();
-        let err_code = indy_crypto_bls_ver_key_new(gen, sign_key3, &mut ver_key2);
+        let err_code = indy_crypto_bls_generator_free(gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let ver_keys = [ver_key1, ver_key2];
-        let mut valid = false;
+        for sign_key in [sign_key1, sign_key2].iter() {
+            let err_code = indy_crypto_bls_sign_key_free(*sign_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
 
-        let err_code = indy_crypto_bls_verify_multi_sig(multi_sig,
-                                                        message, message_len,
-                                                        ver_keys.as_ptr(), ver_keys.len(),
-                                                        gen,
-                                                        &mut valid);
+        for ver_key in ver_keys.iter() {
+            let err_code = indy_crypto_bls_ver_key_free(*ver_key);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        for signature in signatures.iter() {
+            let err_code = indy_crypto_bls_signature_free(*signature);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
-        assert!(!valid);
 
-        let err_code = indy_crypto_bls_generator_free(gen);
+        let err_code = indy_crypto_bls_aggregated_ver_key_free(agg_ver_key);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key1);
+        let err_code = indy_crypto_bls_aggregated_ver_key_free(agg_ver_key2);
         assert_eq!(err_code, ErrorCode::Success);
+    }
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key2);
+    #[test]
+    fn indy_crypto_bls_bitmap_multi_signature_incremental_aggregation_works() {
+        let mut gen: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_generator_new(&mut gen);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_sign_key_free(sign_key3);
+        let message_v = vec![1, 2, 3, 4, 5];
+        let message = message_v.as_ptr();
+        let message_len = message_v.len();
+
+        let mut sign_keys: Vec<*const c_void> = Vec::new();
+        let mut ver_keys: Vec<*const c_void> = Vec::new();
+        let mut pops: Vec<*const c_void> = Vec::new();
+        let mut proven_ver_keys: Vec<*const c_void> = Vec::new();
+        let mut signatures: Vec<*const c_void> = Vec::new();
+
+        for _ in 0..3 {
+            let mut sign_key: *const c_void = ptr::null();
+            let err_code = indy_crypto_bls_sign_key_new(ptr::null(), 0, &mut sign_key);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let mut ver_key: *const c_void = ptr::null();
+            let err_code = indy_crypto_bls_ver_key_new(gen, sign_key, &mut ver_key);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let mut pop: *const c_void = ptr::null();
+            let err_code = indy_crypto_bls_pop_new(ver_key, sign_key, &mut pop);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let mut proven_ver_key: *const c_void = ptr::null();
+            let err_code = indy_crypto_bls_proven_ver_key_new(ver_key, pop, gen, &mut proven_ver_key);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let mut signature: *const c_void = ptr::null();
+            let err_code = indy_crypto_bls_sign(message, message_len, sign_key, &mut signature);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            sign_keys.push(sign_key);
+            ver_keys.push(ver_key);
+            pops.push(pop);
+            proven_ver_keys.push(proven_ver_key);
+            signatures.push(signature);
+        }
+
+        let indexes = [0usize];
+        let mut multi_sig: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_new(indexes.as_ptr(), signatures[..1].as_ptr(), 1, 3, &mut multi_sig);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key1);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_add_signature(multi_sig, 1, signatures[1]);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_ver_key_free(ver_key2);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_add_signature(multi_sig, 1, signatures[1]);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+
+        let indexes = [2usize];
+        let mut other: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_new(indexes.as_ptr(), signatures[2..].as_ptr(), 1, 3, &mut other);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature1);
+        let err_code = indy_crypto_bls_bitmap_multi_signature_merge(multi_sig, other);
         assert_eq!(err_code, ErrorCode::Success);
 
-        let err_code = indy_crypto_bls_signature_free(signature2);
+        let mut is_participant = false;
+        let err_code = indy_crypto_bls_bitmap_multi_signature_is_participant(multi_sig, 2, &mut is_participant);
         assert_eq!(err_code, ErrorCode::Success);
+        assert!(is_participant);
 
-        let err_code = indy_crypto_bls_multi_signature_free(multi_sig);
+        let mut bytes: *const u8 = ptr::null();
+        let mut bytes_len: usize = 0;
+        let err_code = indy_crypto_bls_bitmap_multi_signature_as_bytes(multi_sig, &mut bytes, &mut bytes_len);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut multi_sig2: *const c_void = ptr::null();
+        let err_code = indy_crypto_bls_bitmap_multi_signature_from_bytes(bytes, bytes_len, &mut multi_sig2);
         assert_eq!(err_code, ErrorCode::Success);
+
+        let mut valid = false;
+        let err_code = indy_crypto_bls_verify_bitmap_multi_sig(multi_sig2,
+                                                               message, message_len,
+                                                               proven_ver_keys.as_ptr(), proven_ver_keys.len(),
+                                                               gen,
+                                                               &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        let err_code = indy_crypto_bls_generator_free(gen);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        for i in 0..3 {
+            let err_code = indy_crypto_bls_sign_key_free(sign_keys[i]);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let err_code = indy_crypto_bls_ver_key_free(ver_keys[i]);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let err_code = indy_crypto_bls_pop_free(pops[i]);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let err_code = indy_crypto_bls_proven_ver_key_free(proven_ver_keys[i]);
+            assert_eq!(err_code, ErrorCode::Success);
+
+            let err_code = indy_crypto_bls_signature_free(signatures[i]);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
+
+        for multi_sig in [multi_sig, other, multi_sig2].iter() {
+            let err_code = indy_crypto_bls_bitmap_multi_signature_free(*multi_sig);
+            assert_eq!(err_code, ErrorCode::Success);
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,13 +1,17 @@
 use cl::issuer::*;
 use cl::*;
+use cl::tails::{self, TailsReader};
 use errors::ToErrorCode;
 use ffi::ErrorCode;
 use utils::ctypes::CTypesUtils;
+#[cfg(feature = "serialization")]
+use utils::encrypted_json::EncryptedJson;
 use utils::json::{JsonEncodable, JsonDecodable};
 
 use libc::c_char;
 
 use std::os::raw::c_void;
+use std::ptr;
 
 /// Creates and returns issuer keys (public and private) entities.
 ///
@@ -201,6 +205,76 @@ pub extern fn indy_crypto_cl_issuer_private_key_from_json(issuer_priv_key_json:
     res
 }
 
+/// Returns password-encrypted json representation of issuer private key.
+///
+/// Key is derived from password by scrypt and json is encrypted by ChaCha20-Poly1305.
+///
+/// # Arguments
+/// * `issuer_priv_key` - Reference that contains issuer private key instance pointer.
+/// * `password` - Password used for encryption.
+/// * `issuer_priv_key_json_p` - Reference that will contain encrypted issuer private key json.
+#[cfg(feature = "serialization")]
+#[no_mangle]
+pub extern fn indy_crypto_cl_issuer_private_key_to_encrypted_json(issuer_priv_key: *const c_void,
+                                                                  password: *const c_char,
+                                                                  issuer_priv_key_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_issuer_private_key_to_encrypted_json: >>> issuer_priv_key: {:?}, issuer_priv_key_json_p: {:?}", issuer_priv_key, issuer_priv_key_json_p);
+
+    check_useful_c_reference!(issuer_priv_key, IssuerPrivateKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(password, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(issuer_priv_key_json_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match issuer_priv_key.to_encrypted_json(&password) {
+        Ok(issuer_priv_key_json) => {
+            unsafe {
+                let issuer_priv_key_json = CTypesUtils::string_to_cstring(issuer_priv_key_json);
+                *issuer_priv_key_json_p = issuer_priv_key_json.into_raw();
+                trace!("indy_crypto_cl_issuer_private_key_to_encrypted_json: issuer_priv_key_json_p: {:?}", *issuer_priv_key_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_issuer_private_key_to_encrypted_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns issuer private key from password-encrypted json.
+///
+/// Note: Issuer private key instance deallocation must be performed
+/// by calling indy_crypto_cl_issuer_private_key_free
+///
+/// # Arguments
+/// * `issuer_priv_key_json` - Reference that contains encrypted issuer private key json.
+/// * `password` - Password used for encryption.
+/// * `issuer_priv_key_p` - Reference that will contain issuer private key instance pointer.
+#[cfg(feature = "serialization")]
+#[no_mangle]
+pub extern fn indy_crypto_cl_issuer_private_key_from_encrypted_json(issuer_priv_key_json: *const c_char,
+                                                                    password: *const c_char,
+                                                                    issuer_priv_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_issuer_private_key_from_encrypted_json: >>> issuer_priv_key_json: {:?}, issuer_priv_key_p: {:?}", issuer_priv_key_json, issuer_priv_key_p);
+
+    check_useful_c_str!(issuer_priv_key_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(password, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(issuer_priv_key_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match IssuerPrivateKey::from_encrypted_json(&issuer_priv_key_json, &password) {
+        Ok(issuer_priv_key) => {
+            unsafe {
+                *issuer_priv_key_p = Box::into_raw(Box::new(issuer_priv_key)) as *const c_void;
+                trace!("indy_crypto_cl_issuer_private_key_from_encrypted_json: *issuer_priv_key_p: {:?}", *issuer_priv_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_issuer_private_key_from_encrypted_json: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates issuer private key instance.
 ///
 /// # Arguments
@@ -220,38 +294,44 @@ pub extern fn indy_crypto_cl_issuer_private_key_free(issuer_priv_key: *const c_v
     res
 }
 
-/// Creates and returns revocation registries (public and private) entities.
+/// Creates and returns revocation registries (public and private) and accumulator tails entities.
 ///
 /// Note that keys registries deallocation must be performed by
-/// calling indy_crypto_cl_revocation_registry_public_free and
-/// indy_crypto_cl_revocation_registry_private_free.
+/// calling indy_crypto_cl_revocation_registry_public_free,
+/// indy_crypto_cl_revocation_registry_private_free and
+/// indy_crypto_cl_revocation_tails_free.
 ///
 /// # Arguments
 /// * `issuer_pub_key` - Reference that contains issuer pub key instance pointer.
 /// * `max_claim_num` - Max claim number in generated registry.
 /// * `rev_reg_pub_p` - Reference that will contain revocation registry public instance pointer.
 /// * `rev_reg_priv_p` - Reference that will contain revocation registry private instance pointer.
+/// * `rev_tails_p` - Reference that will contain revocation accumulator tails instance pointer.
 #[no_mangle]
 pub extern fn indy_crypto_cl_issuer_new_revocation_registry(issuer_pub_key: *const c_void,
                                                             max_claim_num: u32,
                                                             rev_reg_pub_p: *mut *const c_void,
-                                                            rev_reg_priv_p: *mut *const c_void) -> ErrorCode {
-    trace!("indy_crypto_cl_issuer_new_revocation_registry: >>> issuer_pub_key: {:?}, max_claim_num: {:?}, rev_reg_pub_p: {:?}, rev_reg_priv_p: {:?}",
-           issuer_pub_key, max_claim_num, rev_reg_pub_p, rev_reg_priv_p);
+                                                            rev_reg_priv_p: *mut *const c_void,
+                                                            rev_tails_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_issuer_new_revocation_registry: >>> issuer_pub_key: {:?}, max_claim_num: {:?}, rev_reg_pub_p: {:?}, rev_reg_priv_p: {:?}, \
+    rev_tails_p: {:?}", issuer_pub_key, max_claim_num, rev_reg_pub_p, rev_reg_priv_p, rev_tails_p);
 
     check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam1);
     check_useful_c_ptr!(rev_reg_pub_p, ErrorCode::CommonInvalidParam3);
     check_useful_c_ptr!(rev_reg_priv_p, ErrorCode::CommonInvalidParam4);
+    check_useful_c_ptr!(rev_tails_p, ErrorCode::CommonInvalidParam5);
 
     trace!("indy_crypto_cl_issuer_new_revocation_registry: entities: issuer_pub_key: {:?}, max_claim_num: {:?}", issuer_pub_key, max_claim_num);
 
     let res = match Issuer::new_revocation_registry(issuer_pub_key, max_claim_num) {
-        Ok((rev_reg_pub, rev_reg_priv)) => {
-            trace!("indy_crypto_cl_issuer_new_revocation_registry: rev_reg_pub: {:?}, rev_reg_priv: {:?}", rev_reg_pub, rev_reg_priv);
+        Ok((rev_reg_pub, rev_reg_priv, rev_tails)) => {
+            trace!("indy_crypto_cl_issuer_new_revocation_registry: rev_reg_pub: {:?}, rev_reg_priv: {:?}, rev_tails: {:?}", rev_reg_pub, rev_reg_priv, rev_tails);
             unsafe {
                 *rev_reg_pub_p = Box::into_raw(Box::new(rev_reg_pub)) as *const c_void;
                 *rev_reg_priv_p = Box::into_raw(Box::new(rev_reg_priv)) as *const c_void;
-                trace!("indy_crypto_cl_issuer_new_revocation_registry: *rev_reg_pub_p: {:?}, *rev_reg_priv_p: {:?}", *rev_reg_pub_p, *rev_reg_priv_p);
+                *rev_tails_p = Box::into_raw(Box::new(rev_tails)) as *const c_void;
+                trace!("indy_crypto_cl_issuer_new_revocation_registry: *rev_reg_pub_p: {:?}, *rev_reg_priv_p: {:?}, *rev_tails_p: {:?}",
+                       *rev_reg_pub_p, *rev_reg_priv_p, *rev_tails_p);
             }
             ErrorCode::Success
         }
@@ -346,6 +426,242 @@ pub extern fn indy_crypto_cl_revocation_registry_public_free(rev_reg_pub: *const
     res
 }
 
+/// Returns revocation accumulator of revocation registry public.
+///
+/// Note: Revocation accumulator instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_accumulator_free
+///
+/// # Arguments
+/// * `rev_reg_pub` - Reference that contains revocation registry public instance pointer.
+/// * `rev_acc_p` - Reference that will contain revocation accumulator instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_public_get_accumulator(rev_reg_pub: *const c_void,
+                                                                        rev_acc_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_public_get_accumulator: >>> rev_reg_pub: {:?}, rev_acc_p: {:?}", rev_reg_pub, rev_acc_p);
+
+    check_useful_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_acc_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_registry_public_get_accumulator: entity: rev_reg_pub: {:?}", rev_reg_pub);
+
+    let res = match rev_reg_pub.get_accumulator() {
+        Ok(rev_acc) => {
+            trace!("indy_crypto_cl_revocation_registry_public_get_accumulator: rev_acc: {:?}", rev_acc);
+            unsafe {
+                *rev_acc_p = Box::into_raw(Box::new(rev_acc)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_registry_public_get_accumulator: *rev_acc_p: {:?}", *rev_acc_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_public_get_accumulator: <<< res: {:?}", res);
+    res
+}
+
+/// Returns revocation accumulator public key of revocation registry public.
+///
+/// Note: Revocation accumulator public key instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_accumulator_public_key_free
+///
+/// # Arguments
+/// * `rev_reg_pub` - Reference that contains revocation registry public instance pointer.
+/// * `rev_acc_pub_key_p` - Reference that will contain revocation accumulator public key instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_public_get_accumulator_key(rev_reg_pub: *const c_void,
+                                                                            rev_acc_pub_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_public_get_accumulator_key: >>> rev_reg_pub: {:?}, rev_acc_pub_key_p: {:?}", rev_reg_pub, rev_acc_pub_key_p);
+
+    check_useful_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_acc_pub_key_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_registry_public_get_accumulator_key: entity: rev_reg_pub: {:?}", rev_reg_pub);
+
+    let res = match rev_reg_pub.get_accumulator_key() {
+        Ok(rev_acc_pub_key) => {
+            trace!("indy_crypto_cl_revocation_registry_public_get_accumulator_key: rev_acc_pub_key: {:?}", rev_acc_pub_key);
+            unsafe {
+                *rev_acc_pub_key_p = Box::into_raw(Box::new(rev_acc_pub_key)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_registry_public_get_accumulator_key: *rev_acc_pub_key_p: {:?}", *rev_acc_pub_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_public_get_accumulator_key: <<< res: {:?}", res);
+    res
+}
+
+/// Returns json representation of revocation accumulator.
+///
+/// # Arguments
+/// * `rev_acc` - Reference that contains revocation accumulator instance pointer.
+/// * `rev_acc_json_p` - Reference that will contain revocation accumulator json.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_accumulator_to_json(rev_acc: *const c_void,
+                                                            rev_acc_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_accumulator_to_json: >>> rev_acc: {:?}, rev_acc_json_p: {:?}", rev_acc, rev_acc_json_p);
+
+    check_useful_c_reference!(rev_acc, RevocationAccumulator, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_acc_json_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_accumulator_to_json: entity >>> rev_acc: {:?}", rev_acc);
+
+    let res = match rev_acc.to_json() {
+        Ok(rev_acc_json) => {
+            trace!("indy_crypto_cl_revocation_accumulator_to_json: rev_acc_json: {:?}", rev_acc_json);
+            unsafe {
+                let rev_acc_json = CTypesUtils::string_to_cstring(rev_acc_json);
+                *rev_acc_json_p = rev_acc_json.into_raw();
+                trace!("indy_crypto_cl_revocation_accumulator_to_json: rev_acc_json_p: {:?}", *rev_acc_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_accumulator_to_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns revocation accumulator from json.
+///
+/// Note: Revocation accumulator instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_accumulator_free
+///
+/// # Arguments
+/// * `rev_acc_json` - Reference that contains revocation accumulator json.
+/// * `rev_acc_p` - Reference that will contain revocation accumulator instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_accumulator_from_json(rev_acc_json: *const c_char,
+                                                              rev_acc_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_accumulator_from_json: >>> rev_acc_json: {:?}, rev_acc_p: {:?}", rev_acc_json, rev_acc_p);
+
+    check_useful_c_str!(rev_acc_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_acc_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_accumulator_from_json: entity: rev_acc_json: {:?}", rev_acc_json);
+
+    let res = match RevocationAccumulator::from_json(&rev_acc_json) {
+        Ok(rev_acc) => {
+            trace!("indy_crypto_cl_revocation_accumulator_from_json: rev_acc: {:?}", rev_acc);
+            unsafe {
+                *rev_acc_p = Box::into_raw(Box::new(rev_acc)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_accumulator_from_json: *rev_acc_p: {:?}", *rev_acc_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_accumulator_from_json: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates revocation accumulator instance.
+///
+/// # Arguments
+/// * `rev_acc` - Reference that contains revocation accumulator instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_accumulator_free(rev_acc: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_accumulator_free: >>> rev_acc: {:?}", rev_acc);
+
+    check_useful_c_ptr!(rev_acc, ErrorCode::CommonInvalidParam1);
+    let rev_acc = unsafe { Box::from_raw(rev_acc as *mut RevocationAccumulator); };
+    trace!("indy_crypto_cl_revocation_accumulator_free: entity: rev_acc: {:?}", rev_acc);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_cl_revocation_accumulator_free: <<< res: {:?}", res);
+    res
+}
+
+/// Returns json representation of revocation accumulator public key.
+///
+/// # Arguments
+/// * `rev_acc_pub_key` - Reference that contains revocation accumulator public key instance pointer.
+/// * `rev_acc_pub_key_json_p` - Reference that will contain revocation accumulator public key json.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_accumulator_public_key_to_json(rev_acc_pub_key: *const c_void,
+                                                                       rev_acc_pub_key_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_to_json: >>> rev_acc_pub_key: {:?}, rev_acc_pub_key_json_p: {:?}", rev_acc_pub_key, rev_acc_pub_key_json_p);
+
+    check_useful_c_reference!(rev_acc_pub_key, RevocationAccumulatorPublicKey, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_acc_pub_key_json_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_to_json: entity >>> rev_acc_pub_key: {:?}", rev_acc_pub_key);
+
+    let res = match rev_acc_pub_key.to_json() {
+        Ok(rev_acc_pub_key_json) => {
+            trace!("indy_crypto_cl_revocation_accumulator_public_key_to_json: rev_acc_pub_key_json: {:?}", rev_acc_pub_key_json);
+            unsafe {
+                let rev_acc_pub_key_json = CTypesUtils::string_to_cstring(rev_acc_pub_key_json);
+                *rev_acc_pub_key_json_p = rev_acc_pub_key_json.into_raw();
+                trace!("indy_crypto_cl_revocation_accumulator_public_key_to_json: rev_acc_pub_key_json_p: {:?}", *rev_acc_pub_key_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_to_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns revocation accumulator public key from json.
+///
+/// Note: Revocation accumulator public key instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_accumulator_public_key_free
+///
+/// # Arguments
+/// * `rev_acc_pub_key_json` - Reference that contains revocation accumulator public key json.
+/// * `rev_acc_pub_key_p` - Reference that will contain revocation accumulator public key instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_accumulator_public_key_from_json(rev_acc_pub_key_json: *const c_char,
+                                                                         rev_acc_pub_key_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_from_json: >>> rev_acc_pub_key_json: {:?}, rev_acc_pub_key_p: {:?}", rev_acc_pub_key_json, rev_acc_pub_key_p);
+
+    check_useful_c_str!(rev_acc_pub_key_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_acc_pub_key_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_from_json: entity: rev_acc_pub_key_json: {:?}", rev_acc_pub_key_json);
+
+    let res = match RevocationAccumulatorPublicKey::from_json(&rev_acc_pub_key_json) {
+        Ok(rev_acc_pub_key) => {
+            trace!("indy_crypto_cl_revocation_accumulator_public_key_from_json: rev_acc_pub_key: {:?}", rev_acc_pub_key);
+            unsafe {
+                *rev_acc_pub_key_p = Box::into_raw(Box::new(rev_acc_pub_key)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_accumulator_public_key_from_json: *rev_acc_pub_key_p: {:?}", *rev_acc_pub_key_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_from_json: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates revocation accumulator public key instance.
+///
+/// # Arguments
+/// * `rev_acc_pub_key` - Reference that contains revocation accumulator public key instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_accumulator_public_key_free(rev_acc_pub_key: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_free: >>> rev_acc_pub_key: {:?}", rev_acc_pub_key);
+
+    check_useful_c_ptr!(rev_acc_pub_key, ErrorCode::CommonInvalidParam1);
+    let rev_acc_pub_key = unsafe { Box::from_raw(rev_acc_pub_key as *mut RevocationAccumulatorPublicKey); };
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_free: entity: rev_acc_pub_key: {:?}", rev_acc_pub_key);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_cl_revocation_accumulator_public_key_free: <<< res: {:?}", res);
+    res
+}
+
 /// Returns json representation of revocation registry private.
 ///
 /// # Arguments
@@ -412,6 +728,76 @@ pub extern fn indy_crypto_cl_revocation_registry_private_from_json(rev_reg_priva
     res
 }
 
+/// Returns password-encrypted json representation of revocation registry private.
+///
+/// Key is derived from password by scrypt and json is encrypted by ChaCha20-Poly1305.
+///
+/// # Arguments
+/// * `rev_reg_private` - Reference that contains revocation registry private instance pointer.
+/// * `password` - Password used for encryption.
+/// * `rev_reg_private_json_p` - Reference that will contain encrypted revocation registry private json.
+#[cfg(feature = "serialization")]
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_private_to_encrypted_json(rev_reg_private: *const c_void,
+                                                                           password: *const c_char,
+                                                                           rev_reg_private_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_private_to_encrypted_json: >>> rev_reg_private: {:?}, rev_reg_private_json_p: {:?}", rev_reg_private, rev_reg_private_json_p);
+
+    check_useful_c_reference!(rev_reg_private, RevocationRegistryPrivate, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(password, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(rev_reg_private_json_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match rev_reg_private.to_encrypted_json(&password) {
+        Ok(rev_reg_private_json) => {
+            unsafe {
+                let rev_reg_private_json = CTypesUtils::string_to_cstring(rev_reg_private_json);
+                *rev_reg_private_json_p = rev_reg_private_json.into_raw();
+                trace!("indy_crypto_cl_revocation_registry_private_to_encrypted_json: rev_reg_private_json_p: {:?}", *rev_reg_private_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_private_to_encrypted_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns revocation registry private from password-encrypted json.
+///
+/// Note: Revocation registry private instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_registry_private_free
+///
+/// # Arguments
+/// * `rev_reg_private_json` - Reference that contains encrypted revocation registry private json.
+/// * `password` - Password used for encryption.
+/// * `rev_reg_private_p` - Reference that will contain revocation registry private instance pointer.
+#[cfg(feature = "serialization")]
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_private_from_encrypted_json(rev_reg_private_json: *const c_char,
+                                                                             password: *const c_char,
+                                                                             rev_reg_private_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_private_from_encrypted_json: >>> rev_reg_private_json: {:?}, rev_reg_private_p: {:?}", rev_reg_private_json, rev_reg_private_p);
+
+    check_useful_c_str!(rev_reg_private_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(password, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(rev_reg_private_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match RevocationRegistryPrivate::from_encrypted_json(&rev_reg_private_json, &password) {
+        Ok(rev_reg_private) => {
+            unsafe {
+                *rev_reg_private_p = Box::into_raw(Box::new(rev_reg_private)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_registry_private_from_encrypted_json: *rev_reg_private_p: {:?}", *rev_reg_private_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_private_from_encrypted_json: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates revocation registry private instance.
 ///
 /// # Arguments
@@ -431,6 +817,117 @@ pub extern fn indy_crypto_cl_revocation_registry_private_free(rev_reg_priv: *con
     res
 }
 
+/// Returns json representation of revocation accumulator tails.
+///
+/// # Arguments
+/// * `rev_tails` - Reference that contains revocation accumulator tails pointer.
+/// * `rev_tails_json_p` - Reference that will contain revocation accumulator tails json.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_tails_to_json(rev_tails: *const c_void,
+                                                      rev_tails_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_tails_to_json: >>> rev_tails: {:?}, rev_tails_json_p: {:?}", rev_tails, rev_tails_json_p);
+
+    check_useful_c_reference!(rev_tails, RevocationAccumulatorTails, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_tails_json_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_tails_to_json: entity >>> rev_tails: {:?}", rev_tails);
+
+    let res = match rev_tails.to_json() {
+        Ok(rev_tails_json) => {
+            trace!("indy_crypto_cl_revocation_tails_to_json: rev_tails_json: {:?}", rev_tails_json);
+            unsafe {
+                let rev_tails_json = CTypesUtils::string_to_cstring(rev_tails_json);
+                *rev_tails_json_p = rev_tails_json.into_raw();
+                trace!("indy_crypto_cl_revocation_tails_to_json: rev_tails_json_p: {:?}", *rev_tails_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_tails_to_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns revocation accumulator tails from json.
+///
+/// Note: Revocation accumulator tails instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_tails_free
+///
+/// # Arguments
+/// * `rev_tails_json` - Reference that contains revocation accumulator tails json.
+/// * `rev_tails_p` - Reference that will contain revocation accumulator tails instance pointer
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_tails_from_json(rev_tails_json: *const c_char,
+                                                        rev_tails_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_tails_from_json: >>> rev_tails_json: {:?}, rev_tails_p: {:?}", rev_tails_json, rev_tails_p);
+
+    check_useful_c_str!(rev_tails_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_tails_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_tails_from_json: entity: rev_tails_json: {:?}", rev_tails_json);
+
+    let res = match RevocationAccumulatorTails::from_json(&rev_tails_json) {
+        Ok(rev_tails) => {
+            trace!("indy_crypto_cl_revocation_tails_from_json: rev_tails: {:?}", rev_tails);
+            unsafe {
+                *rev_tails_p = Box::into_raw(Box::new(rev_tails)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_tails_from_json: *rev_tails_p: {:?}", *rev_tails_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_tails_from_json: <<< res: {:?}", res);
+    res
+}
+
+/// Writes revocation accumulator tails to file in fixed-width binary format.
+///
+/// # Arguments
+/// * `rev_tails` - Reference that contains revocation accumulator tails pointer.
+/// * `max_claim_num` - Max claim number in revocation registry.
+/// * `path` - Path to tails file as null terminated string.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_tails_write_to_file(rev_tails: *const c_void,
+                                                            max_claim_num: u32,
+                                                            path: *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_tails_write_to_file: >>> rev_tails: {:?}, max_claim_num: {:?}, path: {:?}", rev_tails, max_claim_num, path);
+
+    check_useful_c_reference!(rev_tails, RevocationAccumulatorTails, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(path, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_cl_revocation_tails_write_to_file: entities: max_claim_num: {:?}, path: {:?}", max_claim_num, path);
+
+    let res = match tails::write_tails_file(rev_tails, max_claim_num, &path) {
+        Ok(_) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_tails_write_to_file: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates revocation accumulator tails instance.
+///
+/// # Arguments
+/// * `rev_tails` - Reference that contains revocation accumulator tails instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_tails_free(rev_tails: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_tails_free: >>> rev_tails: {:?}", rev_tails);
+
+    check_useful_c_ptr!(rev_tails, ErrorCode::CommonInvalidParam1);
+
+    let rev_tails = unsafe { Box::from_raw(rev_tails as *mut RevocationAccumulatorTails); };
+    trace!("indy_crypto_cl_revocation_tails_free: entity: rev_tails: {:?}", rev_tails);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_cl_revocation_tails_free: <<< res: {:?}", res);
+    res
+}
+
 /// Sign given claim values instance.
 ///
 /// Note that claim_signature deallocation must be performed by
@@ -445,6 +942,7 @@ pub extern fn indy_crypto_cl_revocation_registry_private_free(rev_reg_priv: *con
 /// * `rev_idx` - (Optional) User index in revocation accumulator. Required for non-revocation claim_signature part generation.
 /// * `rev_reg_pub` - (Optional) Reference that contains revocation registry public instance pointer.
 /// * `rev_reg_priv` - (Optional) Reference that contains revocation registry private instance pointer.
+/// * `rev_tails_reader` - (Optional) Reference that contains tails reader instance pointer.
 /// * `claim_signature_p` - Reference that will contain claim signature instance pointer.
 #[no_mangle]
 pub extern fn indy_crypto_cl_issuer_sign_claim(prover_id: *const c_char,
@@ -455,10 +953,11 @@ pub extern fn indy_crypto_cl_issuer_sign_claim(prover_id: *const c_char,
                                                rev_idx: i32,
                                                rev_reg_pub: *const c_void,
                                                rev_reg_priv: *const c_void,
+                                               rev_tails_reader: *const c_void,
                                                claim_signature_p: *mut *const c_void) -> ErrorCode {
     trace!("indy_crypto_cl_issuer_sign_claim: >>> prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, \
-    issuer_priv_key: {:?}, rev_idx: {:?}, rev_reg_pub: {:?}, rev_reg_priv: {:?}, claim_signature_p: {:?}",
-           prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rev_reg_pub, rev_reg_priv, claim_signature_p);
+    issuer_priv_key: {:?}, rev_idx: {:?}, rev_reg_pub: {:?}, rev_reg_priv: {:?}, rev_tails_reader: {:?}, claim_signature_p: {:?}",
+           prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rev_reg_pub, rev_reg_priv, rev_tails_reader, claim_signature_p);
 
     check_useful_c_str!(prover_id, ErrorCode::CommonInvalidParam1);
     check_useful_c_reference!(blinded_ms, BlindedMasterSecret, ErrorCode::CommonInvalidParam2);
@@ -466,7 +965,8 @@ pub extern fn indy_crypto_cl_issuer_sign_claim(prover_id: *const c_char,
     check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam4);
     check_useful_c_reference!(issuer_priv_key, IssuerPrivateKey, ErrorCode::CommonInvalidParam5);
     check_useful_opt_c_reference!(rev_reg_priv, RevocationRegistryPrivate);
-    check_useful_c_ptr!(claim_signature_p, ErrorCode::CommonInvalidParam9);
+    check_useful_opt_c_reference!(rev_tails_reader, Box<TailsReader>);
+    check_useful_c_ptr!(claim_signature_p, ErrorCode::CommonInvalidParam10);
 
     let rev_idx = if rev_idx != -1 { Some(rev_idx as u32) } else { None };
 
@@ -485,7 +985,8 @@ pub extern fn indy_crypto_cl_issuer_sign_claim(prover_id: *const c_char,
                                        &issuer_priv_key,
                                        rev_idx,
                                        rev_reg_pub.as_mut().map(Box::as_mut),
-                                       rev_reg_priv) {
+                                       rev_reg_priv,
+                                       rev_tails_reader.map(|r_tails_reader| &**r_tails_reader)) {
         Ok(claim_signature) => {
             trace!("indy_crypto_cl_issuer_sign_claim: claim_signature: {:?}", claim_signature);
             unsafe {
@@ -502,6 +1003,218 @@ pub extern fn indy_crypto_cl_issuer_sign_claim(prover_id: *const c_char,
     res
 }
 
+/// Sign given claim values instance without modification of revocation registry public.
+///
+/// Note that claim_signature deallocation must be performed by
+/// calling indy_crypto_cl_claim_signature_free. Revocation registry delta deallocation
+/// must be performed by calling indy_crypto_cl_revocation_registry_delta_free.
+///
+/// # Arguments
+/// * `prover_id` - Reference that contains prover identifier as null terminated string.
+/// * `blinded_ms` - Reference that contains blinded master secret instance pointer.
+/// * `claim_values` - Reference that contains claim values instance pointer.
+/// * `issuer_pub_key` - Reference that contains issuer public key instance pointer.
+/// * `issuer_priv_key` - Reference that contains issuer private key instance pointer.
+/// * `rev_idx` - (Optional) User index in revocation accumulator. Required for non-revocation claim_signature part generation.
+/// * `rev_reg_pub` - (Optional) Reference that contains revocation registry public instance pointer.
+/// * `rev_reg_priv` - (Optional) Reference that contains revocation registry private instance pointer.
+/// * `rev_tails_reader` - (Optional) Reference that contains tails reader instance pointer.
+/// * `claim_signature_p` - Reference that will contain claim signature instance pointer.
+/// * `rev_reg_delta_p` - Reference that will contain revocation registry delta instance pointer
+///   or null if non-revocation part wasn't generated.
+#[no_mangle]
+pub extern fn indy_crypto_cl_issuer_sign_claim_with_delta(prover_id: *const c_char,
+                                                          blinded_ms: *const c_void,
+                                                          claim_values: *const c_void,
+                                                          issuer_pub_key: *const c_void,
+                                                          issuer_priv_key: *const c_void,
+                                                          rev_idx: i32,
+                                                          rev_reg_pub: *const c_void,
+                                                          rev_reg_priv: *const c_void,
+                                                          rev_tails_reader: *const c_void,
+                                                          claim_signature_p: *mut *const c_void,
+                                                          rev_reg_delta_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_issuer_sign_claim_with_delta: >>> prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, \
+    issuer_priv_key: {:?}, rev_idx: {:?}, rev_reg_pub: {:?}, rev_reg_priv: {:?}, rev_tails_reader: {:?}, claim_signature_p: {:?}, rev_reg_delta_p: {:?}",
+           prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rev_reg_pub, rev_reg_priv, rev_tails_reader, claim_signature_p, rev_reg_delta_p);
+
+    check_useful_c_str!(prover_id, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(blinded_ms, BlindedMasterSecret, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(claim_values, ClaimValues, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(issuer_priv_key, IssuerPrivateKey, ErrorCode::CommonInvalidParam5);
+    check_useful_opt_c_reference!(rev_reg_pub, RevocationRegistryPublic);
+    check_useful_opt_c_reference!(rev_reg_priv, RevocationRegistryPrivate);
+    check_useful_opt_c_reference!(rev_tails_reader, Box<TailsReader>);
+    check_useful_c_ptr!(claim_signature_p, ErrorCode::CommonInvalidParam10);
+    check_useful_c_ptr!(rev_reg_delta_p, ErrorCode::CommonInvalidParam11);
+
+    let rev_idx = if rev_idx != -1 { Some(rev_idx as u32) } else { None };
+
+    trace!("indy_crypto_cl_issuer_sign_claim_with_delta: entities: prover_id: {:?}, blinded_ms: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, \
+    issuer_priv_key: {:?}, rev_idx: {:?}, rev_reg_pub: {:?}, rev_reg_priv: {:?}",
+           prover_id, blinded_ms, claim_values, issuer_pub_key, issuer_priv_key, rev_idx, rev_reg_pub, rev_reg_priv);
+
+    let res = match Issuer::sign_claim_with_delta(&prover_id,
+                                                  &blinded_ms,
+                                                  &claim_values,
+                                                  &issuer_pub_key,
+                                                  &issuer_priv_key,
+                                                  rev_idx,
+                                                  rev_reg_pub,
+                                                  rev_reg_priv,
+                                                  rev_tails_reader.map(|r_tails_reader| &**r_tails_reader)) {
+        Ok((claim_signature, rev_reg_delta)) => {
+            trace!("indy_crypto_cl_issuer_sign_claim_with_delta: claim_signature: {:?}, rev_reg_delta: {:?}", claim_signature, rev_reg_delta);
+            unsafe {
+                *claim_signature_p = Box::into_raw(Box::new(claim_signature)) as *const c_void;
+                *rev_reg_delta_p = match rev_reg_delta {
+                    Some(rev_reg_delta) => Box::into_raw(Box::new(rev_reg_delta)) as *const c_void,
+                    None => ptr::null()
+                };
+                trace!("indy_crypto_cl_issuer_sign_claim_with_delta: *claim_signature_p: {:?}, *rev_reg_delta_p: {:?}", *claim_signature_p, *rev_reg_delta_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_issuer_sign_claim_with_delta: <<< res: {:?}", res);
+    res
+}
+
+/// Applies revocation registry delta to revocation registry public.
+///
+/// # Arguments
+/// * `rev_reg_pub` - Reference that contains revocation registry public instance pointer.
+/// * `rev_reg_delta` - Reference that contains revocation registry delta instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_public_apply_delta(rev_reg_pub: *const c_void,
+                                                                    rev_reg_delta: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_public_apply_delta: >>> rev_reg_pub: {:?}, rev_reg_delta: {:?}", rev_reg_pub, rev_reg_delta);
+
+    check_useful_mut_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(rev_reg_delta, RevocationRegistryDelta, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_registry_public_apply_delta: entities: rev_reg_pub: {:?}, rev_reg_delta: {:?}", rev_reg_pub, rev_reg_delta);
+
+    let res = match rev_reg_pub.apply_delta(rev_reg_delta) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_public_apply_delta: <<< res: {:?}", res);
+    res
+}
+
+/// Stores current accumulator state of revocation registry public as snapshot with given id.
+///
+/// # Arguments
+/// * `rev_reg_pub` - Reference that contains revocation registry public instance pointer.
+/// * `snapshot_id` - Accumulator snapshot id (must be greater than ids of existing snapshots).
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_public_take_snapshot(rev_reg_pub: *const c_void,
+                                                                      snapshot_id: u64) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_public_take_snapshot: >>> rev_reg_pub: {:?}, snapshot_id: {:?}", rev_reg_pub, snapshot_id);
+
+    check_useful_mut_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam1);
+
+    trace!("indy_crypto_cl_revocation_registry_public_take_snapshot: entities: rev_reg_pub: {:?}", rev_reg_pub);
+
+    let res = match rev_reg_pub.take_snapshot(snapshot_id) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_public_take_snapshot: <<< res: {:?}", res);
+    res
+}
+
+/// Returns json representation of revocation registry delta.
+///
+/// # Arguments
+/// * `rev_reg_delta` - Reference that contains revocation registry delta pointer.
+/// * `rev_reg_delta_json_p` - Reference that will contain revocation registry delta json.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_delta_to_json(rev_reg_delta: *const c_void,
+                                                               rev_reg_delta_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_delta_to_json: >>> rev_reg_delta: {:?}, rev_reg_delta_json_p: {:?}", rev_reg_delta, rev_reg_delta_json_p);
+
+    check_useful_c_reference!(rev_reg_delta, RevocationRegistryDelta, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_reg_delta_json_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_registry_delta_to_json: entity >>> rev_reg_delta: {:?}", rev_reg_delta);
+
+    let res = match rev_reg_delta.to_json() {
+        Ok(rev_reg_delta_json) => {
+            trace!("indy_crypto_cl_revocation_registry_delta_to_json: rev_reg_delta_json: {:?}", rev_reg_delta_json);
+            unsafe {
+                let rev_reg_delta_json = CTypesUtils::string_to_cstring(rev_reg_delta_json);
+                *rev_reg_delta_json_p = rev_reg_delta_json.into_raw();
+                trace!("indy_crypto_cl_revocation_registry_delta_to_json: rev_reg_delta_json_p: {:?}", *rev_reg_delta_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_delta_to_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns revocation registry delta from json.
+///
+/// Note: Revocation registry delta instance deallocation must be performed
+/// by calling indy_crypto_cl_revocation_registry_delta_free
+///
+/// # Arguments
+/// * `rev_reg_delta_json` - Reference that contains revocation registry delta json.
+/// * `rev_reg_delta_p` - Reference that will contain revocation registry delta instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_delta_from_json(rev_reg_delta_json: *const c_char,
+                                                                 rev_reg_delta_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_delta_from_json: >>> rev_reg_delta_json: {:?}, rev_reg_delta_p: {:?}", rev_reg_delta_json, rev_reg_delta_p);
+
+    check_useful_c_str!(rev_reg_delta_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_ptr!(rev_reg_delta_p, ErrorCode::CommonInvalidParam2);
+
+    trace!("indy_crypto_cl_revocation_registry_delta_from_json: entity: rev_reg_delta_json: {:?}", rev_reg_delta_json);
+
+    let res = match RevocationRegistryDelta::from_json(&rev_reg_delta_json) {
+        Ok(rev_reg_delta) => {
+            trace!("indy_crypto_cl_revocation_registry_delta_from_json: rev_reg_delta: {:?}", rev_reg_delta);
+            unsafe {
+                *rev_reg_delta_p = Box::into_raw(Box::new(rev_reg_delta)) as *const c_void;
+                trace!("indy_crypto_cl_revocation_registry_delta_from_json: *rev_reg_delta_p: {:?}", *rev_reg_delta_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_revocation_registry_delta_from_json: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates revocation registry delta instance.
+///
+/// # Arguments
+/// * `rev_reg_delta` - Reference that contains revocation registry delta instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_revocation_registry_delta_free(rev_reg_delta: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_revocation_registry_delta_free: >>> rev_reg_delta: {:?}", rev_reg_delta);
+
+    check_useful_c_ptr!(rev_reg_delta, ErrorCode::CommonInvalidParam1);
+
+    let rev_reg_delta = unsafe { Box::from_raw(rev_reg_delta as *mut RevocationRegistryDelta); };
+    trace!("indy_crypto_cl_revocation_registry_delta_free: entity: rev_reg_delta: {:?}", rev_reg_delta);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_cl_revocation_registry_delta_free: <<< res: {:?}", res);
+    res
+}
+
 /// Returns json representation of claim signature.
 ///
 /// # Arguments
@@ -590,17 +1303,20 @@ pub extern fn indy_crypto_cl_claim_signature_free(claim_signature: *const c_void
 ///
 /// # Arguments
 /// * `rev_reg_pub` - Reference that contain revocation registry instance pointer.
-///  * rev_idx` - index of the user in the accumulator
+/// * `rev_idx` - index of the user in the accumulator
+/// * `rev_tails_reader` - Reference that contains tails reader instance pointer.
 #[no_mangle]
 pub extern fn indy_crypto_cl_issuer_revoke_claim(rev_reg_pub: *const c_void,
-                                                 rev_idx: u32) -> ErrorCode {
-    trace!("indy_crypto_cl_issuer_revoke_claim: >>> rev_reg_pub: {:?}, rev_idx: {:?}", rev_reg_pub, rev_idx);
+                                                 rev_idx: u32,
+                                                 rev_tails_reader: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_issuer_revoke_claim: >>> rev_reg_pub: {:?}, rev_idx: {:?}, rev_tails_reader: {:?}", rev_reg_pub, rev_idx, rev_tails_reader);
 
     check_useful_mut_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(rev_tails_reader, Box<TailsReader>, ErrorCode::CommonInvalidParam3);
 
     trace!("indy_crypto_cl_issuer_revoke_claim: entities: rev_reg_pub: {:?}, rev_idx: {:?}", rev_reg_pub, rev_idx);
 
-    let res = match Issuer::revoke_claim(rev_reg_pub, rev_idx) {
+    let res = match Issuer::revoke_claim(rev_reg_pub, rev_idx, &**rev_tails_reader) {
         Ok(()) => ErrorCode::Success,
         Err(err) => err.to_error_code()
     };
@@ -613,6 +1329,7 @@ pub extern fn indy_crypto_cl_issuer_revoke_claim(rev_reg_pub: *const c_void,
 mod tests {
     use super::*;
 
+    use std::ffi::CString;
     use std::ptr;
     use ffi::cl::mocks::*;
     use ffi::cl::issuer::mocks::*;
@@ -648,6 +1365,7 @@ mod tests {
 
         _free_claim_schema(claim_schema);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_str(issuer_pub_key_json_p);
     }
 
     #[test]
@@ -665,6 +1383,7 @@ mod tests {
 
         _free_claim_schema(claim_schema);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_str(issuer_pub_key_json_p);
     }
 
     #[test]
@@ -678,6 +1397,7 @@ mod tests {
 
         _free_claim_schema(claim_schema);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_str(issuer_priv_key_json_p);
     }
 
     #[test]
@@ -695,6 +1415,25 @@ mod tests {
 
         _free_claim_schema(claim_schema);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_str(issuer_priv_key_json_p);
+    }
+
+    #[test]
+    #[cfg(feature = "serialization")]
+    fn indy_crypto_cl_issuer_private_key_from_encrypted_json_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let password = CString::new("password").unwrap();
+
+        let mut issuer_priv_key_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_issuer_private_key_to_encrypted_json(issuer_priv_key, password.as_ptr(), &mut issuer_priv_key_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut issuer_priv_key_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_issuer_private_key_from_encrypted_json(issuer_priv_key_json_p, password.as_ptr(), &mut issuer_priv_key_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_str(issuer_priv_key_json_p);
     }
 
     #[test]
@@ -713,33 +1452,36 @@ mod tests {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
         let mut rev_reg_pub: *const c_void = ptr::null();
         let mut rev_reg_priv: *const c_void = ptr::null();
+        let mut rev_tails: *const c_void = ptr::null();
 
-        let err_code = indy_crypto_cl_issuer_new_revocation_registry(issuer_pub_key, 100, &mut rev_reg_pub, &mut rev_reg_priv);
+        let err_code = indy_crypto_cl_issuer_new_revocation_registry(issuer_pub_key, 100, &mut rev_reg_pub, &mut rev_reg_priv, &mut rev_tails);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(!rev_reg_pub.is_null());
         assert!(!rev_reg_priv.is_null());
+        assert!(!rev_tails.is_null());
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
     }
 
     #[test]
     fn indy_crypto_cl_revocation_registry_public_to_json_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
 
         let mut rev_reg_pub_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_revocation_registry_public_to_json(rev_reg_pub, &mut rev_reg_pub_json_p);
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_str(rev_reg_pub_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_revocation_registry_public_from_json_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
 
         let mut rev_reg_pub_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_revocation_registry_public_to_json(rev_reg_pub, &mut rev_reg_pub_json_p);
@@ -750,26 +1492,28 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_str(rev_reg_pub_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_revocation_registry_private_to_json_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
 
         let mut rev_reg_priv_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_revocation_registry_private_to_json(rev_reg_priv, &mut rev_reg_priv_json_p);
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_str(rev_reg_priv_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_revocation_registry_private_from_json_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
 
         let mut rev_reg_priv_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_revocation_registry_private_to_json(rev_reg_priv, &mut rev_reg_priv_json_p);
@@ -780,13 +1524,34 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_str(rev_reg_priv_json_p);
+    }
+
+    #[test]
+    #[cfg(feature = "serialization")]
+    fn indy_crypto_cl_revocation_registry_private_from_encrypted_json_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let password = CString::new("password").unwrap();
+
+        let mut rev_reg_priv_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_revocation_registry_private_to_encrypted_json(rev_reg_priv, password.as_ptr(), &mut rev_reg_priv_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut rev_reg_priv_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_registry_private_from_encrypted_json(rev_reg_priv_json_p, password.as_ptr(), &mut rev_reg_priv_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_str(rev_reg_priv_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_revocation_registries_free_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
 
         let err_code = indy_crypto_cl_revocation_registry_public_free(rev_reg_pub);
         assert_eq!(err_code, ErrorCode::Success);
@@ -794,20 +1559,77 @@ mod tests {
         let err_code = indy_crypto_cl_revocation_registry_private_free(rev_reg_priv);
         assert_eq!(err_code, ErrorCode::Success);
 
+        let err_code = indy_crypto_cl_revocation_tails_free(rev_tails);
+        assert_eq!(err_code, ErrorCode::Success);
+
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
     }
 
+    #[test]
+    fn indy_crypto_cl_revocation_accumulator_from_json_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let (rev_acc, rev_acc_pub_key) = _revocation_accumulator(rev_reg_pub);
+
+        let mut rev_acc_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_revocation_accumulator_to_json(rev_acc, &mut rev_acc_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut rev_acc_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_accumulator_from_json(rev_acc_json_p, &mut rev_acc_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut rev_acc_pub_key_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_revocation_accumulator_public_key_to_json(rev_acc_pub_key, &mut rev_acc_pub_key_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut rev_acc_pub_key_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_accumulator_public_key_from_json(rev_acc_pub_key_json_p, &mut rev_acc_pub_key_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_revocation_accumulator(rev_acc, rev_acc_pub_key);
+        _free_revocation_accumulator(rev_acc_p, rev_acc_pub_key_p);
+        _free_str(rev_acc_json_p);
+        _free_str(rev_acc_pub_key_json_p);
+    }
+
+    #[test]
+    fn indy_crypto_cl_revocation_tails_from_json_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+
+        let mut rev_tails_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_revocation_tails_to_json(rev_tails, &mut rev_tails_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut rev_tails_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_tails_from_json(rev_tails_json_p, &mut rev_tails_p);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!rev_tails_p.is_null());
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+
+        let err_code = indy_crypto_cl_revocation_tails_free(rev_tails_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        _free_str(rev_tails_json_p);
+    }
+
     #[test]
     fn indy_crypto_cl_issuer_sign_claim_works() {
         let prover_id = _prover_did();
         let claim_values = _claim_values();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let rev_idx = 1;
 
         let mut claim_signature: *const c_void = ptr::null();
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
         let err_code = indy_crypto_cl_issuer_sign_claim(prover_id.as_ptr(),
                                                         blinded_master_secret,
                                                         claim_values,
@@ -816,26 +1638,113 @@ mod tests {
                                                         rev_idx,
                                                         rev_reg_pub,
                                                         rev_reg_priv,
+                                                        rev_tails_reader,
                                                         &mut claim_signature);
+        _free_tails_reader(rev_tails_reader);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!claim_signature.is_null());
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_claim_values(claim_values);
+        _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
+        _free_master_secret(master_secret);
+        _free_claim_signature(claim_signature);
+    }
+
+    #[test]
+    fn indy_crypto_cl_issuer_sign_claim_with_delta_works() {
+        let prover_id = _prover_did();
+        let claim_values = _claim_values();
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let master_secret = _master_secret();
+        let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
+        let rev_idx = 1;
+
+        let mut claim_signature: *const c_void = ptr::null();
+        let mut rev_reg_delta: *const c_void = ptr::null();
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
+        let err_code = indy_crypto_cl_issuer_sign_claim_with_delta(prover_id.as_ptr(),
+                                                                   blinded_master_secret,
+                                                                   claim_values,
+                                                                   issuer_pub_key,
+                                                                   issuer_priv_key,
+                                                                   rev_idx,
+                                                                   rev_reg_pub,
+                                                                   rev_reg_priv,
+                                                                   rev_tails_reader,
+                                                                   &mut claim_signature,
+                                                                   &mut rev_reg_delta);
+        _free_tails_reader(rev_tails_reader);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(!claim_signature.is_null());
+        assert!(!rev_reg_delta.is_null());
+
+        let err_code = indy_crypto_cl_revocation_registry_public_apply_delta(rev_reg_pub, rev_reg_delta);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_cl_revocation_registry_public_apply_delta(rev_reg_pub, rev_reg_delta);
+        assert_eq!(err_code, ErrorCode::CommonInvalidState);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_claim_values(claim_values);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_master_secret(master_secret);
         _free_claim_signature(claim_signature);
+        _free_revocation_registry_delta(rev_reg_delta);
+    }
+
+    #[test]
+    fn indy_crypto_cl_revocation_registry_public_take_snapshot_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+
+        let err_code = indy_crypto_cl_revocation_registry_public_take_snapshot(rev_reg_pub, 100);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_cl_revocation_registry_public_take_snapshot(rev_reg_pub, 100);
+        assert_eq!(err_code, ErrorCode::CommonInvalidState);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+    }
+
+    #[test]
+    fn indy_crypto_cl_revocation_registry_delta_from_json_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let master_secret = _master_secret();
+        let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
+        let (claim_signature, rev_reg_delta) = _claim_signature_with_delta(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
+
+        let mut rev_reg_delta_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_revocation_registry_delta_to_json(rev_reg_delta, &mut rev_reg_delta_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut rev_reg_delta_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_registry_delta_from_json(rev_reg_delta_json_p, &mut rev_reg_delta_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
+        _free_master_secret(master_secret);
+        _free_claim_signature(claim_signature);
+        _free_revocation_registry_delta(rev_reg_delta);
+        _free_revocation_registry_delta(rev_reg_delta_p);
+        _free_str(rev_reg_delta_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_claim_signature_to_json_works() {
         let claim_values = _claim_values();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
 
 
         let mut claim_signature_json_p: *const c_char = ptr::null();
@@ -843,21 +1752,22 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_claim_values(claim_values);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_master_secret(master_secret);
         _free_claim_signature(claim_signature);
+        _free_str(claim_signature_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_claim_signature_from_json_works() {
         let claim_values = _claim_values();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
 
         let mut claim_signature_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_claim_signature_to_json(claim_signature, &mut claim_signature_json_p);
@@ -868,26 +1778,27 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_claim_values(claim_values);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_master_secret(master_secret);
         _free_claim_signature(claim_signature);
+        _free_str(claim_signature_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_claim_signature_free_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
 
         let err_code = indy_crypto_cl_claim_signature_free(claim_signature);
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_master_secret(master_secret);
     }
@@ -895,16 +1806,18 @@ mod tests {
     #[test]
     fn indy_crypto_cl_issuer_revoke_claim_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
 
-        let err_code = indy_crypto_cl_issuer_revoke_claim(rev_reg_pub, 1);
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
+        let err_code = indy_crypto_cl_issuer_revoke_claim(rev_reg_pub, 1, rev_tails_reader);
+        _free_tails_reader(rev_tails_reader);
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_master_secret(master_secret);
         _free_claim_signature(claim_signature);
@@ -942,33 +1855,61 @@ pub mod mocks {
         assert_eq!(err_code, ErrorCode::Success);
     }
 
-    pub fn _revocation_registry(issuer_pub_key: *const c_void) -> (*const c_void, *const c_void) {
+    pub fn _revocation_registry(issuer_pub_key: *const c_void) -> (*const c_void, *const c_void, *const c_void) {
         let mut rev_reg_pub: *const c_void = ptr::null();
         let mut rev_reg_priv: *const c_void = ptr::null();
+        let mut rev_tails: *const c_void = ptr::null();
 
-        let err_code = indy_crypto_cl_issuer_new_revocation_registry(issuer_pub_key, 100, &mut rev_reg_pub, &mut rev_reg_priv);
+        let err_code = indy_crypto_cl_issuer_new_revocation_registry(issuer_pub_key, 100, &mut rev_reg_pub, &mut rev_reg_priv, &mut rev_tails);
         assert_eq!(err_code, ErrorCode::Success);
         assert!(!rev_reg_pub.is_null());
         assert!(!rev_reg_priv.is_null());
+        assert!(!rev_tails.is_null());
 
-        (rev_reg_pub, rev_reg_priv)
+        (rev_reg_pub, rev_reg_priv, rev_tails)
     }
 
-    pub fn _free_revocation_registry(rev_reg_pub: *const c_void, rev_reg_priv: *const c_void) {
+    pub fn _free_revocation_registry(rev_reg_pub: *const c_void, rev_reg_priv: *const c_void, rev_tails: *const c_void) {
         let err_code = indy_crypto_cl_revocation_registry_public_free(rev_reg_pub);
         assert_eq!(err_code, ErrorCode::Success);
 
         let err_code = indy_crypto_cl_revocation_registry_private_free(rev_reg_priv);
         assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_cl_revocation_tails_free(rev_tails);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
+    pub fn _revocation_accumulator(rev_reg_pub: *const c_void) -> (*const c_void, *const c_void) {
+        let mut rev_acc: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_registry_public_get_accumulator(rev_reg_pub, &mut rev_acc);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!rev_acc.is_null());
+
+        let mut rev_acc_pub_key: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_revocation_registry_public_get_accumulator_key(rev_reg_pub, &mut rev_acc_pub_key);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!rev_acc_pub_key.is_null());
+
+        (rev_acc, rev_acc_pub_key)
+    }
+
+    pub fn _free_revocation_accumulator(rev_acc: *const c_void, rev_acc_pub_key: *const c_void) {
+        let err_code = indy_crypto_cl_revocation_accumulator_free(rev_acc);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let err_code = indy_crypto_cl_revocation_accumulator_public_key_free(rev_acc_pub_key);
+        assert_eq!(err_code, ErrorCode::Success);
     }
 
     pub fn _claim_signature(blinded_master_secret: *const c_void, issuer_pub_key: *const c_void, issuer_priv_key: *const c_void,
-                            rev_reg_pub: *const c_void, rev_reg_priv: *const c_void) -> *const c_void {
+                            rev_reg_pub: *const c_void, rev_reg_priv: *const c_void, rev_tails: *const c_void) -> *const c_void {
         let prover_id = _prover_did();
         let claim_values = _claim_values();
         let rev_idx = 1;
 
         let mut claim_signature: *const c_void = ptr::null();
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
         let err_code = indy_crypto_cl_issuer_sign_claim(prover_id.as_ptr(),
                                                         blinded_master_secret,
                                                         claim_values,
@@ -977,7 +1918,9 @@ pub mod mocks {
                                                         rev_idx,
                                                         rev_reg_pub,
                                                         rev_reg_priv,
+                                                        rev_tails_reader,
                                                         &mut claim_signature);
+        _free_tails_reader(rev_tails_reader);
 
         assert_eq!(err_code, ErrorCode::Success);
         assert!(!claim_signature.is_null());
@@ -987,6 +1930,43 @@ pub mod mocks {
         claim_signature
     }
 
+    pub fn _claim_signature_with_delta(blinded_master_secret: *const c_void, issuer_pub_key: *const c_void, issuer_priv_key: *const c_void,
+                                       rev_reg_pub: *const c_void, rev_reg_priv: *const c_void,
+                                       rev_tails: *const c_void) -> (*const c_void, *const c_void) {
+        let prover_id = _prover_did();
+        let claim_values = _claim_values();
+        let rev_idx = 1;
+
+        let mut claim_signature: *const c_void = ptr::null();
+        let mut rev_reg_delta: *const c_void = ptr::null();
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
+        let err_code = indy_crypto_cl_issuer_sign_claim_with_delta(prover_id.as_ptr(),
+                                                                   blinded_master_secret,
+                                                                   claim_values,
+                                                                   issuer_pub_key,
+                                                                   issuer_priv_key,
+                                                                   rev_idx,
+                                                                   rev_reg_pub,
+                                                                   rev_reg_priv,
+                                                                   rev_tails_reader,
+                                                                   &mut claim_signature,
+                                                                   &mut rev_reg_delta);
+        _free_tails_reader(rev_tails_reader);
+
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!claim_signature.is_null());
+        assert!(!rev_reg_delta.is_null());
+
+        _free_claim_values(claim_values);
+
+        (claim_signature, rev_reg_delta)
+    }
+
+    pub fn _free_revocation_registry_delta(rev_reg_delta: *const c_void) {
+        let err_code = indy_crypto_cl_revocation_registry_delta_free(rev_reg_delta);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+
     pub fn _free_claim_signature(claim_signature: *const c_void) {
         let err_code = indy_crypto_cl_claim_signature_free(claim_signature);
         assert_eq!(err_code, ErrorCode::Success);
@@ -1,5 +1,6 @@
 use cl::*;
 use cl::issuer::Issuer;
+use cl::tails::{self, FileTailsReader, TailsReader};
 use cl::verifier::Verifier;
 use errors::ToErrorCode;
 use ffi::ErrorCode;
@@ -384,13 +385,113 @@ pub extern fn indy_crypto_cl_sub_proof_request_free(sub_proof_request: *const c_
     res
 }
 
+/// Opens tails file and checks it against tails hash of revocation registry.
+///
+/// Tails reader is passed to the functions that need accumulator tails
+/// instead of in-memory revocation accumulator tails instance.
+///
+/// Note that tails reader deallocation must be performed by
+/// calling indy_crypto_cl_tails_reader_free.
+///
+/// # Arguments
+/// * `path` - Path to tails file as null terminated string.
+/// * `rev_reg_pub` - Reference that contains revocation registry public instance pointer.
+/// * `tails_reader_p` - Reference that will contain tails reader instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_tails_reader_open(path: *const c_char,
+                                               rev_reg_pub: *const c_void,
+                                               tails_reader_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_tails_reader_open: >>> path: {:?}, rev_reg_pub: {:?}, tails_reader_p: {:?}", path, rev_reg_pub, tails_reader_p);
+
+    check_useful_c_str!(path, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(tails_reader_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_cl_tails_reader_open: entities: path: {:?}, rev_reg_pub: {:?}", path, rev_reg_pub);
+
+    let res = match FileTailsReader::open(&path, rev_reg_pub) {
+        Ok(tails_reader) => {
+            trace!("indy_crypto_cl_tails_reader_open: tails_reader: {:?}", tails_reader);
+            let tails_reader: Box<TailsReader> = Box::new(tails_reader);
+            unsafe {
+                *tails_reader_p = Box::into_raw(Box::new(tails_reader)) as *const c_void;
+                trace!("indy_crypto_cl_tails_reader_open: *tails_reader_p: {:?}", *tails_reader_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_tails_reader_open: <<< res: {:?}", res);
+    res
+}
+
+/// Creates tails reader over in-memory revocation accumulator tails.
+/// Tails are checked against tails hash of revocation registry and copied into the reader.
+///
+/// Note that tails reader deallocation must be performed by
+/// calling indy_crypto_cl_tails_reader_free.
+///
+/// # Arguments
+/// * `rev_tails` - Reference that contains revocation accumulator tails instance pointer.
+/// * `rev_reg_pub` - Reference that contains revocation registry public instance pointer.
+/// * `tails_reader_p` - Reference that will contain tails reader instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_tails_reader_from_tails(rev_tails: *const c_void,
+                                                     rev_reg_pub: *const c_void,
+                                                     tails_reader_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_tails_reader_from_tails: >>> rev_tails: {:?}, rev_reg_pub: {:?}, tails_reader_p: {:?}", rev_tails, rev_reg_pub, tails_reader_p);
+
+    check_useful_c_reference!(rev_tails, RevocationAccumulatorTails, ErrorCode::CommonInvalidParam1);
+    check_useful_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(tails_reader_p, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_crypto_cl_tails_reader_from_tails: entities: rev_tails: {:?}, rev_reg_pub: {:?}", rev_tails, rev_reg_pub);
+
+    let res = match tails::verify_tails(rev_tails, rev_reg_pub) {
+        Ok(()) => {
+            let tails_reader: Box<TailsReader> = Box::new(rev_tails.clone());
+            unsafe {
+                *tails_reader_p = Box::into_raw(Box::new(tails_reader)) as *const c_void;
+                trace!("indy_crypto_cl_tails_reader_from_tails: *tails_reader_p: {:?}", *tails_reader_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_tails_reader_from_tails: <<< res: {:?}", res);
+    res
+}
+
+/// Deallocates tails reader instance.
+///
+/// # Arguments
+/// * `tails_reader` - Reference that contains tails reader instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_tails_reader_free(tails_reader: *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_tails_reader_free: >>> tails_reader: {:?}", tails_reader);
+
+    check_useful_c_ptr!(tails_reader, ErrorCode::CommonInvalidParam1);
+
+    unsafe { Box::from_raw(tails_reader as *mut Box<TailsReader>); }
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_crypto_cl_tails_reader_free: <<< res: {:?}", res);
+    res
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::env;
     use std::ffi::CString;
     use std::ptr;
     use ffi::cl::mocks::*;
+    use ffi::cl::issuer::indy_crypto_cl_revocation_tails_write_to_file;
+    use ffi::cl::issuer::mocks::*;
 
     #[test]
     fn indy_crypto_cl_claim_schema_builder_new_works() {
@@ -552,6 +653,55 @@ mod tests {
         let err_code = indy_crypto_cl_sub_proof_request_free(sub_proof_request);
         assert_eq!(err_code, ErrorCode::Success);
     }
+
+    #[test]
+    fn indy_crypto_cl_tails_reader_open_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+
+        let path = env::temp_dir().join("indy_crypto_cl_tails_reader_open_works.tails");
+        let path = CString::new(path.to_str().unwrap()).unwrap();
+
+        let err_code = indy_crypto_cl_revocation_tails_write_to_file(rev_tails, 100, path.as_ptr());
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut tails_reader: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_tails_reader_open(path.as_ptr(), rev_reg_pub, &mut tails_reader);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!tails_reader.is_null());
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_tails_reader(tails_reader);
+    }
+
+    #[test]
+    fn indy_crypto_cl_tails_reader_from_tails_works_for_other_registry() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let (other_rev_reg_pub, other_rev_reg_priv, other_rev_tails) = _revocation_registry(issuer_pub_key);
+
+        let mut tails_reader: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_tails_reader_from_tails(other_rev_tails, rev_reg_pub, &mut tails_reader);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_revocation_registry(other_rev_reg_pub, other_rev_reg_priv, other_rev_tails);
+    }
+
+    #[test]
+    fn indy_crypto_cl_tails_reader_free_works() {
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let tails_reader = _tails_reader(rev_tails, rev_reg_pub);
+
+        let err_code = indy_crypto_cl_tails_reader_free(tails_reader);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+    }
 }
 
 pub mod mocks {
@@ -727,4 +877,22 @@ pub mod mocks {
         let err_code = indy_crypto_cl_sub_proof_request_free(sub_proof_request);
         assert_eq!(err_code, ErrorCode::Success);
     }
-}
\ No newline at end of file
+
+    pub fn _free_str(str: *const c_char) {
+        unsafe { CString::from_raw(str as *mut c_char); }
+    }
+
+    pub fn _tails_reader(rev_tails: *const c_void, rev_reg_pub: *const c_void) -> *const c_void {
+        let mut tails_reader: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_tails_reader_from_tails(rev_tails, rev_reg_pub, &mut tails_reader);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!tails_reader.is_null());
+
+        tails_reader
+    }
+
+    pub fn _free_tails_reader(tails_reader: *const c_void) {
+        let err_code = indy_crypto_cl_tails_reader_free(tails_reader);
+        assert_eq!(err_code, ErrorCode::Success);
+    }
+}
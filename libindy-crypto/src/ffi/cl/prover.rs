@@ -1,13 +1,17 @@
 use cl::prover::*;
 use cl::*;
+use cl::tails::TailsReader;
 use errors::ToErrorCode;
 use ffi::ErrorCode;
 use utils::ctypes::CTypesUtils;
+#[cfg(feature = "serialization")]
+use utils::encrypted_json::EncryptedJson;
 use utils::json::{JsonEncodable, JsonDecodable};
 
 use libc::c_char;
 
 use std::os::raw::c_void;
+use std::slice;
 
 /// Creates a master secret.
 ///
@@ -38,6 +42,40 @@ pub extern fn indy_crypto_cl_prover_new_master_secret(master_secret_p: *mut *con
     res
 }
 
+/// Creates a master secret deterministically derived from seed.
+///
+/// Note that master secret deallocation must be performed by
+/// calling indy_crypto_cl_master_secret_free.
+///
+/// # Arguments
+/// * `seed` - Seed buffer pointer (at least 32 bytes of high-entropy data).
+/// * `seed_len` - Seed buffer len.
+/// * `master_secret_p` - Reference that will contain master secret instance pointer.
+#[no_mangle]
+pub extern fn indy_crypto_cl_prover_new_master_secret_from_seed(seed: *const u8,
+                                                                seed_len: usize,
+                                                                master_secret_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_prover_new_master_secret_from_seed: >>> seed: {:?}, seed_len: {:?}, master_secret_p: {:?}", seed, seed_len, master_secret_p);
+
+    check_useful_c_byte_array!(seed, seed_len,
+                               ErrorCode::CommonInvalidParam1, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(master_secret_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match Prover::new_master_secret_from_seed(seed) {
+        Ok(master_secret) => {
+            unsafe {
+                *master_secret_p = Box::into_raw(Box::new(master_secret)) as *const c_void;
+                trace!("indy_crypto_cl_prover_new_master_secret_from_seed: *master_secret_p: {:?}", *master_secret_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_prover_new_master_secret_from_seed: <<< res: {:?}", res);
+    res
+}
+
 /// Returns json representation of master secret.
 ///
 /// # Arguments
@@ -104,6 +142,76 @@ pub extern fn indy_crypto_cl_master_secret_from_json(master_secret_json: *const
     res
 }
 
+/// Returns password-encrypted json representation of master secret.
+///
+/// Key is derived from password by scrypt and json is encrypted by ChaCha20-Poly1305.
+///
+/// # Arguments
+/// * `master_secret` - Reference that contains master secret instance pointer.
+/// * `password` - Password used for encryption.
+/// * `master_secret_json_p` - Reference that will contain encrypted master secret json.
+#[cfg(feature = "serialization")]
+#[no_mangle]
+pub extern fn indy_crypto_cl_master_secret_to_encrypted_json(master_secret: *const c_void,
+                                                             password: *const c_char,
+                                                             master_secret_json_p: *mut *const c_char) -> ErrorCode {
+    trace!("indy_crypto_cl_master_secret_to_encrypted_json: >>> master_secret: {:?}, master_secret_json_p: {:?}", master_secret, master_secret_json_p);
+
+    check_useful_c_reference!(master_secret, MasterSecret, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(password, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(master_secret_json_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match master_secret.to_encrypted_json(&password) {
+        Ok(master_secret_json) => {
+            unsafe {
+                let master_secret_json = CTypesUtils::string_to_cstring(master_secret_json);
+                *master_secret_json_p = master_secret_json.into_raw();
+                trace!("indy_crypto_cl_master_secret_to_encrypted_json: master_secret_json_p: {:?}", *master_secret_json_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_master_secret_to_encrypted_json: <<< res: {:?}", res);
+    res
+}
+
+/// Creates and returns master secret from password-encrypted json.
+///
+/// Note: Master secret instance deallocation must be performed
+/// by calling indy_crypto_cl_master_secret_free
+///
+/// # Arguments
+/// * `master_secret_json` - Reference that contains encrypted master secret json.
+/// * `password` - Password used for encryption.
+/// * `master_secret_p` - Reference that will contain master secret instance pointer.
+#[cfg(feature = "serialization")]
+#[no_mangle]
+pub extern fn indy_crypto_cl_master_secret_from_encrypted_json(master_secret_json: *const c_char,
+                                                               password: *const c_char,
+                                                               master_secret_p: *mut *const c_void) -> ErrorCode {
+    trace!("indy_crypto_cl_master_secret_from_encrypted_json: >>> master_secret_json: {:?}, master_secret_p: {:?}", master_secret_json, master_secret_p);
+
+    check_useful_c_str!(master_secret_json, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(password, ErrorCode::CommonInvalidParam2);
+    check_useful_c_ptr!(master_secret_p, ErrorCode::CommonInvalidParam3);
+
+    let res = match MasterSecret::from_encrypted_json(&master_secret_json, &password) {
+        Ok(master_secret) => {
+            unsafe {
+                *master_secret_p = Box::into_raw(Box::new(master_secret)) as *const c_void;
+                trace!("indy_crypto_cl_master_secret_from_encrypted_json: *master_secret_p: {:?}", *master_secret_p);
+            }
+            ErrorCode::Success
+        }
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_master_secret_from_encrypted_json: <<< res: {:?}", res);
+    res
+}
+
 /// Deallocates master secret instance.
 ///
 /// # Arguments
@@ -248,7 +356,7 @@ pub extern fn indy_crypto_cl_blinded_master_secret_free(blinded_master_secret: *
 
     check_useful_c_ptr!(blinded_master_secret, ErrorCode::CommonInvalidParam1);
 
-    let blinded_master_secret = unsafe { Box::from_raw(blinded_master_secret as *mut MasterSecret); };
+    let blinded_master_secret = unsafe { Box::from_raw(blinded_master_secret as *mut BlindedMasterSecret); };
     trace!("indy_crypto_cl_master_secret_free: entity: blinded_master_secret: {:?}", blinded_master_secret);
 
     let res = ErrorCode::Success;
@@ -420,6 +528,7 @@ pub extern fn indy_crypto_cl_prover_new_proof_builder(proof_builder_p: *mut *con
 /// * `claim_values` - Reference that contain claim values instance pointer.
 /// * `issuer_pub_key` - Reference that contain issuer public key instance pointer.
 /// * `rev_reg_pub` - (Optional) Reference that contain public revocation registry instance pointer.
+/// * `rev_tails_reader` - (Optional) Reference that contain tails reader instance pointer.
 #[no_mangle]
 pub extern fn indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder: *const c_void,
                                                                  key_id: *const c_char,
@@ -428,10 +537,11 @@ pub extern fn indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder:
                                                                  claim_signature: *const c_void,
                                                                  claim_values: *const c_void,
                                                                  issuer_pub_key: *const c_void,
-                                                                 rev_reg_pub: *const c_void) -> ErrorCode {
+                                                                 rev_reg_pub: *const c_void,
+                                                                 rev_tails_reader: *const c_void) -> ErrorCode {
     trace!("indy_crypto_cl_proof_builder_add_sub_proof_request: >>> proof_builder: {:?}, key_id: {:?}, sub_proof_request: {:?}, claim_schema: {:?}, \
-            claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, rev_reg_pub: {:?}",
-           proof_builder, key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key, rev_reg_pub);
+            claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, rev_reg_pub: {:?}, rev_tails_reader: {:?}",
+           proof_builder, key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key, rev_reg_pub, rev_tails_reader);
 
     check_useful_mut_c_reference!(proof_builder, ProofBuilder, ErrorCode::CommonInvalidParam1);
     check_useful_c_str!(key_id, ErrorCode::CommonInvalidParam2);
@@ -441,6 +551,7 @@ pub extern fn indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder:
     check_useful_c_reference!(claim_values, ClaimValues, ErrorCode::CommonInvalidParam6);
     check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam7);
     check_useful_opt_c_reference!(rev_reg_pub, RevocationRegistryPublic);
+    check_useful_opt_c_reference!(rev_tails_reader, Box<TailsReader>);
 
     trace!("indy_crypto_cl_proof_builder_add_sub_proof_request: entities: proof_builder: {:?}, key_id: {:?}, sub_proof_request: {:?}, claim_schema: {:?}, \
             claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, rev_reg_pub: {:?}",
@@ -452,7 +563,8 @@ pub extern fn indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder:
                                                         claim_signature,
                                                         claim_values,
                                                         issuer_pub_key,
-                                                        rev_reg_pub) {
+                                                        rev_reg_pub,
+                                                        rev_tails_reader.map(|r_tails_reader| &**r_tails_reader)) {
         Ok(()) => ErrorCode::Success,
         Err(err) => err.to_error_code()
     };
@@ -461,6 +573,70 @@ pub extern fn indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder:
     res
 }
 
+/// Add sub proof request to proof builder which will prove that claim wasn't revoked
+/// at the moment of accumulator snapshot with given id.
+///
+/// # Arguments
+/// * `proof_builder` - Reference that contain proof builder instance pointer.
+/// * `key_id` - Reference that contains unique claim identifier.
+/// * `sub_proof_request` - Reference that contain requested attributes and predicates instance pointer.
+/// * `claim_schema` - Reference that contain claim schema instance pointer.
+/// * `claim_signature` - Reference that contain claim signature instance pointer.
+/// * `claim_values` - Reference that contain claim values instance pointer.
+/// * `issuer_pub_key` - Reference that contain issuer public key instance pointer.
+/// * `rev_reg_pub` - Reference that contain public revocation registry instance pointer.
+/// * `rev_tails_reader` - Reference that contain tails reader instance pointer.
+/// * `acc_snapshot` - Reference that contain revocation accumulator instance pointer of the snapshot moment.
+/// * `acc_snapshot_id` - Accumulator snapshot id.
+#[no_mangle]
+pub extern fn indy_crypto_cl_proof_builder_add_sub_proof_request_for_snapshot(proof_builder: *const c_void,
+                                                                              key_id: *const c_char,
+                                                                              sub_proof_request: *const c_void,
+                                                                              claim_schema: *const c_void,
+                                                                              claim_signature: *const c_void,
+                                                                              claim_values: *const c_void,
+                                                                              issuer_pub_key: *const c_void,
+                                                                              rev_reg_pub: *const c_void,
+                                                                              rev_tails_reader: *const c_void,
+                                                                              acc_snapshot: *const c_void,
+                                                                              acc_snapshot_id: u64) -> ErrorCode {
+    trace!("indy_crypto_cl_proof_builder_add_sub_proof_request_for_snapshot: >>> proof_builder: {:?}, key_id: {:?}, sub_proof_request: {:?}, claim_schema: {:?}, \
+            claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, rev_reg_pub: {:?}, rev_tails_reader: {:?}, acc_snapshot: {:?}, acc_snapshot_id: {:?}",
+           proof_builder, key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key, rev_reg_pub, rev_tails_reader, acc_snapshot, acc_snapshot_id);
+
+    check_useful_mut_c_reference!(proof_builder, ProofBuilder, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(key_id, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(sub_proof_request, SubProofRequest, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(claim_schema, ClaimSchema, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(claim_signature, ClaimSignature, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(claim_values, ClaimValues, ErrorCode::CommonInvalidParam6);
+    check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam7);
+    check_useful_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam8);
+    check_useful_c_reference!(rev_tails_reader, Box<TailsReader>, ErrorCode::CommonInvalidParam9);
+    check_useful_c_reference!(acc_snapshot, RevocationAccumulator, ErrorCode::CommonInvalidParam10);
+
+    trace!("indy_crypto_cl_proof_builder_add_sub_proof_request_for_snapshot: entities: proof_builder: {:?}, key_id: {:?}, sub_proof_request: {:?}, claim_schema: {:?}, \
+            claim_signature: {:?}, claim_values: {:?}, issuer_pub_key: {:?}, rev_reg_pub: {:?}, acc_snapshot: {:?}",
+           proof_builder, key_id, sub_proof_request, claim_schema, claim_signature, claim_values, issuer_pub_key, rev_reg_pub, acc_snapshot);
+
+    let res = match proof_builder.add_sub_proof_request_for_snapshot(&key_id,
+                                                                     sub_proof_request,
+                                                                     claim_schema,
+                                                                     claim_signature,
+                                                                     claim_values,
+                                                                     issuer_pub_key,
+                                                                     rev_reg_pub,
+                                                                     &**rev_tails_reader,
+                                                                     acc_snapshot,
+                                                                     acc_snapshot_id) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_proof_builder_add_sub_proof_request_for_snapshot: <<< res: {:?}", res);
+    res
+}
+
 
 /// Finalize proof.
 ///
@@ -612,6 +788,25 @@ mod tests {
         _free_master_secret(master_secret_p)
     }
 
+    #[test]
+    fn indy_crypto_cl_prover_new_master_secret_from_seed_works() {
+        let seed = vec![1u8; 32];
+        let mut master_secret_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_prover_new_master_secret_from_seed(seed.as_ptr(), seed.len(), &mut master_secret_p);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(!master_secret_p.is_null());
+
+        _free_master_secret(master_secret_p)
+    }
+
+    #[test]
+    fn indy_crypto_cl_prover_new_master_secret_from_seed_works_for_short_seed() {
+        let seed = vec![1u8; 16];
+        let mut master_secret_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_prover_new_master_secret_from_seed(seed.as_ptr(), seed.len(), &mut master_secret_p);
+        assert_eq!(err_code, ErrorCode::CommonInvalidStructure);
+    }
+
     #[test]
     fn indy_crypto_cl_master_secret_to_json_works() {
         let master_secret = _master_secret();
@@ -620,7 +815,8 @@ mod tests {
         let err_code = indy_crypto_cl_master_secret_to_json(master_secret, &mut master_secret_json_p);
         assert_eq!(err_code, ErrorCode::Success);
 
-        _free_master_secret(master_secret)
+        _free_master_secret(master_secret);
+        _free_str(master_secret_json_p);
     }
 
     #[test]
@@ -635,7 +831,32 @@ mod tests {
         let err_code = indy_crypto_cl_master_secret_from_json(master_secret_json_p, &mut master_secret_p);
         assert_eq!(err_code, ErrorCode::Success);
 
-        _free_master_secret(master_secret)
+        _free_master_secret(master_secret);
+        _free_str(master_secret_json_p);
+    }
+
+    #[test]
+    #[cfg(feature = "serialization")]
+    fn indy_crypto_cl_master_secret_from_encrypted_json_works() {
+        let master_secret = _master_secret();
+        let password = CString::new("password").unwrap();
+
+        let mut master_secret_json_p: *const c_char = ptr::null();
+        let err_code = indy_crypto_cl_master_secret_to_encrypted_json(master_secret, password.as_ptr(), &mut master_secret_json_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut master_secret_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_master_secret_from_encrypted_json(master_secret_json_p, password.as_ptr(), &mut master_secret_p);
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let wrong_password = CString::new("wrong password").unwrap();
+        let mut wrong_master_secret_p: *const c_void = ptr::null();
+        let err_code = indy_crypto_cl_master_secret_from_encrypted_json(master_secret_json_p, wrong_password.as_ptr(), &mut wrong_master_secret_p);
+        assert_eq!(err_code, ErrorCode::CommonDecryptionFailed);
+
+        _free_master_secret(master_secret);
+        _free_master_secret(master_secret_p);
+        _free_str(master_secret_json_p);
     }
 
     #[test]
@@ -681,11 +902,11 @@ mod tests {
     #[test]
     fn indy_crypto_cl_prover_process_claim_signature_signature_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
 
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
 
         let err_code = indy_crypto_cl_prover_process_claim_signature(claim_signature,
                                                                      master_secret_blinding_data,
@@ -694,7 +915,7 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_master_secret(master_secret);
         _free_claim_signature(claim_signature);
@@ -717,16 +938,17 @@ mod tests {
     fn indy_crypto_cl_prover_proof_builder_add_sub_proof_request_works() {
         let uuid = CString::new("uuid").unwrap();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let claim_values = _claim_values();
         let sub_proof_request = _sub_proof_request();
         let claim_schema = _claim_schema();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
         let proof_builder = _proof_builder();
 
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
         let err_code = indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder,
                                                                           uuid.as_ptr(),
                                                                           sub_proof_request,
@@ -734,14 +956,16 @@ mod tests {
                                                                           claim_signature,
                                                                           claim_values,
                                                                           issuer_pub_key,
-                                                                          rev_reg_pub);
+                                                                          rev_reg_pub,
+                                                                          rev_tails_reader);
+        _free_tails_reader(rev_tails_reader);
         assert_eq!(err_code, ErrorCode::Success);
 
         let nonce = _nonce();
 
         _free_proof_builder(proof_builder, nonce, master_secret);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_claim_values(claim_values);
@@ -753,16 +977,17 @@ mod tests {
     fn indy_crypto_cl_prover_proof_builder_finalize_works() {
         let uuid = CString::new("uuid").unwrap();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let claim_values = _claim_values();
         let sub_proof_request = _sub_proof_request();
         let claim_schema = _claim_schema();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
         let proof_builder = _proof_builder();
 
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
         let err_code = indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder,
                                                                           uuid.as_ptr(),
                                                                           sub_proof_request,
@@ -770,7 +995,9 @@ mod tests {
                                                                           claim_signature,
                                                                           claim_values,
                                                                           issuer_pub_key,
-                                                                          rev_reg_pub);
+                                                                          rev_reg_pub,
+                                                                          rev_tails_reader);
+        _free_tails_reader(rev_tails_reader);
         assert_eq!(err_code, ErrorCode::Success);
 
         let nonce = _nonce();
@@ -781,7 +1008,7 @@ mod tests {
         assert!(!proof.is_null());
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_claim_values(claim_values);
@@ -793,37 +1020,38 @@ mod tests {
     #[test]
     fn indy_crypto_cl_proof_to_json_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let nonce = _nonce();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
-        let proof = _proof(issuer_pub_key, rev_reg_pub, claim_signature, nonce, master_secret);
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
 
         let mut proof_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_proof_to_json(proof, &mut proof_json_p);
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_nonce(nonce);
         _free_claim_signature(claim_signature);
         _free_proof(proof);
+        _free_str(proof_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_proof_from_json_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let nonce = _nonce();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
-        let proof = _proof(issuer_pub_key, rev_reg_pub, claim_signature, nonce, master_secret);
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
 
         let mut proof_json_p: *const c_char = ptr::null();
         let err_code = indy_crypto_cl_proof_to_json(proof, &mut proof_json_p);
@@ -834,27 +1062,28 @@ mod tests {
         assert_eq!(err_code, ErrorCode::Success);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_nonce(nonce);
         _free_claim_signature(claim_signature);
         _free_proof(proof);
+        _free_str(proof_json_p);
     }
 
     #[test]
     fn indy_crypto_cl_proof_free_works() {
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let nonce = _nonce();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
-        let proof = _proof(issuer_pub_key, rev_reg_pub, claim_signature, nonce, master_secret);
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_nonce(nonce);
@@ -931,7 +1160,7 @@ pub mod mocks {
         assert!(!proof.is_null());
     }
 
-    pub fn _proof(issuer_pub_key: *const c_void, rev_reg_pub: *const c_void, claim_signature: *const c_void,
+    pub fn _proof(issuer_pub_key: *const c_void, rev_reg_pub: *const c_void, rev_tails: *const c_void, claim_signature: *const c_void,
                   nonce: *const c_void, master_secret: *const c_void) -> *const c_void {
         let proof_builder = _proof_builder();
         let claim_schema = _claim_schema();
@@ -939,6 +1168,7 @@ pub mod mocks {
         let sub_proof_request = _sub_proof_request();
         let key_id = CString::new("key_id").unwrap();
 
+        let rev_tails_reader = _tails_reader(rev_tails, rev_reg_pub);
         indy_crypto_cl_proof_builder_add_sub_proof_request(proof_builder,
                                                            key_id.as_ptr(),
                                                            sub_proof_request,
@@ -946,7 +1176,9 @@ pub mod mocks {
                                                            claim_signature,
                                                            claim_values,
                                                            issuer_pub_key,
-                                                           rev_reg_pub);
+                                                           rev_reg_pub,
+                                                           rev_tails_reader);
+        _free_tails_reader(rev_tails_reader);
 
         let mut proof: *const c_void = ptr::null();
         let err_code = indy_crypto_cl_proof_builder_finalize(proof_builder,
@@ -193,6 +193,105 @@ pub extern fn indy_crypto_cl_proof_verifier_add_sub_proof_request(proof_verifier
     res
 }
 
+/// Add sub proof request to proof verifier which requires proof that claim wasn't revoked
+/// at the moment of accumulator snapshot with given id.
+///
+/// # Arguments
+/// * `proof_verifier` - Reference that contain proof verifier instance pointer.
+/// * `key_id` - Reference that contains unique claim identifier.
+/// * `sub_proof_request` - Reference that contain requested attributes and predicates instance pointer.
+/// * `claim_schema` - Reference that contain claim schema instance pointer.
+/// * `issuer_pub_key` - Reference that contain public key instance pointer.
+/// * `rev_reg_pub` - Reference that contain public revocation registry instance pointer.
+/// * `acc_snapshot_id` - Accumulator snapshot id.
+#[no_mangle]
+pub extern fn indy_crypto_cl_proof_verifier_add_sub_proof_request_for_snapshot(proof_verifier: *const c_void,
+                                                                               key_id: *const c_char,
+                                                                               sub_proof_request: *const c_void,
+                                                                               claim_schema: *const c_void,
+                                                                               issuer_pub_key: *const c_void,
+                                                                               rev_reg_pub: *const c_void,
+                                                                               acc_snapshot_id: u64) -> ErrorCode {
+    trace!("indy_crypto_cl_proof_verifier_add_sub_proof_request_for_snapshot: >>> proof_verifier: {:?}, key_id: {:?}, sub_proof_request: {:?} ,\
+            issuer_pub_key: {:?}, rev_reg_pub: {:?}, acc_snapshot_id: {:?}", proof_verifier, key_id, sub_proof_request, issuer_pub_key, rev_reg_pub, acc_snapshot_id);
+
+    check_useful_mut_c_reference!(proof_verifier, ProofVerifier, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(key_id, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(sub_proof_request, SubProofRequest, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(claim_schema, ClaimSchema, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(rev_reg_pub, RevocationRegistryPublic, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_crypto_cl_proof_verifier_add_sub_proof_request_for_snapshot: entities: proof_verifier: {:?}, key_id: {:?}, sub_proof_request: {:?},\
+            issuer_pub_key: {:?}, rev_reg_pub: {:?}", proof_verifier, key_id, sub_proof_request, issuer_pub_key, rev_reg_pub);
+
+    let res = match proof_verifier.add_sub_proof_request_for_snapshot(&key_id,
+                                                                      sub_proof_request,
+                                                                      claim_schema,
+                                                                      issuer_pub_key,
+                                                                      rev_reg_pub,
+                                                                      acc_snapshot_id) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_proof_verifier_add_sub_proof_request_for_snapshot: <<< res: {:?}", res);
+    res
+}
+
+/// Add sub proof request to proof verifier using only revocation accumulator and its public key
+/// instead of whole public revocation registry.
+///
+/// # Arguments
+/// * `proof_verifier` - Reference that contain proof verifier instance pointer.
+/// * `key_id` - Reference that contains unique claim identifier.
+/// * `sub_proof_request` - Reference that contain requested attributes and predicates instance pointer.
+/// * `claim_schema` - Reference that contain claim schema instance pointer.
+/// * `issuer_pub_key` - Reference that contain public key instance pointer.
+/// * `rev_acc` - Reference that contain revocation accumulator instance pointer.
+/// * `rev_acc_pub_key` - Reference that contain revocation accumulator public key instance pointer.
+/// * `acc_snapshot_id` - (Optional) Reference that contain accumulator snapshot id if accumulator is taken from snapshot.
+#[no_mangle]
+pub extern fn indy_crypto_cl_proof_verifier_add_sub_proof_request_with_accumulator(proof_verifier: *const c_void,
+                                                                                   key_id: *const c_char,
+                                                                                   sub_proof_request: *const c_void,
+                                                                                   claim_schema: *const c_void,
+                                                                                   issuer_pub_key: *const c_void,
+                                                                                   rev_acc: *const c_void,
+                                                                                   rev_acc_pub_key: *const c_void,
+                                                                                   acc_snapshot_id: *const u64) -> ErrorCode {
+    trace!("indy_crypto_cl_proof_verifier_add_sub_proof_request_with_accumulator: >>> proof_verifier: {:?}, key_id: {:?}, sub_proof_request: {:?} ,\
+            issuer_pub_key: {:?}, rev_acc: {:?}, rev_acc_pub_key: {:?}, acc_snapshot_id: {:?}",
+           proof_verifier, key_id, sub_proof_request, issuer_pub_key, rev_acc, rev_acc_pub_key, acc_snapshot_id);
+
+    check_useful_mut_c_reference!(proof_verifier, ProofVerifier, ErrorCode::CommonInvalidParam1);
+    check_useful_c_str!(key_id, ErrorCode::CommonInvalidParam2);
+    check_useful_c_reference!(sub_proof_request, SubProofRequest, ErrorCode::CommonInvalidParam3);
+    check_useful_c_reference!(claim_schema, ClaimSchema, ErrorCode::CommonInvalidParam4);
+    check_useful_c_reference!(issuer_pub_key, IssuerPublicKey, ErrorCode::CommonInvalidParam5);
+    check_useful_c_reference!(rev_acc, RevocationAccumulator, ErrorCode::CommonInvalidParam6);
+    check_useful_c_reference!(rev_acc_pub_key, RevocationAccumulatorPublicKey, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_c_reference!(acc_snapshot_id, u64);
+
+    trace!("indy_crypto_cl_proof_verifier_add_sub_proof_request_with_accumulator: entities: proof_verifier: {:?}, key_id: {:?}, sub_proof_request: {:?},\
+            issuer_pub_key: {:?}, rev_acc: {:?}, rev_acc_pub_key: {:?}, acc_snapshot_id: {:?}",
+           proof_verifier, key_id, sub_proof_request, issuer_pub_key, rev_acc, rev_acc_pub_key, acc_snapshot_id);
+
+    let res = match proof_verifier.add_sub_proof_request_with_accumulator(&key_id,
+                                                                          sub_proof_request,
+                                                                          claim_schema,
+                                                                          issuer_pub_key,
+                                                                          rev_acc,
+                                                                          rev_acc_pub_key,
+                                                                          acc_snapshot_id.cloned()) {
+        Ok(()) => ErrorCode::Success,
+        Err(err) => err.to_error_code()
+    };
+
+    trace!("indy_crypto_cl_proof_verifier_add_sub_proof_request_with_accumulator: <<< res: {:?}", res);
+    res
+}
+
 
 /// Verifies proof and deallocates proof verifier.
 ///
@@ -262,7 +361,8 @@ mod tests {
         let err_code = indy_crypto_cl_nonce_to_json(nonce, &mut nonce_json_p);
         assert_eq!(err_code, ErrorCode::Success);
 
-        _free_nonce(nonce)
+        _free_nonce(nonce);
+        _free_str(nonce_json_p);
     }
 
     #[test]
@@ -277,7 +377,8 @@ mod tests {
         let err_code = indy_crypto_cl_nonce_from_json(nonce_json_p, &mut nonce_p);
         assert_eq!(err_code, ErrorCode::Success);
 
-        _free_nonce(nonce)
+        _free_nonce(nonce);
+        _free_str(nonce_json_p);
     }
 
     #[test]
@@ -292,15 +393,15 @@ mod tests {
     fn indy_crypto_cl_verifier_new_proof_verifier_works() {
         let key_id = CString::new("key_id").unwrap();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let nonce = _nonce();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         let claim_schema = _claim_schema();
         let sub_proof_request = _sub_proof_request();
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
-        let proof = _proof(issuer_pub_key, rev_reg_pub, claim_signature, nonce, master_secret);
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
 
         let mut proof_verifier_p: *const c_void = ptr::null();
         let err_code = indy_crypto_cl_verifier_new_proof_verifier(&mut proof_verifier_p);
@@ -310,7 +411,7 @@ mod tests {
         _add_sub_proof_request(proof_verifier_p, key_id, claim_schema, issuer_pub_key, rev_reg_pub, sub_proof_request);
         _free_proof_verifier(proof_verifier_p, proof, nonce);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_nonce(nonce);
@@ -323,15 +424,15 @@ mod tests {
     fn indy_crypto_cl_proof_verifier_add_sub_proof_request_works() {
         let key_id = CString::new("key_id").unwrap();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let claim_schema = _claim_schema();
         let sub_proof_request = _sub_proof_request();
         let nonce = _nonce();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
-        let proof = _proof(issuer_pub_key, rev_reg_pub, claim_signature, nonce, master_secret);
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
         let proof_verifier = _proof_verifier();
 
         let err_code = indy_crypto_cl_proof_verifier_add_sub_proof_request(proof_verifier,
@@ -344,7 +445,49 @@ mod tests {
 
         _free_proof_verifier(proof_verifier, proof, nonce);
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_master_secret(master_secret);
+        _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
+        _free_nonce(nonce);
+        _free_claim_schema(claim_schema);
+        _free_sub_proof_request(sub_proof_request);
+        _free_claim_signature(claim_signature);
+    }
+
+    #[test]
+    fn indy_crypto_cl_proof_verifier_add_sub_proof_request_with_accumulator_works() {
+        let key_id = CString::new("key_id").unwrap();
+        let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
+        let master_secret = _master_secret();
+        let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
+        let claim_schema = _claim_schema();
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
+        _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
+        let sub_proof_request = _sub_proof_request();
+        let nonce = _nonce();
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
+        let (rev_acc, rev_acc_pub_key) = _revocation_accumulator(rev_reg_pub);
+        let proof_verifier = _proof_verifier();
+
+        let err_code = indy_crypto_cl_proof_verifier_add_sub_proof_request_with_accumulator(proof_verifier,
+                                                                                            key_id.as_ptr(),
+                                                                                            sub_proof_request,
+                                                                                            claim_schema,
+                                                                                            issuer_pub_key,
+                                                                                            rev_acc,
+                                                                                            rev_acc_pub_key,
+                                                                                            ptr::null());
+        assert_eq!(err_code, ErrorCode::Success);
+
+        let mut valid = false;
+        let err_code = indy_crypto_cl_proof_verifier_verify(proof_verifier, proof, nonce, &mut valid);
+        assert_eq!(err_code, ErrorCode::Success);
+        assert!(valid);
+
+        _free_issuer_keys(issuer_pub_key, issuer_priv_key);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
+        _free_revocation_accumulator(rev_acc, rev_acc_pub_key);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_nonce(nonce);
@@ -357,15 +500,15 @@ mod tests {
     fn indy_crypto_cl_proof_verifier_verify_works() {
         let key_id = CString::new("key_id").unwrap();
         let (issuer_pub_key, issuer_priv_key) = _issuer_keys();
-        let (rev_reg_pub, rev_reg_priv) = _revocation_registry(issuer_pub_key);
+        let (rev_reg_pub, rev_reg_priv, rev_tails) = _revocation_registry(issuer_pub_key);
         let master_secret = _master_secret();
         let (blinded_master_secret, master_secret_blinding_data) = _blinded_master_secret(issuer_pub_key, master_secret);
         let claim_schema = _claim_schema();
-        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv);
+        let claim_signature = _claim_signature(blinded_master_secret, issuer_pub_key, issuer_priv_key, rev_reg_pub, rev_reg_priv, rev_tails);
         _process_claim_signature(claim_signature, master_secret_blinding_data, issuer_pub_key, rev_reg_pub);
         let sub_proof_request = _sub_proof_request();
         let nonce = _nonce();
-        let proof = _proof(issuer_pub_key, rev_reg_pub, claim_signature, nonce, master_secret);
+        let proof = _proof(issuer_pub_key, rev_reg_pub, rev_tails, claim_signature, nonce, master_secret);
         let proof_verifier = _proof_verifier();
 
         let err_code = indy_crypto_cl_proof_verifier_add_sub_proof_request(proof_verifier,
@@ -382,7 +525,7 @@ mod tests {
         assert!(valid); //TODO: Uncomment
 
         _free_issuer_keys(issuer_pub_key, issuer_priv_key);
-        _free_revocation_registry(rev_reg_pub, rev_reg_priv);
+        _free_revocation_registry(rev_reg_pub, rev_reg_priv, rev_tails);
         _free_master_secret(master_secret);
         _free_blinded_master_secret(blinded_master_secret, master_secret_blinding_data);
         _free_nonce(nonce);
@@ -56,6 +56,10 @@ pub enum ErrorCode
     // IO Error
     CommonIOError = 114,
 
+    // Encrypted data can't be decrypted: wrong password or corrupted data
+    // (codes 115-119 were already taken by Anoncreds errors)
+    CommonDecryptionFailed = 120,
+
     // Trying to issue non-revocation claim with full anoncreds revocation accumulator
     AnoncredsRevocationAccumulatorIsFull = 115,
 
@@ -67,6 +71,9 @@ pub enum ErrorCode
 
     // Proof rejected
     AnoncredsProofRejected = 118,
+
+    // Trying to issue non-revocation claim with revocation accumulator index that is already used
+    AnoncredsRevocationAccumulatorIndexAlreadyUsed = 119,
 }
 
 #[no_mangle]
@@ -0,0 +1,756 @@
+use bn::BigNumber;
+use errors::IndyCryptoError;
+
+use sha2::{Sha256, Digest};
+
+/// Output size of SHA-256 in bytes (`b_in_bytes` in RFC 9380).
+const SHA256_OUTPUT_LEN: usize = 32;
+/// Input block size of SHA-256 in bytes (`s_in_bytes` in RFC 9380).
+const SHA256_BLOCK_LEN: usize = 64;
+/// Target security level in bits (`k` in RFC 9380).
+const SECURITY_LEVEL: usize = 128;
+/// Upper bound for the search of the SvdW `Z` constant.
+const SVDW_Z_SEARCH_LIMIT: usize = 1000;
+
+/// Expands the message into `len_in_bytes` uniformly random bytes as defined
+/// by `expand_message_xmd` in RFC 9380 (section 5.3.1) instantiated with SHA-256.
+///
+/// # Arguments
+///
+/// * `message` - Message to expand
+/// * `dst` - Domain separation tag, from 1 to 255 bytes
+/// * `len_in_bytes` - Length of the output, at most 255 * 32 bytes
+///
+/// # Example
+///
+/// ```
+/// use indy_crypto::hash_to_curve::expand_message_xmd;
+/// let bytes = expand_message_xmd(b"abc", b"QUUX-V01-CS02-with-expander-SHA256-128", 32).unwrap();
+/// assert_eq!(bytes.len(), 32);
+/// ```
+pub fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, IndyCryptoError> {
+    trace!("expand_message_xmd: >>> message: {:?}, dst: {:?}, len_in_bytes: {:?}", message, dst, len_in_bytes);
+
+    if dst.is_empty() || dst.len() > 255 {
+        return Err(IndyCryptoError::InvalidStructure(
+            format!("Invalid len of domain separation tag: expected from 1 to 255, actual {}", dst.len())));
+    }
+
+    let ell = (len_in_bytes + SHA256_OUTPUT_LEN - 1) / SHA256_OUTPUT_LEN;
+
+    if len_in_bytes == 0 || ell > 255 || len_in_bytes > 65535 {
+        return Err(IndyCryptoError::InvalidStructure(
+            format!("Invalid len of expanded message: {}", len_in_bytes)));
+    }
+
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+
+    let mut hasher = Sha256::default();
+    hasher.input(&[0u8; SHA256_BLOCK_LEN]);
+    hasher.input(message);
+    hasher.input(&[(len_in_bytes >> 8) as u8, len_in_bytes as u8, 0u8]);
+    hasher.input(&dst_prime);
+    let b_0 = hasher.result().to_vec();
+
+    let mut hasher = Sha256::default();
+    hasher.input(&b_0);
+    hasher.input(&[1u8]);
+    hasher.input(&dst_prime);
+    let mut b_i = hasher.result().to_vec();
+
+    let mut uniform_bytes = b_i.clone();
+
+    for i in 2..(ell + 1) {
+        let xored: Vec<u8> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+
+        let mut hasher = Sha256::default();
+        hasher.input(&xored);
+        hasher.input(&[i as u8]);
+        hasher.input(&dst_prime);
+        b_i = hasher.result().to_vec();
+
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+
+    trace!("expand_message_xmd: <<< uniform_bytes: {:?}", uniform_bytes);
+
+    Ok(uniform_bytes)
+}
+
+/// Hashes the message to `count` elements of the prime field GF(p) as defined
+/// by `hash_to_field` in RFC 9380 (section 5.2) using `expand_message_xmd` with SHA-256.
+///
+/// # Arguments
+///
+/// * `message` - Message to hash
+/// * `dst` - Domain separation tag
+/// * `count` - Number of field elements to output
+/// * `p` - Field modulus
+pub fn hash_to_field(message: &[u8], dst: &[u8], count: usize, p: &BigNumber) -> Result<Vec<BigNumber>, IndyCryptoError> {
+    trace!("hash_to_field: >>> message: {:?}, dst: {:?}, count: {:?}, p: {:?}", message, dst, count, p);
+
+    let l = (p.num_bits()? as usize + SECURITY_LEVEL + 7) / 8;
+    let uniform_bytes = expand_message_xmd(message, dst, count * l)?;
+
+    let mut elements: Vec<BigNumber> = Vec::with_capacity(count);
+
+    for chunk in uniform_bytes.chunks(l) {
+        elements.push(BigNumber::from_bytes(chunk)?.modulus(p, None)?);
+    }
+
+    trace!("hash_to_field: <<< elements: {:?}", elements);
+
+    Ok(elements)
+}
+
+/// Shallue-van de Woestijne map (RFC 9380, section 6.6.1) for curves
+/// `y^2 = x^3 + B` over GF(p) with `p = 3 mod 4`.
+///
+/// The simplified SWU map requires `A != 0`, so for pairing friendly curves of
+/// this form it's only applicable through an isogenous curve, see `SswuMap` and `IsogenyMap`.
+#[derive(Debug)]
+pub struct SvdwMap {
+    p: BigNumber,
+    b: BigNumber,
+    z: BigNumber,
+    c1: BigNumber,
+    c2: BigNumber,
+    c3: BigNumber,
+    c4: BigNumber
+}
+
+impl SvdwMap {
+    /// Creates the map for the curve `y^2 = x^3 + B` over GF(p).
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Field modulus, must be equal to 3 mod 4
+    /// * `b` - Curve coefficient B
+    pub fn new(p: &BigNumber, b: &BigNumber) -> Result<SvdwMap, IndyCryptoError> {
+        trace!("SvdwMap::new: >>> p: {:?}, b: {:?}", p, b);
+
+        if !p.is_bit_set(0)? || !p.is_bit_set(1)? {
+            return Err(IndyCryptoError::InvalidStructure("Field modulus must be equal to 3 mod 4".to_string()));
+        }
+
+        let field = Field { p };
+        let b = b.modulus(p, None)?;
+
+        let z = SvdwMap::_find_z(&field, &b)?;
+        let gz = field.curve_rhs(&z, &b)?;
+        let three_z2 = field.mul(&BigNumber::from_u32(3)?, &field.sqr(&z)?)?;
+
+        // c1 = g(Z)
+        let c1 = gz.clone()?;
+        // c2 = -Z / 2
+        let c2 = field.neg(&field.mul(&z, &field.inv0(&BigNumber::from_u32(2)?)?)?)?;
+        // c3 = sqrt(-g(Z) * 3 * Z^2), sgn0(c3) == 0
+        let mut c3 = field.sqrt(&field.neg(&field.mul(&gz, &three_z2)?)?)?
+            .ok_or(IndyCryptoError::InvalidStructure("Invalid SvdW constant Z".to_string()))?;
+        if field.sgn0(&c3)? {
+            c3 = field.neg(&c3)?;
+        }
+        // c4 = -4 * g(Z) / (3 * Z^2)
+        let c4 = field.neg(&field.mul(&field.mul(&BigNumber::from_u32(4)?, &gz)?, &field.inv0(&three_z2)?)?)?;
+
+        let map = SvdwMap {
+            p: p.clone()?,
+            b,
+            z,
+            c1,
+            c2,
+            c3,
+            c4
+        };
+
+        trace!("SvdwMap::new: <<< map: {:?}", map);
+
+        Ok(map)
+    }
+
+    /// Maps the field element to an affine point `(x, y)` of the curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - Field element, usually an output of `hash_to_field`
+    pub fn map_to_curve(&self, u: &BigNumber) -> Result<(BigNumber, BigNumber), IndyCryptoError> {
+        trace!("SvdwMap::map_to_curve: >>> u: {:?}", u);
+
+        let field = Field { p: &self.p };
+        let one = BigNumber::from_u32(1)?;
+        let u = u.modulus(&self.p, None)?;
+
+        let tv1 = field.mul(&field.sqr(&u)?, &self.c1)?;
+        let tv2 = field.add(&one, &tv1)?;
+        let tv1 = field.sub(&one, &tv1)?;
+        let tv3 = field.inv0(&field.mul(&tv1, &tv2)?)?;
+        let tv4 = field.mul(&field.mul(&field.mul(&u, &tv1)?, &tv3)?, &self.c3)?;
+
+        let x1 = field.sub(&self.c2, &tv4)?;
+        let x2 = field.add(&self.c2, &tv4)?;
+
+        let x = if field.is_square(&field.curve_rhs(&x1, &self.b)?)? {
+            x1
+        } else if field.is_square(&field.curve_rhs(&x2, &self.b)?)? {
+            x2
+        } else {
+            let x3 = field.sqr(&field.mul(&field.sqr(&tv2)?, &tv3)?)?;
+            field.add(&field.mul(&x3, &self.c4)?, &self.z)?
+        };
+
+        let mut y = field.sqrt(&field.curve_rhs(&x, &self.b)?)?
+            .ok_or(IndyCryptoError::InvalidState("SvdW map produced x without a square root".to_string()))?;
+
+        if field.sgn0(&u)? != field.sgn0(&y)? {
+            y = field.neg(&y)?;
+        }
+
+        trace!("SvdwMap::map_to_curve: <<< x: {:?}, y: {:?}", x, y);
+
+        Ok((x, y))
+    }
+
+    fn _find_z(field: &Field, b: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        let zero = BigNumber::new()?;
+
+        for ctr in 1..SVDW_Z_SEARCH_LIMIT {
+            let ctr = BigNumber::from_u32(ctr)?;
+
+            for z in &[ctr.clone()?, field.neg(&ctr)?] {
+                let gz = field.curve_rhs(z, b)?;
+                if gz == zero {
+                    continue;
+                }
+
+                // -(3 * Z^2) / (4 * g(Z)) must be a nonzero square
+                let three_z2 = field.mul(&BigNumber::from_u32(3)?, &field.sqr(z)?)?;
+                let h = field.neg(&field.mul(&three_z2, &field.inv0(&field.mul(&BigNumber::from_u32(4)?, &gz)?)?)?)?;
+                if h == zero || !field.is_square(&h)? {
+                    continue;
+                }
+
+                let minus_z_half = field.neg(&field.mul(z, &field.inv0(&BigNumber::from_u32(2)?)?)?)?;
+                if field.is_square(&gz)? || field.is_square(&field.curve_rhs(&minus_z_half, b)?)? {
+                    return Ok(z.clone()?);
+                }
+            }
+        }
+
+        Err(IndyCryptoError::InvalidStructure("Unable to find SvdW constant Z".to_string()))
+    }
+}
+
+/// Simplified Shallue-van de Woestijne-Ulas map (RFC 9380, section 6.6.2) for curves
+/// `y^2 = x^3 + A * x + B` with `A * B != 0` over GF(p) with `p = 3 mod 4`.
+#[derive(Debug)]
+pub struct SswuMap {
+    p: BigNumber,
+    a: BigNumber,
+    b: BigNumber,
+    z: BigNumber,
+    c1: BigNumber,
+    c2: BigNumber
+}
+
+impl SswuMap {
+    /// Creates the map for the curve `y^2 = x^3 + A * x + B` over GF(p).
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Field modulus, must be equal to 3 mod 4
+    /// * `a` - Curve coefficient A, must be nonzero
+    /// * `b` - Curve coefficient B, must be nonzero
+    /// * `z` - Non-square constant Z of the suite
+    pub fn new(p: &BigNumber, a: &BigNumber, b: &BigNumber, z: &BigNumber) -> Result<SswuMap, IndyCryptoError> {
+        trace!("SswuMap::new: >>> p: {:?}, a: {:?}, b: {:?}, z: {:?}", p, a, b, z);
+
+        if !p.is_bit_set(0)? || !p.is_bit_set(1)? {
+            return Err(IndyCryptoError::InvalidStructure("Field modulus must be equal to 3 mod 4".to_string()));
+        }
+
+        let field = Field { p };
+        let zero = BigNumber::new()?;
+        let a = a.modulus(p, None)?;
+        let b = b.modulus(p, None)?;
+        let z = z.modulus(p, None)?;
+
+        if a == zero || b == zero {
+            return Err(IndyCryptoError::InvalidStructure("Curve coefficients A and B must be nonzero".to_string()));
+        }
+
+        // c1 = -B / A
+        let c1 = field.neg(&field.mul(&b, &field.inv0(&a)?)?)?;
+        // c2 = B / (Z * A)
+        let c2 = field.mul(&b, &field.inv0(&field.mul(&z, &a)?)?)?;
+
+        if z == zero || field.is_square(&z)? || !field.is_square(&field.curve_rhs_with_a(&c2, &a, &b)?)? {
+            return Err(IndyCryptoError::InvalidStructure("Invalid SSWU constant Z".to_string()));
+        }
+
+        let map = SswuMap {
+            p: p.clone()?,
+            a,
+            b,
+            z,
+            c1,
+            c2
+        };
+
+        trace!("SswuMap::new: <<< map: {:?}", map);
+
+        Ok(map)
+    }
+
+    /// Maps the field element to an affine point `(x, y)` of the curve.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - Field element, usually an output of `hash_to_field`
+    pub fn map_to_curve(&self, u: &BigNumber) -> Result<(BigNumber, BigNumber), IndyCryptoError> {
+        trace!("SswuMap::map_to_curve: >>> u: {:?}", u);
+
+        let field = Field { p: &self.p };
+        let one = BigNumber::from_u32(1)?;
+        let u = u.modulus(&self.p, None)?;
+
+        let z_u2 = field.mul(&self.z, &field.sqr(&u)?)?;
+        let tv1 = field.inv0(&field.add(&field.sqr(&z_u2)?, &z_u2)?)?;
+
+        let x1 = if tv1 == BigNumber::new()? {
+            self.c2.clone()?
+        } else {
+            field.mul(&self.c1, &field.add(&one, &tv1)?)?
+        };
+        let gx1 = field.curve_rhs_with_a(&x1, &self.a, &self.b)?;
+
+        let (x, gx) = if field.is_square(&gx1)? {
+            (x1, gx1)
+        } else {
+            let x2 = field.mul(&z_u2, &x1)?;
+            let gx2 = field.curve_rhs_with_a(&x2, &self.a, &self.b)?;
+            (x2, gx2)
+        };
+
+        let mut y = field.sqrt(&gx)?
+            .ok_or(IndyCryptoError::InvalidState("SSWU map produced x without a square root".to_string()))?;
+
+        if field.sgn0(&u)? != field.sgn0(&y)? {
+            y = field.neg(&y)?;
+        }
+
+        trace!("SswuMap::map_to_curve: <<< x: {:?}, y: {:?}", x, y);
+
+        Ok((x, y))
+    }
+}
+
+/// Rational map `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))` over GF(p)
+/// used to move points of `SswuMap` to the target curve (RFC 9380, section 6.6.3).
+#[derive(Debug)]
+pub struct IsogenyMap {
+    p: BigNumber,
+    x_num: Vec<BigNumber>,
+    x_den: Vec<BigNumber>,
+    y_num: Vec<BigNumber>,
+    y_den: Vec<BigNumber>
+}
+
+impl IsogenyMap {
+    /// Creates the map from polynomial coefficients listed from the constant term up,
+    /// i.e. in the order of `k_(i,0), k_(i,1), ...` constants of RFC 9380 appendix E.
+    /// Leading coefficients of monic denominators must be listed too.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Field modulus
+    /// * `x_num` - Coefficients of x numerator
+    /// * `x_den` - Coefficients of x denominator
+    /// * `y_num` - Coefficients of y numerator
+    /// * `y_den` - Coefficients of y denominator
+    pub fn new(p: &BigNumber,
+               x_num: Vec<BigNumber>,
+               x_den: Vec<BigNumber>,
+               y_num: Vec<BigNumber>,
+               y_den: Vec<BigNumber>) -> Result<IsogenyMap, IndyCryptoError> {
+        trace!("IsogenyMap::new: >>> p: {:?}, x_num: {:?}, x_den: {:?}, y_num: {:?}, y_den: {:?}", p, x_num, x_den, y_num, y_den);
+
+        if x_num.is_empty() || x_den.is_empty() || y_num.is_empty() || y_den.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure("Isogeny map polynomials must be nonempty".to_string()));
+        }
+
+        let map = IsogenyMap {
+            p: p.clone()?,
+            x_num,
+            x_den,
+            y_num,
+            y_den
+        };
+
+        trace!("IsogenyMap::new: <<< map: {:?}", map);
+
+        Ok(map)
+    }
+
+    /// Maps the affine point of the source curve to an affine point of the target curve
+    /// or None for the identity, which is the image of the kernel points of the isogeny.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Affine point `(x, y)` of the source curve
+    pub fn map(&self, (x, y): (BigNumber, BigNumber)) -> Result<Option<(BigNumber, BigNumber)>, IndyCryptoError> {
+        trace!("IsogenyMap::map: >>> x: {:?}, y: {:?}", x, y);
+
+        let field = Field { p: &self.p };
+        let zero = BigNumber::new()?;
+
+        let x_den = field.poly(&self.x_den, &x)?;
+        let y_den = field.poly(&self.y_den, &x)?;
+
+        let point = if x_den == zero || y_den == zero {
+            None
+        } else {
+            let x_mapped = field.mul(&field.poly(&self.x_num, &x)?, &field.inv0(&x_den)?)?;
+            let y_mapped = field.mul(&field.mul(&y, &field.poly(&self.y_num, &x)?)?, &field.inv0(&y_den)?)?;
+            Some((x_mapped, y_mapped))
+        };
+
+        trace!("IsogenyMap::map: <<< point: {:?}", point);
+
+        Ok(point)
+    }
+}
+
+/// Arithmetic in GF(p) over reduced BigNumbers.
+struct Field<'a> {
+    p: &'a BigNumber
+}
+
+impl<'a> Field<'a> {
+    fn add(&self, a: &BigNumber, b: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        a.add(b)?.modulus(self.p, None)
+    }
+
+    fn sub(&self, a: &BigNumber, b: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        a.sub(b)?.modulus(self.p, None)
+    }
+
+    fn neg(&self, a: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        self.sub(&BigNumber::new()?, a)
+    }
+
+    fn mul(&self, a: &BigNumber, b: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        a.mul(b, None)?.modulus(self.p, None)
+    }
+
+    fn sqr(&self, a: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        a.sqr(None)?.modulus(self.p, None)
+    }
+
+    fn pow(&self, a: &BigNumber, e: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        a.mod_exp(e, self.p, None)
+    }
+
+    /// Inverse of `a`, or 0 when `a == 0`.
+    fn inv0(&self, a: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        if *a == BigNumber::new()? {
+            return BigNumber::new();
+        }
+        a.inverse(self.p, None)
+    }
+
+    /// Legendre symbol test, 0 is considered to be a square.
+    fn is_square(&self, a: &BigNumber) -> Result<bool, IndyCryptoError> {
+        if *a == BigNumber::new()? {
+            return Ok(true);
+        }
+        let e = self.p.sub(&BigNumber::from_u32(1)?)?.div(&BigNumber::from_u32(2)?, None)?;
+        Ok(self.pow(a, &e)? == BigNumber::from_u32(1)?)
+    }
+
+    /// Square root for `p = 3 mod 4` or None if `a` isn't a square.
+    fn sqrt(&self, a: &BigNumber) -> Result<Option<BigNumber>, IndyCryptoError> {
+        let e = self.p.add(&BigNumber::from_u32(1)?)?.div(&BigNumber::from_u32(4)?, None)?;
+        let root = self.pow(a, &e)?;
+
+        if self.sqr(&root)? != a.modulus(self.p, None)? {
+            return Ok(None);
+        }
+        Ok(Some(root))
+    }
+
+    /// Sign of the element as defined by `sgn0` for m = 1.
+    fn sgn0(&self, a: &BigNumber) -> Result<bool, IndyCryptoError> {
+        a.modulus(self.p, None)?.is_bit_set(0)
+    }
+
+    /// g(x) = x^3 + B
+    fn curve_rhs(&self, x: &BigNumber, b: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        self.add(&self.mul(&self.sqr(x)?, x)?, b)
+    }
+
+    /// g(x) = x^3 + A * x + B
+    fn curve_rhs_with_a(&self, x: &BigNumber, a: &BigNumber, b: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        self.add(&self.curve_rhs(x, b)?, &self.mul(a, x)?)
+    }
+
+    /// Evaluates the polynomial with coefficients listed from the constant term up.
+    fn poly(&self, coeffs: &[BigNumber], x: &BigNumber) -> Result<BigNumber, IndyCryptoError> {
+        let mut res = BigNumber::new()?;
+        for coeff in coeffs.iter().rev() {
+            res = self.add(&self.mul(&res, x)?, coeff)?;
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
+
+    // Test vectors from RFC 9380, appendix K.1
+    const EXPANDER_DST: &'static [u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    // BN254 curve y^2 = x^3 + 2 used by the amcl backend
+    const BN254_P: &'static str = "2523648240000001BA344D80000000086121000000000013A700000000000013";
+
+    fn _bn254_map() -> SvdwMap {
+        let p = BigNumber::from_hex(BN254_P).unwrap();
+        SvdwMap::new(&p, &BigNumber::from_u32(2).unwrap()).unwrap()
+    }
+
+    // Curve y^2 = x^3 + A' * x + B' 11-isogenous to BLS12-381 G1 (RFC 9380, section 8.8.1)
+    const BLS12_381_P: &'static str = "1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB";
+    const BLS12_381_ISO_A: &'static str = "144698A3B8E9433D693A02C96D4982B0EA985383EE66A8D8E8981AEFD881AC98936F8DA0E0F97F5CF428082D584C1D";
+    const BLS12_381_ISO_B: &'static str = "12E2908D11688030018B12E8753EEE3B2016C1F0F24F4070A0B9C14FCEF35EF55A23215A316CEAA5D1CC48E98E172BE0";
+
+    fn _bls12_381_iso_map() -> SswuMap {
+        let p = BigNumber::from_hex(BLS12_381_P).unwrap();
+        SswuMap::new(&p,
+                     &BigNumber::from_hex(BLS12_381_ISO_A).unwrap(),
+                     &BigNumber::from_hex(BLS12_381_ISO_B).unwrap(),
+                     &BigNumber::from_u32(11).unwrap()).unwrap()
+    }
+
+    fn _hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_empty_message() {
+        let bytes = expand_message_xmd(b"", EXPANDER_DST, 0x20).unwrap();
+        assert_eq!("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235", _hex(&bytes));
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_short_messages() {
+        let bytes = expand_message_xmd(b"abc", EXPANDER_DST, 0x20).unwrap();
+        assert_eq!("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615", _hex(&bytes));
+
+        let bytes = expand_message_xmd(b"abcdef0123456789", EXPANDER_DST, 0x20).unwrap();
+        assert_eq!("eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1", _hex(&bytes));
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_long_messages() {
+        let mut message = b"q128_".to_vec();
+        message.extend_from_slice(&[b'q'; 128]);
+        let bytes = expand_message_xmd(&message, EXPANDER_DST, 0x20).unwrap();
+        assert_eq!("b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9", _hex(&bytes));
+
+        let mut message = b"a512_".to_vec();
+        message.extend_from_slice(&[b'a'; 512]);
+        let bytes = expand_message_xmd(&message, EXPANDER_DST, 0x20).unwrap();
+        assert_eq!("4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c", _hex(&bytes));
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_long_output() {
+        let bytes = expand_message_xmd(b"", EXPANDER_DST, 0x80).unwrap();
+        assert_eq!("af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                    e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                    eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                    c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced", _hex(&bytes));
+
+        let bytes = expand_message_xmd(b"abc", EXPANDER_DST, 0x80).unwrap();
+        assert_eq!("abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a\
+                    647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635\
+                    bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00\
+                    058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40", _hex(&bytes));
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_invalid_dst() {
+        let err = expand_message_xmd(b"abc", b"", 0x20).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+
+        let err = expand_message_xmd(b"abc", &[0u8; 256], 0x20).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn expand_message_xmd_works_for_invalid_len() {
+        let err = expand_message_xmd(b"abc", EXPANDER_DST, 256 * 32).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    // Checks constants against their definitions in RFC 9380, section 6.6.1,
+    // with plain BigNumber arithmetic instead of the map's own field helpers
+    fn _check_svdw_constants(map: &SvdwMap) {
+        let p = &map.p;
+        let n = |v: usize| BigNumber::from_u32(v).unwrap();
+        let reduce = |v: BigNumber| v.modulus(p, None).unwrap();
+        let zero = BigNumber::new().unwrap();
+
+        let z2 = map.z.sqr(None).unwrap();
+        let gz = reduce(z2.mul(&map.z, None).unwrap().add(&map.b).unwrap());
+        let three_z2 = reduce(z2.mul(&n(3), None).unwrap());
+
+        // c1 = g(Z)
+        assert_eq!(gz, map.c1);
+        // 2 * c2 = -Z
+        assert_eq!(zero, reduce(map.c2.mul(&n(2), None).unwrap().add(&map.z).unwrap()));
+        // c3^2 = -g(Z) * 3 * Z^2 and sgn0(c3) == 0
+        assert_eq!(zero, reduce(map.c3.sqr(None).unwrap().add(&gz.mul(&three_z2, None).unwrap()).unwrap()));
+        assert!(!map.c3.is_bit_set(0).unwrap());
+        // 3 * Z^2 * c4 = -4 * g(Z)
+        assert_eq!(zero, reduce(map.c4.mul(&three_z2, None).unwrap().add(&gz.mul(&n(4), None).unwrap()).unwrap()));
+    }
+
+    fn _is_square(a: &BigNumber, p: &BigNumber) -> bool {
+        let e = p.sub(&BigNumber::from_u32(1).unwrap()).unwrap().div(&BigNumber::from_u32(2).unwrap(), None).unwrap();
+        a.mod_exp(&e, p, None).unwrap() == BigNumber::from_u32(1).unwrap()
+    }
+
+    #[test]
+    fn svdw_map_new_works_for_bn254() {
+        let map = _bn254_map();
+        let p = BigNumber::from_hex(BN254_P).unwrap();
+        _check_svdw_constants(&map);
+
+        // RFC 9380, appendix H.1 tries Z = 1 first: it's rejected as -(3 * 1) / (4 * g(1)) = -1/4
+        // isn't a square for p = 3 mod 4. Z = -1 is accepted: -(3 * 1) / (4 * g(-1)) = -3/4
+        // is a square for p = 1 mod 3 and g(-1) = 1 is a square.
+        let minus_one = p.sub(&BigNumber::from_u32(1).unwrap()).unwrap();
+        let minus_three = p.sub(&BigNumber::from_u32(3).unwrap()).unwrap();
+        assert!(!_is_square(&minus_one, &p));
+        assert!(_is_square(&minus_three, &p));
+        assert_eq!(BigNumber::from_u32(1).unwrap(), p.modulus(&BigNumber::from_u32(3).unwrap(), None).unwrap());
+
+        assert_eq!(p.sub(&BigNumber::from_u32(1).unwrap()).unwrap(), map.z);
+        assert_eq!(BigNumber::from_u32(1).unwrap(), map.c1);
+        assert_eq!(BigNumber::from_hex("1291B24120000000DD1A26C0000000043090800000000009D38000000000000A").unwrap(), map.c2);
+        assert_eq!(BigNumber::from_hex("252364824000000126CD890000000003CF0F0000000000060C00000000000004").unwrap(), map.c3);
+        assert_eq!(BigNumber::from_hex("0C612180C00000009366C48000000002CB0B0000000000068D00000000000005").unwrap(), map.c4);
+    }
+
+    #[test]
+    fn svdw_map_new_works_for_bls12_381() {
+        // Z = -3 is the first constant satisfying the criteria of RFC 9380, appendix H.1
+        let p = BigNumber::from_hex("1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB").unwrap();
+        let map = SvdwMap::new(&p, &BigNumber::from_u32(4).unwrap()).unwrap();
+
+        assert_eq!(p.sub(&BigNumber::from_u32(3).unwrap()).unwrap(), map.z);
+        _check_svdw_constants(&map);
+    }
+
+    #[test]
+    fn svdw_map_new_works_for_invalid_modulus() {
+        let err = SvdwMap::new(&BigNumber::from_u32(13).unwrap(), &BigNumber::from_u32(2).unwrap()).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn svdw_map_to_curve_works() {
+        let map = _bn254_map();
+        let field = Field { p: &map.p };
+
+        for u in &["0", "1", "2", "041F46DDF04216BC7F46D9B937D649FFA352595F9A3953716A45327E5BE6AEA8"] {
+            let u = BigNumber::from_hex(u).unwrap();
+            let (x, y) = map.map_to_curve(&u).unwrap();
+            assert_eq!(field.sqr(&y).unwrap(), field.curve_rhs(&x, &map.b).unwrap());
+            assert_eq!(field.sgn0(&u).unwrap(), field.sgn0(&y).unwrap());
+        }
+    }
+
+    // Generated by this implementation, RFC 9380 doesn't define BN254 suites
+    #[test]
+    fn hash_to_field_works() {
+        let p = BigNumber::from_hex(BN254_P).unwrap();
+        let dst = b"INDY-CRYPTO-V01-CS01-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+        let elements = hash_to_field(b"abc", dst, 2, &p).unwrap();
+
+        assert_eq!(BigNumber::from_hex("04DEA7764D87C55664AC53F0DBD44CA717E951EBED9B0CD9D12A08229C8EE534").unwrap(), elements[0]);
+        assert_eq!(BigNumber::from_hex("207114CF03A8C96381FC05C6F12CBAED986A3E765425F0C9026A5D030835F07A").unwrap(), elements[1]);
+    }
+
+    #[test]
+    fn hash_to_field_works_for_bls12_381() {
+        // u values of RFC 9380 appendix J.9.1 vector for the empty message
+        let p = BigNumber::from_hex(BLS12_381_P).unwrap();
+        let dst = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+        let elements = hash_to_field(b"", dst, 2, &p).unwrap();
+
+        assert_eq!(BigNumber::from_hex("0BA14BD907AD64A016293EE7C2D276B8EAE71F25A4B941EECE7B0D89F17F75CB3AE5438A614FB61D6835AD59F29C564F").unwrap(), elements[0]);
+        assert_eq!(BigNumber::from_hex("019B9BD7979F12657976DE2884C7CCE192B82C177C80E0EC604436A7F538D231552F0D96D9F7BABE5FA3B19B3FF25AC9").unwrap(), elements[1]);
+    }
+
+    #[test]
+    fn sswu_map_new_works_for_invalid_params() {
+        let p = BigNumber::from_hex(BLS12_381_P).unwrap();
+        let a = BigNumber::from_hex(BLS12_381_ISO_A).unwrap();
+        let b = BigNumber::from_hex(BLS12_381_ISO_B).unwrap();
+
+        let err = SswuMap::new(&p, &BigNumber::new().unwrap(), &b, &BigNumber::from_u32(11).unwrap()).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+
+        // 4 is a square
+        let err = SswuMap::new(&p, &a, &b, &BigNumber::from_u32(4).unwrap()).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn sswu_map_to_curve_works() {
+        let map = _bls12_381_iso_map();
+        let field = Field { p: &map.p };
+
+        for u in &["0", "1", "2", "0BA14BD907AD64A016293EE7C2D276B8EAE71F25A4B941EECE7B0D89F17F75CB3AE5438A614FB61D6835AD59F29C564F"] {
+            let u = BigNumber::from_hex(u).unwrap();
+            let (x, y) = map.map_to_curve(&u).unwrap();
+            assert_eq!(field.sqr(&y).unwrap(), field.curve_rhs_with_a(&x, &map.a, &map.b).unwrap());
+            assert_eq!(field.sgn0(&u).unwrap(), field.sgn0(&y).unwrap());
+        }
+
+        // Q0 of RFC 9380 appendix J.9.1 vector for the empty message before the isogeny
+        let u = BigNumber::from_hex("0BA14BD907AD64A016293EE7C2D276B8EAE71F25A4B941EECE7B0D89F17F75CB3AE5438A614FB61D6835AD59F29C564F").unwrap();
+        let (x, y) = map.map_to_curve(&u).unwrap();
+        assert_eq!(BigNumber::from_hex("005CBC3C711CEF5B30F2EA9193A25275FE14EC9AE2B9DFC7F7BCAE7EDD7A2CC1C8499138300AEC11F791430F0921A08C").unwrap(), x);
+        assert_eq!(BigNumber::from_hex("0E1E665B91553233A8B01C1124DB33C65D807D5E990DD3AD72189A1E18BC726559B5269679AB120BC1E1555E8A836E09").unwrap(), y);
+    }
+
+    #[test]
+    fn isogeny_map_works() {
+        let p = BigNumber::from_u32(23).unwrap();
+        let n = |v: usize| BigNumber::from_u32(v).unwrap();
+
+        // (x, y) -> ((x + 1) / (x - 2), 2 * y / x)
+        let map = IsogenyMap::new(&p, vec![n(1), n(1)], vec![n(21), n(1)], vec![n(2)], vec![n(0), n(1)]).unwrap();
+
+        assert_eq!(Some((n(4), n(2))), map.map((n(3), n(3))).unwrap());
+        assert_eq!(None, map.map((n(2), n(5))).unwrap());
+        assert_eq!(None, map.map((n(0), n(5))).unwrap());
+    }
+
+    #[test]
+    fn isogeny_map_new_works_for_empty_polynomial() {
+        let p = BigNumber::from_u32(23).unwrap();
+        let one = || vec![BigNumber::from_u32(1).unwrap()];
+
+        let err = IsogenyMap::new(&p, one(), vec![], one(), one()).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+}
@@ -1,6 +1,9 @@
+#[cfg(feature = "pair_amcl")]
 extern crate amcl;
 extern crate env_logger;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
 extern crate rand;
 extern crate sha2;
@@ -20,18 +23,38 @@ extern crate serde_derive;
 #[cfg(feature = "serialization")]
 extern crate serde_json;
 
+#[cfg(feature = "serialization")]
+extern crate scrypt;
+
+#[cfg(feature = "serialization")]
+extern crate chacha20poly1305;
+
 #[cfg(feature = "bn_openssl")]
 extern crate openssl;
 
 #[cfg(feature = "bn_openssl")]
 extern crate int_traits;
 
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_bls12_381;
+
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_ec;
+
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_ff;
+
+#[cfg(feature = "pair_bls12_381")]
+extern crate ark_serialize;
+
 extern crate libc;
 
 extern crate time;
 
 pub mod cl;
 pub mod bls;
+pub mod secret_sharing;
+pub mod hash_to_curve;
 
 #[cfg(feature = "bn_openssl")]
 #[path = "bn/openssl.rs"]
@@ -40,6 +63,13 @@ pub mod bn;
 pub mod errors;
 pub mod ffi;
 
+#[cfg(all(feature = "pair_amcl", feature = "pair_bls12_381"))]
+compile_error!("features `pair_amcl` (BN254) and `pair_bls12_381` (BLS12-381) are mutually exclusive");
+
 #[cfg(feature = "pair_amcl")]
 #[path = "pair/amcl.rs"]
-pub mod pair;
\ No newline at end of file
+pub mod pair;
+
+#[cfg(feature = "pair_bls12_381")]
+#[path = "pair/bls12_381.rs"]
+pub mod pair;
@@ -1,16 +1,20 @@
+use bn::BigNumber;
 use errors::IndyCryptoError;
+use hash_to_curve::{hash_to_field, SvdwMap};
 
 use amcl::big::BIG;
 
 use amcl::rom::{
     CURVE_GX,
     CURVE_GY,
+    CURVE_B,
     CURVE_ORDER,
     CURVE_PXA,
     CURVE_PYA,
     CURVE_PXB,
     CURVE_PYB,
-    MODBYTES
+    MODBYTES,
+    MODULUS
 };
 
 use amcl::ecp::ECP;
@@ -30,6 +34,33 @@ use serde::de::{Deserialize, Deserializer, Visitor, Error as DError};
 #[cfg(feature = "serialization")]
 use std::fmt;
 
+/// Hash-to-curve suite used by `PointG1::hash_to_curve`.
+pub const HASH_TO_CURVE_SUITE: &'static str = "BN254G1_XMD:SHA-256_SVDW_RO_";
+
+/// Map of field elements to G1 curve used by `PointG1::hash_to_curve`.
+struct G1Map {
+    svdw: SvdwMap
+}
+
+impl G1Map {
+    fn new() -> Result<G1Map, IndyCryptoError> {
+        Ok(G1Map {
+            svdw: SvdwMap::new(&big_to_bignum(&BIG::new_ints(&MODULUS))?, &big_to_bignum(&BIG::new_ints(&CURVE_B))?)?
+        })
+    }
+
+    fn map_to_curve(&self, u: &BigNumber) -> Result<PointG1, IndyCryptoError> {
+        PointG1::_from_affine(self.svdw.map_to_curve(u)?)
+    }
+}
+
+thread_local! {
+    /// Map of G1 curve used by `PointG1::hash_to_curve`. Preparation of its constants is
+    /// expensive and BigNumber isn't guaranteed to be shareable between threads,
+    /// so the map is computed once per thread.
+    static G1_MAP: Result<G1Map, IndyCryptoError> = G1Map::new();
+}
+
 fn random_mod_order() -> Result<BIG, IndyCryptoError> {
     let mut seed = vec![0; MODBYTES];
     let mut os_rng = OsRng::new().unwrap();
@@ -40,13 +71,31 @@ fn random_mod_order() -> Result<BIG, IndyCryptoError> {
     Ok(BIG::randomnum(&BIG::new_ints(&CURVE_ORDER), &mut rng))
 }
 
+fn big_to_bignum(big: &BIG) -> Result<BigNumber, IndyCryptoError> {
+    let mut big = *big;
+    let mut bytes = vec![0u8; MODBYTES];
+    big.tobytes(&mut bytes);
+    BigNumber::from_bytes(&bytes)
+}
+
+fn bignum_to_big(bn: &BigNumber) -> Result<BIG, IndyCryptoError> {
+    let bytes = bn.to_bytes()?;
+    if bytes.len() > MODBYTES {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid len of bytes representation".to_string()));
+    }
+    let mut padded = vec![0u8; MODBYTES - bytes.len()];
+    padded.extend_from_slice(&bytes);
+    Ok(BIG::frombytes(&padded))
+}
+
 #[derive(Copy, Clone, PartialEq, Debug)]
 pub struct PointG1 {
     point: ECP
 }
 
 impl PointG1 {
-    const BYTES_REPR_SIZE: usize = MODBYTES * 4;
+    pub const BYTES_REPR_SIZE: usize = MODBYTES * 4;
 
     /// Creates new random PointG1
     pub fn new() -> Result<PointG1, IndyCryptoError> {
@@ -77,6 +126,13 @@ impl PointG1 {
         Ok(r.is_infinity())
     }
 
+    /// Checks that point belongs to prime order subgroup G1
+    pub fn is_in_subgroup(&self) -> Result<bool, IndyCryptoError> {
+        let mut r = self.point;
+        let mut q = r.mul(&mut BIG::new_ints(&CURVE_ORDER));
+        Ok(q.is_infinity())
+    }
+
     /// PointG1 ^ GroupOrderElement
     pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG1, IndyCryptoError> {
         let mut r = self.point;
@@ -157,6 +213,41 @@ impl PointG1 {
             point: point
         })
     }
+
+    /// Hashes the message to PointG1 with the IETF hash-to-curve construction
+    /// (RFC 9380) using suite `HASH_TO_CURVE_SUITE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to hash
+    /// * `dst` - Domain separation tag, from 1 to 255 bytes
+    pub fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let p = big_to_bignum(&BIG::new_ints(&MODULUS))?;
+
+        G1_MAP.with(|map| {
+            let map = map.as_ref()
+                .map_err(|err| IndyCryptoError::InvalidState(format!("Unable to create map of G1: {:?}", err)))?;
+
+            let u = hash_to_field(message, dst, 2, &p)?;
+            let q0 = map.map_to_curve(&u[0])?;
+            let q1 = map.map_to_curve(&u[1])?;
+
+            // G1 cofactor of BN254 is 1, so clearing of cofactor is not needed
+            q0.add(&q1)
+        })
+    }
+
+    fn _from_affine((x, y): (BigNumber, BigNumber)) -> Result<PointG1, IndyCryptoError> {
+        let mut point = ECP::new_bigs(&bignum_to_big(&x)?, &bignum_to_big(&y)?);
+
+        if point.is_infinity() {
+            return Err(IndyCryptoError::InvalidStructure("Point is not on the curve".to_string()));
+        }
+
+        Ok(PointG1 {
+            point: point
+        })
+    }
 }
 
 #[cfg(feature = "serialization")]
@@ -195,7 +286,7 @@ pub struct PointG2 {
 }
 
 impl PointG2 {
-    const BYTES_REPR_SIZE: usize = MODBYTES * 4;
+    pub const BYTES_REPR_SIZE: usize = MODBYTES * 4;
 
     /// Creates new random PointG2
     pub fn new() -> Result<PointG2, IndyCryptoError> {
@@ -226,6 +317,12 @@ impl PointG2 {
         })
     }
 
+    /// Checks infinity
+    pub fn is_inf(&self) -> Result<bool, IndyCryptoError> {
+        let mut r = self.point;
+        Ok(r.is_infinity())
+    }
+
     /// PointG2 * PointG2
     pub fn add(&self, q: &PointG2) -> Result<PointG2, IndyCryptoError> {
         let mut r = self.point;
@@ -323,7 +420,7 @@ pub struct GroupOrderElement {
 }
 
 impl GroupOrderElement {
-    const BYTES_REPR_SIZE: usize = MODBYTES;
+    pub const BYTES_REPR_SIZE: usize = MODBYTES;
 
     pub fn new() -> Result<GroupOrderElement, IndyCryptoError> {
         // returns random element in 0, ..., GroupOrder-1
@@ -414,7 +511,8 @@ impl GroupOrderElement {
     }
 
     pub fn to_string(&self) -> Result<String, IndyCryptoError> {
-        Ok(self.bn.to_hex())
+        let mut bn = self.bn;
+        Ok(bn.to_hex())
     }
 
     pub fn from_string(str: &str) -> Result<GroupOrderElement, IndyCryptoError> {
@@ -504,6 +602,28 @@ impl Pair {
         })
     }
 
+    /// e(p1, q1) * e(p2, q2) * ... computed with single final exponentiation
+    pub fn multi_pair(pairs: &[(PointG1, PointG2)]) -> Result<Pair, IndyCryptoError> {
+        let (first, rest) = pairs.split_first()
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Pairs list is empty")))?;
+
+        let (mut p, mut q) = *first;
+        let mut miller = ate(&mut q.point, &mut p.point);
+
+        for &(p, q) in rest {
+            let (mut p, mut q) = (p, q);
+            let mut e = ate(&mut q.point, &mut p.point);
+            miller.mul(&mut e);
+        }
+
+        let mut result = fexp(&miller);
+        result.reduce();
+
+        Ok(Pair {
+            pair: result
+        })
+    }
+
     /// e() * e()
     pub fn mul(&self, b: &Pair) -> Result<Pair, IndyCryptoError> {
         let mut base = self.pair;
@@ -594,6 +714,84 @@ mod tests {
         assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
     }
 
+    #[test]
+    fn multi_pair_works() {
+        let p1 = PointG1::new().unwrap();
+        let q1 = PointG2::new().unwrap();
+        let p2 = PointG1::new().unwrap();
+        let q2 = PointG2::new().unwrap();
+        let left = Pair::multi_pair(&[(p1, q1), (p2, q2)]).unwrap();
+        let right = Pair::pair(&p1, &q1).unwrap().mul(&Pair::pair(&p2, &q2).unwrap()).unwrap();
+        assert_eq!(left, right);
+    }
+
+    const HASH_TO_CURVE_DST: &'static [u8] = b"INDY-CRYPTO-V01-CS01-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+
+    // RFC 9380 doesn't define BN254 suites, so there are no published vectors for it.
+    // These vectors were generated by this implementation and only guard against regressions,
+    // its parts are checked in `hash_to_curve` tests: `expand_message_xmd` against RFC 9380
+    // vectors and SvdW constants against their definitions.
+    fn _hash_to_curve_vectors() -> Vec<(&'static [u8], &'static str, &'static str)> {
+        vec![
+            (&b""[..],
+             "08315F8F1AF5C3F440B328AB253DDF857E9261F60C359A02BD3D063CABC31DA6",
+             "23844CF413A5B7A233A2ECB67B85D598CBE2063AA463F6EFA6DC7B7AE5F79922"),
+            (&b"abc"[..],
+             "197E04F1288E45947BE833BAC466DE1BB2BC33578388F8A2DB83C3944140EB29",
+             "02E8C7E29C236C8B1922FC0B91FA079831B7BDC10815BAB64D2C362E1DB904D1"),
+            (&b"abcdef0123456789"[..],
+             "081B71EBD7610D689F11397D568FF2B7FDC62D11A588AACE92FC12A6FBDF1B70",
+             "1FA57AED077DCEC38D53BAE6334174405147C1D81D66760B47A59BEEDD233475")
+        ]
+    }
+
+    fn _point_g1_from_hex(x: &str, y: &str) -> PointG1 {
+        PointG1::_from_affine((BigNumber::from_hex(x).unwrap(), BigNumber::from_hex(y).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works() {
+        for (message, x, y) in _hash_to_curve_vectors() {
+            let point = PointG1::hash_to_curve(message, HASH_TO_CURVE_DST).unwrap();
+            assert_eq!(_point_g1_from_hex(x, y).to_bytes().unwrap(), point.to_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works_for_long_message() {
+        let mut message = b"a512_".to_vec();
+        message.extend_from_slice(&[b'a'; 512]);
+
+        let point = PointG1::hash_to_curve(&message, HASH_TO_CURVE_DST).unwrap();
+        // Generated by this implementation, see `_hash_to_curve_vectors`
+        let expected = _point_g1_from_hex("22C71B45EBB806470B028FA2090EB605B3E976DB9C86963156E841B3BCFF8E3B",
+                                          "07D872D2C504AE76AE8DBA73D9D6A00AF1D179DA1BC5F65848D073422E09A574");
+        assert_eq!(expected.to_bytes().unwrap(), point.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_from_hash_works() {
+        let hash = vec![7u8; 32];
+        let point = PointG1::from_hash(&hash).unwrap();
+        let order_minus_one = GroupOrderElement::from_bytes(&[1]).unwrap().mod_neg().unwrap();
+
+        // point is in G1 if point * (r - 1) == -point
+        assert_eq!(point.neg().unwrap().to_bytes().unwrap(), point.mul(&order_minus_one).unwrap().to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works_for_different_dst() {
+        let point1 = PointG1::hash_to_curve(b"abc", HASH_TO_CURVE_DST).unwrap();
+        let point2 = PointG1::hash_to_curve(b"abc", b"OTHER-DST").unwrap();
+        assert_ne!(point1.to_bytes().unwrap(), point2.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works_for_empty_dst() {
+        let err = PointG1::hash_to_curve(b"abc", b"").unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
     #[test]
     fn pairing_definition_bilinearity() {
         let a = GroupOrderElement::new().unwrap();
@@ -0,0 +1,975 @@
+use bn::BigNumber;
+use errors::IndyCryptoError;
+use hash_to_curve::{expand_message_xmd, hash_to_field, IsogenyMap, SswuMap};
+
+use ark_bls12_381::{Bls12_381, Fq, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::pairing::{Pairing, PairingOutput};
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use rand::os::OsRng;
+use rand::Rng;
+
+#[cfg(feature = "serialization")]
+use serde::ser::{Serialize, Serializer, Error as SError};
+#[cfg(feature = "serialization")]
+use serde::de::{Deserialize, Deserializer, Visitor, Error as DError};
+#[cfg(feature = "serialization")]
+use std::fmt;
+
+use std::str;
+
+/// Hash-to-curve suite used by `PointG1::hash_to_curve`.
+pub const HASH_TO_CURVE_SUITE: &'static str = "BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// Domain separation tag used to expand seeds of `GroupOrderElement::new_from_seed`.
+const SEED_DST: &'static [u8] = b"INDY-CRYPTO-V01-BLS12381-GROUP-ORDER-ELEMENT-SEED";
+
+/// Effective cofactor of G1 (`h_eff` in RFC 9380, section 8.8.1).
+const G1_COFACTOR: u64 = 0xd201000000010001;
+
+/// Coefficient A' of the curve 11-isogenous to G1 curve, mapped by SSWU (RFC 9380, section 8.8.1).
+const G1_ISO_A: &'static str = "144698A3B8E9433D693A02C96D4982B0EA985383EE66A8D8E8981AEFD881AC98936F8DA0E0F97F5CF428082D584C1D";
+
+/// Coefficient B' of the curve 11-isogenous to G1 curve.
+const G1_ISO_B: &'static str = "12E2908D11688030018B12E8753EEE3B2016C1F0F24F4070A0B9C14FCEF35EF55A23215A316CEAA5D1CC48E98E172BE0";
+
+/// SSWU constant Z of the suite.
+const G1_SSWU_Z: usize = 11;
+
+/// Coefficients of x numerator of the 11-isogeny map (RFC 9380, appendix E.2), from the constant term up.
+const G1_ISO_X_NUM: &'static [&'static str] = &[
+    "11A05F2B1E833340B809101DD99815856B303E88A2D7005FF2627B56CDB4E2C85610C2D5F2E62D6EAEAC1662734649B7",
+    "17294ED3E943AB2F0588BAB22147A81C7C17E75B2F6A8417F565E33C70D1E86B4838F2A6F318C356E834EEF1B3CB83BB",
+    "0D54005DB97678EC1D1048C5D10A9A1BCE032473295983E56878E501EC68E25C958C3E3D2A09729FE0179F9DAC9EDCB0",
+    "1778E7166FCC6DB74E0609D307E55412D7F5E4656A8DBF25F1B33289F1B330835336E25CE3107193C5B388641D9B6861",
+    "0E99726A3199F4436642B4B3E4118E5499DB995A1257FB3F086EEB65982FAC18985A286F301E77C451154CE9AC8895D9",
+    "1630C3250D7313FF01D1201BF7A74AB5DB3CB17DD952799B9ED3AB9097E68F90A0870D2DCAE73D19CD13C1C66F652983",
+    "0D6ED6553FE44D296A3726C38AE652BFB11586264F0F8CE19008E218F9C86B2A8DA25128C1052ECADDD7F225A139ED84",
+    "17B81E7701ABDBE2E8743884D1117E53356DE5AB275B4DB1A682C62EF0F2753339B7C8F8C8F475AF9CCB5618E3F0C88E",
+    "080D3CF1F9A78FC47B90B33563BE990DC43B756CE79F5574A2C596C928C5D1DE4FA295F296B74E956D71986A8497E317",
+    "169B1F8E1BCFA7C42E0C37515D138F22DD2ECB803A0C5C99676314BAF4BB1B7FA3190B2EDC0327797F241067BE390C9E",
+    "10321DA079CE07E272D8EC09D2565B0DFA7DCCDDE6787F96D50AF36003B14866F69B771F8C285DECCA67DF3F1605FB7B",
+    "06E08C248E260E70BD1E962381EDEE3D31D79D7E22C837BC23C0BF1BC24C6B68C24B1B80B64D391FA9C8BA2E8BA2D229"
+];
+
+/// Coefficients of x denominator of the 11-isogeny map including the leading one.
+const G1_ISO_X_DEN: &'static [&'static str] = &[
+    "08CA8D548CFF19AE18B2E62F4BD3FA6F01D5EF4BA35B48BA9C9588617FC8AC62B558D681BE343DF8993CF9FA40D21B1C",
+    "12561A5DEB559C4348B4711298E536367041E8CA0CF0800C0126C2588C48BF5713DAA8846CB026E9E5C8276EC82B3BFF",
+    "0B2962FE57A3225E8137E629BFF2991F6F89416F5A718CD1FCA64E00B11ACEACD6A3D0967C94FEDCFCC239BA5CB83E19",
+    "03425581A58AE2FEC83AAFEF7C40EB545B08243F16B1655154CCA8ABC28D6FD04976D5243EECF5C4130DE8938DC62CD8",
+    "13A8E162022914A80A6F1D5F43E7A07DFFDFC759A12062BB8D6B44E833B306DA9BD29BA81F35781D539D395B3532A21E",
+    "0E7355F8E4E667B955390F7F0506C6E9395735E9CE9CAD4D0A43BCEF24B8982F7400D24BC4228F11C02DF9A29F6304A5",
+    "0772CAACF16936190F3E0C63E0596721570F5799AF53A1894E2E073062AEDE9CEA73B3538F0DE06CEC2574496EE84A3A",
+    "14A7AC2A9D64A8B230B3F5B074CF01996E7F63C21BCA68A81996E1CDF9822C580FA5B9489D11E2D311F7D99BBDCC5A5E",
+    "0A10ECF6ADA54F825E920B3DAFC7A3CCE07F8D1D7161366B74100DA67F39883503826692ABBA43704776EC3A79A1D641",
+    "095FC13AB9E92AD4476D6E3EB3A56680F682B4EE96F7D03776DF533978F31C1593174E4B4B7865002D6384D168ECDD0A",
+    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"
+];
+
+/// Coefficients of y numerator of the 11-isogeny map.
+const G1_ISO_Y_NUM: &'static [&'static str] = &[
+    "090D97C81BA24EE0259D1F094980DCFA11AD138E48A869522B52AF6C956543D3CD0C7AEE9B3BA3C2BE9845719707BB33",
+    "134996A104EE5811D51036D776FB46831223E96C254F383D0F906343EB67AD34D6C56711962FA8BFE097E75A2E41C696",
+    "00CC786BAA966E66F4A384C86A3B49942552E2D658A31CE2C344BE4B91400DA7D26D521628B00523B8DFE240C72DE1F6",
+    "01F86376E8981C217898751AD8746757D42AA7B90EEB791C09E4A3EC03251CF9DE405ABA9EC61DECA6355C77B0E5F4CB",
+    "08CC03FDEFE0FF135CAF4FE2A21529C4195536FBE3CE50B879833FD221351ADC2EE7F8DC099040A841B6DAECF2E8FEDB",
+    "16603FCA40634B6A2211E11DB8F0A6A074A7D0D4AFADB7BD76505C3D3AD5544E203F6326C95A807299B23AB13633A5F0",
+    "04AB0B9BCFAC1BBCB2C977D027796B3CE75BB8CA2BE184CB5231413C4D634F3747A87AC2460F415EC961F8855FE9D6F2",
+    "0987C8D5333AB86FDE9926BD2CA6C674170A05BFE3BDD81FFD038DA6C26C842642F64550FEDFE935A15E4CA31870FB29",
+    "09FC4018BD96684BE88C9E221E4DA1BB8F3ABD16679DC26C1E8B6E6A1F20CABE69D65201C78607A360370E577BDBA587",
+    "0E1BBA7A1186BDB5223ABDE7ADA14A23C42A0CA7915AF6FE06985E7ED1E4D43B9B3F7055DD4EBA6F2BAFAAEBCA731C30",
+    "19713E47937CD1BE0DFD0B8F1D43FB93CD2FCBCB6CAF493FD1183E416389E61031BF3A5CCE3FBAFCE813711AD011C132",
+    "18B46A908F36F6DEB918C143FED2EDCC523559B8AAF0C2462E6BFE7F911F643249D9CDF41B44D606CE07C8A4D0074D8E",
+    "0B182CAC101B9399D155096004F53F447AA7B12A3426B08EC02710E807B4633F06C851C1919211F20D4C04F00B971EF8",
+    "0245A394AD1ECA9B72FC00AE7BE315DC757B3B080D4C158013E6632D3C40659CC6CF90AD1C232A6442D9D3F5DB980133",
+    "05C129645E44CF1102A159F748C4A3FC5E673D81D7E86568D9AB0F5D396A7CE46BA1049B6579AFB7866B1E715475224B",
+    "15E6BE4E990F03CE4EA50B3B42DF2EB5CB181D8F84965A3957ADD4FA95AF01B2B665027EFEC01C7704B456BE69C8B604"
+];
+
+/// Coefficients of y denominator of the 11-isogeny map including the leading one.
+const G1_ISO_Y_DEN: &'static [&'static str] = &[
+    "16112C4C3A9C98B252181140FAD0EAE9601A6DE578980BE6EEC3232B5BE72E7A07F3688EF60C206D01479253B03663C1",
+    "1962D75C2381201E1A0CBD6C43C348B885C84FF731C4D59CA4A10356F453E01F78A4260763529E3532F6102C2E49A03D",
+    "058DF3306640DA276FAAAE7D6E8EB15778C4855551AE7F310C35A5DD279CD2ECA6757CD636F96F891E2538B53DBF67F2",
+    "16B7D288798E5395F20D23BF89EDB4D1D115C5DBDDBCD30E123DA489E726AF41727364F2C28297ADA8D26D98445F5416",
+    "0BE0E079545F43E4B00CC912F8228DDCC6D19C9F0F69BBB0542EDA0FC9DEC916A20B15DC0FD2EDEDDA39142311A5001D",
+    "08D9E5297186DB2D9FB266EAAC783182B70152C65550D881C5ECD87B6F0F5A6449F38DB9DFA9CCE202C6477FAAF9B7AC",
+    "166007C08A99DB2FC3BA8734ACE9824B5EECFDFA8D0CF8EF5DD365BC400A0051D5FA9C01A58B1FB93D1A1399126A775C",
+    "16A3EF08BE3EA7EA03BCDDFABBA6FF6EE5A4375EFA1F4FD7FEB34FD206357132B920F5B00801DEE460EE415A15812ED9",
+    "1866C8ED336C61231A1BE54FD1D74CC4F9FB0CE4C6AF5920ABC5750C4BF39B4852CFE2F7BB9248836B233D9D55535D4A",
+    "167A55CDA70A6E1CEA820597D94A84903216F763E13D87BB5308592E7EA7D4FBC7385EA3D529B35E346EF48BB8913F55",
+    "04D2F259EEA405BD48F010A01AD2911D9C6DD039BB61A6290E591B36E636A5C871A5C29F4F83060400F8B49CBA8F6AA8",
+    "0ACCBB67481D033FF5852C1E48C50C477F94FF8AEFCE42D28C0F9A88CEA7913516F968986F7EBBEA9684B529E2561092",
+    "0AD6B9514C767FE3C3613144B45F1496543346D98ADF02267D5CEEF9A00D9B8693000763E3B90AC11E99B138573345CC",
+    "02660400EB2E4F3B628BDD0D53CD76F2BF565B94E72927C1CB748DF27942480E420517BD8714CC80D1FADC1326ED06F7",
+    "0E0FA1D816DDC03E6B24255E0D7819C171C40F65E273B853324EFCD6356CAA205CA2F570F13497804415473A1D634B8F",
+    "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"
+];
+
+/// Map of field elements to G1 curve used by `PointG1::hash_to_curve`: SSWU map
+/// to the isogenous curve followed by the isogeny.
+struct G1Map {
+    sswu: SswuMap,
+    isogeny: IsogenyMap
+}
+
+impl G1Map {
+    fn new() -> Result<G1Map, IndyCryptoError> {
+        let p = modulus()?;
+
+        let sswu = SswuMap::new(&p,
+                                &BigNumber::from_hex(G1_ISO_A)?,
+                                &BigNumber::from_hex(G1_ISO_B)?,
+                                &BigNumber::from_u32(G1_SSWU_Z)?)?;
+
+        let isogeny = IsogenyMap::new(&p,
+                                      G1Map::_coeffs(G1_ISO_X_NUM)?,
+                                      G1Map::_coeffs(G1_ISO_X_DEN)?,
+                                      G1Map::_coeffs(G1_ISO_Y_NUM)?,
+                                      G1Map::_coeffs(G1_ISO_Y_DEN)?)?;
+
+        Ok(G1Map { sswu, isogeny })
+    }
+
+    fn map_to_curve(&self, u: &BigNumber) -> Result<PointG1, IndyCryptoError> {
+        match self.isogeny.map(self.sswu.map_to_curve(u)?)? {
+            Some(point) => PointG1::_from_affine(point),
+            None => PointG1::new_inf()
+        }
+    }
+
+    fn _coeffs(coeffs: &[&str]) -> Result<Vec<BigNumber>, IndyCryptoError> {
+        coeffs.iter().map(|coeff| BigNumber::from_hex(coeff)).collect()
+    }
+}
+
+thread_local! {
+    /// Map of G1 curve used by `PointG1::hash_to_curve`. Preparation of its constants is
+    /// expensive and BigNumber isn't guaranteed to be shareable between threads,
+    /// so the map is computed once per thread.
+    static G1_MAP: Result<G1Map, IndyCryptoError> = G1Map::new();
+}
+
+fn modulus() -> Result<BigNumber, IndyCryptoError> {
+    BigNumber::from_bytes(&Fq::MODULUS.to_bytes_be())
+}
+
+fn random_mod_order() -> Result<Fr, IndyCryptoError> {
+    // twice longer than order to make the bias of reduction negligible
+    let mut seed = vec![0; GroupOrderElement::BYTES_REPR_SIZE * 2];
+    let mut os_rng = OsRng::new().unwrap();
+    os_rng.fill_bytes(&mut seed.as_mut_slice());
+    Ok(Fr::from_be_bytes_mod_order(&seed))
+}
+
+fn bignum_to_fq(bn: &BigNumber) -> Result<Fq, IndyCryptoError> {
+    let bytes = bn.to_bytes()?;
+    if bytes.len() > PointG1::BYTES_REPR_SIZE / 2 {
+        return Err(IndyCryptoError::InvalidStructure(
+            "Invalid len of bytes representation".to_string()));
+    }
+    Ok(Fq::from_be_bytes_mod_order(&bytes))
+}
+
+fn serialize<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>, IndyCryptoError> {
+    let mut vec = Vec::new();
+    value.serialize_uncompressed(&mut vec)
+        .map_err(|err| IndyCryptoError::InvalidState(format!("Unable to serialize: {:?}", err)))?;
+    Ok(vec)
+}
+
+fn _to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
+fn _from_hex(hex: &str) -> Result<Vec<u8>, IndyCryptoError> {
+    if hex.len() % 2 != 0 {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid hex string length")));
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|chunk| str::from_utf8(chunk).ok()
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Invalid hex string"))))
+        .collect()
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PointG1 {
+    point: G1Projective
+}
+
+impl PointG1 {
+    pub const BYTES_REPR_SIZE: usize = 96;
+
+    /// Creates new random PointG1
+    pub fn new() -> Result<PointG1, IndyCryptoError> {
+        // generate random point from the group G1
+        Ok(PointG1 {
+            point: G1Projective::generator() * random_mod_order()?
+        })
+    }
+
+    /// Creates new infinity PointG1
+    pub fn new_inf() -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: G1Projective::zero()
+        })
+    }
+
+    /// Checks infinity
+    pub fn is_inf(&self) -> Result<bool, IndyCryptoError> {
+        Ok(self.point.is_zero())
+    }
+
+    /// Checks that point belongs to prime order subgroup G1
+    pub fn is_in_subgroup(&self) -> Result<bool, IndyCryptoError> {
+        Ok(self.point.into_affine().is_in_correct_subgroup_assuming_on_curve())
+    }
+
+    /// PointG1 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: self.point * e.bn
+        })
+    }
+
+    /// PointG1 * PointG1
+    pub fn add(&self, q: &PointG1) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: self.point + q.point
+        })
+    }
+
+    /// PointG1 / PointG1
+    pub fn sub(&self, q: &PointG1) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: self.point - q.point
+        })
+    }
+
+    /// 1 / PointG1
+    pub fn neg(&self) -> Result<PointG1, IndyCryptoError> {
+        Ok(PointG1 {
+            point: -self.point
+        })
+    }
+
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(_to_hex(&self.to_bytes()?))
+    }
+
+    pub fn from_string(str: &str) -> Result<PointG1, IndyCryptoError> {
+        PointG1::from_bytes(&_from_hex(str)?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        serialize(&self.point.into_affine())
+    }
+
+    pub fn from_bytes(b: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        if b.len() != Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        let point = G1Affine::deserialize_uncompressed_unchecked(b)
+            .map_err(|err| IndyCryptoError::InvalidStructure(format!("Invalid bytes representation of PointG1: {:?}", err)))?;
+
+        if !point.is_on_curve() {
+            return Err(IndyCryptoError::InvalidStructure("Point is not on the curve".to_string()));
+        }
+
+        Ok(PointG1 {
+            point: point.into_group()
+        })
+    }
+
+    pub fn from_hash(hash: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let mut x = Fq::from_be_bytes_mod_order(hash);
+        let mut point = G1Affine::get_point_from_x_unchecked(x, true);
+
+        while point.is_none() {
+            x += Fq::one();
+            point = G1Affine::get_point_from_x_unchecked(x, true);
+        }
+
+        Ok(PointG1 {
+            point: point.unwrap().into_group().mul_bigint(&[G1_COFACTOR])
+        })
+    }
+
+    /// Hashes the message to PointG1 with the IETF hash-to-curve construction
+    /// (RFC 9380) using suite `HASH_TO_CURVE_SUITE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Message to hash
+    /// * `dst` - Domain separation tag, from 1 to 255 bytes
+    pub fn hash_to_curve(message: &[u8], dst: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let p = modulus()?;
+
+        G1_MAP.with(|map| {
+            let map = map.as_ref()
+                .map_err(|err| IndyCryptoError::InvalidState(format!("Unable to create map of G1: {:?}", err)))?;
+
+            let u = hash_to_field(message, dst, 2, &p)?;
+            let q0 = map.map_to_curve(&u[0])?;
+            let q1 = map.map_to_curve(&u[1])?;
+
+            Ok(PointG1 {
+                point: q0.add(&q1)?.point.mul_bigint(&[G1_COFACTOR])
+            })
+        })
+    }
+
+    fn _from_affine((x, y): (BigNumber, BigNumber)) -> Result<PointG1, IndyCryptoError> {
+        let point = G1Affine::new_unchecked(bignum_to_fq(&x)?, bignum_to_fq(&y)?);
+
+        if !point.is_on_curve() {
+            return Err(IndyCryptoError::InvalidStructure("Point is not on the curve".to_string()));
+        }
+
+        Ok(PointG1 {
+            point: point.into_group()
+        })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for PointG1 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("PointG1", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for PointG1 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct PointG1Visitor;
+
+        impl<'a> Visitor<'a> for PointG1Visitor {
+            type Value = PointG1;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected PointG1")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<PointG1, E>
+                where E: DError
+            {
+                Ok(PointG1::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(PointG1Visitor)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PointG2 {
+    point: G2Projective
+}
+
+impl PointG2 {
+    pub const BYTES_REPR_SIZE: usize = 192;
+
+    /// Creates new random PointG2
+    pub fn new() -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: G2Projective::generator() * random_mod_order()?
+        })
+    }
+
+    /// Creates new infinity PointG2
+    pub fn new_inf() -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: G2Projective::zero()
+        })
+    }
+
+    /// Checks infinity
+    pub fn is_inf(&self) -> Result<bool, IndyCryptoError> {
+        Ok(self.point.is_zero())
+    }
+
+    /// PointG2 * PointG2
+    pub fn add(&self, q: &PointG2) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: self.point + q.point
+        })
+    }
+
+    /// PointG2 / PointG2
+    pub fn sub(&self, q: &PointG2) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: self.point - q.point
+        })
+    }
+
+    /// PointG2 ^ GroupOrderElement
+    pub fn mul(&self, e: &GroupOrderElement) -> Result<PointG2, IndyCryptoError> {
+        Ok(PointG2 {
+            point: self.point * e.bn
+        })
+    }
+
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(_to_hex(&self.to_bytes()?))
+    }
+
+    pub fn from_string(str: &str) -> Result<PointG2, IndyCryptoError> {
+        PointG2::from_bytes(&_from_hex(str)?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        serialize(&self.point.into_affine())
+    }
+
+    pub fn from_bytes(b: &[u8]) -> Result<PointG2, IndyCryptoError> {
+        if b.len() != Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        let point = G2Affine::deserialize_uncompressed_unchecked(b)
+            .map_err(|err| IndyCryptoError::InvalidStructure(format!("Invalid bytes representation of PointG2: {:?}", err)))?;
+
+        if !point.is_on_curve() {
+            return Err(IndyCryptoError::InvalidStructure("Point is not on the curve".to_string()));
+        }
+
+        Ok(PointG2 {
+            point: point.into_group()
+        })
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for PointG2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("PointG2", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for PointG2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct PointG2Visitor;
+
+        impl<'a> Visitor<'a> for PointG2Visitor {
+            type Value = PointG2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected PointG2")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<PointG2, E>
+                where E: DError
+            {
+                Ok(PointG2::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(PointG2Visitor)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GroupOrderElement {
+    bn: Fr
+}
+
+impl GroupOrderElement {
+    pub const BYTES_REPR_SIZE: usize = 32;
+
+    pub fn new() -> Result<GroupOrderElement, IndyCryptoError> {
+        // returns random element in 0, ..., GroupOrder-1
+        Ok(GroupOrderElement {
+            bn: random_mod_order()?
+        })
+    }
+
+    pub fn new_from_seed(seed: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        // returns random element in 0, ..., GroupOrder-1
+        if seed.len() != Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid len of seed: expected {}, actual {}", Self::BYTES_REPR_SIZE, seed.len())));
+        }
+
+        let bytes = expand_message_xmd(seed, SEED_DST, Self::BYTES_REPR_SIZE * 2)?;
+
+        Ok(GroupOrderElement {
+            bn: Fr::from_be_bytes_mod_order(&bytes)
+        })
+    }
+
+    /// (GroupOrderElement ^ GroupOrderElement) mod GroupOrder
+    pub fn pow_mod(&self, e: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn.pow(e.bn.into_bigint())
+        })
+    }
+
+    /// (GroupOrderElement + GroupOrderElement) mod GroupOrder
+    pub fn add_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn + r.bn
+        })
+    }
+
+    /// (GroupOrderElement - GroupOrderElement) mod GroupOrder
+    pub fn sub_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn - r.bn
+        })
+    }
+
+    /// (GroupOrderElement * GroupOrderElement) mod GroupOrder
+    pub fn mul_mod(&self, r: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: self.bn * r.bn
+        })
+    }
+
+    /// 1 / GroupOrderElement
+    pub fn inverse(&self) -> Result<GroupOrderElement, IndyCryptoError> {
+        let bn = self.bn.inverse()
+            .ok_or(IndyCryptoError::InvalidStructure("Zero element has no inverse".to_string()))?;
+
+        Ok(GroupOrderElement {
+            bn: bn
+        })
+    }
+
+    /// - GroupOrderElement mod GroupOrder
+    pub fn mod_neg(&self) -> Result<GroupOrderElement, IndyCryptoError> {
+        Ok(GroupOrderElement {
+            bn: -self.bn
+        })
+    }
+
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(_to_hex(&self.to_bytes()?))
+    }
+
+    pub fn from_string(str: &str) -> Result<GroupOrderElement, IndyCryptoError> {
+        GroupOrderElement::from_bytes(&_from_hex(str)?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        Ok(self.bn.into_bigint().to_bytes_be())
+    }
+
+    pub fn from_bytes(b: &[u8]) -> Result<GroupOrderElement, IndyCryptoError> {
+        if b.len() > Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+        Ok(
+            GroupOrderElement {
+                bn: Fr::from_be_bytes_mod_order(b)
+            }
+        )
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for GroupOrderElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("GroupOrderElement", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for GroupOrderElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct GroupOrderElementVisitor;
+
+        impl<'a> Visitor<'a> for GroupOrderElementVisitor {
+            type Value = GroupOrderElement;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected GroupOrderElement")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<GroupOrderElement, E>
+                where E: DError
+            {
+                Ok(GroupOrderElement::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(GroupOrderElementVisitor)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pair {
+    pair: PairingOutput<Bls12_381>
+}
+
+impl Pair {
+    const BYTES_REPR_SIZE: usize = 576;
+    /// e(PointG1, PointG2)
+    pub fn pair(p: &PointG1, q: &PointG2) -> Result<Pair, IndyCryptoError> {
+        Ok(Pair {
+            pair: Bls12_381::pairing(p.point, q.point)
+        })
+    }
+
+    /// e(p1, q1) * e(p2, q2) * ... computed with single final exponentiation
+    pub fn multi_pair(pairs: &[(PointG1, PointG2)]) -> Result<Pair, IndyCryptoError> {
+        if pairs.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Pairs list is empty")));
+        }
+
+        let ps: Vec<G1Projective> = pairs.iter().map(|&(p, _)| p.point).collect();
+        let qs: Vec<G2Projective> = pairs.iter().map(|&(_, q)| q.point).collect();
+
+        Ok(Pair {
+            pair: Bls12_381::multi_pairing(ps, qs)
+        })
+    }
+
+    /// e() * e()
+    pub fn mul(&self, b: &Pair) -> Result<Pair, IndyCryptoError> {
+        // target group is written additively by arkworks
+        Ok(Pair {
+            pair: self.pair + b.pair
+        })
+    }
+
+    /// e() ^ GroupOrderElement
+    pub fn pow(&self, b: &GroupOrderElement) -> Result<Pair, IndyCryptoError> {
+        Ok(Pair {
+            pair: self.pair * b.bn
+        })
+    }
+
+    /// 1 / e()
+    pub fn inverse(&self) -> Result<Pair, IndyCryptoError> {
+        Ok(Pair {
+            pair: -self.pair
+        })
+    }
+
+    pub fn to_string(&self) -> Result<String, IndyCryptoError> {
+        Ok(_to_hex(&self.to_bytes()?))
+    }
+
+    pub fn from_string(str: &str) -> Result<Pair, IndyCryptoError> {
+        let bytes = _from_hex(str)?;
+
+        if bytes.len() != Self::BYTES_REPR_SIZE {
+            return Err(IndyCryptoError::InvalidStructure(
+                "Invalid len of bytes representation".to_string()));
+        }
+
+        let pair = PairingOutput::<Bls12_381>::deserialize_uncompressed(&bytes[..])
+            .map_err(|err| IndyCryptoError::InvalidStructure(format!("Invalid bytes representation of Pair: {:?}", err)))?;
+
+        Ok(Pair {
+            pair: pair
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, IndyCryptoError> {
+        serialize(&self.pair)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl Serialize for Pair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_newtype_struct("Pair", &self.to_string().map_err(SError::custom)?)
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl<'a> Deserialize<'a> for Pair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'a> {
+        struct PairVisitor;
+
+        impl<'a> Visitor<'a> for PairVisitor {
+            type Value = Pair;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("expected Pair")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Pair, E>
+                where E: DError
+            {
+                Ok(Pair::from_string(value).map_err(DError::custom)?)
+            }
+        }
+
+        deserializer.deserialize_str(PairVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
+
+    #[test]
+    fn group_order_element_new_from_seed_works_for_invalid_seed_len() {
+        let err = GroupOrderElement::new_from_seed(&[0, 1, 2]).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn group_order_element_new_from_seed_works() {
+        let seed = vec![7u8; GroupOrderElement::BYTES_REPR_SIZE];
+        let e1 = GroupOrderElement::new_from_seed(&seed).unwrap();
+        let e2 = GroupOrderElement::new_from_seed(&seed).unwrap();
+        let e3 = GroupOrderElement::new_from_seed(&vec![8u8; GroupOrderElement::BYTES_REPR_SIZE]).unwrap();
+        assert_eq!(e1, e2);
+        assert_ne!(e1, e3);
+    }
+
+    #[test]
+    fn group_order_element_inverse_works_for_zero() {
+        let zero = GroupOrderElement::from_bytes(&[0]).unwrap();
+        let err = zero.inverse().unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn multi_pair_works() {
+        let p1 = PointG1::new().unwrap();
+        let q1 = PointG2::new().unwrap();
+        let p2 = PointG1::new().unwrap();
+        let q2 = PointG2::new().unwrap();
+        let left = Pair::multi_pair(&[(p1, q1), (p2, q2)]).unwrap();
+        let right = Pair::pair(&p1, &q1).unwrap().mul(&Pair::pair(&p2, &q2).unwrap()).unwrap();
+        assert_eq!(left, right);
+    }
+
+    const HASH_TO_CURVE_DST: &'static [u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+    // Test vectors from RFC 9380, appendix J.9.1
+    fn _hash_to_curve_vectors() -> Vec<(Vec<u8>, &'static str, &'static str)> {
+        let mut q128 = b"q128_".to_vec();
+        q128.extend_from_slice(&[b'q'; 128]);
+
+        let mut a512 = b"a512_".to_vec();
+        a512.extend_from_slice(&[b'a'; 512]);
+
+        vec![
+            (b"".to_vec(),
+             "052926ADD2207B76CA4FA57A8734416C8DC95E24501772C814278700EED6D1E4E8CF62D9C09DB0FAC349612B759E79A1",
+             "08BA738453BFED09CB546DBB0783DBB3A5F1F566ED67BB6BE0E8C67E2E81A4CC68EE29813BB7994998F3EAE0C9C6A265"),
+            (b"abc".to_vec(),
+             "03567BC5EF9C690C2AB2ECDF6A96EF1C139CC0B2F284DCA0A9A7943388A49A3AEE664BA5379A7655D3C68900BE2F6903",
+             "0B9C15F3FE6E5CF4211F346271D7B01C8F3B28BE689C8429C85B67AF215533311F0B8DFAAA154FA6B88176C229F2885D"),
+            (b"abcdef0123456789".to_vec(),
+             "11E0B079DEA29A68F0383EE94FED1B940995272407E3BB916BBF268C263DDD57A6A27200A784CBC248E84F357CE82D98",
+             "03A87AE2CAF14E8EE52E51FA2ED8EEFE80F02457004BA4D486D6AA1F517C0889501DC7413753F9599B099EBCBBD2D709"),
+            (q128,
+             "15F68EAA693B95CCB85215DC65FA81038D69629F70AEEE0D0F677CF22285E7BF58D7CB86EEFE8F2E9BC3F8CB84FAC488",
+             "1807A1D50C29F430B8CAFC4F8638DFEEADF51211E1602A5F184443076715F91BB90A48BA1E370EDCE6AE1062F5E6DD38"),
+            (a512,
+             "082AABAE8B7DEDB0E78AEB619AD3BFD9277A2F77BA7FAD20EF6AABDC6C31D19BA5A6D12283553294C1825C4B3CA2DCFE",
+             "05B84AE5A942248EEA39E1D91030458C40153F3B654AB7872D779AD1E942856A20C438E8D99BC8ABFBF74729CE1F7AC8")
+        ]
+    }
+
+    fn _point_g1_from_hex(x: &str, y: &str) -> PointG1 {
+        PointG1::_from_affine((BigNumber::from_hex(x).unwrap(), BigNumber::from_hex(y).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works() {
+        for (message, x, y) in _hash_to_curve_vectors() {
+            let point = PointG1::hash_to_curve(&message, HASH_TO_CURVE_DST).unwrap();
+            assert_eq!(_point_g1_from_hex(x, y).to_bytes().unwrap(), point.to_bytes().unwrap());
+        }
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works_for_different_dst() {
+        let point1 = PointG1::hash_to_curve(b"abc", HASH_TO_CURVE_DST).unwrap();
+        let point2 = PointG1::hash_to_curve(b"abc", b"OTHER-DST").unwrap();
+        assert_ne!(point1.to_bytes().unwrap(), point2.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn point_g1_hash_to_curve_works_for_empty_dst() {
+        let err = PointG1::hash_to_curve(b"abc", b"").unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn point_g1_from_hash_works() {
+        let hash = vec![7u8; 32];
+        let point = PointG1::from_hash(&hash).unwrap();
+        assert!(point.is_in_subgroup().unwrap());
+        assert!(!point.is_inf().unwrap());
+    }
+
+    #[test]
+    fn point_g1_is_in_subgroup_works_for_point_out_of_subgroup() {
+        let point = PointG1 {
+            point: G1Affine::get_point_from_x_unchecked(Fq::from(4u64), true).unwrap().into_group()
+        };
+        assert!(!point.is_in_subgroup().unwrap());
+
+        let cleared = PointG1 {
+            point: point.point.mul_bigint(&[G1_COFACTOR])
+        };
+        assert!(cleared.is_in_subgroup().unwrap());
+    }
+
+    #[test]
+    fn point_g1_from_bytes_works_for_point_not_on_curve() {
+        let mut bytes = PointG1::new().unwrap().to_bytes().unwrap();
+        bytes[PointG1::BYTES_REPR_SIZE - 1] ^= 1;
+        let err = PointG1::from_bytes(&bytes).unwrap_err();
+        assert_eq!(err.to_error_code(), ErrorCode::CommonInvalidStructure);
+    }
+
+    #[test]
+    fn pairing_definition_bilinearity() {
+        let a = GroupOrderElement::new().unwrap();
+        let b = GroupOrderElement::new().unwrap();
+        let p = PointG1::new().unwrap();
+        let q = PointG2::new().unwrap();
+        let left = Pair::pair(&p.mul(&a).unwrap(), &q.mul(&b).unwrap()).unwrap();
+        let right = Pair::pair(&p, &q).unwrap().pow(&a.mul_mod(&b).unwrap()).unwrap();
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn point_g1_infinity_test() {
+        let p = PointG1::new_inf().unwrap();
+        let q = PointG1::new().unwrap();
+        let result = p.add(&q).unwrap();
+        assert_eq!(q, result);
+    }
+
+    #[test]
+    fn point_g1_infinity_test2() {
+        let p = PointG1::new().unwrap();
+        let inf = p.sub(&p).unwrap();
+        let q = PointG1::new().unwrap();
+        let result = inf.add(&q).unwrap();
+        assert_eq!(q, result);
+    }
+
+    #[test]
+    fn point_g2_infinity_test() {
+        let p = PointG2::new_inf().unwrap();
+        let q = PointG2::new().unwrap();
+        let result = p.add(&q).unwrap();
+        assert_eq!(q, result);
+    }
+
+    #[test]
+    fn inverse_for_pairing() {
+        let p1 = PointG1::new().unwrap();
+        let q1 = PointG2::new().unwrap();
+        let p2 = PointG1::new().unwrap();
+        let q2 = PointG2::new().unwrap();
+        let pair1 = Pair::pair(&p1, &q1).unwrap();
+        let pair2 = Pair::pair(&p2, &q2).unwrap();
+        let pair_result = pair1.mul(&pair2).unwrap();
+        let pair3 = pair_result.mul(&pair1.inverse().unwrap()).unwrap();
+        assert_eq!(pair2, pair3);
+    }
+}
+
+#[cfg(feature = "serialization")]
+#[cfg(test)]
+mod serialization_tests {
+    use super::*;
+
+    extern crate serde_json;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TestGroupOrderElementStructure {
+        field: GroupOrderElement
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TestPointG1Structure {
+        field: PointG1
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TestPointG2Structure {
+        field: PointG2
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct TestPairStructure {
+        field: Pair
+    }
+
+    #[test]
+    fn from_bytes_to_bytes_works_for_group_order_element() {
+        let vec = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 116, 221, 243, 243, 0, 77, 170, 65, 179, 245, 119, 182, 251, 185, 78, 98];
+        let bytes = GroupOrderElement::from_bytes(&vec).unwrap();
+        let result = bytes.to_bytes().unwrap();
+        assert_eq!(vec, result);
+    }
+
+    #[test]
+    fn serialize_deserialize_works_for_group_order_element() {
+        let structure = TestGroupOrderElementStructure {
+            field: GroupOrderElement::from_string("00A6F18598A10FAE736E4DAFC3F174FA57A6D2B80083D533FEDE2C88B8129AA6").unwrap()
+        };
+        let deserialized: TestGroupOrderElementStructure = serde_json::from_str(&serde_json::to_string(&structure).unwrap()).unwrap();
+
+        assert_eq!(structure, deserialized);
+    }
+
+    #[test]
+    fn serialize_deserialize_works_for_point_g1() {
+        let structure = TestPointG1Structure {
+            field: PointG1::new().unwrap()
+        };
+
+        let deserialized: TestPointG1Structure = serde_json::from_str(&serde_json::to_string(&structure).unwrap()).unwrap();
+
+        assert_eq!(structure, deserialized);
+    }
+
+    #[test]
+    fn serialize_deserialize_works_for_point_g1_infinity() {
+        let structure = TestPointG1Structure {
+            field: PointG1::new_inf().unwrap()
+        };
+
+        let deserialized: TestPointG1Structure = serde_json::from_str(&serde_json::to_string(&structure).unwrap()).unwrap();
+
+        assert_eq!(structure, deserialized);
+    }
+
+    #[test]
+    fn deserialize_works_for_point_g2() {
+        let structure = TestPointG2Structure {
+            field: PointG2::new().unwrap()
+        };
+        let deserialized: TestPointG2Structure = serde_json::from_str(&serde_json::to_string(&structure).unwrap()).unwrap();
+
+        assert_eq!(structure, deserialized);
+    }
+
+    #[test]
+    fn serialize_deserialize_works_for_pair() {
+        let pair = TestPairStructure {
+            field: Pair::pair(&PointG1::new().unwrap(), &PointG2::new().unwrap()).unwrap()
+        };
+        let deserialized: TestPairStructure = serde_json::from_str(&serde_json::to_string(&pair).unwrap()).unwrap();
+
+        assert_eq!(pair, deserialized);
+    }
+}
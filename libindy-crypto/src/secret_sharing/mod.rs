@@ -0,0 +1,340 @@
+use errors::IndyCryptoError;
+use pair::{GroupOrderElement, PointG1};
+#[cfg(feature = "serialization")]
+use utils::encrypted_json::EncryptedJson;
+use utils::json::{JsonEncodable, JsonDecodable};
+
+use sha2::{Sha256, Digest};
+
+use std::collections::HashSet;
+
+/// Domain separation prefix of value generator of share commitments.
+pub const SHARE_COMMITMENT_VALUE_GENERATOR_DOMAIN: &'static [u8] = b"indy_crypto:secret_sharing:value_generator:1";
+
+/// Domain separation prefix of blinding generator of share commitments.
+pub const SHARE_COMMITMENT_GENERATOR_DOMAIN: &'static [u8] = b"indy_crypto:secret_sharing:blinding_generator:1";
+
+/// Share of secret split by `SecretSharing::split`.
+/// Secret is represented as one or more limbs (elements of group order field),
+/// each limb is shared by its own random polynomial and blinded by another random polynomial.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecretShare {
+    index: u32,
+    limbs: Vec<GroupOrderElement>,
+    blindings: Vec<GroupOrderElement>
+}
+
+impl SecretShare {
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl JsonEncodable for SecretShare {}
+
+impl<'a> JsonDecodable<'a> for SecretShare {}
+
+#[cfg(feature = "serialization")]
+impl EncryptedJson for SecretShare {
+    const KIND: &'static str = "SecretShare";
+}
+
+/// Pedersen commitments to coefficients of sharing polynomials.
+/// Allows any share holder to check that share is consistent with other shares.
+/// Commitments are hiding, so they reveal nothing about secret even if limbs are small.
+/// Both generators are derived from hashes, so dealer doesn't know relation between them
+/// and can't open commitments to other values.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ShareCommitments {
+    limbs: Vec<Vec<PointG1>> /* g * a_ij + h * b_ij for coefficient j of polynomials of limb i */
+}
+
+impl ShareCommitments {
+    /// Returns number of shares required to recover secret.
+    pub fn get_threshold(&self) -> usize {
+        self.limbs.first().map_or(0, |coefficients| coefficients.len())
+    }
+}
+
+impl JsonEncodable for ShareCommitments {}
+
+impl<'a> JsonDecodable<'a> for ShareCommitments {}
+
+/// Verifiable (Shamir with Pedersen commitments) secret sharing.
+pub struct SecretSharing {}
+
+impl SecretSharing {
+    /// Splits secret into `shares_count` shares so that any `threshold` of them recover secret.
+    ///
+    /// # Arguments
+    /// * `secret` - Secret limbs.
+    /// * `threshold` - Number of shares required to recover secret.
+    /// * `shares_count` - Number of shares.
+    pub fn split(secret: &[GroupOrderElement], threshold: usize, shares_count: usize) -> Result<(Vec<SecretShare>, ShareCommitments), IndyCryptoError> {
+        trace!("SecretSharing::split: >>> threshold: {:?}, shares_count: {:?}", threshold, shares_count);
+
+        if secret.is_empty() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Secret is empty")));
+        }
+
+        if threshold == 0 || threshold > shares_count || shares_count >= u32::max_value() as usize {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Invalid threshold {} for {} shares", threshold, shares_count)));
+        }
+
+        let (g, h) = SecretSharing::_generators()?;
+
+        let mut polynomials: Vec<(Vec<GroupOrderElement>, Vec<GroupOrderElement>)> = Vec::new();
+        let mut commitments: Vec<Vec<PointG1>> = Vec::new();
+
+        for limb in secret {
+            let mut coefficients = vec![*limb];
+            let mut blinding_coefficients = vec![GroupOrderElement::new()?];
+            for _ in 1..threshold {
+                coefficients.push(GroupOrderElement::new()?);
+                blinding_coefficients.push(GroupOrderElement::new()?);
+            }
+
+            let mut limb_commitments: Vec<PointG1> = Vec::new();
+            for (coefficient, blinding_coefficient) in coefficients.iter().zip(blinding_coefficients.iter()) {
+                limb_commitments.push(g.mul(coefficient)?.add(&h.mul(blinding_coefficient)?)?);
+            }
+
+            polynomials.push((coefficients, blinding_coefficients));
+            commitments.push(limb_commitments);
+        }
+
+        let mut shares: Vec<SecretShare> = Vec::new();
+
+        for index in 1..(shares_count as u32 + 1) {
+            let x = SecretSharing::index_to_element(index)?;
+
+            let mut limbs: Vec<GroupOrderElement> = Vec::new();
+            let mut blindings: Vec<GroupOrderElement> = Vec::new();
+            for &(ref coefficients, ref blinding_coefficients) in polynomials.iter() {
+                limbs.push(SecretSharing::eval_polynomial(coefficients, &x)?);
+                blindings.push(SecretSharing::eval_polynomial(blinding_coefficients, &x)?);
+            }
+
+            shares.push(SecretShare { index, limbs, blindings });
+        }
+
+        let commitments = ShareCommitments { limbs: commitments };
+
+        trace!("SecretSharing::split: <<< commitments: {:?}", commitments);
+
+        Ok((shares, commitments))
+    }
+
+    /// Checks that share is consistent with commitments published on split.
+    ///
+    /// # Arguments
+    /// * `share` - Secret share.
+    /// * `commitments` - Share commitments.
+    pub fn verify_share(share: &SecretShare, commitments: &ShareCommitments) -> Result<bool, IndyCryptoError> {
+        trace!("SecretSharing::verify_share: >>> index: {:?}, commitments: {:?}", share.index, commitments);
+
+        if share.index == 0
+            || share.limbs.len() != commitments.limbs.len()
+            || share.blindings.len() != commitments.limbs.len() {
+            return Ok(false);
+        }
+
+        let (g, h) = SecretSharing::_generators()?;
+        let x = SecretSharing::index_to_element(share.index)?;
+        let mut valid = true;
+
+        for ((limb, blinding), limb_commitments) in share.limbs.iter().zip(share.blindings.iter()).zip(commitments.limbs.iter()) {
+            let mut expected = PointG1::new_inf()?;
+            for commitment in limb_commitments.iter().rev() {
+                expected = expected.mul(&x)?.add(commitment)?;
+            }
+
+            valid &= g.mul(limb)?.add(&h.mul(blinding)?)?.to_bytes()? == expected.to_bytes()?;
+        }
+
+        trace!("SecretSharing::verify_share: <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    /// Recovers secret from shares. Every share is checked against commitments,
+    /// so corrupted share is detected.
+    ///
+    /// # Arguments
+    /// * `shares` - At least threshold shares with distinct indexes.
+    /// * `commitments` - Share commitments.
+    pub fn combine(shares: &[&SecretShare], commitments: &ShareCommitments) -> Result<Vec<GroupOrderElement>, IndyCryptoError> {
+        trace!("SecretSharing::combine: >>> indexes: {:?}, commitments: {:?}", shares.iter().map(|share| share.index).collect::<Vec<u32>>(), commitments);
+
+        let threshold = commitments.get_threshold();
+
+        if threshold == 0 || shares.len() < threshold {
+            return Err(IndyCryptoError::InvalidStructure(
+                format!("Not enough shares: expected at least {}, actual {}", threshold, shares.len())));
+        }
+
+        if commitments.limbs.iter().any(|coefficients| coefficients.len() != threshold) {
+            return Err(IndyCryptoError::InvalidStructure(format!("Invalid share commitments")));
+        }
+
+        if shares.iter().map(|share| share.index).collect::<HashSet<u32>>().len() != shares.len() {
+            return Err(IndyCryptoError::InvalidStructure(format!("Shares contain duplicate indexes")));
+        }
+
+        for share in shares {
+            if !SecretSharing::verify_share(share, commitments)? {
+                return Err(IndyCryptoError::InvalidStructure(format!("Share {} doesn't correspond to commitments", share.index)));
+            }
+        }
+
+        let (g, h) = SecretSharing::_generators()?;
+        let shares = &shares[..threshold];
+        let xs = shares.iter()
+            .map(|share| SecretSharing::index_to_element(share.index))
+            .collect::<Result<Vec<GroupOrderElement>, IndyCryptoError>>()?;
+
+        let mut secret: Vec<GroupOrderElement> = Vec::new();
+
+        for i in 0..commitments.limbs.len() {
+            let mut limb = SecretSharing::index_to_element(0)?;
+            let mut blinding = SecretSharing::index_to_element(0)?;
+
+            for (k, share) in shares.iter().enumerate() {
+                let lagrange_coefficient = SecretSharing::lagrange_coefficient(&xs, k)?;
+                limb = limb.add_mod(&share.limbs[i].mul_mod(&lagrange_coefficient)?)?;
+                blinding = blinding.add_mod(&share.blindings[i].mul_mod(&lagrange_coefficient)?)?;
+            }
+
+            if g.mul(&limb)?.add(&h.mul(&blinding)?)?.to_bytes()? != commitments.limbs[i][0].to_bytes()? {
+                return Err(IndyCryptoError::InvalidStructure(format!("Recovered secret doesn't correspond to commitments")));
+            }
+
+            secret.push(limb);
+        }
+
+        trace!("SecretSharing::combine: <<<");
+
+        Ok(secret)
+    }
+
+    /// Value and blinding generators of commitments. They are derived from hashes
+    /// of distinct domains, so nobody knows discrete log of one to the base of the other.
+    fn _generators() -> Result<(PointG1, PointG1), IndyCryptoError> {
+        Ok((SecretSharing::_hash_to_generator(SHARE_COMMITMENT_VALUE_GENERATOR_DOMAIN)?,
+            SecretSharing::_hash_to_generator(SHARE_COMMITMENT_GENERATOR_DOMAIN)?))
+    }
+
+    fn _hash_to_generator(domain: &[u8]) -> Result<PointG1, IndyCryptoError> {
+        let mut hasher = Sha256::default();
+        hasher.input(domain);
+        PointG1::from_hash(hasher.result().as_slice())
+    }
+
+    /// Returns share index as element of group order field.
+    pub fn index_to_element(index: u32) -> Result<GroupOrderElement, IndyCryptoError> {
+        GroupOrderElement::from_bytes(&[(index >> 24) as u8, (index >> 16) as u8, (index >> 8) as u8, index as u8])
+    }
+
+    /// Evaluates polynomial with given coefficients (lowest degree first) at `x`.
+    pub fn eval_polynomial(coefficients: &[GroupOrderElement], x: &GroupOrderElement) -> Result<GroupOrderElement, IndyCryptoError> {
+        let mut res = SecretSharing::index_to_element(0)?;
+
+        for coefficient in coefficients.iter().rev() {
+            res = res.mul_mod(x)?.add_mod(coefficient)?;
+        }
+
+        Ok(res)
+    }
+
+    /// Lagrange coefficient of point `xs[k]` for interpolation at zero.
+    pub fn lagrange_coefficient(xs: &[GroupOrderElement], k: usize) -> Result<GroupOrderElement, IndyCryptoError> {
+        let mut num = SecretSharing::index_to_element(1)?;
+        let mut den = SecretSharing::index_to_element(1)?;
+
+        for (j, x) in xs.iter().enumerate() {
+            if j != k {
+                num = num.mul_mod(x)?;
+                den = den.mul_mod(&x.add_mod(&xs[k].mod_neg()?)?)?;
+            }
+        }
+
+        num.mul_mod(&den.inverse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_combine_works() {
+        let secret = vec![GroupOrderElement::new().unwrap(), GroupOrderElement::new().unwrap()];
+
+        let (shares, commitments) = SecretSharing::split(&secret, 3, 5).unwrap();
+        assert_eq!(5, shares.len());
+        assert_eq!(3, commitments.get_threshold());
+
+        let recovered = SecretSharing::combine(&[&shares[4], &shares[0], &shares[2]], &commitments).unwrap();
+        assert_eq!(secret, recovered);
+    }
+
+    #[test]
+    fn verify_share_works() {
+        let secret = vec![GroupOrderElement::new().unwrap()];
+
+        let (shares, commitments) = SecretSharing::split(&secret, 2, 3).unwrap();
+
+        for share in shares.iter() {
+            assert!(SecretSharing::verify_share(share, &commitments).unwrap());
+        }
+    }
+
+    #[test]
+    fn share_commitments_to_json_works_without_generator() {
+        let secret = vec![GroupOrderElement::new().unwrap()];
+
+        let (_, commitments) = SecretSharing::split(&secret, 2, 3).unwrap();
+
+        let json = commitments.to_json().unwrap();
+        assert!(!json.contains(r#""g""#));
+        assert_eq!(2, ShareCommitments::from_json(&json).unwrap().get_threshold());
+    }
+
+    #[test]
+    fn verify_share_works_for_corrupted_share() {
+        let secret = vec![GroupOrderElement::new().unwrap()];
+
+        let (mut shares, commitments) = SecretSharing::split(&secret, 2, 3).unwrap();
+        shares[1].limbs[0] = GroupOrderElement::new().unwrap();
+
+        assert!(!SecretSharing::verify_share(&shares[1], &commitments).unwrap());
+        assert!(SecretSharing::combine(&[&shares[0], &shares[1]], &commitments).is_err());
+    }
+
+    #[test]
+    fn combine_works_for_not_enough_shares() {
+        let secret = vec![GroupOrderElement::new().unwrap()];
+
+        let (shares, commitments) = SecretSharing::split(&secret, 3, 5).unwrap();
+
+        assert!(SecretSharing::combine(&[&shares[0], &shares[1]], &commitments).is_err());
+    }
+
+    #[test]
+    fn combine_works_for_duplicate_shares() {
+        let secret = vec![GroupOrderElement::new().unwrap()];
+
+        let (shares, commitments) = SecretSharing::split(&secret, 2, 3).unwrap();
+
+        assert!(SecretSharing::combine(&[&shares[0], &shares[0]], &commitments).is_err());
+    }
+
+    #[test]
+    fn split_works_for_invalid_threshold() {
+        let secret = vec![GroupOrderElement::new().unwrap()];
+
+        assert!(SecretSharing::split(&secret, 0, 3).is_err());
+        assert!(SecretSharing::split(&secret, 4, 3).is_err());
+    }
+}
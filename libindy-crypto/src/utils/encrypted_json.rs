@@ -0,0 +1,251 @@
+extern crate serde;
+extern crate serde_json;
+
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce, Tag};
+use chacha20poly1305::aead::{AeadInPlace, KeyInit};
+use errors::IndyCryptoError;
+use rand::os::OsRng;
+use rand::Rng;
+use utils::json::JsonEncodable;
+
+use scrypt::{scrypt, Params as ScryptParams};
+use self::serde::de::DeserializeOwned;
+use std::str;
+
+/// Version of encrypted envelope format.
+pub const ENCRYPTED_ENVELOPE_VERSION: u32 = 1;
+
+const KDF_SCRYPT: &'static str = "scrypt";
+const CIPHER_CHACHA20_POLY1305: &'static str = "chacha20-poly1305";
+
+/// scrypt cost parameters used for export: N = 2^15, r = 8, p = 1 (32 MiB of memory).
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+/// Upper bounds of scrypt cost parameters accepted on import. Parameters are read
+/// before authentication, so they can't exceed ones used for export.
+const MAX_SCRYPT_LOG_N: u8 = SCRYPT_LOG_N;
+const MAX_SCRYPT_R: u32 = SCRYPT_R;
+const MAX_SCRYPT_P: u32 = SCRYPT_P;
+
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// Versioned envelope of password-encrypted JSON.
+/// Key is derived from password by scrypt, JSON is encrypted by ChaCha20-Poly1305.
+/// Envelope header (version, kind and KDF parameters) is authenticated as associated data.
+#[derive(Debug, Deserialize, Serialize)]
+struct EncryptedEnvelope {
+    version: u32,
+    kind: String,
+    kdf: String,
+    kdf_params: ScryptEnvelopeParams,
+    cipher: String,
+    nonce: String,
+    ciphertext: String,
+    tag: String
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ScryptEnvelopeParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+    salt: String
+}
+
+/// Password-encrypted export and import of entities containing secret data.
+pub trait EncryptedJson: JsonEncodable + DeserializeOwned {
+    /// Entity type stored in envelope. Envelope of another type can't be imported.
+    const KIND: &'static str;
+
+    fn to_encrypted_json(&self, password: &str) -> Result<String, IndyCryptoError> {
+        encrypt(Self::KIND, &self.to_json()?, password)
+    }
+
+    fn from_encrypted_json(encrypted_json: &str, password: &str) -> Result<Self, IndyCryptoError> {
+        let json = decrypt(Self::KIND, encrypted_json, password)?;
+        serde_json::from_str(&json)
+            .map_err(|err| IndyCryptoError::from(err))
+    }
+}
+
+fn encrypt(kind: &str, json: &str, password: &str) -> Result<String, IndyCryptoError> {
+    trace!("encrypt: >>> kind: {:?}", kind);
+
+    let mut rng = OsRng::new()?;
+
+    let mut salt = vec![0u8; SALT_LEN];
+    rng.fill_bytes(&mut salt);
+
+    let mut nonce = vec![0u8; NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    let key = _derive_key(password, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)?;
+    let aad = _associated_data(ENCRYPTED_ENVELOPE_VERSION, kind, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P, &salt);
+
+    let mut ciphertext = json.as_bytes().to_vec();
+    let tag = ChaCha20Poly1305::new(Key::from_slice(&key))
+        .encrypt_in_place_detached(Nonce::from_slice(&nonce), &aad, &mut ciphertext)
+        .map_err(|_| IndyCryptoError::InvalidState(format!("Can't encrypt data")))?;
+
+    let envelope = EncryptedEnvelope {
+        version: ENCRYPTED_ENVELOPE_VERSION,
+        kind: kind.to_owned(),
+        kdf: KDF_SCRYPT.to_owned(),
+        kdf_params: ScryptEnvelopeParams {
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: _to_hex(&salt)
+        },
+        cipher: CIPHER_CHACHA20_POLY1305.to_owned(),
+        nonce: _to_hex(&nonce),
+        ciphertext: _to_hex(&ciphertext),
+        tag: _to_hex(&tag)
+    };
+
+    let res = serde_json::to_string(&envelope)?;
+
+    trace!("encrypt: <<<");
+
+    Ok(res)
+}
+
+fn decrypt(kind: &str, encrypted_json: &str, password: &str) -> Result<String, IndyCryptoError> {
+    trace!("decrypt: >>> kind: {:?}", kind);
+
+    let envelope: EncryptedEnvelope = serde_json::from_str(encrypted_json)?;
+
+    if envelope.version != ENCRYPTED_ENVELOPE_VERSION {
+        return Err(IndyCryptoError::InvalidStructure(format!("Unsupported encrypted envelope version: {}", envelope.version)));
+    }
+
+    if envelope.kind != kind {
+        return Err(IndyCryptoError::InvalidStructure(format!("Encrypted envelope contains {}, expected {}", envelope.kind, kind)));
+    }
+
+    if envelope.kdf != KDF_SCRYPT || envelope.cipher != CIPHER_CHACHA20_POLY1305 {
+        return Err(IndyCryptoError::InvalidStructure(format!("Unsupported encrypted envelope algorithms: {}, {}", envelope.kdf, envelope.cipher)));
+    }
+
+    let params = &envelope.kdf_params;
+
+    if params.log_n == 0 || params.log_n > MAX_SCRYPT_LOG_N
+        || params.r == 0 || params.r > MAX_SCRYPT_R
+        || params.p == 0 || params.p > MAX_SCRYPT_P
+        || params.log_n as u32 >= params.r * 16 {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid scrypt parameters")));
+    }
+
+    let salt = _from_hex(&params.salt)?;
+    let nonce = _from_hex(&envelope.nonce)?;
+    let ciphertext = _from_hex(&envelope.ciphertext)?;
+    let tag = _from_hex(&envelope.tag)?;
+
+    if salt.len() != SALT_LEN || nonce.len() != NONCE_LEN || tag.len() != TAG_LEN {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid len of encrypted envelope fields")));
+    }
+
+    let key = _derive_key(password, &salt, params.log_n, params.r, params.p)?;
+    let aad = _associated_data(envelope.version, &envelope.kind, params.log_n, params.r, params.p, &salt);
+
+    let mut plaintext = ciphertext;
+
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+        .decrypt_in_place_detached(Nonce::from_slice(&nonce), &aad, &mut plaintext, Tag::from_slice(&tag))
+        .map_err(|_| IndyCryptoError::DecryptionFailed(format!("Wrong password or corrupted data")))?;
+
+    let res = String::from_utf8(plaintext)
+        .map_err(|err| IndyCryptoError::InvalidStructure(format!("Invalid UTF-8 in decrypted data: {:?}", err)))?;
+
+    trace!("decrypt: <<<");
+
+    Ok(res)
+}
+
+fn _derive_key(password: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<Vec<u8>, IndyCryptoError> {
+    let params = ScryptParams::new(log_n, r, p, KEY_LEN)
+        .map_err(|_| IndyCryptoError::InvalidStructure(format!("Invalid scrypt parameters")))?;
+
+    let mut key = vec![0u8; KEY_LEN];
+    scrypt(password.as_bytes(), salt, &params, &mut key)
+        .map_err(|_| IndyCryptoError::InvalidState(format!("Invalid scrypt output length")))?;
+
+    Ok(key)
+}
+
+fn _associated_data(version: u32, kind: &str, log_n: u8, r: u32, p: u32, salt: &[u8]) -> Vec<u8> {
+    format!("{}:{}:{}:{}:{}:{}:{}:{}", version, kind, KDF_SCRYPT, log_n, r, p, _to_hex(salt), CIPHER_CHACHA20_POLY1305).into_bytes()
+}
+
+fn _to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn _from_hex(hex: &str) -> Result<Vec<u8>, IndyCryptoError> {
+    if hex.len() % 2 != 0 {
+        return Err(IndyCryptoError::InvalidStructure(format!("Invalid hex string length")));
+    }
+
+    hex.as_bytes()
+        .chunks(2)
+        .map(|chunk| str::from_utf8(chunk).ok()
+            .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+            .ok_or(IndyCryptoError::InvalidStructure(format!("Invalid hex string"))))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use errors::ToErrorCode;
+    use ffi::ErrorCode;
+
+    #[test]
+    fn encrypt_decrypt_works() {
+        let json = r#"{"ms":"123"}"#;
+
+        let encrypted = encrypt("MasterSecret", json, "password").unwrap();
+
+        assert!(!encrypted.contains(r#""ms""#));
+        assert_eq!(json, decrypt("MasterSecret", &encrypted, "password").unwrap());
+    }
+
+    #[test]
+    fn decrypt_works_for_wrong_password() {
+        let encrypted = encrypt("MasterSecret", r#"{"ms":"123"}"#, "password").unwrap();
+
+        let res = decrypt("MasterSecret", &encrypted, "wrong password");
+        assert_eq!(ErrorCode::CommonDecryptionFailed, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn decrypt_works_for_other_kind() {
+        let encrypted = encrypt("MasterSecret", r#"{"ms":"123"}"#, "password").unwrap();
+
+        let res = decrypt("IssuerPrivateKey", &encrypted, "password");
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn decrypt_works_for_tampered_header() {
+        let encrypted = encrypt("MasterSecret", r#"{"ms":"123"}"#, "password").unwrap();
+        let tampered = encrypted.replace(r#""log_n":15"#, r#""log_n":14"#);
+
+        let res = decrypt("MasterSecret", &tampered, "password");
+        assert_eq!(ErrorCode::CommonDecryptionFailed, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn decrypt_works_for_too_expensive_scrypt_params() {
+        let encrypted = encrypt("MasterSecret", r#"{"ms":"123"}"#, "password").unwrap();
+        let tampered = encrypted.replace(r#""log_n":15"#, r#""log_n":20"#);
+
+        let res = decrypt("MasterSecret", &tampered, "password");
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+    }
+}
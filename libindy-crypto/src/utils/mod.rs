@@ -1,3 +1,5 @@
 #[macro_use]
 pub mod ctypes;
+#[cfg(feature = "serialization")]
+pub mod encrypted_json;
 pub mod json;
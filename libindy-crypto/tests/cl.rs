@@ -1,9 +1,16 @@
 extern crate indy_crypto;
+extern crate time;
 
+use indy_crypto::bls::{Generator, SignKey, VerKey};
+use indy_crypto::cl::RevocationBackend;
 use indy_crypto::cl::issuer::Issuer;
 use indy_crypto::cl::prover::Prover;
+use indy_crypto::cl::tails::{FileTailsReader, write_tails_file};
 use indy_crypto::cl::verifier::Verifier;
 
+use std::env;
+use std::fs;
+
 pub const PROVER_ID: &'static str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
 
 mod test {
@@ -29,7 +36,7 @@ mod test {
         let (gvt_issuer_pub_key, gvt_issuer_priv_key) = Issuer::new_keys(&gvt_claim_schema, true).unwrap();
 
         // 4. Issuer creates GVT revocation registry
-        let (mut gvt_rev_reg_pub, gvt_rev_reg_priv) =
+        let (mut gvt_rev_reg_pub, gvt_rev_reg_priv, gvt_rev_tails) =
             Issuer::new_revocation_registry(&gvt_issuer_pub_key, 5).unwrap();
 
         // 5. Prover blinds master secret
@@ -52,7 +59,8 @@ mod test {
                                                          &gvt_issuer_priv_key,
                                                          Some(1),
                                                          Some(&mut gvt_rev_reg_pub),
-                                                         Some(&gvt_rev_reg_priv)).unwrap();
+                                                         Some(&gvt_rev_reg_priv),
+                                                         Some(&gvt_rev_tails)).unwrap();
 
         // 8. Prover processes GVT claim signature
         Prover::process_claim_signature(&mut gvt_claim_signature,
@@ -71,7 +79,7 @@ mod test {
         let (xyz_issuer_pub_key, xyz_issuer_priv_key) = Issuer::new_keys(&xyz_claim_schema, true).unwrap();
 
         // 11. Issuer creates XYZ revocation registry
-        let (mut xyz_rev_reg_pub, xyz_rev_reg_priv) =
+        let (mut xyz_rev_reg_pub, xyz_rev_reg_priv, xyz_rev_tails) =
             Issuer::new_revocation_registry(&xyz_issuer_pub_key, 5).unwrap();
 
         // 12. Prover blinds master secret
@@ -92,7 +100,8 @@ mod test {
                                                          &xyz_issuer_priv_key,
                                                          Some(1),
                                                          Some(&mut xyz_rev_reg_pub),
-                                                         Some(&xyz_rev_reg_priv)).unwrap();
+                                                         Some(&xyz_rev_reg_priv),
+                                                         Some(&xyz_rev_tails)).unwrap();
 
         // 15. Prover processes XYZ claim signature
         Prover::process_claim_signature(&mut xyz_claim_signature,
@@ -126,7 +135,8 @@ mod test {
                                             &gvt_claim_signature,
                                             &gvt_claim_values,
                                             &gvt_issuer_pub_key,
-                                            Some(&gvt_rev_reg_pub)).unwrap();
+                                            Some(&gvt_rev_reg_pub),
+                                            Some(&gvt_rev_tails)).unwrap();
 
         proof_builder.add_sub_proof_request(xyz_key_id,
                                             &xyz_sub_proof_request,
@@ -134,7 +144,8 @@ mod test {
                                             &xyz_claim_signature,
                                             &xyz_claim_values,
                                             &xyz_issuer_pub_key,
-                                            Some(&xyz_rev_reg_pub)).unwrap();
+                                            Some(&xyz_rev_reg_pub),
+                                            Some(&xyz_rev_tails)).unwrap();
 
 
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
@@ -180,6 +191,7 @@ mod test {
                                                      &issuer_pub_key, &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 7. Prover processes claim signature
@@ -194,7 +206,7 @@ mod test {
 
         // 10. Prover creates proof
         let mut proof_builder = Prover::new_proof_builder().unwrap();
-        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, None).unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, None, None).unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
         // 11. Verifier verifies proof
@@ -220,6 +232,7 @@ mod test {
                                                          &gvt_issuer_priv_key,
                                                          None,
                                                          None,
+                                                         None,
                                                          None).unwrap();
 
         // 3. Prover processes GVT claim
@@ -237,6 +250,7 @@ mod test {
                                                          &xyz_issuer_priv_key,
                                                          None,
                                                          None,
+                                                         None,
                                                          None).unwrap();
 
         // 5. Prover processes XYZ claim
@@ -260,6 +274,7 @@ mod test {
                                             &gvt_claim_signature,
                                             &gvt_claim_values,
                                             &gvt_issuer_pub_key,
+                                            None,
                                             None).unwrap();
 
         // 10. Prover adds XYZ sub proof request
@@ -270,6 +285,7 @@ mod test {
                                             &xyz_claim_signature,
                                             &xyz_claim_values,
                                             &xyz_issuer_pub_key,
+                                            None,
                                             None).unwrap();
 
         // 11. Prover gets proof which contains sub proofs for GVT and XYZ sub proof requests
@@ -283,6 +299,156 @@ mod test {
         assert!(proof_verifier.verify(&proof, &nonce).unwrap());
     }
 
+    #[test]
+    fn anoncreds_works_for_pseudonym() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, false).unwrap();
+
+        // 3. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 4. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 5. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim(PROVER_ID,
+                                                     &blinded_ms,
+                                                     &claim_values,
+                                                     &issuer_pub_key,
+                                                     &issuer_priv_key,
+                                                     None,
+                                                     None,
+                                                     None,
+                                                     None).unwrap();
+
+        // 6. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
+
+        // 7. Verifier creates nonce and sub proof request
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 8. Prover creates proof with pseudonym for Verifier scope
+        let scope = "example.com";
+        let key_id = "key_id";
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, None, None).unwrap();
+        proof_builder.add_pseudonym_request(scope).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 9. Verifier verifies proof and pseudonym
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, None).unwrap();
+        proof_verifier.add_pseudonym_request(scope).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+
+        // 10. Pseudonym is stable within scope and differs between scopes
+        let pseudonym = proof.get_pseudonym().unwrap();
+        assert_eq!(&Prover::new_pseudonym(&master_secret, scope).unwrap(), pseudonym);
+        assert_ne!(Prover::new_pseudonym(&master_secret, "another.com").unwrap().get_value().unwrap(),
+                   pseudonym.get_value().unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_pseudonym_with_wrong_master_secret() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, false).unwrap();
+
+        // 3. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 4. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 5. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim(PROVER_ID,
+                                                     &blinded_ms,
+                                                     &claim_values,
+                                                     &issuer_pub_key,
+                                                     &issuer_priv_key,
+                                                     None,
+                                                     None,
+                                                     None,
+                                                     None).unwrap();
+
+        // 6. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
+
+        // 7. Verifier creates nonce and sub proof request
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 8. Prover creates proof with pseudonym using another master secret
+        let scope = "example.com";
+        let key_id = "key_id";
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, None, None).unwrap();
+        proof_builder.add_pseudonym_request(scope).unwrap();
+        let another_master_secret = Prover::new_master_secret().unwrap();
+        let proof = proof_builder.finalize(&nonce, &another_master_secret).unwrap();
+
+        // 9. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, None).unwrap();
+        proof_verifier.add_pseudonym_request(scope).unwrap();
+        assert_eq!(false, proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_proof_without_requested_pseudonym() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, false).unwrap();
+
+        // 3. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 4. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 5. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim(PROVER_ID,
+                                                     &blinded_ms,
+                                                     &claim_values,
+                                                     &issuer_pub_key,
+                                                     &issuer_priv_key,
+                                                     None,
+                                                     None,
+                                                     None,
+                                                     None).unwrap();
+
+        // 6. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
+
+        // 7. Verifier creates nonce and sub proof request
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 8. Prover creates proof without pseudonym
+        let key_id = "key_id";
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, None, None).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 9. Verifier requests pseudonym and verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, None).unwrap();
+        proof_verifier.add_pseudonym_request("example.com").unwrap();
+        let res = proof_verifier.verify(&proof, &nonce);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+    }
+
     #[test]
     fn anoncreds_works_for_revocation_proof() {
         // 1. Issuer creates claim schema
@@ -292,7 +458,7 @@ mod test {
         let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
 
         // 3. Issuer creates revocation registry
-        let (mut rev_reg_pub, rev_reg_priv) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
 
         // 4. Prover creates master secret
         let master_secret = Prover::new_master_secret().unwrap();
@@ -309,7 +475,8 @@ mod test {
                                                      &issuer_priv_key,
                                                      Some(1),
                                                      Some(&mut rev_reg_pub),
-                                                     Some(&rev_reg_priv)).unwrap();
+                                                     Some(&rev_reg_priv),
+                                                     Some(&rev_tails)).unwrap();
 
         // 7. Prover processes claim signature
         Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
@@ -323,7 +490,7 @@ mod test {
         // 10. Prover creates proof
         let mut proof_builder = Prover::new_proof_builder().unwrap();
         let key_id = "key_id";
-        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
         // 11. Verifier verifies proof
@@ -332,6 +499,62 @@ mod test {
         assert!(proof_verifier.verify(&proof, &nonce).unwrap());
     }
 
+    #[test]
+    fn anoncreds_works_for_revocation_proof_with_file_tails() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+
+        // 3. Issuer creates revocation registry and stores tails to file, prover opens and verifies it
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let tails_path = env::temp_dir().join("anoncreds_works_for_revocation_proof_with_file_tails.tails");
+        write_tails_file(&rev_tails, 5, &tails_path).unwrap();
+        let file_tails_reader = FileTailsReader::open(&tails_path, &rev_reg_pub).unwrap();
+
+        // 4. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 6. Issuer creates and sign claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim(PROVER_ID,
+                                                     &blinded_ms,
+                                                     &claim_values,
+                                                     &issuer_pub_key,
+                                                     &issuer_priv_key,
+                                                     Some(1),
+                                                     Some(&mut rev_reg_pub),
+                                                     Some(&rev_reg_priv),
+                                                     Some(&file_tails_reader)).unwrap();
+
+        // 7. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        // 8. Verifier creates nonce
+        let nonce = Verifier::new_nonce().unwrap();
+
+        // 9. Verifier create sub proof request
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 10. Prover creates proof
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id = "key_id";
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key,
+                                            Some(&rev_reg_pub), Some(&file_tails_reader)).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 11. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+
+        fs::remove_file(&tails_path).unwrap();
+    }
+
     #[test]
     fn anoncreds_works_for_proof_created_before_claim_revoked() {
         // 1. Issuer creates claim schema
@@ -341,7 +564,7 @@ mod test {
         let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
 
         // 3. Issuer creates revocation registry
-        let (mut rev_reg_pub, rev_reg_priv) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
         let rev_idx = 1;
 
         // 4. Prover creates master secret
@@ -359,7 +582,8 @@ mod test {
                                                      &issuer_priv_key,
                                                      Some(rev_idx),
                                                      Some(&mut rev_reg_pub),
-                                                     Some(&rev_reg_priv)).unwrap();
+                                                     Some(&rev_reg_priv),
+                                                     Some(&rev_tails)).unwrap();
 
         // 7. Prover processes claim signature
         Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
@@ -373,11 +597,11 @@ mod test {
         // 10. Prover creates proof
         let mut proof_builder = Prover::new_proof_builder().unwrap();
         let key_id = "key_id";
-        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
         // 11. Issuer revokes claim used for proof building
-        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx).unwrap();
+        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx, &rev_tails).unwrap();
 
         // 12. Verifier verifies proof
         let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
@@ -394,7 +618,7 @@ mod test {
         let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
 
         // 3. Issuer creates revocation registry
-        let (mut rev_reg_pub, rev_reg_priv) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
         let rev_idx = 1;
 
         // 4. Prover creates master secret
@@ -412,13 +636,14 @@ mod test {
                                                      &issuer_priv_key,
                                                      Some(rev_idx),
                                                      Some(&mut rev_reg_pub),
-                                                     Some(&rev_reg_priv)).unwrap();
+                                                     Some(&rev_reg_priv),
+                                                     Some(&rev_tails)).unwrap();
 
         // 7. Prover processes claim signature
         Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
 
         // 8. Issuer revokes claim used for proof building
-        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx).unwrap();
+        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx, &rev_tails).unwrap();
 
         // 9. Verifier creates sub proof request
         let sub_proof_request = helpers::gvt_sub_proof_request();
@@ -433,8 +658,442 @@ mod test {
                                                       &claim_signature,
                                                       &claim_values,
                                                       &issuer_pub_key,
-                                                      Some(&rev_reg_pub));
+                                                      Some(&rev_reg_pub),
+                                                      Some(&rev_tails));
+        assert_eq!(ErrorCode::AnoncredsClaimRevoked, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn anoncreds_works_for_proof_against_accumulator_snapshot_taken_before_claim_revoked() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+
+        // 3. Issuer creates revocation registry
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let rev_idx = 1;
+
+        // 4. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 6. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim(PROVER_ID,
+                                                     &blinded_ms,
+                                                     &claim_values,
+                                                     &issuer_pub_key,
+                                                     &issuer_priv_key,
+                                                     Some(rev_idx),
+                                                     Some(&mut rev_reg_pub),
+                                                     Some(&rev_reg_priv),
+                                                     Some(&rev_tails)).unwrap();
+
+        // 7. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        // 8. Issuer takes accumulator snapshot, revokes claim and takes another snapshot
+        //    Prover keeps accumulators of snapshot moments
+        rev_reg_pub.take_snapshot(100).unwrap();
+        let acc_snapshot_100 = rev_reg_pub.get_accumulator().unwrap();
+        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx, &rev_tails).unwrap();
+        rev_reg_pub.take_snapshot(200).unwrap();
+        let acc_snapshot_200 = rev_reg_pub.get_accumulator().unwrap();
+        assert_eq!(vec![100, 200], rev_reg_pub.get_snapshot_ids());
+
+        // 9. Verifier creates nonce and sub proof request
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 10. Prover can't create proof against snapshot taken after revocation
+        let key_id = "key_id";
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let res = proof_builder.add_sub_proof_request_for_snapshot(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values,
+                                                                   &issuer_pub_key, &rev_reg_pub, &rev_tails, &acc_snapshot_200, 200);
         assert_eq!(ErrorCode::AnoncredsClaimRevoked, res.unwrap_err().to_error_code());
+
+        // 11. Prover can't use accumulator of another snapshot
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let res = proof_builder.add_sub_proof_request_for_snapshot(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values,
+                                                                   &issuer_pub_key, &rev_reg_pub, &rev_tails, &acc_snapshot_200, 100);
+        assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
+
+        // 12. Prover creates proof against snapshot taken before revocation
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        proof_builder.add_sub_proof_request_for_snapshot(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values,
+                                                         &issuer_pub_key, &rev_reg_pub, &rev_tails, &acc_snapshot_100, 100).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 13. Verifier verifies proof against the same snapshot
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request_for_snapshot(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, &rev_reg_pub, 100).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+
+        // 14. Verifier rejects proof if current accumulator is requested
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        assert_eq!(ErrorCode::AnoncredsProofRejected, proof_verifier.verify(&proof, &nonce).unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn anoncreds_works_for_revocation_proof_verified_with_signed_accumulator_statement() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with revocation keys) and BLS keys for accumulator statements
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+        let gen = Generator::new().unwrap();
+        let sign_key = SignKey::new(None).unwrap();
+        let ver_key = VerKey::new(&gen, &sign_key).unwrap();
+
+        // 3. Issuer creates revocation registry
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+        // 4. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 6. Issuer creates and sign claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim(PROVER_ID,
+                                                     &blinded_ms,
+                                                     &claim_values,
+                                                     &issuer_pub_key,
+                                                     &issuer_priv_key,
+                                                     Some(1),
+                                                     Some(&mut rev_reg_pub),
+                                                     Some(&rev_reg_priv),
+                                                     Some(&rev_tails)).unwrap();
+
+        // 7. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        // 8. Issuer announces signed accumulator statement
+        let now = time::get_time().sec as u64;
+        let statement = Issuer::sign_accumulator_statement("rev_reg_id", 1, now, &rev_reg_pub.get_accumulator().unwrap(), &sign_key).unwrap();
+
+        // 9. Verifier creates nonce and sub proof request
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 10. Prover creates proof
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id = "key_id";
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 11. Verifier rejects statement for another registry
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        let res = proof_verifier.add_sub_proof_request_with_accumulator_statement(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, &statement,
+                                                                                  &rev_reg_pub.get_accumulator_key().unwrap(), "other_rev_reg_id", &ver_key, &gen, 1, 60);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
+
+        // 12. Verifier verifies proof using accumulator from statement
+        proof_verifier.add_sub_proof_request_with_accumulator_statement(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, &statement,
+                                                                        &rev_reg_pub.get_accumulator_key().unwrap(), "rev_reg_id", &ver_key, &gen, 1, 60).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_aggregated_revocation_proofs() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+
+        // 3. Issuer creates revocation registry
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+        // 4. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 5. Issuer signs two claims in the same revocation registry, prover processes
+        //    the first one before the second changes accumulator
+        let claim_values = helpers::gvt_claim_values();
+
+        let (blinded_ms_1, master_secret_blinding_data_1) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+        let mut claim_signature_1 = Issuer::sign_claim(PROVER_ID,
+                                                       &blinded_ms_1,
+                                                       &claim_values,
+                                                       &issuer_pub_key,
+                                                       &issuer_priv_key,
+                                                       Some(1),
+                                                       Some(&mut rev_reg_pub),
+                                                       Some(&rev_reg_priv),
+                                                       Some(&rev_tails)).unwrap();
+        Prover::process_claim_signature(&mut claim_signature_1, &master_secret_blinding_data_1, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        let (blinded_ms_2, master_secret_blinding_data_2) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+        let mut claim_signature_2 = Issuer::sign_claim(PROVER_ID,
+                                                       &blinded_ms_2,
+                                                       &claim_values,
+                                                       &issuer_pub_key,
+                                                       &issuer_priv_key,
+                                                       Some(2),
+                                                       Some(&mut rev_reg_pub),
+                                                       Some(&rev_reg_priv),
+                                                       Some(&rev_tails)).unwrap();
+
+        // 6. Prover processes second claim signature
+        Prover::process_claim_signature(&mut claim_signature_2, &master_secret_blinding_data_2, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        // 7. Verifier creates nonce
+        let nonce = Verifier::new_nonce().unwrap();
+
+        // 8. Verifier create sub proof request
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 9. Prover adds both claims to proof builder
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id_1 = "key_id_1";
+        let key_id_2 = "key_id_2";
+        proof_builder.add_sub_proof_request(key_id_1, &sub_proof_request, &claim_schema, &claim_signature_1, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
+        proof_builder.add_sub_proof_request(key_id_2, &sub_proof_request, &claim_schema, &claim_signature_2, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
+
+        // 10. Prover aggregates non-revocation proofs and creates proof
+        proof_builder.aggregate_non_revocation_proofs(&[key_id_1, key_id_2]).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 11. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id_1, &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        proof_verifier.add_sub_proof_request(key_id_2, &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_aggregated_revocation_proofs_when_claim_revoked() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
+
+        // 3. Issuer creates revocation registry
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+        // 4. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 5. Issuer signs two claims in the same revocation registry, prover processes
+        //    the first one before the second changes accumulator
+        let claim_values = helpers::gvt_claim_values();
+
+        let (blinded_ms_1, master_secret_blinding_data_1) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+        let mut claim_signature_1 = Issuer::sign_claim(PROVER_ID,
+                                                       &blinded_ms_1,
+                                                       &claim_values,
+                                                       &issuer_pub_key,
+                                                       &issuer_priv_key,
+                                                       Some(1),
+                                                       Some(&mut rev_reg_pub),
+                                                       Some(&rev_reg_priv),
+                                                       Some(&rev_tails)).unwrap();
+        Prover::process_claim_signature(&mut claim_signature_1, &master_secret_blinding_data_1, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        let (blinded_ms_2, master_secret_blinding_data_2) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+        let mut claim_signature_2 = Issuer::sign_claim(PROVER_ID,
+                                                       &blinded_ms_2,
+                                                       &claim_values,
+                                                       &issuer_pub_key,
+                                                       &issuer_priv_key,
+                                                       Some(2),
+                                                       Some(&mut rev_reg_pub),
+                                                       Some(&rev_reg_priv),
+                                                       Some(&rev_tails)).unwrap();
+
+        // 6. Prover processes second claim signature
+        Prover::process_claim_signature(&mut claim_signature_2, &master_secret_blinding_data_2, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+
+        // 7. Verifier creates nonce
+        let nonce = Verifier::new_nonce().unwrap();
+
+        // 8. Verifier create sub proof request
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 9. Prover adds both claims to proof builder
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id_1 = "key_id_1";
+        let key_id_2 = "key_id_2";
+        proof_builder.add_sub_proof_request(key_id_1, &sub_proof_request, &claim_schema, &claim_signature_1, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
+        proof_builder.add_sub_proof_request(key_id_2, &sub_proof_request, &claim_schema, &claim_signature_2, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
+
+        // 10. Prover aggregates non-revocation proofs and creates proof
+        proof_builder.aggregate_non_revocation_proofs(&[key_id_1, key_id_2]).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 11. Issuer revokes second claim
+        Issuer::revoke_claim(&mut rev_reg_pub, 2, &rev_tails).unwrap();
+
+        // 12. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id_1, &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        proof_verifier.add_sub_proof_request(key_id_2, &sub_proof_request, &claim_schema, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        assert_eq!(false, proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_rsa_revocation_proof() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with RSA revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+
+        // 3. Issuer creates RSA revocation registry
+        let mut rsa_rev_reg = Issuer::new_rsa_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+        // 4. Prover creates master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+
+        // 5. Prover blinds master secret
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 6. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim_with_rsa_revocation(PROVER_ID,
+                                                                         &blinded_ms,
+                                                                         &claim_values,
+                                                                         &issuer_pub_key,
+                                                                         &issuer_priv_key,
+                                                                         1,
+                                                                         &mut rsa_rev_reg).unwrap();
+
+        // 7. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
+
+        // 8. Issuer issues and revokes other claims
+        let (other_blinded_ms, _) = Prover::blind_master_secret(&issuer_pub_key, &Prover::new_master_secret().unwrap()).unwrap();
+        Issuer::sign_claim_with_rsa_revocation(PROVER_ID, &other_blinded_ms, &claim_values, &issuer_pub_key, &issuer_priv_key, 2, &mut rsa_rev_reg).unwrap();
+        Issuer::sign_claim_with_rsa_revocation(PROVER_ID, &other_blinded_ms, &claim_values, &issuer_pub_key, &issuer_priv_key, 3, &mut rsa_rev_reg).unwrap();
+        Issuer::revoke_rsa_claim(&mut rsa_rev_reg, 2, &issuer_pub_key, &issuer_priv_key).unwrap();
+
+        // 9. Prover updates witness
+        Prover::update_rsa_non_revocation_witness(&mut claim_signature, &issuer_pub_key, &rsa_rev_reg).unwrap();
+
+        // 10. Verifier creates nonce and sub proof request
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        // 11. Prover creates proof
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id = "key_id";
+        proof_builder.add_sub_proof_request_with_rsa_accumulator(key_id, &sub_proof_request, &claim_schema, &claim_signature,
+                                                                 &claim_values, &issuer_pub_key, &rsa_rev_reg).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 12. Verifier verifies proof
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request_with_rsa_accumulator(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key,
+                                                                  &rsa_rev_reg.get_accumulator().unwrap()).unwrap();
+        assert!(proof_verifier.verify(&proof, &nonce).unwrap());
+    }
+
+    #[test]
+    fn anoncreds_works_for_rsa_revocation_proof_when_claim_revoked() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with RSA revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+
+        // 3. Issuer creates RSA revocation registry
+        let mut rsa_rev_reg = Issuer::new_rsa_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let rev_idx = 1;
+
+        // 4. Prover creates and blinds master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 5. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim_with_rsa_revocation(PROVER_ID,
+                                                                         &blinded_ms,
+                                                                         &claim_values,
+                                                                         &issuer_pub_key,
+                                                                         &issuer_priv_key,
+                                                                         rev_idx,
+                                                                         &mut rsa_rev_reg).unwrap();
+
+        // 6. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
+
+        // 7. Prover creates proof
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id = "key_id";
+        proof_builder.add_sub_proof_request_with_rsa_accumulator(key_id, &sub_proof_request, &claim_schema, &claim_signature,
+                                                                 &claim_values, &issuer_pub_key, &rsa_rev_reg).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 8. Issuer revokes claim used for proof building
+        Issuer::revoke_rsa_claim(&mut rsa_rev_reg, rev_idx, &issuer_pub_key, &issuer_priv_key).unwrap();
+
+        // 9. Verifier rejects proof against current accumulator
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request_with_rsa_accumulator(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key,
+                                                                  &rsa_rev_reg.get_accumulator().unwrap()).unwrap();
+        assert_eq!(false, proof_verifier.verify(&proof, &nonce).unwrap());
+
+        // 10. Prover can't create new proof
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let res = proof_builder.add_sub_proof_request_with_rsa_accumulator(key_id, &sub_proof_request, &claim_schema, &claim_signature,
+                                                                           &claim_values, &issuer_pub_key, &rsa_rev_reg);
+        assert_eq!(ErrorCode::AnoncredsClaimRevoked, res.unwrap_err().to_error_code());
+    }
+
+    #[test]
+    fn anoncreds_works_for_rsa_revocation_proof_verified_without_rsa_accumulator() {
+        // 1. Issuer creates claim schema
+        let claim_schema = helpers::gvt_claim_schema();
+
+        // 2. Issuer creates keys(with RSA revocation keys)
+        let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys_with_revocation_backend(&claim_schema, RevocationBackend::Rsa).unwrap();
+
+        // 3. Issuer creates RSA revocation registry
+        let mut rsa_rev_reg = Issuer::new_rsa_revocation_registry(&issuer_pub_key, 5).unwrap();
+
+        // 4. Prover creates and blinds master secret
+        let master_secret = Prover::new_master_secret().unwrap();
+        let (blinded_ms, master_secret_blinding_data) = Prover::blind_master_secret(&issuer_pub_key, &master_secret).unwrap();
+
+        // 5. Issuer creates and signs claim values
+        let claim_values = helpers::gvt_claim_values();
+        let mut claim_signature = Issuer::sign_claim_with_rsa_revocation(PROVER_ID,
+                                                                         &blinded_ms,
+                                                                         &claim_values,
+                                                                         &issuer_pub_key,
+                                                                         &issuer_priv_key,
+                                                                         1,
+                                                                         &mut rsa_rev_reg).unwrap();
+
+        // 6. Prover processes claim signature
+        Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, None).unwrap();
+
+        // 7. Prover creates proof
+        let nonce = Verifier::new_nonce().unwrap();
+        let sub_proof_request = helpers::gvt_sub_proof_request();
+
+        let mut proof_builder = Prover::new_proof_builder().unwrap();
+        let key_id = "key_id";
+        proof_builder.add_sub_proof_request_with_rsa_accumulator(key_id, &sub_proof_request, &claim_schema, &claim_signature,
+                                                                 &claim_values, &issuer_pub_key, &rsa_rev_reg).unwrap();
+        let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
+
+        // 8. Verifier rejects proof when sub proof request is added without RSA accumulator
+        let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
+        proof_verifier.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &issuer_pub_key, None).unwrap();
+        let res = proof_verifier.verify(&proof, &nonce);
+        assert_eq!(ErrorCode::AnoncredsProofRejected, res.unwrap_err().to_error_code());
     }
 
     #[test]
@@ -446,7 +1105,7 @@ mod test {
         let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
 
         // 3. Issuer creates revocation registry for only 1 claim
-        let (mut rev_reg_pub, rev_reg_priv) = Issuer::new_revocation_registry(&issuer_pub_key, 1).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 1).unwrap();
 
         // 4. Prover creates master secret
         let master_secret = Prover::new_master_secret().unwrap();
@@ -463,7 +1122,8 @@ mod test {
                            &issuer_priv_key,
                            Some(1),
                            Some(&mut rev_reg_pub),
-                           Some(&rev_reg_priv)).unwrap();
+                           Some(&rev_reg_priv),
+                           Some(&rev_tails)).unwrap();
 
         // 7. Issuer creates and sign second claim values
         let res = Issuer::sign_claim(PROVER_ID,
@@ -473,7 +1133,8 @@ mod test {
                                      &issuer_priv_key,
                                      Some(2),
                                      Some(&mut rev_reg_pub),
-                                     Some(&rev_reg_priv));
+                                     Some(&rev_reg_priv),
+                                     Some(&rev_tails));
         assert_eq!(ErrorCode::AnoncredsRevocationAccumulatorIsFull, res.unwrap_err().to_error_code());
     }
 
@@ -487,7 +1148,7 @@ mod test {
         let (issuer_pub_key, issuer_priv_key) = Issuer::new_keys(&claim_schema, true).unwrap();
 
         // 3. Issuer creates revocation registry
-        let (mut rev_reg_pub, rev_reg_priv) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let (mut rev_reg_pub, rev_reg_priv, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
         let rev_idx = 1;
 
         // FIRST Issue of claim
@@ -506,7 +1167,8 @@ mod test {
                                                      &issuer_priv_key,
                                                      Some(rev_idx),
                                                      Some(&mut rev_reg_pub),
-                                                     Some(&rev_reg_priv)).unwrap();
+                                                     Some(&rev_reg_priv),
+                                                     Some(&rev_tails)).unwrap();
 
         // 7. Prover processes claim signature
         Prover::process_claim_signature(&mut claim_signature, &master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
@@ -521,7 +1183,7 @@ mod test {
         // 10. Prover creates proof
         let mut proof_builder = Prover::new_proof_builder().unwrap();
         let key_id = "key_id";
-        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
+        proof_builder.add_sub_proof_request(key_id, &sub_proof_request, &claim_schema, &claim_signature, &claim_values, &issuer_pub_key, Some(&rev_reg_pub), Some(&rev_tails)).unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
         // 11. Verifier verifies proof
@@ -530,7 +1192,7 @@ mod test {
         assert_eq!(false, proof_verifier.verify(&proof, &nonce).unwrap());
 
         // 12. Issuer revokes claim used for proof building
-        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx).unwrap();
+        Issuer::revoke_claim(&mut rev_reg_pub, rev_idx, &rev_tails).unwrap();
 
         // 13. Verifier verifies proof after revocation
         let mut proof_verifier = Verifier::new_proof_verifier().unwrap();
@@ -556,7 +1218,8 @@ mod test {
                                                          &issuer_priv_key,
                                                          Some(rev_idx),
                                                          Some(&mut rev_reg_pub),
-                                                         Some(&rev_reg_priv)).unwrap();
+                                                         Some(&rev_reg_priv),
+                                                         Some(&rev_tails)).unwrap();
 
         // 16. Prover processes new claim signature
         Prover::process_claim_signature(&mut new_claim_signature, &new_master_secret_blinding_data, &issuer_pub_key, Some(&rev_reg_pub)).unwrap();
@@ -570,7 +1233,8 @@ mod test {
                                                 &new_claim_signature,
                                                 &claim_values,
                                                 &issuer_pub_key,
-                                                Some(&rev_reg_pub)).unwrap();
+                                                Some(&rev_reg_pub),
+                                                Some(&rev_tails)).unwrap();
 
         let new_proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
@@ -608,6 +1272,7 @@ mod test {
                                                  &issuer_priv_key,
                                                  None,
                                                  None,
+                                                 None,
                                                  None).unwrap();
 
         // 6. Verifier creates nonce and sub proof request
@@ -624,6 +1289,7 @@ mod test {
                                             &claim_signature,
                                             &claim_values,
                                             &issuer_pub_key,
+                                            None,
                                             None).unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
@@ -657,6 +1323,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -676,6 +1343,7 @@ mod test {
                                             &claim_signature,
                                             &claim_values,
                                             &issuer_pub_key,
+                                            None,
                                             None).unwrap();
 
         let another_master_secret = Prover::new_master_secret().unwrap();
@@ -711,6 +1379,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -730,6 +1399,7 @@ mod test {
                                             &claim_signature,
                                             &claim_values,
                                             &issuer_pub_key,
+                                            None,
                                             None).unwrap();
 
         let proof = proof_builder.finalize(&nonce_for_proof_creation, &master_secret).unwrap();
@@ -765,6 +1435,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -783,6 +1454,7 @@ mod test {
                                             &claim_signature,
                                             &claim_values,
                                             &issuer_pub_key,
+                                            None,
                                             None).unwrap();
         let proof = proof_builder.finalize(&nonce, &master_secret).unwrap();
 
@@ -830,11 +1502,11 @@ mod test {
         let (issuer_pub_key, _) = Issuer::new_keys(&claim_schema, true).unwrap();
 
         // 3. Issuer creates revocation registry
-        let (mut rev_reg_pub, _) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
+        let (mut rev_reg_pub, _, rev_tails) = Issuer::new_revocation_registry(&issuer_pub_key, 5).unwrap();
 
         // 4. Issuer tries revoke not not added index
         let rev_idx = 1;
-        let res = Issuer::revoke_claim(&mut rev_reg_pub, rev_idx);
+        let res = Issuer::revoke_claim(&mut rev_reg_pub, rev_idx, &rev_tails);
         assert_eq!(ErrorCode::AnoncredsInvalidRevocationAccumulatorIndex, res.unwrap_err().to_error_code());
     }
 
@@ -863,6 +1535,7 @@ mod test {
                                      &issuer_priv_key,
                                      None,
                                      None,
+                                     None,
                                      None);
 
 
@@ -892,6 +1565,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -912,6 +1586,7 @@ mod test {
                                                       &claim_signature,
                                                       &claim_values,
                                                       &issuer_pub_key,
+                                                      None,
                                                       None);
 
         assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
@@ -940,6 +1615,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -958,6 +1634,7 @@ mod test {
                                                       &claim_signature,
                                                       &claim_values,
                                                       &issuer_pub_key,
+                                                      None,
                                                       None);
         assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
     }
@@ -985,6 +1662,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -1005,6 +1683,7 @@ mod test {
                                                       &claim_signature,
                                                       &claim_values,
                                                       &issuer_pub_key,
+                                                      None,
                                                       None);
         assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
     }
@@ -1032,6 +1711,7 @@ mod test {
                                                      &issuer_priv_key,
                                                      None,
                                                      None,
+                                                     None,
                                                      None).unwrap();
 
         // 6. Prover processes claim signature
@@ -1053,6 +1733,7 @@ mod test {
                                                       &claim_signature,
                                                       &claim_values,
                                                       &issuer_pub_key,
+                                                      None,
                                                       None);
         assert_eq!(ErrorCode::CommonInvalidStructure, res.unwrap_err().to_error_code());
     }